@@ -13,7 +13,7 @@ fn main() {
     println!("\n=== Character Analysis ===");
 
     // Show Unicode codepoints for each character
-    for (i, c) in text.chars().enumerate() {
+    for c in text.chars() {
         if !c.is_whitespace() {
             println!(
                 "  '{}' -> U+{:04X} ({})",
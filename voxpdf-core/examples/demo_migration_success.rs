@@ -10,7 +10,7 @@ fn main() {
     let words = extract_word_positions(&doc, 0).unwrap();
 
     println!("✅ PDF Backend: MuPDF v0.5.0 (production-grade)\n");
-    println!("📄 Document: {} ({} pages)", "simple.pdf", doc.page_count());
+    println!("📄 Document: simple.pdf ({} pages)", doc.page_count());
     println!("📝 Words extracted: {}\n", words.len());
 
     for word in &words {
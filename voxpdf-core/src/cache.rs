@@ -20,9 +20,18 @@ use std::collections::HashMap;
 use std::sync::{Arc, RwLock};
 use crate::models::{Word, Paragraph};
 
+/// (doc_path, page) -> extracted items
+type PageCache<T> = Arc<RwLock<HashMap<(String, u32), Vec<T>>>>;
+
 pub struct ExtractionCache {
-    word_cache: Arc<RwLock<HashMap<(String, u32), Vec<Word>>>>, // (doc_path, page) -> words
-    paragraph_cache: Arc<RwLock<HashMap<(String, u32), Vec<Paragraph>>>>,
+    word_cache: PageCache<Word>,
+    paragraph_cache: PageCache<Paragraph>,
+}
+
+impl Default for ExtractionCache {
+    fn default() -> Self {
+        Self::new()
+    }
 }
 
 impl ExtractionCache {
@@ -30,6 +30,13 @@ pub enum VoxPDFError {
     #[error("IO error: {0}")]
     Io(#[from] std::io::Error),
 
+    /// Document is encrypted and has not been unlocked.
+    ///
+    /// Call [`PDFDocument::authenticate`](crate::PDFDocument::authenticate)
+    /// with the user or owner password before extracting content.
+    #[error("PDF is encrypted and requires a password")]
+    PasswordRequired,
+
     /// Error occurred during text extraction.
     ///
     /// This includes parsing errors, unsupported PDF features, etc.
@@ -0,0 +1,536 @@
+use crate::extraction::paragraphs::body_font_size;
+use crate::extraction::ExtractionOptions;
+use crate::models::{Rect, Word};
+use serde::{Deserialize, Serialize};
+
+/// Recursion guard; real pages need a handful of levels
+const MAX_CUT_DEPTH: usize = 32;
+
+/// A column must span at least this fraction of the page's text width, so
+/// the gap after list numbers, bullets or a hanging label isn't a gutter
+const MIN_COLUMN_WIDTH_FRACTION: f32 = 0.25;
+
+/// Lines each side of a gutter needs before it counts as a column
+const MIN_COLUMN_LINES: usize = 3;
+
+/// Column structure of a page, found by recursive XY-cut.
+///
+/// A two-column paper with a full-width title has three regions: the title,
+/// then the left column, then the right column.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ColumnLayout {
+    /// Page number (0-indexed)
+    pub page_number: u32,
+
+    /// Text regions in reading order
+    pub regions: Vec<TextRegion>,
+
+    /// Most regions side by side anywhere on the page (1 for single-column)
+    pub column_count: usize,
+}
+
+/// A block of text with no column gutter inside it.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct TextRegion {
+    /// Bounding box of the region's words
+    pub bounds: Rect,
+
+    /// Indices of the region's words in reading order
+    ///
+    /// For [`detect_column_layout`] these index the slice it was given; for
+    /// layouts returned by the extraction functions they index the extracted
+    /// (already reordered) words, so each region is a consecutive run.
+    pub word_indices: Vec<usize>,
+}
+
+impl ColumnLayout {
+    /// A single region holding every word in its original order
+    pub fn single_region(page_number: u32, words: &[Word]) -> Self {
+        let indices: Vec<usize> = (0..words.len()).collect();
+        let regions = if words.is_empty() {
+            Vec::new()
+        } else {
+            vec![TextRegion {
+                bounds: bounding_box(words, &indices),
+                word_indices: indices,
+            }]
+        };
+
+        ColumnLayout {
+            page_number,
+            regions,
+            column_count: 1,
+        }
+    }
+
+    /// The same layout after [`order_words`]: indices become consecutive
+    pub(crate) fn renumbered(mut self) -> Self {
+        let mut next = 0;
+        for region in &mut self.regions {
+            for index in &mut region.word_indices {
+                *index = next;
+                next += 1;
+            }
+        }
+        self
+    }
+
+    /// Word indices of the whole page in reading order
+    pub fn reading_order(&self) -> impl Iterator<Item = usize> + '_ {
+        self.regions
+            .iter()
+            .flat_map(|region| region.word_indices.iter().copied())
+    }
+}
+
+/// Segment a page's words into column regions.
+///
+/// Splits along vertical gutters at least `options.column_gap_ratio` times the
+/// body font size wide whenever one runs the full height of the current
+/// block and the text on each side is a real column: several lines deep and
+/// at least a quarter of the page's text width across. Otherwise splits at
+/// the widest horizontal gap (e.g. under a full-width title) and tries
+/// again. Regions are read top to bottom and columns left to right, and
+/// words within a region line by line.
+///
+/// # Example
+/// ```
+/// use voxpdf_core::extraction::{detect_column_layout, ExtractionOptions};
+/// use voxpdf_core::models::{Rect, Word};
+///
+/// // Three lines in each of two columns, extracted in interleaved order
+/// let words = vec![
+///     Word::new("left1", Rect::new(72.0, 100.0, 100.0, 10.0), 0, 10.0),
+///     Word::new("right1", Rect::new(320.0, 100.0, 100.0, 10.0), 0, 10.0),
+///     Word::new("left2", Rect::new(72.0, 114.0, 100.0, 10.0), 0, 10.0),
+///     Word::new("right2", Rect::new(320.0, 114.0, 100.0, 10.0), 0, 10.0),
+///     Word::new("left3", Rect::new(72.0, 128.0, 100.0, 10.0), 0, 10.0),
+///     Word::new("right3", Rect::new(320.0, 128.0, 100.0, 10.0), 0, 10.0),
+/// ];
+///
+/// let layout = detect_column_layout(&words, &ExtractionOptions::default());
+/// assert_eq!(layout.column_count, 2);
+///
+/// let order: Vec<&str> = layout.reading_order().map(|i| words[i].text.as_str()).collect();
+/// assert_eq!(order, ["left1", "left2", "left3", "right1", "right2", "right3"]);
+/// ```
+pub fn detect_column_layout(words: &[Word], options: &ExtractionOptions) -> ColumnLayout {
+    let page_number = words.first().map(|w| w.page_number).unwrap_or(0);
+    if words.is_empty() {
+        return ColumnLayout::single_region(page_number, words);
+    }
+
+    let body_size = body_font_size(words).unwrap_or(12.0).max(1.0);
+    let all: Vec<usize> = (0..words.len()).collect();
+    let text_width = bounding_box(words, &all).width;
+    let cutter = Cutter {
+        words,
+        min_column_width: text_width * MIN_COLUMN_WIDTH_FRACTION,
+        min_column_gap: body_size * options.column_gap_ratio,
+        min_row_gap: body_size * options.line_tolerance_ratio,
+        line_tolerance: options.line_tolerance_ratio,
+    };
+
+    let mut regions = Vec::new();
+    let column_count = cutter.cut(all, 0, &mut regions);
+
+    ColumnLayout {
+        page_number,
+        regions,
+        column_count,
+    }
+}
+
+/// Reorder words into the layout's reading order.
+pub fn order_words(words: Vec<Word>, layout: &ColumnLayout) -> Vec<Word> {
+    let mut slots: Vec<Option<Word>> = words.into_iter().map(Some).collect();
+    layout
+        .reading_order()
+        .filter_map(|i| slots.get_mut(i).and_then(Option::take))
+        .collect()
+}
+
+struct Cutter<'a> {
+    words: &'a [Word],
+    min_column_width: f32,
+    min_column_gap: f32,
+    min_row_gap: f32,
+    line_tolerance: f32,
+}
+
+impl Cutter<'_> {
+    /// Split `indices` into regions appended to `out`; returns the most
+    /// columns found side by side
+    fn cut(&self, indices: Vec<usize>, depth: usize, out: &mut Vec<TextRegion>) -> usize {
+        if indices.len() > 1 && depth < MAX_CUT_DEPTH {
+            // Columns first: a gutter running the full height of this block
+            let x_spans = indices.iter().map(|&i| {
+                (
+                    self.words[i].bounds.x,
+                    self.words[i].bounds.x + self.words[i].bounds.width,
+                )
+            });
+            let candidates: Vec<f32> = gaps(x_spans)
+                .into_iter()
+                .filter(|&(_, width)| width >= self.min_column_gap)
+                .map(|(at, _)| at)
+                .collect();
+            let gutters = self.column_gutters(&indices, &candidates);
+
+            if !gutters.is_empty() {
+                let columns = self.split(&indices, &gutters, |w| w.bounds.x);
+                let count = columns.len();
+                let widest = columns
+                    .into_iter()
+                    .map(|column| self.cut(column, depth + 1, out))
+                    .max()
+                    .unwrap_or(1);
+                return widest.max(count);
+            }
+
+            // Otherwise the widest horizontal gap, e.g. under a full-width title
+            let y_spans = indices.iter().map(|&i| {
+                (
+                    self.words[i].bounds.y,
+                    self.words[i].bounds.y + self.words[i].bounds.height,
+                )
+            });
+            let widest = gaps(y_spans)
+                .into_iter()
+                .filter(|&(_, height)| height >= self.min_row_gap)
+                .max_by(|a, b| a.1.total_cmp(&b.1));
+
+            if let Some((at, _)) = widest {
+                let bands = self.split(&indices, &[at], |w| w.bounds.y);
+                return bands
+                    .into_iter()
+                    .map(|band| self.cut(band, depth + 1, out))
+                    .max()
+                    .unwrap_or(1);
+            }
+        }
+
+        if !indices.is_empty() {
+            out.push(self.region(indices));
+        }
+        1
+    }
+
+    /// The candidate gutters, left to right, that separate real columns.
+    ///
+    /// Each column is measured from the gutter (or text edge) on its left to
+    /// the one on its right, so a short last line doesn't make it narrow. A
+    /// gap after list numbers or a hanging label leaves a narrow strip on
+    /// one side and is skipped; a gap inside a single line (a right-aligned
+    /// page number, say) has too few lines on each side.
+    fn column_gutters(&self, indices: &[usize], candidates: &[f32]) -> Vec<f32> {
+        let bounds = bounding_box(self.words, indices);
+        let right_edge = bounds.x + bounds.width;
+        let is_column = |from: f32, to: f32| {
+            let lines = self.line_count(indices.iter().copied().filter(|&i| {
+                let x = self.words[i].bounds.x;
+                x >= from && x < to
+            }));
+            to - from >= self.min_column_width && lines >= MIN_COLUMN_LINES
+        };
+
+        let mut gutters = Vec::new();
+        let mut left_edge = bounds.x;
+        for &at in candidates {
+            if is_column(left_edge, at) && is_column(at, right_edge) {
+                gutters.push(at);
+                left_edge = at;
+            }
+        }
+        gutters
+    }
+
+    /// Distinct lines among the words, by top edge
+    fn line_count(&self, indices: impl Iterator<Item = usize>) -> usize {
+        let mut tops: Vec<(f32, f32)> = indices
+            .map(|i| (self.words[i].bounds.y, self.words[i].font_size))
+            .collect();
+        tops.sort_by(|a, b| a.0.total_cmp(&b.0));
+
+        let mut lines = 0;
+        let mut line_y = f32::NEG_INFINITY;
+        for (y, size) in tops {
+            if y - line_y >= size * self.line_tolerance {
+                lines += 1;
+                line_y = y;
+            }
+        }
+        lines
+    }
+
+    /// Partition indices at the cut positions along one axis
+    fn split(
+        &self,
+        indices: &[usize],
+        cuts: &[f32],
+        pos: impl Fn(&Word) -> f32,
+    ) -> Vec<Vec<usize>> {
+        let mut parts = vec![Vec::new(); cuts.len() + 1];
+        for &i in indices {
+            let p = pos(&self.words[i]);
+            let slot = cuts.iter().filter(|&&cut| p > cut).count();
+            parts[slot].push(i);
+        }
+        parts.retain(|part| !part.is_empty());
+        parts
+    }
+
+    /// A leaf region with its words sorted line by line
+    fn region(&self, mut indices: Vec<usize>) -> TextRegion {
+        let words = self.words;
+        indices.sort_by(|&a, &b| words[a].bounds.y.total_cmp(&words[b].bounds.y));
+
+        let mut ordered = Vec::with_capacity(indices.len());
+        let mut line: Vec<usize> = Vec::new();
+        let mut line_y = 0.0;
+        let mut line_size = 0.0f32;
+
+        for i in indices {
+            let word = &words[i];
+            let tolerance = line_size.max(word.font_size) * self.line_tolerance;
+            if !line.is_empty() && (word.bounds.y - line_y).abs() >= tolerance {
+                line.sort_by(|&a, &b| words[a].bounds.x.total_cmp(&words[b].bounds.x));
+                ordered.append(&mut line);
+            }
+            if line.is_empty() {
+                line_y = word.bounds.y;
+                line_size = word.font_size;
+            }
+            line.push(i);
+        }
+        line.sort_by(|&a, &b| words[a].bounds.x.total_cmp(&words[b].bounds.x));
+        ordered.append(&mut line);
+
+        TextRegion {
+            bounds: bounding_box(words, &ordered),
+            word_indices: ordered,
+        }
+    }
+}
+
+/// Empty stretches between the projections of `spans` onto one axis, as
+/// (midpoint, width)
+fn gaps(spans: impl Iterator<Item = (f32, f32)>) -> Vec<(f32, f32)> {
+    let mut spans: Vec<(f32, f32)> = spans.collect();
+    spans.sort_by(|a, b| a.0.total_cmp(&b.0));
+
+    let mut gaps = Vec::new();
+    let mut covered_to = match spans.first() {
+        Some(&(_, end)) => end,
+        None => return gaps,
+    };
+
+    for &(start, end) in &spans[1..] {
+        if start > covered_to {
+            gaps.push(((covered_to + start) / 2.0, start - covered_to));
+        }
+        covered_to = covered_to.max(end);
+    }
+
+    gaps
+}
+
+fn bounding_box(words: &[Word], indices: &[usize]) -> Rect {
+    let mut min_x = f32::INFINITY;
+    let mut min_y = f32::INFINITY;
+    let mut max_x = f32::NEG_INFINITY;
+    let mut max_y = f32::NEG_INFINITY;
+
+    for &i in indices {
+        let b = &words[i].bounds;
+        min_x = min_x.min(b.x);
+        min_y = min_y.min(b.y);
+        max_x = max_x.max(b.x + b.width);
+        max_y = max_y.max(b.y + b.height);
+    }
+
+    if indices.is_empty() {
+        return Rect::new(0.0, 0.0, 0.0, 0.0);
+    }
+    Rect::new(min_x, min_y, max_x - min_x, max_y - min_y)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Lines of `count` words per column, emitted row by row across columns
+    /// the way a naive extractor would interleave them
+    fn interleaved_columns(column_xs: &[f32], rows: usize) -> Vec<Word> {
+        let mut words = Vec::new();
+        for row in 0..rows {
+            for (col, &x) in column_xs.iter().enumerate() {
+                let y = 100.0 + row as f32 * 12.0;
+                for n in 0..3 {
+                    let text = format!("c{}r{}w{}", col, row, n);
+                    words.push(Word::new(
+                        text,
+                        Rect::new(x + n as f32 * 33.0, y, 30.0, 10.0),
+                        0,
+                        10.0,
+                    ));
+                }
+            }
+        }
+        words
+    }
+
+    fn texts(words: &[Word], layout: &ColumnLayout) -> Vec<String> {
+        layout
+            .reading_order()
+            .map(|i| words[i].text.clone())
+            .collect()
+    }
+
+    #[test]
+    fn test_two_columns_read_left_then_right() {
+        let words = interleaved_columns(&[72.0, 320.0], 4);
+        let layout = detect_column_layout(&words, &ExtractionOptions::default());
+
+        assert_eq!(layout.column_count, 2);
+        assert_eq!(layout.regions.len(), 2);
+
+        let order = texts(&words, &layout);
+        assert_eq!(order[0], "c0r0w0");
+        assert_eq!(order[3], "c0r1w0");
+        assert_eq!(order[12], "c1r0w0");
+        assert_eq!(order.len(), words.len());
+    }
+
+    #[test]
+    fn test_three_columns() {
+        let words = interleaved_columns(&[50.0, 230.0, 410.0], 3);
+        let layout = detect_column_layout(&words, &ExtractionOptions::default());
+
+        assert_eq!(layout.column_count, 3);
+        let order = texts(&words, &layout);
+        assert!(order[..9].iter().all(|t| t.starts_with("c0")));
+        assert!(order[9..18].iter().all(|t| t.starts_with("c1")));
+        assert!(order[18..].iter().all(|t| t.starts_with("c2")));
+    }
+
+    #[test]
+    fn test_full_width_title_above_columns() {
+        let mut words = vec![
+            Word::new("Title", Rect::new(72.0, 50.0, 200.0, 18.0), 0, 18.0),
+            Word::new("Spanning", Rect::new(280.0, 50.0, 200.0, 18.0), 0, 18.0),
+        ];
+        words.extend(interleaved_columns(&[72.0, 320.0], 3));
+
+        let layout = detect_column_layout(&words, &ExtractionOptions::default());
+        assert_eq!(layout.regions.len(), 3);
+        assert_eq!(layout.column_count, 2);
+
+        let order = texts(&words, &layout);
+        assert_eq!(&order[..2], ["Title", "Spanning"]);
+        assert_eq!(order[2], "c0r0w0");
+        assert_eq!(order[11], "c1r0w0");
+    }
+
+    #[test]
+    fn test_single_column_keeps_line_order() {
+        let words = interleaved_columns(&[72.0], 5);
+        let layout = detect_column_layout(&words, &ExtractionOptions::default());
+
+        assert_eq!(layout.column_count, 1);
+        let ordered = order_words(words.clone(), &layout);
+        assert_eq!(ordered, words);
+    }
+
+    #[test]
+    fn test_gaps() {
+        let spans = [(0.0, 10.0), (5.0, 20.0), (30.0, 40.0)].into_iter();
+        assert_eq!(gaps(spans), vec![(25.0, 10.0)]);
+        assert!(gaps(std::iter::empty()).is_empty());
+    }
+
+    #[test]
+    fn test_gap_within_one_line_is_not_a_column() {
+        let words = vec![
+            Word::new("Chapter", Rect::new(72.0, 50.0, 40.0, 10.0), 0, 10.0),
+            Word::new("12", Rect::new(500.0, 50.0, 10.0, 10.0), 0, 10.0),
+        ];
+        let layout = detect_column_layout(&words, &ExtractionOptions::default());
+        assert_eq!(layout.column_count, 1);
+        assert_eq!(texts(&words, &layout), ["Chapter", "12"]);
+    }
+
+    #[test]
+    fn test_numbered_list_is_one_column() {
+        // Numbers in a strip at the left margin, a full-height gap, then the
+        // item text: read item by item, not all numbers first
+        let mut words = Vec::new();
+        for item in 0..5 {
+            let y = 100.0 + item as f32 * 12.0;
+            let number = format!("{}.", item + 1);
+            words.push(Word::new(number, Rect::new(72.0, y, 10.0, 10.0), 0, 10.0));
+            for n in 0..6 {
+                let x = 100.0 + n as f32 * 55.0;
+                let text = format!("item{}w{}", item, n);
+                words.push(Word::new(text, Rect::new(x, y, 50.0, 10.0), 0, 10.0));
+            }
+        }
+
+        let layout = detect_column_layout(&words, &ExtractionOptions::default());
+        assert_eq!(layout.column_count, 1);
+        let order = texts(&words, &layout);
+        assert_eq!(&order[..3], ["1.", "item0w0", "item0w1"]);
+        assert_eq!(order[7], "2.");
+    }
+
+    #[test]
+    fn test_hanging_indent_is_one_column() {
+        // A label hanging in the margin beside an indented block
+        let mut words = vec![Word::new(
+            "Note:",
+            Rect::new(72.0, 100.0, 30.0, 10.0),
+            0,
+            10.0,
+        )];
+        for line in 0..4 {
+            let y = 100.0 + line as f32 * 12.0;
+            for n in 0..5 {
+                let x = 120.0 + n as f32 * 60.0;
+                let text = format!("l{}w{}", line, n);
+                words.push(Word::new(text, Rect::new(x, y, 55.0, 10.0), 0, 10.0));
+            }
+        }
+
+        let layout = detect_column_layout(&words, &ExtractionOptions::default());
+        assert_eq!(layout.column_count, 1);
+        let order = texts(&words, &layout);
+        assert_eq!(&order[..3], ["Note:", "l0w0", "l0w1"]);
+        assert_eq!(order[6], "l1w0");
+    }
+
+    #[test]
+    fn test_numbered_lists_in_two_columns() {
+        // The real gutter is found; the gaps after the numbers are not
+        let mut words = Vec::new();
+        for item in 0..4 {
+            let y = 100.0 + item as f32 * 12.0;
+            for (col, x) in [72.0, 320.0].into_iter().enumerate() {
+                let number = format!("{}{}.", col, item);
+                words.push(Word::new(number, Rect::new(x, y, 12.0, 10.0), 0, 10.0));
+                for n in 0..4 {
+                    let text = format!("c{}i{}w{}", col, item, n);
+                    let word_x = x + 25.0 + n as f32 * 52.0;
+                    words.push(Word::new(text, Rect::new(word_x, y, 48.0, 10.0), 0, 10.0));
+                }
+            }
+        }
+
+        let layout = detect_column_layout(&words, &ExtractionOptions::default());
+        assert_eq!(layout.column_count, 2);
+        assert_eq!(layout.regions.len(), 2);
+        let order = texts(&words, &layout);
+        assert_eq!(&order[..2], ["00.", "c0i0w0"]);
+        assert_eq!(order[5], "01.");
+        assert_eq!(order[20], "10.");
+    }
+}
@@ -0,0 +1,201 @@
+//! Font, colour and weight of every character on a page.
+//!
+//! `mupdf::TextChar` only exposes a character's code, origin, size and quad.
+//! MuPDF's structured-text XML dump of the same page also records the font,
+//! fill colour and style flags, character by character and in the same order
+//! as the text page, so it is parsed alongside when
+//! [`ExtractionOptions::font_attributes`](crate::extraction::ExtractionOptions::font_attributes)
+//! asks for them.
+
+use crate::error::{Result, VoxPDFError};
+use crate::models::FontInfo;
+use std::collections::HashMap;
+
+/// `FZ_STEXT_BOLD`: set for bold fonts and for text made bold by stroking
+const BOLD_FLAG: u32 = 8;
+
+/// `FZ_STEXT_SYNTHETIC`: a space MuPDF inserted at a gap. The text page
+/// words are read from inhibits these, so they're left out to keep the
+/// two in step.
+const SYNTHETIC_FLAG: u32 = 4;
+
+/// Name fragments (lowercase) of italic and monospaced fonts
+const ITALIC_NAMES: &[&str] = &["italic", "oblique", "slanted", "cmti", "cmsl"];
+const MONOSPACE_NAMES: &[&str] = &[
+    "mono",
+    "courier",
+    "consola",
+    "menlo",
+    "typewriter",
+    "code",
+    "cmtt",
+    "sftt",
+];
+
+/// Style of one character
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub(crate) struct CharStyle {
+    /// Index into [`PageStyles::fonts`]
+    pub font: usize,
+    /// Fill colour as `0xRRGGBB`
+    pub color: u32,
+}
+
+/// Styles of a page's characters in text page order
+#[derive(Debug, Default, PartialEq)]
+pub(crate) struct PageStyles {
+    pub fonts: Vec<FontInfo>,
+    pub chars: Vec<CharStyle>,
+}
+
+impl PageStyles {
+    pub fn read(page: &mupdf::Page) -> Result<Self> {
+        let xml = page
+            .to_xml()
+            .map_err(|e| VoxPDFError::ExtractionError(format!("Failed to read fonts: {}", e)))?;
+        Ok(Self::parse(&xml))
+    }
+
+    /// Font and colour of the character at `index` in text page order
+    pub fn get(&self, index: usize) -> Option<(&FontInfo, u32)> {
+        let style = self.chars.get(index)?;
+        Some((&self.fonts[style.font], style.color))
+    }
+
+    /// Parse MuPDF's stext XML: `<font name=".." size="..">` elements
+    /// holding `<char .. color="#rrggbb" flags="n" c=".."/>` elements
+    fn parse(xml: &str) -> Self {
+        let mut styles = Self::default();
+        // (name, bold) to index into `fonts`
+        let mut known: HashMap<(&str, bool), usize> = HashMap::new();
+        let mut font_name = "";
+
+        let mut rest = xml;
+        while let Some(start) = rest.find('<') {
+            // Attribute values escape '>', so the first one ends the tag
+            let Some(len) = rest[start..].find('>') else {
+                break;
+            };
+            let tag = &rest[start + 1..start + len];
+            rest = &rest[start + len + 1..];
+
+            if tag.starts_with("font ") {
+                font_name = attribute(tag, "name").unwrap_or_default();
+            } else if tag.starts_with("char ") {
+                let flags: u32 = attribute(tag, "flags")
+                    .and_then(|f| f.parse().ok())
+                    .unwrap_or(0);
+                if flags & SYNTHETIC_FLAG != 0 {
+                    continue;
+                }
+                let color = attribute(tag, "color")
+                    .and_then(|c| u32::from_str_radix(c.trim_start_matches('#'), 16).ok())
+                    .unwrap_or(0);
+
+                let bold = flags & BOLD_FLAG != 0;
+                let font = *known.entry((font_name, bold)).or_insert_with(|| {
+                    styles.fonts.push(font_info(font_name, bold));
+                    styles.fonts.len() - 1
+                });
+                styles.chars.push(CharStyle { font, color });
+            }
+        }
+
+        styles
+    }
+}
+
+/// Value of `name="..."` in a tag, still XML-escaped
+fn attribute<'a>(tag: &'a str, name: &str) -> Option<&'a str> {
+    let key = format!(" {}=\"", name);
+    let start = tag.find(&key)? + key.len();
+    let len = tag[start..].find('"')?;
+    Some(&tag[start..start + len])
+}
+
+fn font_info(name: &str, bold: bool) -> FontInfo {
+    let lower = name.to_ascii_lowercase();
+    // "MinionPro-It", "MinionPro-BoldIt"
+    let style = lower.rsplit_once(['-', ',']).map_or("", |(_, style)| style);
+
+    FontInfo {
+        name: name.to_string(),
+        bold,
+        italic: ITALIC_NAMES.iter().any(|n| lower.contains(n)) || style.ends_with("it"),
+        monospace: MONOSPACE_NAMES.iter().any(|n| lower.contains(n)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const XML: &str = r##"<page id="page0" width="612" height="792">
+<block bbox="72 60 300 100">
+<line bbox="72 60 300 74" wmode="0" dir="1 0" text="Plain &lt;b&gt; bold">
+<font name="Helvetica" size="12">
+<char quad="72 60 79 60 72 74 79 74" x="72" y="72" bidi="0" color="#000000" alpha="#ff" flags="0" c="P"/>
+<char quad="79 60 86 60 79 74 86 74" x="79" y="72" bidi="0" color="#000000" alpha="#ff" flags="0" c="&lt;"/>
+<char quad="86 72 90 72 86 72 90 72" x="86" y="72" bidi="0" color="#000000" alpha="#ff" flags="4" c=" "/>
+</font>
+<font name="Helvetica-Bold" size="12">
+<char quad="90 60 97 60 90 74 97 74" x="90" y="72" bidi="0" color="#cc0000" alpha="#ff" flags="8" c="b"/>
+</font>
+</line>
+</block>
+<image bbox="0 0 10 10" />
+<block bbox="72 100 300 120">
+<line bbox="72 100 300 114" wmode="0" dir="1 0" text="x">
+<font name="Courier-Oblique" size="10">
+<char quad="72 100 78 100 72 114 78 114" x="72" y="112" bidi="0" color="#000000" alpha="#ff" flags="16" c="x"/>
+</font>
+<font name="Helvetica" size="12">
+<char quad="80 100 87 100 80 114 87 114" x="80" y="112" bidi="0" color="#000000" alpha="#ff" flags="0" c="y"/>
+</font>
+</line>
+</block>
+</page>
+"##;
+
+    #[test]
+    fn test_parse_stext_xml() {
+        let styles = PageStyles::parse(XML);
+        assert_eq!(styles.chars.len(), 5);
+
+        let fonts: Vec<(&str, bool, bool, bool)> = (0..5)
+            .map(|i| {
+                let (font, _) = styles.get(i).unwrap();
+                (font.name.as_str(), font.bold, font.italic, font.monospace)
+            })
+            .collect();
+        assert_eq!(
+            fonts,
+            [
+                ("Helvetica", false, false, false),
+                ("Helvetica", false, false, false),
+                ("Helvetica-Bold", true, false, false),
+                ("Courier-Oblique", false, true, true),
+                ("Helvetica", false, false, false),
+            ]
+        );
+
+        // The synthetic space is skipped; repeated fonts share an entry
+        assert_eq!(styles.fonts.len(), 3);
+        assert_eq!(styles.get(2).unwrap().1, 0xCC0000);
+        assert!(styles.get(5).is_none());
+    }
+
+    #[test]
+    fn test_font_styles_from_names() {
+        assert!(font_info("MinionPro-It", false).italic);
+        assert!(font_info("MinionPro-BoldIt", true).italic);
+        assert!(!font_info("MinionPro-Regular", false).italic);
+        assert!(!font_info("Arial,Bold", true).italic);
+        assert!(font_info("Arial,Italic", false).italic);
+        assert!(font_info("CMTI10", false).italic);
+        assert!(font_info("SourceCodePro-Regular", false).monospace);
+        assert!(font_info("DejaVuSansMono", false).monospace);
+        assert!(font_info("CMTT10", false).monospace);
+        assert!(!font_info("Times-Roman", false).monospace);
+    }
+}
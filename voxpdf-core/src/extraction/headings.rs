@@ -0,0 +1,443 @@
+//! Headings inferred from typography, for documents without an outline.
+//!
+//! Scanned and self-published books often have no `/Outlines`. Their
+//! headings still stand out: set larger or bolder than the body text,
+//! numbered ("2.1 Scope", "Chapter 4"), short, unpunctuated and spaced away
+//! from the paragraph above. Each body paragraph is scored on those cues,
+//! and the survivors are ranked into levels by font size, then weight, then
+//! numbering depth.
+//!
+//! Weight is only known for words read with
+//! [`ExtractionOptions::font_attributes`];
+//! [`extract_toc`](crate::extraction::extract_toc) turns it on for inference.
+
+use crate::extraction::paragraphs::body_font_size;
+use crate::extraction::ExtractionOptions;
+use crate::models::{Paragraph, TocEntry, Word};
+
+/// Headings longer than this are more likely short paragraphs
+const MAX_HEADING_WORDS: usize = 12;
+
+/// Fewer inferred headings than this don't make a useful TOC
+const MIN_INFERRED_ENTRIES: usize = 2;
+
+/// A size whose candidates make up more than this share of all body
+/// paragraphs is a second body style (a sidebar, quotes), not a heading level
+const MAX_HEADING_SHARE: f32 = 0.35;
+
+/// Baseline-to-baseline distance from the previous paragraph, as a multiple
+/// of the body font size, that sets a heading apart
+const ISOLATION_GAP_RATIO: f32 = 2.0;
+
+/// Characters that end a sentence; headings rarely do
+const SENTENCE_END: &[char] = &['.', '!', '?', ',', ';'];
+
+/// Words that introduce a numbered heading
+const HEADING_KEYWORDS: &[&str] = &["chapter", "part", "section", "appendix", "book"];
+
+/// Weights of the cues in a heading's confidence; they add up to 1
+const SIZE_WEIGHT: f32 = 0.45;
+const NUMBERED_WEIGHT: f32 = 0.25;
+const ISOLATED_WEIGHT: f32 = 0.2;
+const CASE_WEIGHT: f32 = 0.1;
+
+/// Size score given to bold text set against regular body text, so a bold
+/// heading in the body size counts about as much as one set a quarter larger
+const BOLD_SIZE_SCORE: f32 = 0.6;
+
+/// Infer a table of contents from `paragraphs`, a document-wide sequence
+/// such as [`PDFDocument::document_paragraphs`](crate::pdf::PDFDocument::document_paragraphs).
+///
+/// Entries are marked `inferred` and carry a `confidence` of at least
+/// `options.min_heading_confidence`. Their `paragraph_index` is the
+/// heading's index in `paragraphs`. Returns an empty list if fewer than two
+/// headings are found.
+///
+/// # Example
+/// ```no_run
+/// use voxpdf_core::PDFDocument;
+/// use voxpdf_core::extraction::infer_toc;
+///
+/// let doc = PDFDocument::open("scanned-book.pdf")?;
+/// for entry in infer_toc(doc.document_paragraphs()?, doc.extraction_options()) {
+///     println!("{:.2} {}{}", entry.confidence, "  ".repeat(entry.level as usize), entry.title);
+/// }
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+pub fn infer_toc(paragraphs: &[Paragraph], options: &ExtractionOptions) -> Vec<TocEntry> {
+    let body: Vec<(usize, &Paragraph)> = paragraphs
+        .iter()
+        .enumerate()
+        .filter(|(_, p)| !p.is_running_text())
+        .collect();
+    let Some(body_size) = body_font_size(body.iter().flat_map(|(_, p)| &p.words)) else {
+        return Vec::new();
+    };
+    // Bold can't set a heading apart from body text that is bold itself
+    let body_bold = is_bold(body.iter().flat_map(|(_, p)| &p.words));
+
+    let mut headings: Vec<Heading> = Vec::new();
+    let mut previous: Option<&Paragraph> = None;
+    for (index, paragraph) in body {
+        if let Some(heading) = score(index, paragraph, previous, body_size, body_bold, options) {
+            headings.push(heading);
+        }
+        previous = Some(paragraph);
+    }
+
+    drop_crowded_styles(&mut headings, paragraphs.len());
+    headings.retain(|h| h.confidence >= options.min_heading_confidence);
+    if headings.len() < MIN_INFERRED_ENTRIES {
+        return Vec::new();
+    }
+
+    // Larger sizes nest outside smaller ones; within a size, bold nests
+    // outside regular and "1.2" inside "1"
+    let mut keys: Vec<(i32, bool, usize)> = headings.iter().map(Heading::level_key).collect();
+    keys.sort_unstable();
+    keys.dedup();
+
+    headings
+        .into_iter()
+        .map(|heading| {
+            let level = keys.binary_search(&heading.level_key()).unwrap_or(0);
+            let paragraph = &paragraphs[heading.index];
+            let mut entry = TocEntry::new(
+                heading.title,
+                level.min(u8::MAX as usize) as u8,
+                paragraph.page_number,
+                heading.index,
+            );
+            entry.dest_top = paragraph.words.first().map(|w| w.bounds.y);
+            entry.confidence = heading.confidence;
+            entry.inferred = true;
+            entry
+        })
+        .collect()
+}
+
+/// A body paragraph that looks like a heading
+#[derive(Debug)]
+struct Heading {
+    /// Position in the document-wide paragraph sequence
+    index: usize,
+    title: String,
+    /// Font size in half points
+    size_key: i32,
+    /// Set in bold against regular body text
+    bold: bool,
+    /// Levels of the heading number: 1 for "2" or "Chapter 2", 2 for "2.1"
+    depth: usize,
+    confidence: f32,
+}
+
+impl Heading {
+    /// Sort key for levels: larger sizes first, then bold, then shallower
+    /// numbering
+    fn level_key(&self) -> (i32, bool, usize) {
+        (-self.size_key, !self.bold, self.depth)
+    }
+
+    /// The heading's typographic style: its size and weight
+    fn style_key(&self) -> (i32, bool) {
+        (self.size_key, self.bold)
+    }
+}
+
+fn score(
+    index: usize,
+    paragraph: &Paragraph,
+    previous: Option<&Paragraph>,
+    body_size: f32,
+    body_bold: bool,
+    options: &ExtractionOptions,
+) -> Option<Heading> {
+    let title = paragraph
+        .text
+        .split_whitespace()
+        .collect::<Vec<_>>()
+        .join(" ");
+    let words = paragraph.words.len();
+    if words == 0 || words > MAX_HEADING_WORDS || title.ends_with(SENTENCE_END) {
+        return None;
+    }
+    if !title.chars().any(char::is_alphabetic) {
+        return None;
+    }
+
+    let size = paragraph.words.iter().map(|w| w.font_size).sum::<f32>() / words as f32;
+    let ratio = size / body_size;
+    let depth = numbering_depth(&title);
+    let bold = !body_bold && is_bold(&paragraph.words);
+
+    // Regular body-sized text needs a number to count as a heading
+    let larger = ratio >= options.heading_size_ratio;
+    if !larger && !bold && depth == 0 {
+        return None;
+    }
+
+    let mut size_score = ((ratio - 1.0) / 0.5).clamp(0.0, 1.0);
+    if bold {
+        size_score = size_score.max(BOLD_SIZE_SCORE);
+    }
+    let numbered = if depth > 0 { 1.0 } else { 0.0 };
+    let isolated = if is_isolated(paragraph, previous, body_size) {
+        1.0
+    } else {
+        0.0
+    };
+    let case = if is_heading_case(&title) { 1.0 } else { 0.0 };
+
+    let confidence = SIZE_WEIGHT * size_score
+        + NUMBERED_WEIGHT * numbered
+        + ISOLATED_WEIGHT * isolated
+        + CASE_WEIGHT * case;
+
+    Some(Heading {
+        index,
+        title,
+        size_key: (size * 2.0).round() as i32,
+        bold,
+        depth: depth.max(1),
+        confidence: confidence.min(1.0),
+    })
+}
+
+/// True if most of `words` are set in bold
+fn is_bold<'a>(words: impl IntoIterator<Item = &'a Word>) -> bool {
+    let (mut bold, mut total) = (0, 0);
+    for word in words {
+        total += 1;
+        if word.font.as_ref().is_some_and(|font| font.bold) {
+            bold += 1;
+        }
+    }
+    bold * 2 > total
+}
+
+/// Levels in a leading heading number ("3" → 1, "3.2.1" → 3, "Chapter IV" →
+/// 1), or 0 if the text isn't numbered
+pub(crate) fn numbering_depth(title: &str) -> usize {
+    let mut tokens = title.split_whitespace();
+    let Some(first) = tokens.next() else {
+        return 0;
+    };
+
+    if HEADING_KEYWORDS.contains(&first.to_lowercase().as_str()) {
+        let next = tokens.next().unwrap_or("");
+        let label = next.trim_end_matches([':', '.']);
+        let is_label = !label.is_empty()
+            && (label.chars().all(|c| c.is_ascii_digit())
+                || is_roman(label)
+                || (label.len() == 1 && label.chars().all(|c| c.is_ascii_uppercase())));
+        return usize::from(is_label);
+    }
+
+    // "1", "2.3", "4.1.2" followed by the heading text; a trailing dot is
+    // allowed ("1. Introduction")
+    let number = first.trim_end_matches('.');
+    let parts: Vec<&str> = number.split('.').collect();
+    let numeric = !number.is_empty()
+        && parts
+            .iter()
+            .all(|p| !p.is_empty() && p.len() <= 3 && p.chars().all(|c| c.is_ascii_digit()));
+    if numeric && tokens.next().is_some() {
+        parts.len()
+    } else {
+        0
+    }
+}
+
+fn is_roman(label: &str) -> bool {
+    !label.is_empty()
+        && label
+            .chars()
+            .all(|c| matches!(c.to_ascii_uppercase(), 'I' | 'V' | 'X' | 'L' | 'C'))
+}
+
+/// All capitals, or every longer word capitalized
+fn is_heading_case(title: &str) -> bool {
+    let letters: Vec<char> = title.chars().filter(|c| c.is_alphabetic()).collect();
+    if letters.iter().all(|c| c.is_uppercase()) {
+        return true;
+    }
+    title
+        .split_whitespace()
+        .filter(|w| w.chars().count() > 3)
+        .filter_map(|w| w.chars().find(|c| c.is_alphabetic()))
+        .all(char::is_uppercase)
+}
+
+/// First on its page, or set well below the paragraph before it
+fn is_isolated(paragraph: &Paragraph, previous: Option<&Paragraph>, body_size: f32) -> bool {
+    let (Some(first), Some(previous)) = (paragraph.words.first(), previous) else {
+        return true;
+    };
+    let Some(last) = previous.words.last() else {
+        return true;
+    };
+    if last.page_number != first.page_number {
+        return true;
+    }
+    first.bounds.y - last.bounds.y >= body_size * ISOLATION_GAP_RATIO
+}
+
+/// Drop styles used so often that they can't be headings
+fn drop_crowded_styles(headings: &mut Vec<Heading>, paragraph_count: usize) {
+    let limit = (paragraph_count as f32 * MAX_HEADING_SHARE).max(MIN_INFERRED_ENTRIES as f32);
+    let mut counts: Vec<((i32, bool), usize)> = Vec::new();
+    for style in headings.iter().map(Heading::style_key) {
+        match counts.iter_mut().find(|(key, _)| *key == style) {
+            Some((_, count)) => *count += 1,
+            None => counts.push((style, 1)),
+        }
+    }
+    headings.retain(|h| {
+        counts
+            .iter()
+            .any(|&(key, count)| key == h.style_key() && count as f32 <= limit)
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::FontInfo;
+    use crate::test_support::line_paragraph;
+
+    /// Lay paragraphs out top to bottom on page 0, leaving `gap` points
+    /// above each one
+    fn document(specs: &[(&str, f32, f32)]) -> Vec<Paragraph> {
+        let mut y = 72.0;
+        specs
+            .iter()
+            .enumerate()
+            .map(|(index, &(text, size, gap))| {
+                y += gap;
+                line_paragraph(index, 0, y, size, text)
+            })
+            .collect()
+    }
+
+    const BODY: &str = "Body text that runs on for a while and ends with a full stop.";
+
+    fn titles(entries: &[TocEntry]) -> Vec<(&str, u8)> {
+        entries
+            .iter()
+            .map(|e| (e.title.as_str(), e.level))
+            .collect()
+    }
+
+    #[test]
+    fn test_levels_from_size_and_numbering() {
+        let paragraphs = document(&[
+            ("1 Introduction", 18.0, 0.0),
+            (BODY, 11.0, 30.0),
+            ("1.1 Scope", 14.0, 30.0),
+            (BODY, 11.0, 20.0),
+            ("1.1.1 Limits", 11.0, 30.0),
+            (BODY, 11.0, 15.0),
+            ("2 Methods", 18.0, 40.0),
+            (BODY, 11.0, 30.0),
+            (BODY, 11.0, 15.0),
+        ]);
+
+        let entries = infer_toc(&paragraphs, &ExtractionOptions::default());
+        assert_eq!(
+            titles(&entries),
+            [
+                ("1 Introduction", 0),
+                ("1.1 Scope", 1),
+                ("1.1.1 Limits", 2),
+                ("2 Methods", 0)
+            ]
+        );
+        assert_eq!(
+            entries
+                .iter()
+                .map(|e| e.paragraph_index)
+                .collect::<Vec<_>>(),
+            [0, 2, 4, 6]
+        );
+        assert!(entries.iter().all(|e| e.inferred));
+        assert!(entries[0].confidence > entries[2].confidence);
+    }
+
+    #[test]
+    fn test_rejects_sentences_and_unspaced_numbers() {
+        let paragraphs = document(&[
+            ("Overview", 16.0, 0.0),
+            (BODY, 11.0, 30.0),
+            ("A large pull quote, set apart.", 16.0, 30.0),
+            ("3 apples", 11.0, 14.0),
+            (BODY, 11.0, 14.0),
+            ("Results", 16.0, 30.0),
+            (BODY, 11.0, 30.0),
+            (BODY, 11.0, 15.0),
+        ]);
+
+        let entries = infer_toc(&paragraphs, &ExtractionOptions::default());
+        assert_eq!(titles(&entries), [("Overview", 0), ("Results", 0)]);
+    }
+
+    #[test]
+    fn test_bold_body_size_headings() {
+        let mut paragraphs = document(&[
+            ("Introduction", 11.0, 0.0),
+            (BODY, 11.0, 30.0),
+            ("Background", 11.0, 30.0),
+            (BODY, 11.0, 30.0),
+            (BODY, 11.0, 15.0),
+        ]);
+        let options = ExtractionOptions::default();
+        assert!(infer_toc(&paragraphs, &options).is_empty());
+
+        for index in [0, 2] {
+            for word in &mut paragraphs[index].words {
+                word.font = Some(FontInfo {
+                    name: "Times-Bold".to_string(),
+                    bold: true,
+                    italic: false,
+                    monospace: false,
+                });
+            }
+        }
+        let entries = infer_toc(&paragraphs, &options);
+        assert_eq!(titles(&entries), [("Introduction", 0), ("Background", 0)]);
+    }
+
+    #[test]
+    fn test_needs_two_headings() {
+        let paragraphs = document(&[("Title", 20.0, 0.0), (BODY, 11.0, 30.0)]);
+        assert!(infer_toc(&paragraphs, &ExtractionOptions::default()).is_empty());
+
+        let paragraphs = document(&[(BODY, 11.0, 0.0), (BODY, 11.0, 15.0)]);
+        assert!(infer_toc(&paragraphs, &ExtractionOptions::default()).is_empty());
+    }
+
+    #[test]
+    fn test_crowded_size_is_not_a_heading_level() {
+        // Every other paragraph is a larger, short caption
+        let paragraphs = document(&[
+            ("Figure one", 13.0, 0.0),
+            (BODY, 11.0, 30.0),
+            ("Figure two", 13.0, 30.0),
+            (BODY, 11.0, 30.0),
+            ("Figure three", 13.0, 30.0),
+            (BODY, 11.0, 30.0),
+        ]);
+        assert!(infer_toc(&paragraphs, &ExtractionOptions::default()).is_empty());
+    }
+
+    #[test]
+    fn test_numbering_depth() {
+        assert_eq!(numbering_depth("3 Results"), 1);
+        assert_eq!(numbering_depth("3.2.1 Error bars"), 3);
+        assert_eq!(numbering_depth("1. Introduction"), 1);
+        assert_eq!(numbering_depth("Chapter IV: The Return"), 1);
+        assert_eq!(numbering_depth("Appendix B"), 1);
+        assert_eq!(numbering_depth("2024"), 0);
+        assert_eq!(numbering_depth("Chapters of note"), 0);
+        assert_eq!(numbering_depth("12345 Main Street"), 0);
+    }
+}
@@ -1,50 +1,516 @@
-use crate::models::Paragraph;
+//! Rejoining words hyphenated at line breaks.
+//!
+//! Only a hyphen that ends a printed line is a candidate: "exam-" at the end
+//! of one line and "ple" at the start of the next. Whether the halves become
+//! "example" or stay "well-known" is decided by the kind of hyphen, the
+//! document's own vocabulary and a [`WordList`].
+
+use crate::extraction::ExtractionOptions;
+use crate::models::{Paragraph, Word};
 use once_cell::sync::Lazy;
-use regex::Regex;
+use std::collections::{HashMap, HashSet};
+
+/// Code points that can end a line as a hyphen
+pub const HYPHENS: &[char] = &[
+    '-',        // hyphen-minus
+    '\u{00AD}', // soft hyphen
+    '\u{058A}', // Armenian hyphen
+    '\u{1806}', // Mongolian todo soft hyphen
+    '\u{2010}', // hyphen
+    '\u{2011}', // non-breaking hyphen
+    '\u{2E17}', // double oblique hyphen
+    '\u{FE63}', // small hyphen-minus
+    '\u{FF0D}', // fullwidth hyphen-minus
+];
+
+/// Discretionary hyphens: only printed because the line broke there
+const SOFT_HYPHENS: &[char] = &['\u{00AD}', '\u{1806}'];
+
+/// Never a break point, so a line ending in one ends a real compound
+const NON_BREAKING_HYPHEN: char = '\u{2011}';
+
+static BUILTIN_WORDS: Lazy<WordList> =
+    Lazy::new(|| WordList::from_text(include_str!("data/hyphenated-words.txt")));
+
+/// What to do with a hyphen at a line break
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum HyphenJoin {
+    /// Drop the hyphen: "exam-" + "ple" is "example"
+    Merge,
+    /// Keep the hyphen: "well-" + "known" is "well-known"
+    KeepHyphen,
+}
+
+/// Words that decide how line-break hyphens are resolved.
+///
+/// The text format has one entry per line; `#` starts a comment line.
+/// `well-` marks a prefix that always keeps its hyphen, `well-known` a
+/// hyphenated compound and `example` a plain word.
+///
+/// # Example
+/// ```
+/// use voxpdf_core::extraction::hyphenation::{Dehyphenator, HyphenJoin, WordList};
+///
+/// let words = WordList::from_text("# medical terms\nanti-\nco-morbidity\n");
+/// let dehyphenator = Dehyphenator::with_word_list(words);
+/// assert_eq!(dehyphenator.decide("co-", "morbidity"), Some(HyphenJoin::KeepHyphen));
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct WordList {
+    words: HashSet<String>,
+    prefixes: HashSet<String>,
+}
+
+impl WordList {
+    /// Parse a word list (see the type docs for the format)
+    pub fn from_text(text: &str) -> Self {
+        let mut list = Self::default();
+        for line in text.lines() {
+            let line = line.trim();
+            if !line.is_empty() && !line.starts_with('#') {
+                list.insert(line);
+            }
+        }
+        list
+    }
+
+    /// The English list built into the library
+    pub fn builtin() -> &'static WordList {
+        &BUILTIN_WORDS
+    }
+
+    /// Add an entry; a trailing hyphen marks a prefix
+    pub fn insert(&mut self, entry: &str) {
+        let entry = normalize(entry);
+        match entry.strip_suffix('-') {
+            Some(prefix) if !prefix.is_empty() => self.prefixes.insert(prefix.to_string()),
+            _ => self.words.insert(entry),
+        };
+    }
+
+    /// Add every entry of `other`
+    pub fn extend(&mut self, other: &WordList) {
+        self.words.extend(other.words.iter().cloned());
+        self.prefixes.extend(other.prefixes.iter().cloned());
+    }
+
+    pub fn contains(&self, word: &str) -> bool {
+        self.words.contains(&normalize(word))
+    }
+
+    fn keeps_prefix(&self, prefix: &str) -> bool {
+        self.prefixes.contains(&normalize(prefix))
+    }
+}
+
+/// Decides how to rejoin words hyphenated at line breaks.
+///
+/// Evidence is weighed in order: the hyphen itself (soft hyphens always
+/// merge, non-breaking hyphens always stay), capitals and digits after the
+/// break (keep: "Jean-Paul", "1990-1995"), how the document itself spells
+/// the word elsewhere, then the [`WordList`]. With no evidence the halves are
+/// merged, since most line-end hyphens split a single word.
+#[derive(Debug, Clone)]
+pub struct Dehyphenator {
+    words: WordList,
+    /// How often each form ("example", "well-known") appears unbroken
+    vocabulary: HashMap<String, usize>,
+}
 
-// Regex to match hyphenation at end of words
-static HYPHEN_PATTERN: Lazy<Regex> = Lazy::new(|| {
-    // Matches: word- [space/newline] lowercase-word
-    Regex::new(r"(\w+)-\s+([a-z]\w*)").unwrap()
-});
+impl Default for Dehyphenator {
+    fn default() -> Self {
+        Self::with_word_list(WordList::builtin().clone())
+    }
+}
+
+impl Dehyphenator {
+    /// Use the built-in English word list
+    pub fn new() -> Self {
+        Self::default()
+    }
 
+    /// Use `words` instead of the built-in list
+    pub fn with_word_list(words: WordList) -> Self {
+        Self {
+            words,
+            vocabulary: HashMap::new(),
+        }
+    }
+
+    /// Count how the document spells words that aren't broken by a line end
+    pub fn learn_vocabulary<'a>(&mut self, words: impl IntoIterator<Item = &'a Word>) {
+        for word in words {
+            if word.text.ends_with(HYPHENS) {
+                continue;
+            }
+            let key = normalize(trim_punctuation(&word.text));
+            if !key.is_empty() {
+                *self.vocabulary.entry(key).or_default() += 1;
+            }
+        }
+    }
+
+    /// How to join `head` (ending in a hyphen) with `tail`, or `None` if
+    /// the pair isn't a hyphenated word
+    pub fn decide(&self, head: &str, tail: &str) -> Option<HyphenJoin> {
+        let hyphen = head.chars().last().filter(|c| HYPHENS.contains(c))?;
+        let stem = trim_punctuation(&head[..head.len() - hyphen.len_utf8()]);
+        let rest = trim_punctuation(tail);
+
+        let last = stem.chars().last().filter(|c| c.is_alphanumeric())?;
+        let first = tail.chars().next().filter(|c| c.is_alphanumeric())?;
+
+        if SOFT_HYPHENS.contains(&hyphen) {
+            return Some(HyphenJoin::Merge);
+        }
+        if hyphen == NON_BREAKING_HYPHEN
+            || first.is_uppercase()
+            || first.is_numeric()
+            || last.is_numeric()
+        {
+            return Some(HyphenJoin::KeepHyphen);
+        }
+
+        let merged = format!("{}{}", stem, rest);
+        let hyphenated = format!("{}-{}", stem, rest);
+
+        let seen = |form: &str| self.vocabulary.get(&normalize(form)).copied().unwrap_or(0);
+        let (seen_merged, seen_hyphenated) = (seen(&merged), seen(&hyphenated));
+        if seen_merged + seen_hyphenated > 0 {
+            return Some(if seen_hyphenated > seen_merged {
+                HyphenJoin::KeepHyphen
+            } else {
+                HyphenJoin::Merge
+            });
+        }
+
+        if self.words.keeps_prefix(stem) || self.words.contains(&hyphenated) {
+            return Some(HyphenJoin::KeepHyphen);
+        }
+
+        Some(HyphenJoin::Merge)
+    }
+
+    /// Rejoin hyphenated words in each paragraph.
+    ///
+    /// A pair is only considered when the hyphenated word ends its line: the
+    /// next word is on a later line (per `options.line_tolerance_ratio`) or
+    /// page. Joined words carry both printed pieces in
+    /// [`fragments`](Word::fragments) and the paragraph text and
+    /// `word_ranges` are rebuilt from the words, so they always agree.
+    /// Paragraphs without words fall back to their text, where a line break
+    /// is a newline: "exam-\nple" is joined, "well- known" on one line is
+    /// left alone.
+    pub fn dehyphenate(
+        &self,
+        paragraphs: Vec<Paragraph>,
+        options: &ExtractionOptions,
+    ) -> Vec<Paragraph> {
+        paragraphs
+            .into_iter()
+            .map(|mut para| {
+                self.dehyphenate_in_place(&mut para, options);
+                para
+            })
+            .collect()
+    }
+
+    /// Rejoin hyphenated words in one paragraph
+    pub(crate) fn dehyphenate_in_place(&self, para: &mut Paragraph, options: &ExtractionOptions) {
+        if para.words.is_empty() {
+            para.text = self.dehyphenate_text(&para.text);
+            return;
+        }
+
+        let mut merged: Vec<Word> = Vec::with_capacity(para.words.len());
+        for mut word in std::mem::take(&mut para.words) {
+            if let Some(head) = merged.last() {
+                if ends_line(head, &word, options) {
+                    if let Some(join) = self.decide(&head.text, &word.text) {
+                        let head = merged.pop().unwrap();
+                        merged.push(join_words(head, word, join));
+                        continue;
+                    }
+                }
+            }
+            strip_inner_soft_hyphens(&mut word.text);
+            merged.push(word);
+        }
+
+        para.words = merged;
+        para.text = para
+            .words
+            .iter()
+            .map(|w| w.text.as_str())
+            .collect::<Vec<_>>()
+            .join(" ");
+        para.update_word_ranges();
+    }
+
+    fn dehyphenate_text(&self, text: &str) -> String {
+        let mut tokens: Vec<String> = Vec::new();
+        let mut rest = text;
+        loop {
+            let trimmed = rest.trim_start();
+            let new_line = rest[..rest.len() - trimmed.len()].contains('\n');
+            if trimmed.is_empty() {
+                break;
+            }
+            let end = trimmed.find(char::is_whitespace).unwrap_or(trimmed.len());
+            let (token, after) = trimmed.split_at(end);
+            rest = after;
+
+            let join = tokens
+                .last()
+                .filter(|_| new_line)
+                .and_then(|head| self.decide(head, token));
+            match join {
+                Some(join) => {
+                    let head = tokens.pop().unwrap();
+                    tokens.push(join_text(&head, token, join));
+                }
+                None => tokens.push(token.to_string()),
+            }
+        }
+        tokens.join(" ")
+    }
+}
+
+/// Rejoin words hyphenated across line breaks.
+///
+/// "exam-" followed by "ple" becomes one [`Word`] "example" whose
+/// `fragments` hold both printed pieces, so the paragraph's text and word
+/// list stay in step. The merged word's `bounds` are those of its first
+/// piece. Uses the built-in word list and the vocabulary of `paragraphs`;
+/// see [`Dehyphenator`] for more control.
 pub fn reassemble_hyphenated_words(paragraphs: Vec<Paragraph>) -> Vec<Paragraph> {
-    paragraphs.into_iter().map(reassemble_paragraph).collect()
+    let mut dehyphenator = Dehyphenator::new();
+    dehyphenator.learn_vocabulary(paragraphs.iter().flat_map(|p| &p.words));
+    dehyphenator.dehyphenate(paragraphs, &ExtractionOptions::default())
+}
+
+/// True if `tail` starts a later line than the one `head` is on
+fn ends_line(head: &Word, tail: &Word, options: &ExtractionOptions) -> bool {
+    if tail.page_number != head.page_number {
+        return true;
+    }
+
+    let tolerance = head.font_size.max(tail.font_size) * options.line_tolerance_ratio;
+    (tail.bounds.y - head.bounds.y).abs() >= tolerance || tail.bounds.x < head.bounds.x
+}
+
+fn join_text(head: &str, tail: &str, join: HyphenJoin) -> String {
+    match join {
+        HyphenJoin::KeepHyphen => format!("{}{}", head, tail),
+        HyphenJoin::Merge => {
+            let hyphen = head.chars().last().map_or(0, char::len_utf8);
+            format!("{}{}", &head[..head.len() - hyphen], tail)
+        }
+    }
+}
+
+fn join_words(mut head: Word, tail: Word, join: HyphenJoin) -> Word {
+    let text = join_text(&head.text, &tail.text, join);
+
+    let mut fragments = head.to_fragments();
+    fragments.extend(tail.to_fragments());
+    head.glyphs.extend(tail.glyphs);
+
+    // Position and style are the head's
+    Word {
+        text,
+        fragments,
+        ..head
+    }
 }
 
-fn reassemble_paragraph(para: Paragraph) -> Paragraph {
-    let text = para.text;
+/// Soft hyphens inside a word are invisible when printed; keep a trailing
+/// one, which may still mark a line break
+fn strip_inner_soft_hyphens(text: &mut String) {
+    let inner = &text[..text.trim_end_matches(SOFT_HYPHENS).len()];
+    if inner.contains(SOFT_HYPHENS) {
+        let trailing = &text[inner.len()..];
+        *text = inner.replace(SOFT_HYPHENS, "") + trailing;
+    }
+}
 
-    // Replace "word- nextpart" with "wordnextpart"
-    let reassembled = HYPHEN_PATTERN.replace_all(&text, "$1$2");
+/// Lowercase, with every hyphen variant written as `-`
+fn normalize(word: &str) -> String {
+    word.chars()
+        .map(|c| if HYPHENS.contains(&c) { '-' } else { c })
+        .flat_map(char::to_lowercase)
+        .collect()
+}
 
-    Paragraph::new(
-        para.index,
-        reassembled.to_string(),
-        para.page_number,
-        para.words, // TODO: Update word list to match reassembled text
-    )
+/// Strip quotes, brackets and sentence punctuation around a word
+fn trim_punctuation(word: &str) -> &str {
+    word.trim_matches(|c: char| !c.is_alphanumeric() && !HYPHENS.contains(&c))
+        .trim_start_matches(HYPHENS)
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::models::Rect;
 
-    #[test]
-    fn test_hyphen_pattern_matches() {
-        let text = "exam- ple";
-        assert!(HYPHEN_PATTERN.is_match(text));
+    fn word(text: &str, x: f32, y: f32) -> Word {
+        Word::new(text, Rect::new(x, y, 40.0, 12.0), 0, 12.0)
+    }
+
+    fn paragraph(words: Vec<Word>) -> Paragraph {
+        let text = words
+            .iter()
+            .map(|w| w.text.as_str())
+            .collect::<Vec<_>>()
+            .join(" ");
+        Paragraph::new(0, text, 0, words)
+    }
 
-        let result = HYPHEN_PATTERN.replace_all(text, "$1$2");
-        assert_eq!(result, "example");
+    fn dehyphenate(para: Paragraph) -> Paragraph {
+        reassemble_hyphenated_words(vec![para]).remove(0)
+    }
+
+    #[test]
+    fn test_text_only_paragraph_joins_at_line_break() {
+        let para = Paragraph::new(0, "an exam-\nple".to_string(), 0, vec![]);
+        assert_eq!(dehyphenate(para).text, "an example");
     }
 
     #[test]
     fn test_preserves_intentional_hyphens() {
-        let text = "self-contained";
-        // Should NOT match because both parts start with lowercase
-        // Our pattern only matches: word- [space] lowercase
-        let result = HYPHEN_PATTERN.replace_all(text, "$1$2");
-        assert_eq!(result, "self-contained");
+        let para = Paragraph::new(0, "a self-contained well-\nknown".to_string(), 0, vec![]);
+        assert_eq!(dehyphenate(para).text, "a self-contained well-known");
+    }
+
+    #[test]
+    fn test_text_only_hyphen_within_line_is_kept() {
+        let para = Paragraph::new(0, "a well- known\npre- and post-war".to_string(), 0, vec![]);
+        assert_eq!(dehyphenate(para).text, "a well- known pre- and post-war");
+    }
+
+    #[test]
+    fn test_split_word_becomes_multi_rect_word() {
+        let para = paragraph(vec![
+            word("an", 400.0, 100.0),
+            word("exam-", 450.0, 100.0),
+            word("ple", 72.0, 114.0),
+            word("here", 110.0, 114.0),
+        ]);
+
+        let result = dehyphenate(para);
+
+        assert_eq!(result.text, "an example here");
+        let texts: Vec<_> = result.words.iter().map(|w| w.text.as_str()).collect();
+        assert_eq!(texts, ["an", "example", "here"]);
+
+        let example = &result.words[1];
+        assert!(example.is_split());
+        assert_eq!(example.bounds, Rect::new(450.0, 100.0, 40.0, 12.0));
+        assert_eq!(example.fragments[0].text, "exam-");
+        assert_eq!(example.fragments[1].text, "ple");
+        assert_eq!(
+            example.rects(),
+            vec![
+                (0, &Rect::new(450.0, 100.0, 40.0, 12.0)),
+                (0, &Rect::new(72.0, 114.0, 40.0, 12.0))
+            ]
+        );
+    }
+
+    #[test]
+    fn test_only_line_ends_are_joined() {
+        let para = paragraph(vec![
+            word("self-contained", 72.0, 100.0),
+            word("so-", 200.0, 100.0),
+            word("called", 250.0, 100.0),
+            word("multi-", 300.0, 100.0),
+            word("ply", 72.0, 114.0),
+        ]);
+
+        let result = dehyphenate(para);
+
+        assert_eq!(result.text, "self-contained so- called multiply");
+        assert_eq!(result.words.len(), 4);
+        assert!(!result.words[1].is_split());
+        assert!(result.words[3].is_split());
+    }
+
+    #[test]
+    fn test_hyphen_variants() {
+        let d = Dehyphenator::new();
+        assert_eq!(d.decide("exam\u{00AD}", "ple"), Some(HyphenJoin::Merge));
+        assert_eq!(d.decide("exam\u{2010}", "ple"), Some(HyphenJoin::Merge));
+        assert_eq!(
+            d.decide("exam\u{2011}", "ple"),
+            Some(HyphenJoin::KeepHyphen)
+        );
+        assert_eq!(d.decide("exam\u{2013}", "ple"), None, "en dash");
+        assert_eq!(d.decide("exam", "ple"), None);
+        assert_eq!(d.decide("-", "ple"), None);
+    }
+
+    #[test]
+    fn test_soft_hyphen_is_dropped() {
+        // A soft hyphen both mid-word and at the line end
+        let para = paragraph(vec![
+            word("hy\u{00AD}phen\u{00AD}", 400.0, 100.0),
+            word("ation", 72.0, 114.0),
+        ]);
+        assert_eq!(dehyphenate(para).text, "hyphenation");
+
+        let para = paragraph(vec![word("exam\u{00AD}ple", 72.0, 100.0)]);
+        assert_eq!(dehyphenate(para).text, "example");
+    }
+
+    #[test]
+    fn test_capitals_and_digits_keep_hyphen() {
+        let d = Dehyphenator::new();
+        assert_eq!(d.decide("Jean-", "Paul"), Some(HyphenJoin::KeepHyphen));
+        assert_eq!(d.decide("1990-", "1995"), Some(HyphenJoin::KeepHyphen));
+        assert_eq!(d.decide("non-", "English"), Some(HyphenJoin::KeepHyphen));
+    }
+
+    #[test]
+    fn test_word_list_decides_compounds() {
+        let d = Dehyphenator::new();
+        assert_eq!(d.decide("well-", "known"), Some(HyphenJoin::KeepHyphen));
+        assert_eq!(d.decide("(self-", "aware)"), Some(HyphenJoin::KeepHyphen));
+        assert_eq!(
+            d.decide("state-of-the-", "art,"),
+            Some(HyphenJoin::KeepHyphen)
+        );
+        assert_eq!(d.decide("co-", "operate"), Some(HyphenJoin::KeepHyphen));
+        assert_eq!(d.decide("care-", "fully"), Some(HyphenJoin::Merge));
+    }
+
+    #[test]
+    fn test_document_vocabulary_wins() {
+        let mut d = Dehyphenator::new();
+        let seen = [
+            word("cooperate", 0.0, 0.0),
+            word("cooperate.", 0.0, 0.0),
+            word("re-sign", 0.0, 0.0),
+        ];
+        d.learn_vocabulary(&seen);
+
+        assert_eq!(d.decide("co-", "operate"), Some(HyphenJoin::Merge));
+        assert_eq!(d.decide("re-", "sign"), Some(HyphenJoin::KeepHyphen));
+    }
+
+    #[test]
+    fn test_other_scripts() {
+        let d = Dehyphenator::new();
+        assert_eq!(d.decide("при-", "мер"), Some(HyphenJoin::Merge));
+        assert_eq!(d.decide("Wörter-", "buch"), Some(HyphenJoin::Merge));
+
+        let para = paragraph(vec![word("при-", 400.0, 100.0), word("мер", 72.0, 114.0)]);
+        assert_eq!(dehyphenate(para).text, "пример");
+    }
+
+    #[test]
+    fn test_custom_word_list() {
+        let d = Dehyphenator::with_word_list(WordList::from_text("anti-\n"));
+        assert_eq!(d.decide("anti-", "body"), Some(HyphenJoin::KeepHyphen));
+        assert_eq!(d.decide("well-", "known"), Some(HyphenJoin::Merge));
     }
 }
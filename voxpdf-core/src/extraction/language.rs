@@ -0,0 +1,761 @@
+//! Identifying the language of paragraphs and sentences, offline.
+//!
+//! The script settles most languages outright (Greek, Hangul, Thai). Latin
+//! text is scored against letter-trigram models built from each language's
+//! commonest words, and Cyrillic text is told apart by the letters each
+//! language adds or drops. The document's own language seeds every
+//! decision: text too short to judge keeps it, and it wins close calls.
+//! Text drawn under a `/Lang` tag takes the tag's language without being
+//! looked at.
+//!
+//! This is a lightweight identifier, not a general one. Latin text is only
+//! identified as English, German, Spanish, French, Italian, Dutch or
+//! Portuguese, from a few hundred common words each: enough to tell close
+//! neighbours (Spanish from Portuguese, Dutch from German) apart in a
+//! sentence, but not in a phrase of two or three words. Text in other
+//! Latin-script languages usually comes back with low confidence or none,
+//! though one close to a modelled language (Catalan, Galician) can be taken
+//! for it.
+
+use crate::error::Result;
+use crate::models::{primary_subtag, Language, LanguageSource, Paragraph, Sentence};
+use crate::pdf::{LanguageMark, PDFDocument};
+use std::collections::HashMap;
+
+/// Built-in trigram models, as frequency-ordered lists of a few hundred
+/// words, by primary language subtag
+const MODELS: &[(&str, &str)] = &[
+    ("en", include_str!("data/words-en.txt")),
+    ("de", include_str!("data/words-de.txt")),
+    ("es", include_str!("data/words-es.txt")),
+    ("fr", include_str!("data/words-fr.txt")),
+    ("it", include_str!("data/words-it.txt")),
+    ("nl", include_str!("data/words-nl.txt")),
+    ("pt", include_str!("data/words-pt.txt")),
+];
+
+/// Cyrillic languages told apart by [`cyrillic_language`]
+const CYRILLIC_LANGUAGES: &[&str] = &["ru", "uk", "bg", "sr"];
+
+/// Fewer letters than this are too few to identify
+const MIN_LETTERS: usize = 12;
+
+/// Probability of a trigram a model has never seen
+const UNSEEN_TRIGRAM: f32 = 1e-5;
+
+/// Added to the seed language's mean trigram log-probability
+const SEED_BONUS: f32 = 0.15;
+
+/// Total log-probability lead over the runner-up that gives a confidence
+/// of 1 - 1/e
+const CONFIDENCE_SCALE: f32 = 3.0;
+
+/// Share of the text's trigrams the best model must know for the text to
+/// be in one of the modelled languages at all
+const MIN_COVERAGE: f32 = 0.4;
+
+/// Share of the text's trigrams known to the best model above which its
+/// lead is trusted in full. Related languages without a model (Swedish for
+/// Dutch) fall in between.
+const FULL_COVERAGE: f32 = 0.7;
+
+/// Confidence in a seed language the text couldn't confirm
+const UNCHECKED_CONFIDENCE: f32 = 0.5;
+
+/// Confidence needed to overrule a seed language without a model
+const OVERRULE_CONFIDENCE: f32 = 0.95;
+
+/// Confidence a sentence needs to be in a different language from its
+/// paragraph
+const SENTENCE_SWITCH_CONFIDENCE: f32 = 0.9;
+
+/// Writing systems, as far as telling languages apart goes
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+enum Script {
+    Latin,
+    Cyrillic,
+    Greek,
+    Arabic,
+    Hebrew,
+    Devanagari,
+    Bengali,
+    Tamil,
+    Thai,
+    Georgian,
+    Armenian,
+    Hangul,
+    /// Han ideographs and kana together, since Japanese mixes them
+    Cjk,
+}
+
+/// Letter-trigram log-probabilities for one language
+#[derive(Debug, Clone)]
+struct TrigramModel {
+    language: &'static str,
+    log_probs: HashMap<String, f32>,
+}
+
+impl TrigramModel {
+    /// Weight each word's trigrams by 1/rank, as word frequencies roughly
+    /// fall off (Zipf)
+    fn from_words(language: &'static str, list: &str) -> Self {
+        let mut weights: HashMap<String, f32> = HashMap::new();
+        let words = list
+            .lines()
+            .map(str::trim)
+            .filter(|line| !line.is_empty() && !line.starts_with('#'));
+        for (rank, word) in words.enumerate() {
+            let weight = 1.0 / (rank + 1) as f32;
+            for trigram in word_trigrams(word) {
+                *weights.entry(trigram).or_default() += weight;
+            }
+        }
+
+        let total: f32 = weights.values().sum();
+        let log_probs = weights
+            .into_iter()
+            .map(|(trigram, weight)| (trigram, (weight / total).ln()))
+            .collect();
+        Self {
+            language,
+            log_probs,
+        }
+    }
+
+    /// Share of `trigrams` this model has seen
+    fn coverage(&self, trigrams: &[String]) -> f32 {
+        let seen = trigrams
+            .iter()
+            .filter(|t| self.log_probs.contains_key(*t))
+            .count();
+        seen as f32 / trigrams.len() as f32
+    }
+
+    /// Mean log-probability of `trigrams`
+    fn score(&self, trigrams: &[String]) -> f32 {
+        let unseen = UNSEEN_TRIGRAM.ln();
+        let total: f32 = trigrams
+            .iter()
+            .map(|t| self.log_probs.get(t).copied().unwrap_or(unseen))
+            .sum();
+        total / trigrams.len() as f32
+    }
+}
+
+/// A language identified from text, before the seed has its say
+#[derive(Debug, Clone, PartialEq)]
+struct Guess {
+    language: &'static str,
+    confidence: f32,
+    /// Chosen between languages sharing a script, rather than read off the
+    /// script alone
+    weighed: bool,
+}
+
+/// Tags text with its language.
+///
+/// See the [module documentation](crate::extraction::language) for which languages are identified
+/// and how much text that takes.
+///
+/// # Example
+/// ```
+/// use voxpdf_core::extraction::LanguageDetector;
+/// use voxpdf_core::models::LanguageSource;
+///
+/// let detector = LanguageDetector::for_language(Some("en-GB"));
+///
+/// let english = detector.detect("The committee will publish its findings next week.").unwrap();
+/// assert_eq!((english.tag.as_str(), english.source), ("en-GB", LanguageSource::Document));
+///
+/// let french = detector.detect("Le comité publiera ses conclusions la semaine prochaine.").unwrap();
+/// assert_eq!((french.tag.as_str(), french.source), ("fr", LanguageSource::Detected));
+/// ```
+#[derive(Debug, Clone)]
+pub struct LanguageDetector {
+    models: Vec<TrigramModel>,
+    /// BCP 47 tag of the document's language
+    seed: Option<String>,
+}
+
+impl Default for LanguageDetector {
+    fn default() -> Self {
+        Self::for_language(None)
+    }
+}
+
+impl LanguageDetector {
+    /// A detector seeded with a BCP 47 language tag ("en-US"), usually the
+    /// document's. Text in the seed language is tagged with the seed as
+    /// given, region and all.
+    pub fn for_language(seed: Option<&str>) -> Self {
+        let models = MODELS
+            .iter()
+            .map(|&(language, list)| TrigramModel::from_words(language, list))
+            .collect();
+        Self {
+            models,
+            seed: seed
+                .map(str::trim)
+                .filter(|s| !s.is_empty())
+                .map(str::to_string),
+        }
+    }
+
+    /// A detector seeded with the document's [`language`](PDFDocument::language)
+    pub fn for_document(doc: &PDFDocument) -> Result<Self> {
+        Ok(Self::for_language(doc.language()?))
+    }
+
+    /// The seed language
+    pub fn seed(&self) -> Option<&str> {
+        self.seed.as_deref()
+    }
+
+    /// Language of `text`. Without a seed, `None` if it's too short to
+    /// tell or in none of the languages with a model.
+    pub fn detect(&self, text: &str) -> Option<Language> {
+        self.detect_with_seed(text, self.seed.as_deref())
+    }
+
+    /// Tag paragraphs that don't have a language yet
+    pub fn tag_paragraphs(&self, paragraphs: &mut [Paragraph]) {
+        for para in paragraphs.iter_mut().filter(|p| p.language.is_none()) {
+            para.language = self.detect(&para.text);
+        }
+    }
+
+    /// Tag `paragraph`'s sentences: each keeps the paragraph's language
+    /// unless it is clearly in another. Tagged paragraphs aren't second
+    /// guessed.
+    pub fn tag_sentences(&self, paragraph: &Paragraph, sentences: &mut [Sentence]) {
+        let tagged = paragraph
+            .language
+            .as_ref()
+            .is_some_and(|l| l.source == LanguageSource::Tagged);
+        let seed = paragraph
+            .language
+            .as_ref()
+            .map(|l| l.tag.as_str())
+            .or(self.seed.as_deref());
+
+        for sentence in sentences {
+            sentence.language = paragraph.language.clone();
+            if tagged {
+                continue;
+            }
+            if let Some(language) = self.detect_with_seed(&sentence.text, seed) {
+                let switch = language.source == LanguageSource::Detected
+                    && language.confidence >= SENTENCE_SWITCH_CONFIDENCE;
+                if switch || sentence.language.is_none() {
+                    sentence.language = Some(language);
+                }
+            }
+        }
+    }
+
+    fn detect_with_seed(&self, text: &str, seed: Option<&str>) -> Option<Language> {
+        let unchecked =
+            || seed.map(|s| Language::new(s, UNCHECKED_CONFIDENCE, LanguageSource::Document));
+
+        let mut counts: HashMap<Script, usize> = HashMap::new();
+        for script in text.chars().filter_map(script_of) {
+            *counts.entry(script).or_default() += 1;
+        }
+        let letters: usize = counts.values().sum();
+        let Some((&script, &count)) = counts
+            .iter()
+            .max_by_key(|&(s, &n)| (n, *s == Script::Latin))
+        else {
+            return unchecked();
+        };
+        if letters < MIN_LETTERS {
+            return unchecked();
+        }
+
+        let seed_primary = seed.map(primary_subtag);
+        let seeded = seed_primary
+            .as_deref()
+            .is_some_and(|p| language_script(p) == script);
+        let share = count as f32 / letters as f32;
+        let guess = match script {
+            Script::Latin => self.latin_language(text, seed_primary.as_deref().filter(|_| seeded)),
+            Script::Cyrillic => Some(cyrillic_language(text)),
+            Script::Cjk => Some(Guess {
+                language: if text.chars().any(is_kana) {
+                    "ja"
+                } else {
+                    "zh"
+                },
+                confidence: share,
+                weighed: false,
+            }),
+            other => fixed_language(other).map(|language| Guess {
+                language,
+                confidence: share,
+                weighed: false,
+            }),
+        };
+        let Some(guess) = guess else {
+            return unchecked();
+        };
+
+        match (seed, seed_primary) {
+            (Some(seed), Some(primary)) if seeded => {
+                if guess.language == primary {
+                    Some(Language::new(
+                        seed,
+                        guess.confidence,
+                        LanguageSource::Document,
+                    ))
+                } else if !guess.weighed
+                    || (!has_model(&primary) && guess.confidence < OVERRULE_CONFIDENCE)
+                {
+                    // A language we can't check, written in the script we found
+                    unchecked()
+                } else {
+                    Some(Language::new(
+                        guess.language,
+                        guess.confidence,
+                        LanguageSource::Detected,
+                    ))
+                }
+            }
+            _ => Some(Language::new(
+                guess.language,
+                guess.confidence,
+                LanguageSource::Detected,
+            )),
+        }
+    }
+
+    /// Best trigram model for Latin-script `text`, favouring `seed`
+    fn latin_language(&self, text: &str, seed: Option<&str>) -> Option<Guess> {
+        let trigrams: Vec<String> = text
+            .split(|c: char| !(c.is_alphabetic() && script_of(c) == Some(Script::Latin)))
+            .filter(|w| !w.is_empty())
+            .flat_map(|w| word_trigrams(&w.to_lowercase()))
+            .collect();
+        if trigrams.is_empty() {
+            return None;
+        }
+
+        let mut scores: Vec<(&TrigramModel, f32)> = self
+            .models
+            .iter()
+            .map(|model| {
+                let bonus = if Some(model.language) == seed {
+                    SEED_BONUS
+                } else {
+                    0.0
+                };
+                (model, model.score(&trigrams) + bonus)
+            })
+            .collect();
+        scores.sort_by(|a, b| b.1.total_cmp(&a.1));
+
+        let &(model, best) = scores.first()?;
+        let coverage = model.coverage(&trigrams);
+        if coverage < MIN_COVERAGE {
+            return None;
+        }
+        let fit = ((coverage - MIN_COVERAGE) / (FULL_COVERAGE - MIN_COVERAGE)).min(1.0);
+        let runner_up = scores.get(1).map_or(best, |s| s.1);
+        let lead = (best - runner_up) * trigrams.len() as f32;
+        Some(Guess {
+            language: model.language,
+            confidence: fit * (1.0 - (-lead / CONFIDENCE_SCALE).exp()),
+            weighed: true,
+        })
+    }
+}
+
+/// Tag paragraphs with their language: from `/Lang` tags where the text was
+/// drawn under one, otherwise detected with the document's
+/// [`LanguageDetector`]. Paragraphs that already have a language keep it.
+pub fn detect_paragraph_languages(doc: &PDFDocument, paragraphs: &mut [Paragraph]) -> Result<()> {
+    let mut pages: Vec<u32> = paragraphs
+        .iter()
+        .flat_map(|p| p.words.iter().map(|w| w.page_number))
+        .collect();
+    pages.sort_unstable();
+    pages.dedup();
+
+    let marks = doc.language_marks(&pages)?;
+    apply_language_marks(paragraphs, &marks);
+    doc.language_detector()?.tag_paragraphs(paragraphs);
+    Ok(())
+}
+
+/// Give each untagged paragraph the language most of its words were drawn
+/// under, if most were drawn under one
+pub(crate) fn apply_language_marks(paragraphs: &mut [Paragraph], marks: &[LanguageMark]) {
+    if marks.is_empty() {
+        return;
+    }
+
+    for para in paragraphs.iter_mut().filter(|p| p.language.is_none()) {
+        let mut votes: Vec<(&str, usize)> = Vec::new();
+        for word in &para.words {
+            let Some(tag) = LanguageMark::for_word(marks, word).and_then(|m| m.language.as_deref())
+            else {
+                continue;
+            };
+            match votes.iter_mut().find(|(t, _)| *t == tag) {
+                Some((_, n)) => *n += 1,
+                None => votes.push((tag, 1)),
+            }
+        }
+        let winner = votes.into_iter().max_by_key(|&(_, n)| n);
+        if let Some((tag, _)) = winner.filter(|&(_, n)| 2 * n > para.words.len()) {
+            para.language = Some(Language::new(tag, 1.0, LanguageSource::Tagged));
+        }
+    }
+}
+
+/// Letter trigrams of a lowercase word, padded with a space at each end so
+/// starts and ends of words count
+fn word_trigrams(word: &str) -> Vec<String> {
+    let chars: Vec<char> = std::iter::once(' ')
+        .chain(word.chars())
+        .chain(std::iter::once(' '))
+        .collect();
+    chars.windows(3).map(|w| w.iter().collect()).collect()
+}
+
+fn script_of(c: char) -> Option<Script> {
+    if !c.is_alphabetic() {
+        return None;
+    }
+    let script = match c as u32 {
+        0x41..=0x5A | 0x61..=0x7A | 0xAA | 0xBA | 0xC0..=0x24F | 0x1E00..=0x1EFF => Script::Latin,
+        0x370..=0x3FF | 0x1F00..=0x1FFF => Script::Greek,
+        0x400..=0x52F => Script::Cyrillic,
+        0x530..=0x58F => Script::Armenian,
+        0x590..=0x5FF => Script::Hebrew,
+        0x600..=0x6FF | 0x750..=0x77F | 0xFB50..=0xFDFF | 0xFE70..=0xFEFF => Script::Arabic,
+        0x900..=0x97F => Script::Devanagari,
+        0x980..=0x9FF => Script::Bengali,
+        0xB80..=0xBFF => Script::Tamil,
+        0xE00..=0xE7F => Script::Thai,
+        0x10A0..=0x10FF => Script::Georgian,
+        0x1100..=0x11FF | 0x3130..=0x318F | 0xAC00..=0xD7AF => Script::Hangul,
+        0x3040..=0x30FF | 0x31F0..=0x31FF | 0xFF66..=0xFF9F => Script::Cjk,
+        0x3400..=0x4DBF | 0x4E00..=0x9FFF | 0xF900..=0xFAFF => Script::Cjk,
+        _ => return None,
+    };
+    Some(script)
+}
+
+fn is_kana(c: char) -> bool {
+    matches!(c as u32, 0x3040..=0x30FF | 0x31F0..=0x31FF | 0xFF66..=0xFF9F)
+}
+
+/// Script a language is usually written in
+fn language_script(primary: &str) -> Script {
+    match primary {
+        "ru" | "uk" | "bg" | "sr" | "mk" | "be" | "kk" | "ky" | "mn" | "tg" => Script::Cyrillic,
+        "el" => Script::Greek,
+        "ar" | "fa" | "ur" | "ps" | "ug" => Script::Arabic,
+        "he" | "yi" => Script::Hebrew,
+        "hi" | "mr" | "ne" | "sa" => Script::Devanagari,
+        "bn" | "as" => Script::Bengali,
+        "ta" => Script::Tamil,
+        "th" => Script::Thai,
+        "ka" => Script::Georgian,
+        "hy" => Script::Armenian,
+        "ko" => Script::Hangul,
+        "ja" | "zh" | "yue" => Script::Cjk,
+        _ => Script::Latin,
+    }
+}
+
+/// The language most often written in a script with no models of its own
+fn fixed_language(script: Script) -> Option<&'static str> {
+    Some(match script {
+        Script::Greek => "el",
+        Script::Arabic => "ar",
+        Script::Hebrew => "he",
+        Script::Devanagari => "hi",
+        Script::Bengali => "bn",
+        Script::Tamil => "ta",
+        Script::Thai => "th",
+        Script::Georgian => "ka",
+        Script::Armenian => "hy",
+        Script::Hangul => "ko",
+        Script::Latin | Script::Cyrillic | Script::Cjk => return None,
+    })
+}
+
+/// True if detection can weigh `primary` against other languages of its
+/// script
+fn has_model(primary: &str) -> bool {
+    MODELS.iter().any(|(language, _)| *language == primary) || CYRILLIC_LANGUAGES.contains(&primary)
+}
+
+/// Russian, Ukrainian, Bulgarian or Serbian, by the letters only one of
+/// them uses
+fn cyrillic_language(text: &str) -> Guess {
+    let count = |letters: &str| {
+        text.chars()
+            .flat_map(char::to_lowercase)
+            .filter(|c| letters.contains(*c))
+            .count()
+    };
+    let serbian = count("ђћџљњј");
+    let ukrainian = count("іїєґ");
+    let russian = count("ыэё");
+    let hard_sign = count("ъ");
+
+    let (language, evidence) = if serbian > 0 {
+        ("sr", serbian)
+    } else if ukrainian > russian {
+        ("uk", ukrainian)
+    } else if russian > 0 {
+        ("ru", russian)
+    } else if hard_sign > 1 {
+        // Bulgarian writes ъ as a vowel; Russian rarely needs it
+        ("bg", hard_sign)
+    } else {
+        ("ru", 0)
+    };
+    Guess {
+        language,
+        confidence: 1.0 - 0.5f32.powi(evidence.min(30) as i32 + 1),
+        weighed: true,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::{line_paragraph, paragraph};
+
+    fn detect(text: &str) -> Option<(String, LanguageSource)> {
+        LanguageDetector::default()
+            .detect(text)
+            .map(|l| (l.tag, l.source))
+    }
+
+    #[test]
+    fn test_latin_languages() {
+        let samples = [
+            (
+                "en",
+                "The weather was cold, so they stayed at home and read a book.",
+            ),
+            (
+                "de",
+                "Das Wetter war kalt, deshalb blieben sie zu Hause und lasen ein Buch.",
+            ),
+            (
+                "fr",
+                "Il faisait froid, alors ils sont restés à la maison pour lire un livre.",
+            ),
+            (
+                "es",
+                "Hacía frío, así que se quedaron en casa y leyeron un libro.",
+            ),
+            (
+                "it",
+                "Faceva freddo, così sono rimasti a casa e hanno letto un libro.",
+            ),
+            (
+                "pt",
+                "Estava frio, então eles ficaram em casa e leram um livro.",
+            ),
+            (
+                "nl",
+                "Het was koud, dus bleven ze thuis en lazen ze een boek.",
+            ),
+        ];
+        for (language, text) in samples {
+            let detected = LanguageDetector::default().detect(text).unwrap();
+            assert_eq!(detected.tag, language, "{text}");
+            assert_eq!(detected.source, LanguageSource::Detected);
+            assert!(detected.confidence > 0.5, "{text}: {}", detected.confidence);
+        }
+    }
+
+    #[test]
+    fn test_close_languages() {
+        // Romance and West Germanic neighbours, on the same sentences
+        let samples = [
+            (
+                "es",
+                "La ciudad tiene muchos parques y los niños juegan allí todos los días \
+                 después de la escuela.",
+            ),
+            (
+                "pt",
+                "A cidade tem muitos parques e as crianças brincam lá todos os dias depois \
+                 da escola.",
+            ),
+            (
+                "it",
+                "La città ha molti parchi e i bambini ci giocano tutti i giorni dopo la scuola.",
+            ),
+            (
+                "es",
+                "El gobierno anunció nuevas medidas para reducir el precio de la energía \
+                 este invierno.",
+            ),
+            (
+                "pt",
+                "O governo anunciou novas medidas para reduzir o preço da energia neste inverno.",
+            ),
+            (
+                "it",
+                "Il governo ha annunciato nuove misure per ridurre il prezzo dell'energia \
+                 quest'inverno.",
+            ),
+            ("es", "Tenemos que hablar con ella mañana."),
+            ("pt", "Temos que falar com ela amanhã."),
+            ("it", "Dobbiamo parlare con lei domani."),
+            (
+                "nl",
+                "De stad heeft veel parken en de kinderen spelen daar elke dag na school.",
+            ),
+            (
+                "de",
+                "Die Stadt hat viele Parks und die Kinder spielen dort jeden Tag nach der Schule.",
+            ),
+            (
+                "nl",
+                "De regering heeft nieuwe maatregelen aangekondigd om de energieprijs deze \
+                 winter te verlagen.",
+            ),
+            (
+                "de",
+                "Die Regierung hat neue Maßnahmen angekündigt, um den Energiepreis in diesem \
+                 Winter zu senken.",
+            ),
+            ("nl", "We moeten morgen met haar praten."),
+            ("de", "Wir müssen morgen mit ihr sprechen."),
+        ];
+        for (language, text) in samples {
+            let detected = LanguageDetector::default().detect(text).unwrap();
+            assert_eq!(detected.tag, language, "{text}");
+            assert!(detected.confidence > 0.8, "{text}: {}", detected.confidence);
+        }
+    }
+
+    #[test]
+    fn test_scripts() {
+        assert_eq!(
+            detect("Η γλώσσα του κειμένου είναι ελληνική").unwrap().0,
+            "el"
+        );
+        assert_eq!(
+            detect("これは日本語の文章です。漢字もあります").unwrap().0,
+            "ja"
+        );
+        assert_eq!(detect("这是一个用中文写的句子，没有假名").unwrap().0, "zh");
+        assert_eq!(detect("이 문장은 한국어로 쓰여 있습니다").unwrap().0, "ko");
+        assert_eq!(
+            detect("Это предложение написано по-русски").unwrap().0,
+            "ru"
+        );
+        assert_eq!(
+            detect("Це речення написане українською мовою").unwrap().0,
+            "uk"
+        );
+        assert_eq!(detect("עברית היא שפה שמית").unwrap().0, "he");
+    }
+
+    #[test]
+    fn test_seed() {
+        let detector = LanguageDetector::for_language(Some("de-CH"));
+        // Too short to tell: the seed, unchecked
+        let short = detector.detect("Ja.").unwrap();
+        assert_eq!(short.tag, "de-CH");
+        assert_eq!(short.confidence, UNCHECKED_CONFIDENCE);
+        assert_eq!(LanguageDetector::default().detect("Ja."), None);
+
+        // Agreeing text keeps the seed's region
+        let agreed = detector
+            .detect("Die Ergebnisse werden in der nächsten Woche veröffentlicht.")
+            .unwrap();
+        assert_eq!(agreed.tag, "de-CH");
+        assert_eq!(agreed.source, LanguageSource::Document);
+
+        // A language without a model keeps its seed against weak evidence
+        let swedish = LanguageDetector::for_language(Some("sv"))
+            .detect("Vädret var kallt, så de stannade hemma.")
+            .unwrap();
+        assert_eq!(swedish.tag, "sv");
+        // ...and text far from every model isn't forced into one
+        let polish = "Pogoda była zimna, więc zostali w domu i czytali książkę.";
+        assert_eq!(LanguageDetector::default().detect(polish), None);
+        // Script-only guesses never overrule a seed in the same script
+        let marathi = LanguageDetector::for_language(Some("mr"))
+            .detect("हे वाक्य मराठी भाषेत लिहिलेले आहे")
+            .unwrap();
+        assert_eq!(
+            (marathi.tag.as_str(), marathi.source),
+            ("mr", LanguageSource::Document)
+        );
+        let japanese = LanguageDetector::for_language(Some("ja"))
+            .detect("東京都庁舎は新宿区西新宿に所在する")
+            .unwrap();
+        assert_eq!(japanese.tag, "ja");
+    }
+
+    #[test]
+    fn test_sentences() {
+        let detector = LanguageDetector::for_language(Some("en"));
+        let mut para = paragraph(
+            0,
+            "The sign on the door of the old bakery said it plainly. \
+             Nous sommes fermés pour les vacances jusqu'à la fin du mois.",
+        );
+        detector.tag_paragraphs(std::slice::from_mut(&mut para));
+        assert_eq!(para.language.as_ref().unwrap().tag, "en");
+
+        let mut sentences = crate::extraction::SentenceSplitter::default().split(&para);
+        detector.tag_sentences(&para, &mut sentences);
+        let tags: Vec<&str> = sentences
+            .iter()
+            .map(|s| s.language.as_ref().unwrap().tag.as_str())
+            .collect();
+        assert_eq!(tags, ["en", "fr"]);
+
+        // Tagged paragraphs are trusted
+        para.language = Some(Language::new("en-US", 1.0, LanguageSource::Tagged));
+        detector.tag_sentences(&para, &mut sentences);
+        assert!(sentences
+            .iter()
+            .all(|s| s.language.as_ref().unwrap().tag == "en-US"));
+    }
+
+    #[test]
+    fn test_language_marks() {
+        let mut paragraphs = vec![
+            line_paragraph(0, 0, 0.0, 10.0, "Bonjour tout le monde"),
+            line_paragraph(1, 0, 100.0, 10.0, "Hello world"),
+            line_paragraph(2, 0, 200.0, 10.0, "Hello there, dear reader"),
+        ];
+        let mark = |language: Option<&str>, x: f32, y: f32| LanguageMark {
+            language: language.map(str::to_string),
+            page_number: 0,
+            x,
+            y,
+            sequence: 0,
+        };
+        let marks = [
+            mark(Some("fr-FR"), 72.0, 8.0),
+            mark(None, 72.0, 108.0),
+            // Only "dear reader" is tagged: not enough for the paragraph
+            mark(None, 72.0, 208.0),
+            mark(Some("en-GB"), 192.0, 208.0),
+        ];
+        apply_language_marks(&mut paragraphs, &marks);
+
+        let language = paragraphs[0].language.as_ref().unwrap();
+        assert_eq!(
+            (language.tag.as_str(), language.source),
+            ("fr-FR", LanguageSource::Tagged)
+        );
+        assert_eq!(paragraphs[1].language, None);
+        assert_eq!(paragraphs[2].language, None);
+    }
+}
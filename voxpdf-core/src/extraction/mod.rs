@@ -1,15 +1,38 @@
+pub mod columns;
+pub(crate) mod fonts;
+pub mod headings;
 pub mod hyphenation;
+pub mod language;
+pub mod options;
 pub mod paragraphs;
 pub mod parallel;
+pub mod printed_toc;
+pub mod profiles;
+pub mod running_text;
+pub mod sentences;
+pub mod stitching;
 pub mod streaming;
 pub mod text;
 pub mod toc;
 pub mod words;
 
-pub use hyphenation::reassemble_hyphenated_words;
-pub use paragraphs::detect_paragraphs;
-pub use parallel::{extract_pages_parallel, PageExtractionResult};
-pub use streaming::{StreamingExtractor, ExtractionEvent};
+pub use columns::{detect_column_layout, order_words, ColumnLayout, TextRegion};
+pub use headings::infer_toc;
+pub use hyphenation::{reassemble_hyphenated_words, Dehyphenator, HyphenJoin, WordList};
+pub use language::{detect_paragraph_languages, LanguageDetector};
+pub use options::ExtractionOptions;
+pub use paragraphs::{detect_paragraphs, detect_paragraphs_with_options};
+pub use parallel::{
+    extract_pages_parallel, extract_pages_parallel_with_options, PageExtractionResult,
+};
+pub use printed_toc::{extract_printed_toc, PrintedToc};
+pub use profiles::{detect_profile, ExtractionProfile, ProfileMatch};
+pub use running_text::{detect_running_text, RunningText};
+pub use sentences::SentenceSplitter;
+pub use stitching::{extract_document_paragraphs, stitch_paragraphs};
+pub use streaming::{ExtractionEvent, StreamingExtractor};
 pub use text::extract_page_text;
-pub use toc::extract_toc;
-pub use words::extract_word_positions;
+pub use toc::{extract_outline, extract_toc, extract_toc_tree, map_toc_to_paragraphs};
+pub use words::{
+    extract_column_layout, extract_word_positions, extract_word_positions_with_options,
+};
@@ -0,0 +1,191 @@
+use serde::{Deserialize, Serialize};
+
+/// Tuning knobs for word, line and paragraph detection.
+///
+/// Distances are ratios of the font size (or line height, or space width)
+/// rather than points, so one set of options works for 9pt footnotes and
+/// 24pt headings alike.
+/// Deserializing fills missing fields with defaults, so partial JSON such as
+/// `{"paragraph_gap_ratio": 1.6}` is accepted.
+///
+/// # Example
+/// ```no_run
+/// use voxpdf_core::PDFDocument;
+/// use voxpdf_core::extraction::{extract_word_positions, ExtractionOptions};
+///
+/// let mut doc = PDFDocument::open("example.pdf")?;
+/// doc.set_extraction_options(ExtractionOptions {
+///     word_gap_space_ratio: 0.75,
+///     ..Default::default()
+/// });
+/// let words = extract_word_positions(&doc, 0)?;
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ExtractionOptions {
+    /// Gap between characters that starts a new word, as a fraction of the
+    /// font's space width. Space widths are measured from the page's space
+    /// characters, or taken as a quarter em if there are none (0.6 = 1.8pt
+    /// at 12pt).
+    pub word_gap_space_ratio: f32,
+
+    /// Maximum vertical offset between words on the same line, as a fraction
+    /// of the font size
+    pub line_tolerance_ratio: f32,
+
+    /// Vertical spacing between lines that always starts a new paragraph, as
+    /// a multiple of the line height
+    pub paragraph_gap_ratio: f32,
+
+    /// Font size change between lines (either direction) that starts a new
+    /// paragraph, e.g. 1.15 for a 15% jump into or out of a heading
+    pub heading_size_ratio: f32,
+
+    /// Change in left edge between lines that counts as an indent, as a
+    /// fraction of the font size
+    pub indent_ratio: f32,
+
+    /// Line spacing (multiple of line height) needed alongside an indent
+    /// change to start a new paragraph
+    pub indent_gap_ratio: f32,
+
+    /// A line narrower than this fraction of its neighbour's average width is
+    /// "short" (a heading or the end of a paragraph)
+    pub short_line_ratio: f32,
+
+    /// Line spacing (multiple of line height) needed after a short line to
+    /// start a new paragraph
+    pub short_line_gap_ratio: f32,
+
+    /// Reorder words into reading order by detecting columns (see
+    /// [`detect_column_layout`](crate::extraction::detect_column_layout)).
+    /// Single-column pages are left in stream order
+    pub detect_columns: bool,
+
+    /// Minimum width of a column gutter, as a fraction of the body font size
+    pub column_gap_ratio: f32,
+
+    /// Rejoin words hyphenated across line breaks ("exam-" + "ple" becomes
+    /// "example", "well-" + "known" stays "well-known") in parallel, streaming
+    /// and stitched extraction; see
+    /// [`Dehyphenator`](crate::extraction::Dehyphenator)
+    pub dehyphenate: bool,
+
+    /// Drop footnotes: trailing paragraphs on a page set smaller than the
+    /// page's body text
+    pub skip_footnotes: bool,
+
+    /// Font size, as a fraction of the body text size, below which a trailing
+    /// paragraph counts as a footnote
+    pub footnote_size_ratio: f32,
+
+    /// Keep running headers, footers and page numbers in the output (tagged
+    /// with their [`ParagraphKind`](crate::models::ParagraphKind)) instead of
+    /// removing them
+    pub keep_running_text: bool,
+
+    /// Depth of the top and bottom margins searched for running text, as a
+    /// fraction of the page height
+    pub running_text_margin: f32,
+
+    /// Infer a table of contents from heading typography when the document
+    /// has no outline (see [`infer_toc`](crate::extraction::infer_toc))
+    pub infer_toc: bool,
+
+    /// Minimum confidence (0–1) for an inferred heading to be kept
+    pub min_heading_confidence: f32,
+
+    /// Read each word's font name, weight, style and fill colour (see
+    /// [`Word::font`](crate::models::Word::font)), which SSML emphasis and
+    /// heading inference use. Costs an extra pass over every page; turn it
+    /// off if neither is needed.
+    pub font_attributes: bool,
+
+    /// Keep the box of every character in
+    /// [`Word::glyphs`](crate::models::Word::glyphs), for highlighting
+    /// individual characters
+    pub glyph_boxes: bool,
+
+    /// Tag each paragraph with its language (see
+    /// [`Paragraph::language`](crate::models::Paragraph::language)): from
+    /// tagged-PDF `/Lang` attributes where present, otherwise identified from
+    /// the text and seeded with the document's `/Lang`. Identification from
+    /// the text covers a handful of languages; see
+    /// [`LanguageDetector`](crate::extraction::LanguageDetector)
+    pub detect_language: bool,
+}
+
+impl ExtractionOptions {
+    /// Gap along the baseline, in points, that starts a new word in a font of
+    /// `font_size` whose space is `space_width` em wide
+    pub(crate) fn word_gap(&self, font_size: f32, space_width: f32) -> f32 {
+        font_size * space_width * self.word_gap_space_ratio
+    }
+}
+
+impl Default for ExtractionOptions {
+    fn default() -> Self {
+        Self {
+            word_gap_space_ratio: 0.6,
+            line_tolerance_ratio: 0.4,
+            paragraph_gap_ratio: 2.0,
+            heading_size_ratio: 1.15,
+            indent_ratio: 0.8,
+            indent_gap_ratio: 1.3,
+            short_line_ratio: 0.6,
+            short_line_gap_ratio: 1.2,
+            detect_columns: true,
+            column_gap_ratio: 1.0,
+            dehyphenate: true,
+            skip_footnotes: false,
+            footnote_size_ratio: 0.85,
+            keep_running_text: false,
+            running_text_margin: 0.1,
+            infer_toc: true,
+            min_heading_confidence: 0.5,
+            font_attributes: true,
+            glyph_boxes: false,
+            detect_language: true,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_partial_json_uses_defaults() {
+        let options: ExtractionOptions =
+            serde_json::from_str(r#"{"paragraph_gap_ratio": 1.6}"#).unwrap();
+
+        assert_eq!(options.paragraph_gap_ratio, 1.6);
+        assert_eq!(
+            options.word_gap_space_ratio,
+            ExtractionOptions::default().word_gap_space_ratio
+        );
+    }
+
+    #[test]
+    fn test_word_gap_uses_space_width() {
+        let options = ExtractionOptions::default();
+        // 0.6 of a 0.25em space at 12pt
+        assert!((options.word_gap(12.0, 0.25) - 1.8).abs() < 1e-4);
+        // Wider spaces widen the gap
+        assert!((options.word_gap(12.0, 0.5) - 3.6).abs() < 1e-4);
+    }
+
+    #[test]
+    fn test_json_roundtrip() {
+        let options = ExtractionOptions {
+            indent_ratio: 1.5,
+            ..Default::default()
+        };
+        let json = serde_json::to_string(&options).unwrap();
+        assert_eq!(
+            serde_json::from_str::<ExtractionOptions>(&json).unwrap(),
+            options
+        );
+    }
+}
@@ -1,9 +1,19 @@
+use crate::extraction::ExtractionOptions;
 use crate::models::{Paragraph, Word};
 
 const ESTIMATED_LINES_PER_PAGE: usize = 50;
 const ESTIMATED_PARAGRAPHS_PER_PAGE: usize = 10;
 
+/// Group words into paragraphs using the default [`ExtractionOptions`].
 pub fn detect_paragraphs(words: Vec<Word>) -> Vec<Paragraph> {
+    detect_paragraphs_with_options(words, &ExtractionOptions::default())
+}
+
+/// Group words into paragraphs using explicit [`ExtractionOptions`].
+pub fn detect_paragraphs_with_options(
+    words: Vec<Word>,
+    options: &ExtractionOptions,
+) -> Vec<Paragraph> {
     if words.is_empty() {
         return Vec::new();
     }
@@ -11,13 +21,22 @@ pub fn detect_paragraphs(words: Vec<Word>) -> Vec<Paragraph> {
     // Algorithm:
     // 1. Group words into lines by Y-position
     // 2. Merge lines into paragraphs by spacing
-    // 3. Break on large vertical gaps (>2x line height)
+    // 3. Break on large vertical gaps (>2x line height by default)
+
+    let lines = group_words_into_lines(words, options);
+    let paragraphs = merge_lines_into_paragraphs(lines, options);
 
-    let lines = group_words_into_lines(words);
-    merge_lines_into_paragraphs(lines)
+    if options.skip_footnotes {
+        drop_footnotes(paragraphs, options.footnote_size_ratio)
+    } else {
+        paragraphs
+    }
 }
 
-fn group_words_into_lines(words: Vec<Word>) -> Vec<Vec<Word>> {
+pub(crate) fn group_words_into_lines(
+    words: Vec<Word>,
+    options: &ExtractionOptions,
+) -> Vec<Vec<Word>> {
     if words.is_empty() {
         return Vec::new();
     }
@@ -25,12 +44,13 @@ fn group_words_into_lines(words: Vec<Word>) -> Vec<Vec<Word>> {
     let mut lines: Vec<Vec<Word>> = Vec::with_capacity(ESTIMATED_LINES_PER_PAGE);
     let mut current_line: Vec<Word> = Vec::with_capacity(20); // Average words per line
     let mut current_y = words[0].bounds.y;
-
-    const Y_THRESHOLD: f32 = 5.0; // Words within 5pts are on same line
+    let mut current_size = words[0].font_size;
 
     for word in words {
         let word_y = word.bounds.y;
-        if (word_y - current_y).abs() < Y_THRESHOLD {
+        // Words within a fraction of the font size are on the same line
+        let tolerance = current_size.max(word.font_size) * options.line_tolerance_ratio;
+        if (word_y - current_y).abs() < tolerance {
             // Same line
             current_line.push(word);
         } else {
@@ -39,6 +59,7 @@ fn group_words_into_lines(words: Vec<Word>) -> Vec<Vec<Word>> {
                 lines.push(current_line);
             }
             current_y = word_y;
+            current_size = word.font_size;
             current_line = vec![word];
         }
     }
@@ -50,7 +71,10 @@ fn group_words_into_lines(words: Vec<Word>) -> Vec<Vec<Word>> {
     lines
 }
 
-fn merge_lines_into_paragraphs(lines: Vec<Vec<Word>>) -> Vec<Paragraph> {
+fn merge_lines_into_paragraphs(
+    lines: Vec<Vec<Word>>,
+    options: &ExtractionOptions,
+) -> Vec<Paragraph> {
     if lines.is_empty() {
         return Vec::new();
     }
@@ -71,7 +95,7 @@ fn merge_lines_into_paragraphs(lines: Vec<Vec<Word>>) -> Vec<Paragraph> {
                 prev_line = Some(line);
             }
             Some(prev) => {
-                if should_break_paragraph(prev, line) {
+                if should_break_paragraph(prev, line, options) {
                     // Finish current paragraph
                     paragraphs.push(create_paragraph_from_lines(
                         paragraphs.len(),
@@ -100,13 +124,19 @@ fn merge_lines_into_paragraphs(lines: Vec<Vec<Word>>) -> Vec<Paragraph> {
 }
 
 /// Determine if we should start a new paragraph based on multiple heuristics
-fn should_break_paragraph(prev_line: &[Word], current_line: &[Word]) -> bool {
+fn should_break_paragraph(
+    prev_line: &[Word],
+    current_line: &[Word],
+    options: &ExtractionOptions,
+) -> bool {
     if prev_line.is_empty() || current_line.is_empty() {
         return false;
     }
 
     let prev_y = prev_line[0].bounds.y;
-    let prev_height = prev_line[0].bounds.height;
+    // Word boxes span the font's ascender to descender, which varies from
+    // font to font; the gap ratios are tuned against the em size
+    let prev_height = prev_line[0].font_size;
     let prev_font_size = prev_line[0].font_size;
     let prev_x = prev_line[0].bounds.x;
 
@@ -116,27 +146,29 @@ fn should_break_paragraph(prev_line: &[Word], current_line: &[Word]) -> bool {
 
     let spacing = (current_y - prev_y).abs();
 
-    // Heuristic 1: Large vertical spacing (> 2x line height)
-    if spacing > prev_height * 2.0 {
+    // Heuristic 1: Large vertical spacing (> 2x line height by default)
+    if spacing > prev_height * options.paragraph_gap_ratio {
         return true;
     }
 
     // Heuristic 2: Font size increase (likely a heading)
     // If current line has significantly larger font (>15% increase), it's likely a heading
-    if current_font_size > prev_font_size * 1.15 {
+    if current_font_size > prev_font_size * options.heading_size_ratio {
         return true;
     }
 
     // Heuristic 3: Font size decrease after larger font (end of heading)
     // If previous line had larger font and current line is smaller, break paragraph
-    if prev_font_size > current_font_size * 1.15 {
+    if prev_font_size > current_font_size * options.heading_size_ratio {
         return true;
     }
 
     // Heuristic 4: Significant indentation change combined with spacing
-    // If indentation changes significantly (> 10pt) AND there's moderate spacing (> 1.3x height)
+    // If indentation changes significantly (> ~1em) AND there's moderate spacing (> 1.3x height)
     let indent_change = (current_x - prev_x).abs();
-    if indent_change > 10.0 && spacing > prev_height * 1.3 {
+    if indent_change > prev_font_size * options.indent_ratio
+        && spacing > prev_height * options.indent_gap_ratio
+    {
         return true;
     }
 
@@ -147,13 +179,50 @@ fn should_break_paragraph(prev_line: &[Word], current_line: &[Word]) -> bool {
     let avg_line_width = (prev_line_width + current_line_width) / 2.0;
 
     // If previous line is short and next line is normal length with moderate spacing
-    if prev_line_width < avg_line_width * 0.6 && spacing > prev_height * 1.2 {
+    if prev_line_width < avg_line_width * options.short_line_ratio
+        && spacing > prev_height * options.short_line_gap_ratio
+    {
         return true;
     }
 
     false
 }
 
+/// Most common font size (to the nearest half point), weighted by word count
+pub(crate) fn body_font_size<'a>(words: impl IntoIterator<Item = &'a Word>) -> Option<f32> {
+    let mut counts: Vec<(i32, usize)> = Vec::new();
+    for word in words {
+        let key = (word.font_size * 2.0).round() as i32;
+        match counts.iter_mut().find(|(k, _)| *k == key) {
+            Some((_, count)) => *count += 1,
+            None => counts.push((key, 1)),
+        }
+    }
+
+    counts
+        .into_iter()
+        .max_by_key(|&(key, count)| (count, key))
+        .map(|(key, _)| key as f32 / 2.0)
+}
+
+/// Remove trailing paragraphs set noticeably smaller than the body text
+fn drop_footnotes(mut paragraphs: Vec<Paragraph>, size_ratio: f32) -> Vec<Paragraph> {
+    let Some(body_size) = body_font_size(paragraphs.iter().flat_map(|p| &p.words)) else {
+        return paragraphs;
+    };
+
+    while let Some(last) = paragraphs.last() {
+        let size =
+            last.words.iter().map(|w| w.font_size).sum::<f32>() / last.words.len().max(1) as f32;
+        if size >= body_size * size_ratio {
+            break;
+        }
+        paragraphs.pop();
+    }
+
+    paragraphs
+}
+
 fn create_paragraph_from_lines(index: usize, lines: Vec<Vec<Word>>) -> Paragraph {
     let mut all_words: Vec<Word> = Vec::new();
     let mut text_parts: Vec<String> = Vec::new();
@@ -182,7 +251,7 @@ mod tests {
             Word::new("World", Rect::new(50.0, 100.0, 30.0, 12.0), 0, 12.0),
         ];
 
-        let lines = group_words_into_lines(words);
+        let lines = group_words_into_lines(words, &ExtractionOptions::default());
         assert_eq!(lines.len(), 1);
         assert_eq!(lines[0].len(), 2);
     }
@@ -194,7 +263,54 @@ mod tests {
             Word::new("Line2", Rect::new(10.0, 120.0, 30.0, 12.0), 0, 12.0),
         ];
 
-        let lines = group_words_into_lines(words);
+        let lines = group_words_into_lines(words, &ExtractionOptions::default());
         assert_eq!(lines.len(), 2);
     }
+
+    #[test]
+    fn test_paragraph_gap_ratio_is_configurable() {
+        // Two full-width lines 2.5 line heights apart
+        let words = vec![
+            Word::new("First", Rect::new(10.0, 100.0, 200.0, 12.0), 0, 12.0),
+            Word::new("Second", Rect::new(10.0, 130.0, 200.0, 12.0), 0, 12.0),
+        ];
+
+        assert_eq!(detect_paragraphs(words.clone()).len(), 2);
+
+        let options = ExtractionOptions {
+            paragraph_gap_ratio: 3.0,
+            ..Default::default()
+        };
+        assert_eq!(detect_paragraphs_with_options(words, &options).len(), 1);
+    }
+
+    #[test]
+    fn test_skip_footnotes() {
+        let words = vec![
+            Word::new("Body", Rect::new(10.0, 100.0, 200.0, 12.0), 0, 12.0),
+            Word::new("text", Rect::new(10.0, 114.0, 200.0, 12.0), 0, 12.0),
+            Word::new("¹Footnote", Rect::new(10.0, 700.0, 200.0, 8.0), 0, 8.0),
+        ];
+
+        assert_eq!(detect_paragraphs(words.clone()).len(), 2);
+
+        let options = ExtractionOptions {
+            skip_footnotes: true,
+            ..Default::default()
+        };
+        let paragraphs = detect_paragraphs_with_options(words, &options);
+        assert_eq!(paragraphs.len(), 1);
+        assert_eq!(paragraphs[0].text, "Body text");
+    }
+
+    #[test]
+    fn test_body_font_size() {
+        let words = vec![
+            Word::new("a", Rect::new(0.0, 0.0, 1.0, 1.0), 0, 10.1),
+            Word::new("b", Rect::new(0.0, 0.0, 1.0, 1.0), 0, 9.9),
+            Word::new("c", Rect::new(0.0, 0.0, 1.0, 1.0), 0, 18.0),
+        ];
+        assert_eq!(body_font_size(&words), Some(10.0));
+        assert_eq!(body_font_size(&[]), None);
+    }
 }
@@ -1,20 +1,30 @@
-use rayon::prelude::*;
 use crate::error::Result;
-use crate::models::{Word, Paragraph};
-use crate::pdf::PDFDocument;
-use crate::extraction::{extract_word_positions, detect_paragraphs, reassemble_hyphenated_words};
+use crate::extraction::language::apply_language_marks;
+use crate::extraction::running_text::running_text_for;
+use crate::extraction::words::extract_words_with_layout;
+use crate::extraction::{
+    detect_paragraphs_with_options, ColumnLayout, Dehyphenator, ExtractionOptions,
+};
+use crate::models::{Paragraph, Word};
+use crate::pdf::{LanguageMark, PDFDocument};
+use rayon::prelude::*;
 
 pub struct PageExtractionResult {
     pub page_num: u32,
     pub words: Vec<Word>,
     pub paragraphs: Vec<Paragraph>,
+    /// Columns the words were ordered by
+    pub layout: ColumnLayout,
 }
 
 /// Extract multiple pages in parallel
 ///
 /// **Important Performance Notes:**
 /// - Since MuPDF's Document type is not thread-safe (`!Sync`), each thread must open
-///   its own copy of the document
+///   its own copy of the document. Copies are opened from the document's
+///   [`DocumentSource`](crate::pdf::DocumentSource), so in-memory documents share
+///   one buffer instead of touching the filesystem, and encrypted documents are
+///   unlocked with the password the caller already authenticated with
 /// - This creates overhead that can outweigh parallelization benefits for small to
 ///   moderate page counts
 /// - This function uses chunking to batch pages per thread, amortizing document
@@ -26,6 +36,9 @@ pub struct PageExtractionResult {
 ///
 /// For small documents (<20 pages), sequential extraction may be faster.
 ///
+/// Uses the document's [`ExtractionOptions`]; see
+/// [`extract_pages_parallel_with_options`] to override them.
+///
 /// # Example
 /// ```no_run
 /// use voxpdf_core::{PDFDocument, extraction::extract_pages_parallel};
@@ -39,30 +52,54 @@ pub fn extract_pages_parallel(
     doc: &PDFDocument,
     page_nums: Vec<u32>,
 ) -> Result<Vec<PageExtractionResult>> {
-    let doc_path = doc.path().to_string();
+    extract_pages_parallel_with_options(doc, page_nums, doc.extraction_options())
+}
+
+/// Extract multiple pages in parallel using explicit [`ExtractionOptions`]
+///
+/// See [`extract_pages_parallel`] for performance notes.
+pub fn extract_pages_parallel_with_options(
+    doc: &PDFDocument,
+    page_nums: Vec<u32>,
+    options: &ExtractionOptions,
+) -> Result<Vec<PageExtractionResult>> {
+    doc.ensure_unlocked()?;
+    let reopen = doc.reopener();
+    // Learned once up front; headers are a property of the whole document
+    let running = running_text_for(doc, options)?;
 
     // Use chunks to batch pages per thread
     // This amortizes the cost of opening the document
     let chunk_size = (page_nums.len() / rayon::current_num_threads()).max(1);
 
-    let results: Result<Vec<Vec<PageExtractionResult>>> = page_nums
+    let results: Result<Vec<Vec<_>>> = page_nums
         .par_chunks(chunk_size)
         .map(|chunk| {
             // Each thread opens one copy of the document
             // and processes multiple pages with it
-            let thread_doc = PDFDocument::open(&doc_path)?;
+            let thread_doc = reopen()?;
 
             let mut chunk_results = Vec::with_capacity(chunk.len());
             for &page_num in chunk {
-                let words = extract_word_positions(&thread_doc, page_num)?;
-                let mut paragraphs = detect_paragraphs(words.clone());
-                paragraphs = reassemble_hyphenated_words(paragraphs);
+                let (words, layout) = extract_words_with_layout(&thread_doc, page_num, options)?;
+                let mut paragraphs = detect_paragraphs_with_options(words.clone(), options);
+                let height = thread_doc.page_info(page_num)?.height();
+                paragraphs = running.apply(paragraphs, height, options);
+                // Language tags are read here, where the pages are scanned
+                // in parallel, and applied once the text is final
+                let marks = if options.detect_language {
+                    thread_doc.language_marks(&[page_num])?
+                } else {
+                    Vec::new()
+                };
 
-                chunk_results.push(PageExtractionResult {
+                let result = PageExtractionResult {
                     page_num,
                     words,
                     paragraphs,
-                });
+                    layout,
+                };
+                chunk_results.push((result, marks));
             }
 
             Ok(chunk_results)
@@ -70,5 +107,27 @@ pub fn extract_pages_parallel(
         .collect();
 
     // Flatten the results
-    Ok(results?.into_iter().flatten().collect())
+    let (mut results, marks): (Vec<PageExtractionResult>, Vec<Vec<LanguageMark>>) =
+        results?.into_iter().flatten().unzip();
+
+    // Dehyphenate once every page is in, so words spelled out on any of
+    // them help decide how line-break hyphens are joined
+    if options.dehyphenate {
+        let mut dehyphenator = Dehyphenator::new();
+        dehyphenator.learn_vocabulary(results.iter().flat_map(|r| &r.words));
+        for result in &mut results {
+            let paragraphs = std::mem::take(&mut result.paragraphs);
+            result.paragraphs = dehyphenator.dehyphenate(paragraphs, options);
+        }
+    }
+
+    if options.detect_language {
+        let detector = doc.language_detector()?;
+        for (result, marks) in results.iter_mut().zip(&marks) {
+            apply_language_marks(&mut result.paragraphs, marks);
+            detector.tag_paragraphs(&mut result.paragraphs);
+        }
+    }
+
+    Ok(results)
 }
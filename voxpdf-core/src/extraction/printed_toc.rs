@@ -0,0 +1,628 @@
+//! Typeset "Contents" pages.
+//!
+//! Many books print a table of contents but carry no bookmarks. Each row on
+//! such a page ends in a page number, usually after a dotted leader:
+//! "2.1 Keeping Notes . . . . . 43". Rows are parsed from the page's lines,
+//! nested by indentation and numbering, and their printed numbers mapped to
+//! physical pages through `/PageLabels` or, without labels, an offset found
+//! by looking for the titles on the pages they point to.
+
+use crate::error::Result;
+use crate::extraction::headings::numbering_depth;
+use crate::extraction::paragraphs::group_words_into_lines;
+use crate::extraction::{
+    extract_word_positions_with_options, map_toc_to_paragraphs, ExtractionOptions,
+};
+use crate::models::{to_roman, PageLabels, Paragraph, TocEntry, Word};
+use crate::pdf::PDFDocument;
+use serde::{Deserialize, Serialize};
+
+/// Contents pages are looked for among the first pages only
+const MAX_SCAN_PAGES: u32 = 30;
+
+/// A page is a contents page if at least this many of its lines, and this
+/// share of them, are rows
+const MIN_ROWS: usize = 3;
+const MIN_ROW_SHARE: f32 = 0.4;
+
+/// Largest number of unnumbered pages assumed before printed page 1
+const MAX_PAGE_OFFSET: u32 = 60;
+
+/// Horizontal distance (points) within which rows share an indent
+const INDENT_TOLERANCE: f32 = 4.0;
+
+/// Gap before a page number, as a multiple of the font size, that marks a
+/// right-aligned number when there is no leader
+const NUMBER_GAP_RATIO: f32 = 1.5;
+
+/// Characters that make up dotted leaders
+const LEADER_CHARS: &[char] = &['.', '…', '·', '_', '•'];
+
+/// Headings of a contents page, lowercased
+const CONTENTS_HEADINGS: &[&str] = &["contents", "table of contents", "brief contents"];
+
+/// Confidence of an entry's physical page, by how it was found
+const FOUND_CONFIDENCE: f32 = 1.0;
+const LABEL_CONFIDENCE: f32 = 0.9;
+const OFFSET_CONFIDENCE: f32 = 0.8;
+const GUESS_CONFIDENCE: f32 = 0.4;
+
+/// A table of contents read from the document's printed contents pages.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct PrintedToc {
+    /// Physical pages (0-indexed) the contents are printed on
+    pub pages: Vec<u32>,
+
+    /// Entries in printed order. They are flagged `inferred`, keep the
+    /// number as printed in `printed_page`, and have a `confidence` that
+    /// reflects how their physical page was found.
+    pub entries: Vec<TocEntry>,
+}
+
+impl PrintedToc {
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+}
+
+/// A row of a contents page
+#[derive(Debug, Clone, PartialEq)]
+struct ContentsRow {
+    title: String,
+    printed_page: String,
+    /// Left edge of the title
+    indent: f32,
+}
+
+/// Parse the document's printed contents pages, if it has any.
+///
+/// An alternative to [`extract_toc`](crate::extraction::extract_toc) for
+/// books whose outline is missing or thinner than their printed contents.
+/// Returns an empty [`PrintedToc`] if no contents page is found among the
+/// first pages.
+///
+/// # Example
+/// ```no_run
+/// use voxpdf_core::PDFDocument;
+/// use voxpdf_core::extraction::extract_printed_toc;
+///
+/// let doc = PDFDocument::open("book.pdf")?;
+/// let printed = extract_printed_toc(&doc)?;
+/// for entry in &printed.entries {
+///     println!("{} (p. {})", entry.title, entry.printed_page.as_deref().unwrap_or("?"));
+/// }
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+pub fn extract_printed_toc(doc: &PDFDocument) -> Result<PrintedToc> {
+    doc.ensure_unlocked()?;
+
+    // Leaders can look like a gutter to column detection; keep rows whole
+    let options = ExtractionOptions {
+        detect_columns: false,
+        ..doc.extraction_options().clone()
+    };
+
+    let mut pages = Vec::new();
+    let mut rows = Vec::new();
+    for page in 0..doc.page_count().min(MAX_SCAN_PAGES) {
+        let words = extract_word_positions_with_options(doc, page, &options)?;
+        let lines = group_words_into_lines(words, &options);
+        let page_rows = parse_contents_lines(&lines);
+
+        if is_contents_page(&lines, &page_rows) {
+            pages.push(page);
+            rows.extend(page_rows);
+        } else if !pages.is_empty() {
+            // Contents pages are consecutive
+            break;
+        }
+    }
+
+    let Some(&contents_end) = pages.last() else {
+        return Ok(PrintedToc::default());
+    };
+
+    let paragraphs = doc.document_paragraphs()?;
+    let page_texts = page_texts(paragraphs, doc.page_count());
+    let resolved = resolve_pages(&rows, doc.cached_page_labels()?, &page_texts, contents_end);
+    let levels = levels(&rows);
+
+    let mut entries: Vec<TocEntry> = rows
+        .into_iter()
+        .zip(levels)
+        .zip(resolved)
+        .filter_map(|((row, level), resolved)| {
+            let (page, confidence) = resolved?;
+            let mut entry = TocEntry::new(row.title, level, page, 0);
+            entry.inferred = true;
+            entry.confidence = confidence;
+            entry.printed_page = Some(row.printed_page);
+            Some(entry)
+        })
+        .collect();
+    map_toc_to_paragraphs(&mut entries, paragraphs);
+
+    Ok(PrintedToc { pages, entries })
+}
+
+/// Rows of a page, joining titles wrapped onto a second line
+fn parse_contents_lines(lines: &[Vec<Word>]) -> Vec<ContentsRow> {
+    let mut rows = Vec::new();
+    // An unnumbered line that may be the first half of a wrapped title
+    let mut pending: Option<(&Vec<Word>, String)> = None;
+
+    for line in lines {
+        match parse_row(line) {
+            Some(mut row) => {
+                if let Some((first, text)) = pending.take() {
+                    if wraps_into(first, line) {
+                        row.title = format!("{} {}", text, row.title);
+                        row.indent = first[0].bounds.x;
+                    }
+                }
+                rows.push(row);
+            }
+            None => {
+                // "Part II" heads a group of rows rather than starting one
+                let text = title_text(line);
+                let numbered = line.last().is_some_and(|w| is_page_number(&w.text));
+                pending = (!text.is_empty() && !numbered && !is_contents_heading(&text))
+                    .then_some((line, text));
+            }
+        }
+    }
+
+    rows
+}
+
+/// "Title . . . . 12": a title, then a page number set off by a leader or a
+/// wide gap
+fn parse_row(line: &[Word]) -> Option<ContentsRow> {
+    let (last, rest) = line.split_last()?;
+    let number = last.text.trim_start_matches(LEADER_CHARS);
+    if !is_page_number(number) {
+        return None;
+    }
+
+    let title = title_text(rest);
+    if !title.chars().any(char::is_alphabetic) {
+        return None;
+    }
+
+    let has_leader = last.text.starts_with(LEADER_CHARS)
+        || rest
+            .iter()
+            .any(|w| is_leader(&w.text) || ends_with_leader(&w.text));
+    let before = rest.last()?;
+    let gap = last.bounds.x - (before.bounds.x + before.bounds.width);
+    if !has_leader && gap < last.font_size * NUMBER_GAP_RATIO {
+        // "Part II" is a title ending in a numeral, not a row
+        return None;
+    }
+
+    let first = rest.iter().find(|w| !is_leader(&w.text))?;
+    Some(ContentsRow {
+        title,
+        printed_page: number.to_string(),
+        indent: first.bounds.x,
+    })
+}
+
+/// The line's text with leaders removed
+fn title_text(words: &[Word]) -> String {
+    words
+        .iter()
+        .filter(|w| !is_leader(&w.text))
+        .map(|w| {
+            if ends_with_leader(&w.text) {
+                w.text.trim_end_matches(LEADER_CHARS)
+            } else {
+                w.text.as_str()
+            }
+        })
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+fn is_leader(text: &str) -> bool {
+    !text.is_empty() && text.chars().all(|c| LEADER_CHARS.contains(&c))
+}
+
+/// "Introduction......" but not "etc."
+fn ends_with_leader(text: &str) -> bool {
+    text.chars()
+        .rev()
+        .take_while(|c| LEADER_CHARS.contains(c))
+        .count()
+        >= 2
+}
+
+fn is_page_number(text: &str) -> bool {
+    let arabic = !text.is_empty() && text.len() <= 4 && text.chars().all(|c| c.is_ascii_digit());
+    arabic || roman_value(text).is_some()
+}
+
+/// Value of a well-formed roman numeral such as "xiv" or "XIV"
+fn roman_value(text: &str) -> Option<u32> {
+    if text.is_empty() || text.len() > 8 {
+        return None;
+    }
+
+    let digit = |c: char| match c.to_ascii_lowercase() {
+        'i' => Some(1),
+        'v' => Some(5),
+        'x' => Some(10),
+        'l' => Some(50),
+        'c' => Some(100),
+        'd' => Some(500),
+        'm' => Some(1000),
+        _ => None,
+    };
+    let values: Vec<u32> = text.chars().map(digit).collect::<Option<_>>()?;
+
+    let mut total = 0;
+    for (i, &value) in values.iter().enumerate() {
+        match values.get(i + 1) {
+            Some(&next) if next > value => total -= value as i64,
+            _ => total += value as i64,
+        }
+    }
+
+    // Reject "iiii" or "ic" by writing the value back out
+    let value = u32::try_from(total).ok().filter(|&v| v > 0)?;
+    to_roman(value).eq_ignore_ascii_case(text).then_some(value)
+}
+
+/// True if `next` continues the title started on `first`
+fn wraps_into(first: &[Word], next: &[Word]) -> bool {
+    let (Some(a), Some(b)) = (first.first(), next.first()) else {
+        return false;
+    };
+    let gap = b.bounds.y - a.bounds.y;
+    gap > 0.0 && gap <= a.font_size.max(b.font_size) * 1.5
+}
+
+fn is_contents_heading(text: &str) -> bool {
+    CONTENTS_HEADINGS.contains(&normalize(text).trim())
+}
+
+fn is_contents_page(lines: &[Vec<Word>], rows: &[ContentsRow]) -> bool {
+    let headed = lines
+        .iter()
+        .any(|line| is_contents_heading(&title_text(line)));
+    let enough = rows.len() >= MIN_ROWS && rows.len() as f32 >= lines.len() as f32 * MIN_ROW_SHARE;
+    enough || (headed && rows.len() >= 2)
+}
+
+/// Nesting level of each row: deeper indents nest inside shallower ones,
+/// and at the same indent "1.2" nests inside "1"
+fn levels(rows: &[ContentsRow]) -> Vec<u8> {
+    let mut indents: Vec<f32> = rows.iter().map(|r| r.indent).collect();
+    indents.sort_by(f32::total_cmp);
+
+    let mut columns: Vec<f32> = Vec::new();
+    for x in indents {
+        if columns.last().is_none_or(|&c| x - c > INDENT_TOLERANCE) {
+            columns.push(x);
+        }
+    }
+
+    let key = |row: &ContentsRow| {
+        let column = columns.iter().rposition(|&c| c <= row.indent).unwrap_or(0);
+        (column, numbering_depth(&row.title).max(1))
+    };
+    let mut keys: Vec<(usize, usize)> = rows.iter().map(key).collect();
+    keys.sort_unstable();
+    keys.dedup();
+
+    rows.iter()
+        .map(|row| {
+            let level = keys.binary_search(&key(row)).unwrap_or(0);
+            level.min(u8::MAX as usize) as u8
+        })
+        .collect()
+}
+
+/// Lowercased alphanumeric words separated (and surrounded) by spaces, so
+/// `contains` matches whole words
+fn normalize(text: &str) -> String {
+    let mut out = String::from(" ");
+    for token in text
+        .split(|c: char| !c.is_alphanumeric())
+        .filter(|t| !t.is_empty())
+    {
+        out.push_str(&token.to_lowercase());
+        out.push(' ');
+    }
+    out
+}
+
+/// Normalized text of every page
+fn page_texts(paragraphs: &[Paragraph], page_count: u32) -> Vec<String> {
+    let mut texts = vec![String::from(" "); page_count as usize];
+    for word in paragraphs.iter().flat_map(|p| &p.words) {
+        if let Some(text) = texts.get_mut(word.page_number as usize) {
+            text.push_str(normalize(&word.text).trim_start());
+        }
+    }
+    texts
+}
+
+/// Physical page and confidence for each row
+fn resolve_pages(
+    rows: &[ContentsRow],
+    labels: &PageLabels,
+    page_texts: &[String],
+    contents_end: u32,
+) -> Vec<Option<(u32, f32)>> {
+    let page_count = page_texts.len() as u32;
+    let found = |row: &ContentsRow, page: u32| {
+        let title = normalize(&row.title);
+        !title.trim().is_empty()
+            && page_texts
+                .get(page as usize)
+                .is_some_and(|text| text.contains(&title))
+    };
+    let (offset, confirmed) = guess_offset(rows, page_texts, contents_end, &found);
+
+    rows.iter()
+        .map(|row| {
+            let labelled = if labels.is_empty() {
+                None
+            } else {
+                labels.find(&row.printed_page)
+            };
+
+            let (page, confidence) = match (labelled, row.printed_page.parse::<u32>()) {
+                (Some(page), _) => (page, LABEL_CONFIDENCE),
+                (None, Ok(number)) => {
+                    let confidence = if confirmed {
+                        OFFSET_CONFIDENCE
+                    } else {
+                        GUESS_CONFIDENCE
+                    };
+                    ((number + offset).checked_sub(1)?, confidence)
+                }
+                // Unlabelled front matter: assume it is numbered from the
+                // first physical page
+                (None, Err(_)) => (roman_value(&row.printed_page)? - 1, GUESS_CONFIDENCE),
+            };
+
+            if page >= page_count {
+                return None;
+            }
+            if found(row, page) {
+                return Some((page, FOUND_CONFIDENCE));
+            }
+            Some((page, confidence))
+        })
+        .collect()
+}
+
+/// Pages before printed page 1: the offset that puts the most titles on
+/// their pages, or (unconfirmed) one that starts page 1 after the contents
+fn guess_offset(
+    rows: &[ContentsRow],
+    page_texts: &[String],
+    contents_end: u32,
+    found: &impl Fn(&ContentsRow, u32) -> bool,
+) -> (u32, bool) {
+    let page_count = page_texts.len() as u32;
+    let numbered: Vec<(&ContentsRow, u32)> = rows
+        .iter()
+        .filter_map(|row| Some((row, row.printed_page.parse::<u32>().ok()?)))
+        .collect();
+
+    let matches = |offset: u32| {
+        numbered
+            .iter()
+            .filter(|&&(row, number)| {
+                // Printed page "0" with no offset has no physical page
+                let Some(page) = (number + offset).checked_sub(1) else {
+                    return false;
+                };
+                page > contents_end && page < page_count && found(row, page)
+            })
+            .count()
+    };
+
+    // Earliest offset wins ties
+    let best = (0..=MAX_PAGE_OFFSET)
+        .map(|offset| (offset, matches(offset)))
+        .max_by(|a, b| a.1.cmp(&b.1).then(b.0.cmp(&a.0)));
+    if let Some((offset, count)) = best {
+        if count > 0 {
+            return (offset, true);
+        }
+    }
+
+    let first = numbered.iter().map(|&(_, n)| n).min().unwrap_or(1);
+    ((contents_end + 2).saturating_sub(first), false)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::{PageLabelRange, PageLabelStyle, Rect};
+
+    /// One line at baseline `y`: words laid out left to right from `x`,
+    /// each 6pt per character plus a 3pt space
+    fn line(x: f32, y: f32, text: &str) -> Vec<Word> {
+        let mut x = x;
+        text.split_whitespace()
+            .map(|t| {
+                let width = t.chars().count() as f32 * 6.0;
+                let word = Word::new(t, Rect::new(x, y, width, 10.0), 1, 10.0);
+                x += width + 3.0;
+                word
+            })
+            .collect()
+    }
+
+    fn row(title: &str, printed: &str, indent: f32) -> ContentsRow {
+        ContentsRow {
+            title: title.to_string(),
+            printed_page: printed.to_string(),
+            indent,
+        }
+    }
+
+    #[test]
+    fn test_parse_rows() {
+        let mut right_aligned = line(72.0, 160.0, "Index");
+        right_aligned.extend(line(500.0, 160.0, "201"));
+
+        let lines = vec![
+            line(72.0, 40.0, "Contents"),
+            line(72.0, 70.0, "Preface . . . . . . . ix"),
+            line(72.0, 85.0, "1 Getting Started ........ 1"),
+            line(90.0, 100.0, "1.1 Packing for a Long Day in"),
+            line(90.0, 112.0, "Difficult Weather...... 12"),
+            line(72.0, 130.0, "Part II"),
+            line(72.0, 145.0, "Appendix .....xiv"),
+            right_aligned,
+        ];
+
+        let rows = parse_contents_lines(&lines);
+        assert_eq!(
+            rows,
+            [
+                row("Preface", "ix", 72.0),
+                row("1 Getting Started", "1", 72.0),
+                row(
+                    "1.1 Packing for a Long Day in Difficult Weather",
+                    "12",
+                    90.0
+                ),
+                row("Appendix", "xiv", 72.0),
+                row("Index", "201", 72.0),
+            ]
+        );
+        assert!(is_contents_page(&lines, &rows));
+    }
+
+    #[test]
+    fn test_body_text_is_not_contents() {
+        let lines = vec![
+            line(72.0, 70.0, "The survey ran for 12"),
+            line(72.0, 85.0, "weeks and covered every site."),
+            line(72.0, 100.0, "Nothing else here."),
+        ];
+        let rows = parse_contents_lines(&lines);
+        assert!(rows.is_empty());
+        assert!(!is_contents_page(&lines, &rows));
+    }
+
+    #[test]
+    fn test_roman_values() {
+        assert_eq!(roman_value("xiv"), Some(14));
+        assert_eq!(roman_value("IX"), Some(9));
+        assert_eq!(roman_value("mcm"), Some(1900));
+        assert_eq!(roman_value("iiii"), None);
+        assert_eq!(roman_value("ic"), None);
+        assert_eq!(roman_value("civic"), None);
+        assert_eq!(roman_value("Index"), None);
+    }
+
+    #[test]
+    fn test_levels_from_indent_and_numbering() {
+        let rows = [
+            row("Preface", "ix", 72.0),
+            row("1 Getting Started", "1", 72.0),
+            row("1.1 Tools", "2", 90.0),
+            row("1.1.1 Knives", "2", 108.0),
+            row("1.2 Maps", "5", 91.0),
+            row("2 Field Work", "9", 72.0),
+        ];
+        assert_eq!(levels(&rows), [0, 0, 1, 2, 1, 0]);
+
+        // Same indent throughout: numbering decides
+        let rows = [
+            row("1 Start", "1", 72.0),
+            row("1.1 More", "2", 72.0),
+            row("2 End", "3", 72.0),
+        ];
+        assert_eq!(levels(&rows), [0, 1, 0]);
+    }
+
+    fn texts(pages: &[&str]) -> Vec<String> {
+        pages.iter().map(|p| normalize(p)).collect()
+    }
+
+    #[test]
+    fn test_offset_from_titles() {
+        // Title, contents, then printed pages 1..=3 on physical pages 2..=4
+        let pages = texts(&[
+            "A Field Guide",
+            "Contents Getting Started 1 Field Work 3",
+            "Getting Started body text",
+            "more body text",
+            "Field Work body text",
+        ]);
+        let rows = [
+            row("Getting Started", "1", 72.0),
+            row("Tools", "2", 72.0),
+            row("Field Work", "3", 72.0),
+        ];
+
+        let resolved = resolve_pages(&rows, &PageLabels::default(), &pages, 1);
+        assert_eq!(
+            resolved,
+            [
+                Some((2, FOUND_CONFIDENCE)),
+                Some((3, OFFSET_CONFIDENCE)),
+                Some((4, FOUND_CONFIDENCE))
+            ]
+        );
+    }
+
+    #[test]
+    fn test_offset_guess_without_matches() {
+        let pages = texts(&["Contents", "one", "two", "three"]);
+        let rows = [row("Missing", "1", 72.0), row("Gone", "9", 72.0)];
+
+        let resolved = resolve_pages(&rows, &PageLabels::default(), &pages, 0);
+        assert_eq!(resolved, [Some((1, GUESS_CONFIDENCE)), None]);
+    }
+
+    #[test]
+    fn test_printed_page_zero() {
+        let pages = texts(&["Contents", "Preface body text", "Chapter body text"]);
+        let rows = [row("Preface", "0", 72.0), row("Chapter", "1", 72.0)];
+
+        let resolved = resolve_pages(&rows, &PageLabels::default(), &pages, 0);
+        assert_eq!(
+            resolved,
+            [Some((1, FOUND_CONFIDENCE)), Some((2, FOUND_CONFIDENCE))]
+        );
+    }
+
+    #[test]
+    fn test_page_labels_win() {
+        let labels = PageLabels::new(
+            vec![
+                PageLabelRange {
+                    start_page: 0,
+                    style: PageLabelStyle::LowerRoman,
+                    prefix: String::new(),
+                    first_number: 1,
+                },
+                PageLabelRange {
+                    start_page: 3,
+                    style: PageLabelStyle::Decimal,
+                    prefix: String::new(),
+                    first_number: 1,
+                },
+            ],
+            6,
+        );
+        let pages = texts(&["", "Contents", "Preface", "", "", ""]);
+        let rows = [row("Preface", "iii", 72.0), row("Chapter", "2", 72.0)];
+
+        let resolved = resolve_pages(&rows, &labels, &pages, 1);
+        assert_eq!(
+            resolved,
+            [Some((2, FOUND_CONFIDENCE)), Some((4, LABEL_CONFIDENCE))]
+        );
+    }
+}
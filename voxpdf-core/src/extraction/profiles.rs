@@ -0,0 +1,455 @@
+use crate::error::Result;
+use crate::extraction::paragraphs::{body_font_size, group_words_into_lines};
+use crate::extraction::{extract_word_positions_with_options, ExtractionOptions};
+use crate::models::Word;
+use crate::pdf::PDFDocument;
+use serde::{Deserialize, Serialize};
+
+/// Pages sampled by [`detect_profile`]
+const SAMPLE_PAGES: u32 = 5;
+
+/// Built-in [`ExtractionOptions`] presets for common document genres.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum ExtractionProfile {
+    /// Single column, uniform body text, indented paragraphs
+    Novel,
+    /// Two-column paper with footnotes and dense hyphenation
+    Academic,
+    /// Single column with frequent headings of several sizes
+    Textbook,
+    /// Numbered clauses; hyphens are kept as written
+    Legal,
+    /// Landscape pages with large, sparse text and bullet points
+    Slides,
+    /// Multi-column layout with varied headline sizes
+    Magazine,
+}
+
+/// Result of [`detect_profile`].
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct ProfileMatch {
+    pub profile: ExtractionProfile,
+
+    /// 0.0 to 1.0; low values mean the sampled pages fit several profiles
+    /// (or none) about equally well
+    pub confidence: f32,
+}
+
+impl ExtractionProfile {
+    /// Every profile, in declaration order
+    pub const ALL: [ExtractionProfile; 6] = [
+        ExtractionProfile::Novel,
+        ExtractionProfile::Academic,
+        ExtractionProfile::Textbook,
+        ExtractionProfile::Legal,
+        ExtractionProfile::Slides,
+        ExtractionProfile::Magazine,
+    ];
+
+    /// The options this profile bundles
+    ///
+    /// # Example
+    /// ```no_run
+    /// use voxpdf_core::PDFDocument;
+    /// use voxpdf_core::extraction::ExtractionProfile;
+    ///
+    /// let mut doc = PDFDocument::open("paper.pdf")?;
+    /// doc.set_extraction_options(ExtractionProfile::Academic.options());
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn options(self) -> ExtractionOptions {
+        let defaults = ExtractionOptions::default();
+        match self {
+            // Paragraphs are marked by indentation rather than blank lines;
+            // the running head sits close to the top edge
+            ExtractionProfile::Novel => ExtractionOptions {
+                indent_gap_ratio: 1.0,
+                running_text_margin: 0.08,
+                ..defaults
+            },
+            // Journal headers and footers (DOI, page range) are dropped
+            ExtractionProfile::Academic => ExtractionOptions {
+                paragraph_gap_ratio: 1.6,
+                heading_size_ratio: 1.1,
+                skip_footnotes: true,
+                running_text_margin: 0.08,
+                ..defaults
+            },
+            // Chapter and section running heads can sit well inside the
+            // top margin
+            ExtractionProfile::Textbook => ExtractionOptions {
+                paragraph_gap_ratio: 1.8,
+                heading_size_ratio: 1.1,
+                running_text_margin: 0.12,
+                ..defaults
+            },
+            // Clause numbers hang in the margin, and a line ending in
+            // "non-" followed by "compete" is a real compound. Headers and
+            // footers (case numbers, confidentiality notices) are kept,
+            // tagged, since they can matter to the reader
+            ExtractionProfile::Legal => ExtractionOptions {
+                paragraph_gap_ratio: 1.5,
+                indent_ratio: 1.5,
+                dehyphenate: false,
+                keep_running_text: true,
+                ..defaults
+            },
+            // Every bullet is its own paragraph. Slide titles repeat across
+            // a section and would look like running headers, so only a thin
+            // band is searched and anything found is kept, tagged
+            ExtractionProfile::Slides => ExtractionOptions {
+                line_tolerance_ratio: 0.5,
+                paragraph_gap_ratio: 1.4,
+                heading_size_ratio: 1.1,
+                short_line_ratio: 0.9,
+                short_line_gap_ratio: 1.0,
+                dehyphenate: false,
+                keep_running_text: true,
+                running_text_margin: 0.05,
+                ..defaults
+            },
+            // Folios and issue lines sit in a shallow band at the edges
+            ExtractionProfile::Magazine => ExtractionOptions {
+                word_gap_space_ratio: 0.75,
+                paragraph_gap_ratio: 1.8,
+                heading_size_ratio: 1.2,
+                running_text_margin: 0.06,
+                ..defaults
+            },
+        }
+    }
+}
+
+/// Sample a few pages and pick the profile that fits the document best.
+///
+/// Looks at page orientation, words per page, column layout, the spread of
+/// font sizes and genre vocabulary ("shall", "et al.", quoted dialogue). The
+/// first page is skipped when the document is long enough, since covers and
+/// title pages rarely look like the body.
+///
+/// # Example
+/// ```no_run
+/// use voxpdf_core::PDFDocument;
+/// use voxpdf_core::extraction::detect_profile;
+///
+/// let mut doc = PDFDocument::open("example.pdf")?;
+/// let detected = detect_profile(&doc)?;
+/// if detected.confidence > 0.5 {
+///     doc.set_extraction_options(detected.profile.options());
+/// }
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+pub fn detect_profile(doc: &PDFDocument) -> Result<ProfileMatch> {
+    let defaults = ExtractionOptions::default();
+    let mut samples = Vec::new();
+
+    for page in sample_pages(doc.page_count()) {
+        let info = doc.page_info(page)?;
+        let words = extract_word_positions_with_options(doc, page, &defaults)?;
+        samples.push(PageSample::from_words(&words, info.width(), info.height()));
+    }
+
+    Ok(score_samples(&samples))
+}
+
+/// Evenly spaced pages, skipping the cover of longer documents
+fn sample_pages(page_count: u32) -> Vec<u32> {
+    let first = if page_count > 3 { 1 } else { 0 };
+    let available = page_count.saturating_sub(first);
+    let count = available.min(SAMPLE_PAGES);
+
+    (0..count)
+        .map(|i| first + i * available / count.max(1))
+        .collect()
+}
+
+/// Layout features of one page
+#[derive(Debug, Clone, Default)]
+pub(crate) struct PageSample {
+    pub landscape: bool,
+    pub word_count: usize,
+    pub body_font_size: f32,
+    /// Distinct font sizes (to the nearest point) used by at least 2% of words
+    pub font_size_levels: usize,
+    /// Median line width as a fraction of the page width
+    pub line_width_fraction: f32,
+    pub legal_markers: usize,
+    pub academic_markers: usize,
+    pub dialogue_markers: usize,
+}
+
+impl PageSample {
+    pub(crate) fn from_words(words: &[Word], page_width: f32, page_height: f32) -> Self {
+        let mut sample = PageSample {
+            landscape: page_width > page_height,
+            word_count: words.len(),
+            body_font_size: body_font_size(words).unwrap_or(0.0),
+            ..Default::default()
+        };
+
+        let mut sizes: Vec<(i32, usize)> = Vec::new();
+        for word in words {
+            let key = word.font_size.round() as i32;
+            match sizes.iter_mut().find(|(k, _)| *k == key) {
+                Some((_, count)) => *count += 1,
+                None => sizes.push((key, 1)),
+            }
+        }
+        let min_count = (words.len() / 50).max(1);
+        sample.font_size_levels = sizes.iter().filter(|(_, c)| *c >= min_count).count();
+
+        let lines = group_words_into_lines(words.to_vec(), &ExtractionOptions::default());
+        let mut widths: Vec<f32> = lines
+            .iter()
+            .filter(|line| line.len() > 1)
+            .map(|line| {
+                let left = line
+                    .iter()
+                    .map(|w| w.bounds.x)
+                    .fold(f32::INFINITY, f32::min);
+                let right = line
+                    .iter()
+                    .map(|w| w.bounds.x + w.bounds.width)
+                    .fold(f32::NEG_INFINITY, f32::max);
+                right - left
+            })
+            .collect();
+        widths.sort_by(f32::total_cmp);
+        if let Some(median) = widths.get(widths.len() / 2) {
+            if page_width > 0.0 {
+                sample.line_width_fraction = median / page_width;
+            }
+        }
+
+        for word in words {
+            let text = word.text.as_str();
+            let bare = text.trim_matches(|c: char| !c.is_alphanumeric());
+
+            if matches!(
+                bare,
+                "shall" | "WHEREAS" | "hereinafter" | "herein" | "thereof" | "Section"
+            ) || text.starts_with('§')
+                || is_clause_number(text)
+            {
+                sample.legal_markers += 1;
+            }
+
+            if matches!(
+                bare,
+                "Abstract" | "References" | "et" | "Fig" | "Eq" | "arXiv"
+            ) || is_citation(text)
+            {
+                sample.academic_markers += 1;
+            }
+
+            if text.starts_with(['"', '“', '‘']) {
+                sample.dialogue_markers += 1;
+            }
+        }
+
+        sample
+    }
+}
+
+/// "1.2", "3.1.4" and similar clause numbering
+fn is_clause_number(text: &str) -> bool {
+    let text = text.trim_end_matches('.');
+    text.contains('.')
+        && text.split('.').all(|part| {
+            !part.is_empty() && part.len() <= 2 && part.chars().all(|c| c.is_ascii_digit())
+        })
+}
+
+/// Bracketed numeric citations: "[12]", "[3,", "[4–6]"
+fn is_citation(text: &str) -> bool {
+    text.strip_prefix('[')
+        .and_then(|rest| rest.chars().next())
+        .is_some_and(|c| c.is_ascii_digit())
+}
+
+/// Score each profile from averaged page features and pick the best
+pub(crate) fn score_samples(samples: &[PageSample]) -> ProfileMatch {
+    if samples.is_empty() {
+        return ProfileMatch {
+            profile: ExtractionProfile::Novel,
+            confidence: 0.0,
+        };
+    }
+
+    let n = samples.len() as f32;
+    let share = |f: fn(&PageSample) -> bool| samples.iter().filter(|s| f(s)).count() as f32 / n;
+    let mean = |f: fn(&PageSample) -> f32| samples.iter().map(f).sum::<f32>() / n;
+
+    let landscape = share(|s| s.landscape);
+    let multi_column = share(|s| s.line_width_fraction > 0.0 && s.line_width_fraction < 0.45);
+    let single_column = share(|s| s.line_width_fraction >= 0.45);
+    let words = mean(|s| s.word_count as f32);
+    let body_size = mean(|s| s.body_font_size);
+    let levels = mean(|s| s.font_size_levels as f32);
+    let per_page = |f: fn(&PageSample) -> f32, full: f32| (mean(f) / full).min(1.0);
+    let legal = per_page(|s| s.legal_markers as f32, 4.0);
+    let academic = per_page(|s| s.academic_markers as f32, 3.0);
+    let dialogue = per_page(|s| s.dialogue_markers as f32, 3.0);
+
+    let flag = |b: bool| if b { 1.0 } else { 0.0 };
+
+    let scores = [
+        (
+            ExtractionProfile::Novel,
+            0.3 * single_column
+                + 0.25 * flag(levels <= 2.0)
+                + 0.2 * flag(words >= 200.0)
+                + 0.25 * dialogue
+                - 0.3 * legal,
+        ),
+        (
+            ExtractionProfile::Academic,
+            0.4 * multi_column + 0.4 * academic + 0.2 * flag((8.0..=11.0).contains(&body_size)),
+        ),
+        (
+            ExtractionProfile::Textbook,
+            0.3 * single_column
+                + 0.3 * flag(levels >= 3.0)
+                + 0.2 * flag(words >= 120.0)
+                + 0.2 * (1.0 - landscape)
+                - 0.3 * legal,
+        ),
+        (
+            ExtractionProfile::Legal,
+            0.6 * legal + 0.2 * single_column + 0.2 * flag(levels <= 3.0),
+        ),
+        (
+            ExtractionProfile::Slides,
+            0.5 * landscape + 0.3 * flag(words < 120.0) + 0.2 * flag(body_size >= 18.0),
+        ),
+        (
+            ExtractionProfile::Magazine,
+            0.4 * multi_column + 0.3 * flag(levels >= 4.0) + 0.3 * (1.0 - academic) * multi_column,
+        ),
+    ];
+
+    let mut ranked = scores.map(|(p, s)| (p, s.clamp(0.0, 1.0)));
+    ranked.sort_by(|a, b| b.1.total_cmp(&a.1));
+
+    let (profile, best) = ranked[0];
+    let second = ranked[1].1;
+    let confidence = if best > 0.0 {
+        best * (1.0 - 0.5 * second / best)
+    } else {
+        0.0
+    };
+
+    ProfileMatch {
+        profile,
+        confidence,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sample(overrides: impl FnOnce(&mut PageSample)) -> PageSample {
+        let mut s = PageSample {
+            word_count: 350,
+            body_font_size: 11.0,
+            font_size_levels: 1,
+            line_width_fraction: 0.7,
+            ..Default::default()
+        };
+        overrides(&mut s);
+        s
+    }
+
+    #[test]
+    fn test_sample_pages() {
+        assert_eq!(sample_pages(0), Vec::<u32>::new());
+        assert_eq!(sample_pages(1), vec![0]);
+        assert_eq!(sample_pages(3), vec![0, 1, 2]);
+        assert_eq!(sample_pages(101), vec![1, 21, 41, 61, 81]);
+    }
+
+    #[test]
+    fn test_scores_pick_expected_profiles() {
+        let cases = [
+            (sample(|s| s.dialogue_markers = 6), ExtractionProfile::Novel),
+            (
+                sample(|s| {
+                    s.line_width_fraction = 0.4;
+                    s.body_font_size = 10.0;
+                    s.academic_markers = 5;
+                }),
+                ExtractionProfile::Academic,
+            ),
+            (
+                sample(|s| s.font_size_levels = 4),
+                ExtractionProfile::Textbook,
+            ),
+            (sample(|s| s.legal_markers = 8), ExtractionProfile::Legal),
+            (
+                sample(|s| {
+                    s.landscape = true;
+                    s.word_count = 40;
+                    s.body_font_size = 24.0;
+                    s.font_size_levels = 3;
+                }),
+                ExtractionProfile::Slides,
+            ),
+            (
+                sample(|s| {
+                    s.line_width_fraction = 0.3;
+                    s.font_size_levels = 5;
+                }),
+                ExtractionProfile::Magazine,
+            ),
+        ];
+
+        for (page, expected) in cases {
+            let detected = score_samples(&[page]);
+            assert_eq!(detected.profile, expected);
+            assert!(detected.confidence > 0.3, "{:?}: {:?}", expected, detected);
+        }
+    }
+
+    #[test]
+    fn test_no_samples_has_zero_confidence() {
+        assert_eq!(score_samples(&[]).confidence, 0.0);
+    }
+
+    #[test]
+    fn test_vocabulary_markers() {
+        assert!(is_clause_number("1.2"));
+        assert!(is_clause_number("3.1.4."));
+        assert!(!is_clause_number("3.14159"));
+        assert!(!is_clause_number("end."));
+        assert!(is_citation("[12]"));
+        assert!(!is_citation("[a]"));
+    }
+
+    #[test]
+    fn test_profiles_set_running_text() {
+        let defaults = ExtractionOptions::default();
+        assert!(ExtractionProfile::Slides.options().keep_running_text);
+        assert!(ExtractionProfile::Legal.options().keep_running_text);
+        assert!(!ExtractionProfile::Novel.options().keep_running_text);
+        assert!(
+            ExtractionProfile::Textbook.options().running_text_margin
+                > defaults.running_text_margin
+        );
+        assert!(
+            ExtractionProfile::Slides.options().running_text_margin < defaults.running_text_margin
+        );
+    }
+
+    #[test]
+    fn test_profiles_differ_from_defaults() {
+        for profile in ExtractionProfile::ALL {
+            assert_ne!(
+                profile.options(),
+                ExtractionOptions::default(),
+                "{:?}",
+                profile
+            );
+        }
+    }
+}
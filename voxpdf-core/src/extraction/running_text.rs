@@ -0,0 +1,418 @@
+//! Running headers, footers and page numbers.
+//!
+//! Text repeated in the top or bottom margin of several pages ("Chapter 3 ·
+//! Networking", "Page 12 of 40", a bare folio) is page furniture rather than
+//! content. Detection is document-level: margin paragraphs are reduced to a
+//! key with their numbers masked, and keys seen on enough pages are learned as
+//! running text. Matching on text rather than position also handles books
+//! whose headers alternate between odd and even pages.
+
+use crate::error::Result;
+use crate::extraction::{
+    detect_paragraphs_with_options, extract_word_positions_with_options, ExtractionOptions,
+};
+use crate::models::{Paragraph, ParagraphKind};
+use crate::pdf::PDFDocument;
+use serde::{Deserialize, Serialize};
+use std::collections::{HashMap, HashSet};
+
+/// Documents up to this many pages are learned from every page
+const MAX_SAMPLE_PAGES: u32 = 30;
+
+/// Longer documents are sampled as runs of consecutive pages, so headers that
+/// only repeat within one chapter are still seen more than once
+const SAMPLE_RUN_LENGTH: u32 = 3;
+
+/// Which margin a paragraph sits in
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+enum Margin {
+    Top,
+    Bottom,
+}
+
+/// Running text learned from a document.
+///
+/// # Example
+/// ```no_run
+/// use voxpdf_core::PDFDocument;
+/// use voxpdf_core::extraction::{detect_paragraphs, extract_word_positions, RunningText};
+///
+/// let doc = PDFDocument::open("book.pdf")?;
+/// let running = RunningText::detect(&doc, doc.extraction_options())?;
+///
+/// let height = doc.page_info(10)?.height();
+/// let paragraphs = detect_paragraphs(extract_word_positions(&doc, 10)?);
+/// let paragraphs = running.apply(paragraphs, height, doc.extraction_options());
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct RunningText {
+    headers: HashSet<String>,
+    footers: HashSet<String>,
+}
+
+impl RunningText {
+    /// Learn running text from a document's pages.
+    ///
+    /// Short documents are read in full; longer ones are sampled as
+    /// evenly spaced runs of consecutive pages.
+    pub fn detect(doc: &PDFDocument, options: &ExtractionOptions) -> Result<Self> {
+        let mut pages = Vec::new();
+        for page in sample_pages(doc.page_count()) {
+            let height = doc.page_info(page)?.height();
+            let words = extract_word_positions_with_options(doc, page, options)?;
+            pages.push((detect_paragraphs_with_options(words, options), height));
+        }
+
+        Ok(Self::learn(
+            pages.iter().map(|(p, h)| (p.as_slice(), *h)),
+            options,
+        ))
+    }
+
+    /// Learn running text from already extracted pages, given as each page's
+    /// paragraphs and its height in points.
+    pub fn learn<'a>(
+        pages: impl IntoIterator<Item = (&'a [Paragraph], f32)>,
+        options: &ExtractionOptions,
+    ) -> Self {
+        let mut seen: HashMap<(Margin, String), HashSet<u32>> = HashMap::new();
+        let mut page_count = 0;
+
+        for (paragraphs, height) in pages {
+            page_count += 1;
+            for paragraph in paragraphs {
+                if let Some(margin) = margin_of(paragraph, height, options) {
+                    seen.entry((margin, running_key(&paragraph.text)))
+                        .or_default()
+                        .insert(paragraph.page_number);
+                }
+            }
+        }
+
+        // Body text rarely repeats verbatim in a margin on three pages
+        let min_pages = if page_count <= 4 { 2 } else { 3 };
+
+        let mut running = Self::default();
+        for ((margin, key), pages) in seen {
+            if pages.len() < min_pages || key.is_empty() {
+                continue;
+            }
+            match margin {
+                Margin::Top => running.headers.insert(key),
+                Margin::Bottom => running.footers.insert(key),
+            };
+        }
+        running
+    }
+
+    /// True if nothing repeats in the margins
+    pub fn is_empty(&self) -> bool {
+        self.headers.is_empty() && self.footers.is_empty()
+    }
+
+    /// Classify one paragraph on a page `page_height` points tall
+    pub fn classify(
+        &self,
+        paragraph: &Paragraph,
+        page_height: f32,
+        options: &ExtractionOptions,
+    ) -> ParagraphKind {
+        let Some(margin) = margin_of(paragraph, page_height, options) else {
+            return ParagraphKind::Body;
+        };
+
+        let key = running_key(&paragraph.text);
+        let learned = match margin {
+            Margin::Top => &self.headers,
+            Margin::Bottom => &self.footers,
+        };
+
+        if !learned.contains(&key) {
+            ParagraphKind::Body
+        } else if is_page_number_key(&key) {
+            ParagraphKind::PageNumber
+        } else if margin == Margin::Top {
+            ParagraphKind::Header
+        } else {
+            ParagraphKind::Footer
+        }
+    }
+
+    /// Tag a page's paragraphs, then remove running text unless
+    /// `options.keep_running_text` is set. Remaining paragraphs are
+    /// renumbered from zero.
+    pub fn apply(
+        &self,
+        mut paragraphs: Vec<Paragraph>,
+        page_height: f32,
+        options: &ExtractionOptions,
+    ) -> Vec<Paragraph> {
+        if self.is_empty() {
+            return paragraphs;
+        }
+
+        for paragraph in &mut paragraphs {
+            paragraph.kind = self.classify(paragraph, page_height, options);
+        }
+
+        if !options.keep_running_text {
+            paragraphs.retain(|p| !p.is_running_text());
+            for (index, paragraph) in paragraphs.iter_mut().enumerate() {
+                paragraph.index = index;
+            }
+        }
+
+        paragraphs
+    }
+}
+
+/// Learn the running text of a document with its own extraction options.
+///
+/// The result is cached on the document; see [`PDFDocument::running_text`].
+pub fn detect_running_text(doc: &PDFDocument) -> Result<RunningText> {
+    RunningText::detect(doc, doc.extraction_options())
+}
+
+/// The document's cached running text when `options` are its own, otherwise
+/// learned afresh with `options`
+pub(crate) fn running_text_for(
+    doc: &PDFDocument,
+    options: &ExtractionOptions,
+) -> Result<RunningText> {
+    if options == doc.extraction_options() {
+        Ok(doc.running_text()?.clone())
+    } else {
+        RunningText::detect(doc, options)
+    }
+}
+
+/// Every page of short documents, runs of consecutive pages for longer ones
+fn sample_pages(page_count: u32) -> Vec<u32> {
+    if page_count <= MAX_SAMPLE_PAGES {
+        return (0..page_count).collect();
+    }
+
+    let runs = MAX_SAMPLE_PAGES / SAMPLE_RUN_LENGTH;
+    let stride = (page_count - SAMPLE_RUN_LENGTH) / (runs - 1);
+    (0..runs)
+        .flat_map(|run| {
+            let start = run * stride;
+            start..start + SAMPLE_RUN_LENGTH
+        })
+        .collect()
+}
+
+/// The margin every word of the paragraph sits in, if any
+fn margin_of(
+    paragraph: &Paragraph,
+    page_height: f32,
+    options: &ExtractionOptions,
+) -> Option<Margin> {
+    if paragraph.words.is_empty() || page_height <= 0.0 {
+        return None;
+    }
+
+    let depth = page_height * options.running_text_margin;
+    let centers = || {
+        paragraph
+            .words
+            .iter()
+            .map(|w| w.bounds.y + w.bounds.height / 2.0)
+    };
+
+    if centers().all(|y| y <= depth) {
+        Some(Margin::Top)
+    } else if centers().all(|y| y >= page_height - depth) {
+        Some(Margin::Bottom)
+    } else {
+        None
+    }
+}
+
+/// Lowercased text with numbers (arabic or roman) replaced by `#`, so
+/// "Chapter 3 · Page 41" and "Chapter 3 · Page 42" share a key
+fn running_key(text: &str) -> String {
+    text.split_whitespace()
+        .map(|token| {
+            let token = token.to_lowercase();
+            if is_roman_numeral(&token) {
+                return "#".to_string();
+            }
+
+            let mut key = String::with_capacity(token.len());
+            let mut in_number = false;
+            for c in token.chars() {
+                if c.is_ascii_digit() {
+                    if !in_number {
+                        key.push('#');
+                    }
+                    in_number = true;
+                } else {
+                    key.push(c);
+                    in_number = false;
+                }
+            }
+            key
+        })
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+/// Lowercase roman numerals below 40, the range front matter uses
+fn is_roman_numeral(token: &str) -> bool {
+    let rest = token.trim_start_matches('x');
+    let tens = token.len() - rest.len();
+    let units = ["i", "ii", "iii", "iv", "v", "vi", "vii", "viii", "ix"];
+
+    !token.is_empty() && tens <= 3 && (rest.is_empty() || units.contains(&rest))
+}
+
+/// Keys that are nothing but a folio: "#", "- # -", "page #", "# of #"
+fn is_page_number_key(key: &str) -> bool {
+    let words: Vec<&str> = key
+        .split_whitespace()
+        .map(|w| w.trim_matches(|c: char| !c.is_alphanumeric() && c != '#'))
+        .filter(|w| !w.is_empty())
+        .collect();
+
+    matches!(
+        words.as_slice(),
+        ["#"] | ["page", "#"] | ["p", "#"] | ["#", "of", "#"] | ["page", "#", "of", "#"]
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::line_paragraph;
+
+    const HEIGHT: f32 = 792.0;
+
+    fn book_page(page: u32) -> Vec<Paragraph> {
+        // Book title on even pages, chapter title on odd pages
+        let header = ["The Networking Book", "Chapter 3 · Networking"][page as usize % 2];
+        vec![
+            line_paragraph(0, page, 40.0, 10.0, header),
+            line_paragraph(1, page, 100.0, 10.0, "Packets travel between hosts."),
+            line_paragraph(2, page, 400.0, 10.0, "Routers forward them onwards."),
+            line_paragraph(3, page, 750.0, 10.0, &(page + 41).to_string()),
+        ]
+    }
+
+    fn learn(pages: &[Vec<Paragraph>]) -> RunningText {
+        RunningText::learn(
+            pages.iter().map(|p| (p.as_slice(), HEIGHT)),
+            &ExtractionOptions::default(),
+        )
+    }
+
+    #[test]
+    fn test_running_key_masks_numbers() {
+        assert_eq!(running_key("Page 41 of 300"), "page # of #");
+        assert_eq!(
+            running_key("Chapter 3 · Networking"),
+            "chapter # · networking"
+        );
+        assert_eq!(running_key("xii"), "#");
+        assert_eq!(running_key("Section 2.10"), "section #.#");
+        assert_eq!(running_key("mix vivid"), "mix vivid");
+    }
+
+    #[test]
+    fn test_roman_numerals() {
+        assert!(is_roman_numeral("iv"));
+        assert!(is_roman_numeral("xxxviii"));
+        assert!(is_roman_numeral("x"));
+        assert!(!is_roman_numeral("ivy"));
+        assert!(!is_roman_numeral("xxxx"));
+        assert!(!is_roman_numeral(""));
+    }
+
+    #[test]
+    fn test_alternating_headers_and_folios_are_stripped() {
+        let pages: Vec<_> = (0..8).map(book_page).collect();
+        let running = learn(&pages);
+        let options = ExtractionOptions::default();
+
+        for page in pages {
+            let kept = running.apply(page, HEIGHT, &options);
+            let texts: Vec<_> = kept.iter().map(|p| p.text.as_str()).collect();
+            assert_eq!(
+                texts,
+                [
+                    "Packets travel between hosts.",
+                    "Routers forward them onwards."
+                ]
+            );
+            assert_eq!(kept[1].index, 1);
+        }
+    }
+
+    #[test]
+    fn test_keep_running_text_tags_instead() {
+        let pages: Vec<_> = (0..6).map(book_page).collect();
+        let running = learn(&pages);
+        let options = ExtractionOptions {
+            keep_running_text: true,
+            ..Default::default()
+        };
+
+        let tagged = running.apply(book_page(3), HEIGHT, &options);
+        let kinds: Vec<_> = tagged.iter().map(|p| p.kind).collect();
+        assert_eq!(
+            kinds,
+            [
+                ParagraphKind::Header,
+                ParagraphKind::Body,
+                ParagraphKind::Body,
+                ParagraphKind::PageNumber
+            ]
+        );
+    }
+
+    #[test]
+    fn test_unrepeated_margin_text_is_body() {
+        let mut pages: Vec<_> = (0..6).map(book_page).collect();
+        let caption = line_paragraph(4, 2, 760.0, 10.0, "A one-off caption in the margin");
+        pages[2].push(caption);
+        let running = learn(&pages);
+
+        let caption = &pages[2][4];
+        assert_eq!(
+            running.classify(caption, HEIGHT, &ExtractionOptions::default()),
+            ParagraphKind::Body
+        );
+    }
+
+    #[test]
+    fn test_single_page_learns_nothing() {
+        let running = learn(&[book_page(0)]);
+        assert!(running.is_empty());
+        assert_eq!(
+            running
+                .apply(book_page(0), HEIGHT, &ExtractionOptions::default())
+                .len(),
+            4
+        );
+    }
+
+    #[test]
+    fn test_page_number_keys() {
+        assert!(is_page_number_key("#"));
+        assert!(is_page_number_key("- # -"));
+        assert!(is_page_number_key("page # of #"));
+        assert!(!is_page_number_key("chapter #"));
+    }
+
+    #[test]
+    fn test_sample_pages_uses_runs() {
+        assert_eq!(sample_pages(5), vec![0, 1, 2, 3, 4]);
+
+        let pages = sample_pages(500);
+        assert_eq!(pages.len(), MAX_SAMPLE_PAGES as usize);
+        assert_eq!(&pages[..3], &[0, 1, 2]);
+        assert!(pages.iter().all(|&p| p < 500));
+        assert!(pages.windows(2).all(|w| w[0] < w[1]));
+    }
+}
@@ -0,0 +1,468 @@
+//! Splitting paragraphs into sentences, one TTS utterance each.
+//!
+//! A sentence ends after a word ending in terminal punctuation (closing
+//! quotes and brackets aside) when the next word looks like the start of
+//! one. Full stops are the hard case: they also end abbreviations ("Dr.",
+//! "e.g."), initials ("J. R. Tolkien") and, in German and other languages,
+//! ordinal numbers ("am 3. Oktober"). Decimals ("3.14") and the inner stops
+//! of initialisms ("U.S.") never split, since only the end of a word is
+//! considered.
+
+use crate::error::Result;
+use crate::models::{locate_words, Paragraph, Rect, Sentence, TextDirection, TextRange, Word};
+use crate::pdf::PDFDocument;
+use std::collections::HashSet;
+
+/// Built-in abbreviation lists by primary language subtag; the first is
+/// the fallback
+const ABBREVIATIONS: &[(&str, &str)] = &[
+    ("en", include_str!("data/abbreviations-en.txt")),
+    ("de", include_str!("data/abbreviations-de.txt")),
+    ("es", include_str!("data/abbreviations-es.txt")),
+    ("fr", include_str!("data/abbreviations-fr.txt")),
+    ("it", include_str!("data/abbreviations-it.txt")),
+];
+
+/// Languages that write ordinal numbers with a full stop ("3. Oktober")
+const ORDINAL_DOT_LANGUAGES: &[&str] = &[
+    "cs", "da", "de", "et", "fi", "hr", "hu", "is", "lv", "nb", "nn", "no", "pl", "sk", "sl", "tr",
+];
+
+/// Punctuation that ends a sentence unless a lowercase word follows
+const TERMINALS: &[char] = &[
+    '!', '?', '‼', '⁇', '⁈', '⁉', '。', '｡', '！', '？', '؟', '।', '۔',
+];
+
+/// Quotes and brackets that may follow a sentence's final punctuation
+const CLOSERS: &[char] = &[
+    '"', '\'', '”', '’', '»', '›', ')', ']', '}', '」', '』', '）', '】', '〉', '》',
+];
+
+/// Quotes, brackets and inverted marks that may open a sentence
+const OPENERS: &[char] = &[
+    '"', '\'', '“', '‘', '„', '«', '‹', '(', '[', '{', '¿', '¡', '「', '『', '（', '【', '〈', '《',
+];
+
+/// Longest number read as an ordinal rather than a year ("19. Jahrhundert")
+const MAX_ORDINAL_DIGITS: usize = 3;
+
+/// Splits [`Paragraph`]s into [`Sentence`]s.
+///
+/// # Example
+/// ```
+/// use voxpdf_core::extraction::SentenceSplitter;
+/// use voxpdf_core::models::{Paragraph, Rect, Word};
+///
+/// let text = "Dr. Smith paid $3.50 for it. He left.";
+/// let words = text
+///     .split(' ')
+///     .map(|w| Word::new(w, Rect::new(0.0, 0.0, 10.0, 10.0), 0, 10.0))
+///     .collect();
+/// let paragraph = Paragraph::new(0, text.to_string(), 0, words);
+///
+/// let sentences = SentenceSplitter::for_language(Some("en-GB")).split(&paragraph);
+/// assert_eq!(sentences[0].text, "Dr. Smith paid $3.50 for it.");
+/// assert_eq!(sentences[1].text, "He left.");
+/// ```
+#[derive(Debug, Clone)]
+pub struct SentenceSplitter {
+    /// Lowercase, without the final full stop
+    abbreviations: HashSet<String>,
+    ordinal_dots: bool,
+}
+
+impl Default for SentenceSplitter {
+    fn default() -> Self {
+        Self::for_language(None)
+    }
+}
+
+impl SentenceSplitter {
+    /// Rules for a BCP 47 language tag ("en-US", "de"). Languages without a
+    /// built-in abbreviation list, and `None`, use the English one.
+    pub fn for_language(language: Option<&str>) -> Self {
+        let primary = language
+            .and_then(|tag| tag.split(['-', '_']).next())
+            .map(|tag| tag.trim().to_ascii_lowercase())
+            .unwrap_or_default();
+        let (_, list) = ABBREVIATIONS
+            .iter()
+            .find(|(lang, _)| *lang == primary)
+            .unwrap_or(&ABBREVIATIONS[0]);
+
+        let mut splitter = Self {
+            abbreviations: HashSet::new(),
+            ordinal_dots: ORDINAL_DOT_LANGUAGES.contains(&primary.as_str()),
+        };
+        for line in list.lines() {
+            let line = line.trim();
+            if !line.is_empty() && !line.starts_with('#') {
+                splitter.add_abbreviation(line);
+            }
+        }
+        splitter
+    }
+
+    /// Rules for the document's [`language`](PDFDocument::language)
+    pub fn for_document(doc: &PDFDocument) -> Result<Self> {
+        Ok(Self::for_language(doc.language()?))
+    }
+
+    /// Never end a sentence after `abbreviation` ("Dr", "e.g."); case and a
+    /// final full stop are ignored
+    pub fn add_abbreviation(&mut self, abbreviation: &str) {
+        let abbreviation = abbreviation.trim().trim_end_matches('.').to_lowercase();
+        if !abbreviation.is_empty() {
+            self.abbreviations.insert(abbreviation);
+        }
+    }
+
+    /// The paragraph's sentences in reading order. A paragraph that stops
+    /// mid-sentence ends its last one.
+    ///
+    /// A paragraph with text but no words is split at its whitespace; its
+    /// sentences have no words or boxes.
+    pub fn split(&self, paragraph: &Paragraph) -> Vec<Sentence> {
+        let text = &paragraph.text;
+        let words = &paragraph.words;
+        // The stored ranges are stale if the paragraph was edited by hand,
+        // so find the words again rather than slice with them
+        let tokens: Vec<(&str, TextRange)> = if words.is_empty() {
+            whitespace_tokens(text)
+        } else {
+            words
+                .iter()
+                .map(|w| w.text.as_str())
+                .zip(locate_words(text, words))
+                .collect()
+        };
+
+        let mut sentences = Vec::new();
+        let mut start = 0;
+        for (i, (token, range)) in tokens.iter().enumerate() {
+            let ends = match tokens.get(i + 1) {
+                Some((next, _)) => self.ends_sentence(token, next),
+                None => true,
+            };
+            if !ends {
+                continue;
+            }
+
+            let first = &tokens[start].1;
+            let text_range = TextRange {
+                utf8: first.utf8.start..range.utf8.end,
+                utf16: first.utf16.start..range.utf16.end,
+            };
+            let (word_range, rects) = if words.is_empty() {
+                (0..0, Vec::new())
+            } else {
+                (start..i + 1, line_rects(&words[start..=i]))
+            };
+            sentences.push(Sentence {
+                index: sentences.len(),
+                text: text[text_range.utf8.clone()].to_string(),
+                text_range,
+                word_range,
+                rects,
+                language: paragraph.language.clone(),
+            });
+            start = i + 1;
+        }
+
+        sentences
+    }
+
+    /// True if a sentence ends with `word`, given the word after it
+    fn ends_sentence(&self, word: &str, next: &str) -> bool {
+        let core = word.trim_end_matches(CLOSERS);
+        let Some(last) = core.chars().last() else {
+            return false;
+        };
+
+        if TERMINALS.contains(&last) {
+            return !next
+                .trim_start_matches(OPENERS)
+                .starts_with(char::is_lowercase);
+        }
+        if last == '…' {
+            return starts_sentence(next);
+        }
+        if last != '.' || !starts_sentence(next) {
+            return false;
+        }
+
+        let stem = core[..core.len() - 1].trim_start_matches(OPENERS);
+        if stem.ends_with('.') {
+            // An ellipsis written as full stops
+            return true;
+        }
+        if self.abbreviations.contains(&stem.to_lowercase()) {
+            return false;
+        }
+
+        // An initial: "J. R. Tolkien"
+        let mut letters = stem.chars();
+        if letters.next().is_some_and(char::is_alphabetic) && letters.next().is_none() {
+            return false;
+        }
+
+        // An ordinal: "am 3. Oktober"
+        let is_ordinal = !stem.is_empty()
+            && stem.len() <= MAX_ORDINAL_DIGITS
+            && stem.chars().all(|c| c.is_ascii_digit());
+        !(self.ordinal_dots && is_ordinal)
+    }
+}
+
+/// True if `word` can start a sentence after a full stop: it begins, past
+/// any opening quotes and brackets, with a capital or a letter from a
+/// script without case. Numbers don't count ("p. 12", "No. 5").
+fn starts_sentence(word: &str) -> bool {
+    word.trim_start_matches(OPENERS)
+        .chars()
+        .next()
+        .is_some_and(|c| c.is_alphabetic() && !c.is_lowercase())
+}
+
+/// The whitespace-separated pieces of `text` and their ranges
+fn whitespace_tokens(text: &str) -> Vec<(&str, TextRange)> {
+    let mut tokens = Vec::new();
+    // Start of the current token, in bytes and UTF-16 units
+    let mut start: Option<(usize, usize)> = None;
+    let mut utf16 = 0;
+    for (i, c) in text.char_indices().chain([(text.len(), ' ')]) {
+        match (c.is_whitespace(), start) {
+            (true, Some((start8, start16))) => {
+                tokens.push((
+                    &text[start8..i],
+                    TextRange {
+                        utf8: start8..i,
+                        utf16: start16..utf16,
+                    },
+                ));
+                start = None;
+            }
+            (false, None) => start = Some((i, utf16)),
+            _ => {}
+        }
+        utf16 += c.len_utf16();
+    }
+    tokens
+}
+
+/// Boxes around the words' lines, one per line and page
+fn line_rects(words: &[Word]) -> Vec<(u32, Rect)> {
+    let mut rects: Vec<(u32, Rect)> = Vec::new();
+    for word in words {
+        let vertical = word.direction == TextDirection::TopToBottom;
+        for (page, bounds) in word.rects() {
+            match rects.last_mut() {
+                Some((last_page, line))
+                    if *last_page == page && same_line(line, bounds, vertical) =>
+                {
+                    *line = line.union(bounds);
+                }
+                _ => rects.push((page, bounds.clone())),
+            }
+        }
+    }
+    rects
+}
+
+/// True if `next` is on the line `line` covers: they overlap across the
+/// line by at least half the thinner one
+fn same_line(line: &Rect, next: &Rect, vertical: bool) -> bool {
+    let span = |r: &Rect| {
+        if vertical {
+            (r.x, r.x + r.width)
+        } else {
+            (r.y, r.y + r.height)
+        }
+    };
+    let (a0, a1) = span(line);
+    let (b0, b1) = span(next);
+    a1.min(b1) - a0.max(b0) >= (a1 - a0).min(b1 - b0) / 2.0
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// One line per `\n`, words 10pt apart
+    fn paragraph(text: &str) -> Paragraph {
+        let mut words = Vec::new();
+        for (line, line_text) in text.lines().enumerate() {
+            for (i, word) in line_text.split_whitespace().enumerate() {
+                let y = 100.0 + line as f32 * 14.0;
+                let bounds = Rect::new(72.0 + i as f32 * 50.0, y, 40.0, 12.0);
+                words.push(Word::new(word, bounds, 0, 12.0));
+            }
+        }
+        let text = text.split_whitespace().collect::<Vec<_>>().join(" ");
+        Paragraph::new(0, text, 0, words)
+    }
+
+    fn split(language: &str, text: &str) -> Vec<String> {
+        SentenceSplitter::for_language(Some(language))
+            .split(&paragraph(text))
+            .into_iter()
+            .map(|s| s.text)
+            .collect()
+    }
+
+    #[test]
+    fn test_abbreviations_decimals_and_initialisms() {
+        assert_eq!(
+            split(
+                "en",
+                "Dr. Smith measured 3.14 units, e.g. in the U.S. Army. \
+                 J. R. R. Tolkien agreed. It cost approx. 12 dollars."
+            ),
+            [
+                "Dr. Smith measured 3.14 units, e.g. in the U.S. Army.",
+                "J. R. R. Tolkien agreed.",
+                "It cost approx. 12 dollars.",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_unlisted_abbreviations_need_a_capital() {
+        assert_eq!(
+            split("en", "Pears, apples etc. are fruit. Bananas etc. They too."),
+            ["Pears, apples etc. are fruit.", "Bananas etc.", "They too."]
+        );
+    }
+
+    #[test]
+    fn test_questions_quotes_and_ellipses() {
+        assert_eq!(
+            split(
+                "en",
+                "\"Is it late?\" she asked. \"Yes!\" (He left.) Wait... for it. Done…"
+            ),
+            [
+                "\"Is it late?\" she asked.",
+                "\"Yes!\"",
+                "(He left.)",
+                "Wait... for it.",
+                "Done…",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_language_rules() {
+        // German ordinals and abbreviations
+        assert_eq!(
+            split(
+                "de-DE",
+                "Am 3. Oktober kam er, vgl. Abb. 2 unten. Im Jahr 1990. Dann nicht."
+            ),
+            [
+                "Am 3. Oktober kam er, vgl. Abb. 2 unten.",
+                "Im Jahr 1990.",
+                "Dann nicht."
+            ]
+        );
+        // English has no ordinal dots
+        assert_eq!(
+            split("en", "Chapter 3. The end."),
+            ["Chapter 3.", "The end."]
+        );
+        assert_eq!(
+            split("es", "¿Vino la Sra. García? ¡Sí! Bien."),
+            ["¿Vino la Sra. García?", "¡Sí!", "Bien."]
+        );
+        // Unknown languages fall back to English
+        assert_eq!(split("xx", "Mr. Li left. Bye."), ["Mr. Li left.", "Bye."]);
+    }
+
+    #[test]
+    fn test_cjk_terminals() {
+        assert_eq!(
+            split("ja", "日本 語。 次 の 文？ 終わり"),
+            ["日本 語。", "次 の 文？", "終わり"]
+        );
+    }
+
+    #[test]
+    fn test_custom_abbreviation() {
+        let mut splitter = SentenceSplitter::for_language(Some("en"));
+        let para = paragraph("See Anm. Two for details.");
+        assert_eq!(splitter.split(&para).len(), 2);
+
+        splitter.add_abbreviation("Anm.");
+        assert_eq!(splitter.split(&para).len(), 1);
+    }
+
+    #[test]
+    fn test_ranges_and_rects() {
+        let para = paragraph("Über alles. Ein\nSatz über zwei Zeilen.");
+        let sentences = SentenceSplitter::for_language(Some("de")).split(&para);
+        assert_eq!(sentences.len(), 2);
+
+        let utf16: Vec<u16> = para.text.encode_utf16().collect();
+        for sentence in &sentences {
+            let range = &sentence.text_range;
+            assert_eq!(para.text[range.utf8.clone()], sentence.text);
+            assert_eq!(
+                String::from_utf16(&utf16[range.utf16.clone()]).unwrap(),
+                sentence.text
+            );
+        }
+        assert_eq!(sentences[1].text_range.utf8, 13..40);
+        assert_eq!(sentences[1].text_range.utf16, 12..38);
+        assert_eq!(sentences[0].word_range, 0..2);
+        assert_eq!(sentences[1].word_range, 2..7);
+
+        // The second sentence spans two lines
+        assert_eq!(
+            sentences[0].rects,
+            [(0, Rect::new(72.0, 100.0, 90.0, 12.0))]
+        );
+        assert_eq!(
+            sentences[1].rects,
+            [
+                (0, Rect::new(172.0, 100.0, 40.0, 12.0)),
+                (0, Rect::new(72.0, 114.0, 190.0, 12.0)),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_text_without_words() {
+        let para = Paragraph::new(0, "Ça va. Très  bien.".to_string(), 0, Vec::new());
+        let sentences = SentenceSplitter::for_language(Some("fr")).split(&para);
+
+        let texts: Vec<&str> = sentences.iter().map(|s| s.text.as_str()).collect();
+        assert_eq!(texts, ["Ça va.", "Très  bien."]);
+        assert_eq!(sentences[1].text_range.utf8, 8..20);
+        assert_eq!(sentences[1].text_range.utf16, 7..18);
+        assert!(sentences[1].word_range.is_empty());
+        assert!(sentences[1].rects.is_empty());
+    }
+
+    #[test]
+    fn test_stale_word_ranges() {
+        let mut para = paragraph("Öl ist teuer. Wasser nicht.");
+        // Edited without updating the ranges, which now point past the end
+        // and into the middle of "Ö"
+        para.text = "Öl. Wasser.".to_string();
+        para.words = vec![para.words[0].clone(), para.words[3].clone()];
+        para.words[0].text = "Öl.".to_string();
+        para.words[1].text = "Wasser.".to_string();
+        para.word_ranges.truncate(2);
+        para.word_ranges[1].utf8 = 1..30;
+
+        let sentences = SentenceSplitter::for_language(Some("de")).split(&para);
+        let texts: Vec<&str> = sentences.iter().map(|s| s.text.as_str()).collect();
+        assert_eq!(texts, ["Öl.", "Wasser."]);
+    }
+
+    #[test]
+    fn test_empty_paragraph() {
+        let para = Paragraph::new(0, String::new(), 0, Vec::new());
+        assert!(SentenceSplitter::default().split(&para).is_empty());
+    }
+}
@@ -0,0 +1,253 @@
+//! Joining paragraphs that continue across page breaks.
+//!
+//! Paragraphs are detected per page, so a sentence broken at the bottom of a
+//! page would otherwise be read as two paragraphs with a pause in between.
+
+use crate::error::Result;
+use crate::extraction::{extract_pages_parallel, Dehyphenator, ExtractionOptions};
+use crate::models::Paragraph;
+use crate::pdf::PDFDocument;
+
+/// Characters that end a sentence, possibly followed by closing quotes or
+/// brackets
+const TERMINAL_PUNCTUATION: &[char] = &['.', '!', '?', ':', '…', '。', '！', '？'];
+const CLOSING_CHARS: &[char] = &['"', '\'', '”', '’', ')', ']', '»'];
+
+/// Merge per-page paragraphs into document paragraphs.
+///
+/// `pages` holds each page's paragraphs in reading order. A page's last body
+/// paragraph is joined with the next page's first one when the text breaks
+/// off mid-sentence and both share the same font size. Running headers and
+/// page numbers kept with `keep_running_text` are skipped over and stay on
+/// their page.
+///
+/// Joined paragraphs keep the first page's `page_number`, while every
+/// [`Word`](crate::models::Word) keeps its own. Paragraphs are renumbered
+/// across the whole document.
+///
+/// # Example
+/// ```no_run
+/// use voxpdf_core::PDFDocument;
+/// use voxpdf_core::extraction::{extract_pages_parallel, stitch_paragraphs};
+///
+/// let doc = PDFDocument::open("book.pdf")?;
+/// let pages = extract_pages_parallel(&doc, (0..doc.page_count()).collect())?;
+/// let paragraphs = stitch_paragraphs(
+///     pages.into_iter().map(|page| page.paragraphs),
+///     doc.extraction_options(),
+/// );
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+pub fn stitch_paragraphs(
+    pages: impl IntoIterator<Item = Vec<Paragraph>>,
+    options: &ExtractionOptions,
+) -> Vec<Paragraph> {
+    let pages: Vec<Vec<Paragraph>> = pages.into_iter().collect();
+    let mut dehyphenator = Dehyphenator::new();
+    dehyphenator.learn_vocabulary(pages.iter().flatten().flat_map(|p| &p.words));
+
+    let mut out: Vec<Paragraph> = Vec::new();
+    // Index in `out` of the previous page's last body paragraph, if nothing
+    // but running text follows it
+    let mut open: Option<usize> = None;
+
+    for page in pages {
+        let mut first_body = true;
+        let mut last_body = None;
+
+        for paragraph in page {
+            if paragraph.is_running_text() {
+                out.push(paragraph);
+                continue;
+            }
+
+            let continues = first_body
+                && open.is_some_and(|prev| continues_across_pages(&out[prev], &paragraph, options));
+            first_body = false;
+
+            match open {
+                Some(prev) if continues => {
+                    join(&mut out[prev], paragraph, &dehyphenator, options);
+                    last_body = Some(prev);
+                }
+                _ => {
+                    out.push(paragraph);
+                    last_body = Some(out.len() - 1);
+                }
+            }
+        }
+
+        // Pages without body text (figures, blank pages) don't break the chain
+        if last_body.is_some() {
+            open = last_body;
+        }
+    }
+
+    for (index, paragraph) in out.iter_mut().enumerate() {
+        paragraph.index = index;
+    }
+    out
+}
+
+/// Extract paragraphs from `page_nums` in parallel and stitch them across
+/// page breaks with the document's [`ExtractionOptions`]
+pub fn extract_document_paragraphs(
+    doc: &PDFDocument,
+    page_nums: Vec<u32>,
+) -> Result<Vec<Paragraph>> {
+    let pages = extract_pages_parallel(doc, page_nums)?;
+    Ok(stitch_paragraphs(
+        pages.into_iter().map(|page| page.paragraphs),
+        doc.extraction_options(),
+    ))
+}
+
+/// True if `next` reads as the rest of `prev`
+fn continues_across_pages(prev: &Paragraph, next: &Paragraph, options: &ExtractionOptions) -> bool {
+    let (Some(prev_size), Some(next_size)) = (average_font_size(prev), average_font_size(next))
+    else {
+        return false;
+    };
+
+    // A change of size means a heading, caption or footnote
+    let ratio = prev_size.max(next_size) / prev_size.min(next_size);
+    if ratio > options.heading_size_ratio {
+        return false;
+    }
+
+    let ends_sentence = prev
+        .text
+        .trim_end()
+        .trim_end_matches(CLOSING_CHARS)
+        .ends_with(TERMINAL_PUNCTUATION);
+    !ends_sentence && !next.text.trim_start().is_empty()
+}
+
+/// Append `next` to `prev`, rejoining a word hyphenated over the break
+fn join(
+    prev: &mut Paragraph,
+    next: Paragraph,
+    dehyphenator: &Dehyphenator,
+    options: &ExtractionOptions,
+) {
+    prev.text.push(' ');
+    prev.text.push_str(&next.text);
+    prev.words.extend(next.words);
+
+    if options.dehyphenate {
+        dehyphenator.dehyphenate_in_place(prev, options);
+    } else {
+        prev.update_word_ranges();
+    }
+}
+
+fn average_font_size(paragraph: &Paragraph) -> Option<f32> {
+    if paragraph.words.is_empty() {
+        return None;
+    }
+    Some(paragraph.words.iter().map(|w| w.font_size).sum::<f32>() / paragraph.words.len() as f32)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::ParagraphKind;
+    use crate::test_support::line_paragraph;
+
+    fn texts(paragraphs: &[Paragraph]) -> Vec<&str> {
+        paragraphs.iter().map(|p| p.text.as_str()).collect()
+    }
+
+    #[test]
+    fn test_joins_sentence_broken_by_page() {
+        let pages = vec![
+            vec![
+                line_paragraph(0, 0, 100.0, 11.0, "First paragraph."),
+                line_paragraph(0, 0, 100.0, 11.0, "The packet is sent to the"),
+            ],
+            vec![
+                line_paragraph(0, 1, 100.0, 11.0, "router, which forwards it."),
+                line_paragraph(0, 1, 100.0, 11.0, "Another paragraph."),
+            ],
+        ];
+
+        let out = stitch_paragraphs(pages, &ExtractionOptions::default());
+        assert_eq!(
+            texts(&out),
+            [
+                "First paragraph.",
+                "The packet is sent to the router, which forwards it.",
+                "Another paragraph."
+            ]
+        );
+
+        let joined = &out[1];
+        assert_eq!(joined.index, 1);
+        assert_eq!(joined.page_number, 0);
+        assert_eq!(joined.words.first().unwrap().page_number, 0);
+        assert_eq!(joined.end_page_number(), 1);
+        assert_eq!(out[2].index, 2);
+    }
+
+    #[test]
+    fn test_keeps_finished_sentences_apart() {
+        let pages = vec![
+            vec![line_paragraph(0, 0, 100.0, 11.0, "It ended here.")],
+            vec![line_paragraph(0, 1, 100.0, 11.0, "So this starts fresh.")],
+            vec![line_paragraph(0, 2, 100.0, 11.0, "He said \"stop.\"")],
+            vec![line_paragraph(0, 3, 100.0, 11.0, "Next.")],
+        ];
+
+        let out = stitch_paragraphs(pages, &ExtractionOptions::default());
+        assert_eq!(out.len(), 4);
+    }
+
+    #[test]
+    fn test_size_change_blocks_join() {
+        let pages = vec![
+            vec![line_paragraph(0, 0, 100.0, 18.0, "Chapter Two")],
+            vec![line_paragraph(0, 1, 100.0, 11.0, "begins with body text.")],
+        ];
+
+        let out = stitch_paragraphs(pages, &ExtractionOptions::default());
+        assert_eq!(out.len(), 2);
+    }
+
+    #[test]
+    fn test_rejoins_hyphenated_word_over_break() {
+        let pages = vec![
+            vec![line_paragraph(0, 0, 100.0, 11.0, "A carefully exam-")],
+            vec![line_paragraph(0, 1, 100.0, 11.0, "ined claim.")],
+        ];
+
+        let out = stitch_paragraphs(pages, &ExtractionOptions::default());
+        assert_eq!(texts(&out), ["A carefully examined claim."]);
+    }
+
+    #[test]
+    fn test_skips_running_text_and_empty_pages() {
+        let mut folio = line_paragraph(0, 0, 100.0, 9.0, "12");
+        folio.kind = ParagraphKind::PageNumber;
+        let mut header = line_paragraph(0, 2, 100.0, 9.0, "Chapter 3");
+        header.kind = ParagraphKind::Header;
+
+        let pages = vec![
+            vec![
+                line_paragraph(0, 0, 100.0, 11.0, "The sentence runs on"),
+                folio,
+            ],
+            vec![],
+            vec![
+                header,
+                line_paragraph(0, 2, 100.0, 11.0, "past a blank page."),
+            ],
+        ];
+
+        let out = stitch_paragraphs(pages, &ExtractionOptions::default());
+        assert_eq!(
+            texts(&out),
+            ["The sentence runs on past a blank page.", "12", "Chapter 3"]
+        );
+        assert_eq!(out.iter().map(|p| p.index).collect::<Vec<_>>(), [0, 1, 2]);
+    }
+}
@@ -1,12 +1,17 @@
+use crate::error::Result;
+use crate::extraction::{
+    detect_paragraph_languages, detect_paragraphs_with_options,
+    extract_word_positions_with_options, Dehyphenator, ExtractionOptions, RunningText,
+};
+use crate::models::Paragraph;
+use crate::pdf::{DocumentSource, PDFDocument};
 use std::sync::mpsc::{channel, Receiver, Sender};
 use std::thread;
-use crate::models::Paragraph;
-use crate::pdf::PDFDocument;
-use crate::extraction::{extract_word_positions, detect_paragraphs, reassemble_hyphenated_words};
 
 pub struct StreamingExtractor {
     tx: Sender<ExtractionEvent>,
     rx: Receiver<ExtractionEvent>,
+    options: Option<ExtractionOptions>,
 }
 
 pub enum ExtractionEvent {
@@ -15,17 +20,62 @@ pub enum ExtractionEvent {
     Complete,
 }
 
+impl Default for StreamingExtractor {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 impl StreamingExtractor {
     pub fn new() -> Self {
         let (tx, rx) = channel();
-        Self { tx, rx }
+        Self {
+            tx,
+            rx,
+            options: None,
+        }
+    }
+
+    /// Use `options` for every extraction started by this extractor.
+    ///
+    /// Without this, each document's own
+    /// [`extraction_options`](PDFDocument::extraction_options) are used.
+    pub fn with_options(mut self, options: ExtractionOptions) -> Self {
+        self.options = Some(options);
+        self
+    }
+
+    /// Extract pages `start_page..=end_page` on a background thread.
+    ///
+    /// `source` can be a file path or an in-memory buffer; the worker opens its
+    /// own copy of the document from it.
+    pub fn extract_async(
+        &self,
+        source: impl Into<DocumentSource>,
+        start_page: u32,
+        end_page: u32,
+    ) {
+        let source = source.into();
+        self.spawn(move || PDFDocument::from_source(source), start_page, end_page);
     }
 
-    pub fn extract_async(&self, doc_path: String, start_page: u32, end_page: u32) {
+    /// Extract pages from an already-open document on a background thread.
+    ///
+    /// Encrypted documents must be unlocked first; the worker re-opens its copy
+    /// with the same password.
+    pub fn extract_document_async(&self, doc: &PDFDocument, start_page: u32, end_page: u32) {
+        self.spawn(doc.reopener(), start_page, end_page);
+    }
+
+    fn spawn<F>(&self, open: F, start_page: u32, end_page: u32)
+    where
+        F: FnOnce() -> Result<PDFDocument> + Send + 'static,
+    {
         let tx = self.tx.clone();
+        let options = self.options.clone();
 
         thread::spawn(move || {
-            let doc = match PDFDocument::open(&doc_path) {
+            let doc = match open() {
                 Ok(d) => d,
                 Err(e) => {
                     tx.send(ExtractionEvent::Error {
@@ -36,12 +86,37 @@ impl StreamingExtractor {
                 }
             };
 
+            let options = options.unwrap_or_else(|| doc.extraction_options().clone());
+            // Page errors are reported per page below, so a failure here
+            // only means running headers stay in
+            let running = RunningText::detect(&doc, &options).unwrap_or_default();
+            // Vocabulary grows as pages are read
+            let mut dehyphenator = Dehyphenator::new();
+
             for page in start_page..=end_page {
-                match extract_word_positions(&doc, page) {
-                    Ok(words) => {
-                        let mut paragraphs = detect_paragraphs(words);
-                        paragraphs = reassemble_hyphenated_words(paragraphs);
-                        tx.send(ExtractionEvent::PageComplete { page, paragraphs }).ok();
+                let result = extract_word_positions_with_options(&doc, page, &options)
+                    .and_then(|words| Ok((words, doc.page_info(page)?.height())));
+                match result {
+                    Ok((words, height)) => {
+                        let mut paragraphs = detect_paragraphs_with_options(words, &options);
+                        paragraphs = running.apply(paragraphs, height, &options);
+                        if options.dehyphenate {
+                            dehyphenator.learn_vocabulary(paragraphs.iter().flat_map(|p| &p.words));
+                            paragraphs = dehyphenator.dehyphenate(paragraphs, &options);
+                        }
+                        if options.detect_language {
+                            // Reported like any other page failure, as
+                            // parallel extraction does
+                            if let Err(e) = detect_paragraph_languages(&doc, &mut paragraphs) {
+                                tx.send(ExtractionEvent::Error {
+                                    page,
+                                    error: e.to_string()
+                                }).ok();
+                                continue;
+                            }
+                        }
+                        tx.send(ExtractionEvent::PageComplete { page, paragraphs })
+                            .ok();
                     }
                     Err(e) => {
                         tx.send(ExtractionEvent::Error {
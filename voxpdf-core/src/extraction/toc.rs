@@ -1,8 +1,90 @@
 use crate::error::Result;
-use crate::models::TocEntry;
+use crate::extraction::{
+    extract_pages_parallel_with_options, infer_toc, stitch_paragraphs, ExtractionOptions,
+};
+use crate::models::{Paragraph, TocEntry, TocTree};
 use crate::pdf::PDFDocument;
+use mupdf::pdf::PdfObject;
+use std::collections::HashSet;
 
+/// Minimum word overlap (Dice coefficient) between an entry's title and a
+/// paragraph for the paragraph to count as the entry's heading
+const MIN_TITLE_SIMILARITY: f32 = 0.6;
+
+/// Guards the raw outline walks against cyclic `/First` and `/Next` links
+pub(crate) const MAX_OUTLINE_ITEMS: usize = 10_000;
+pub(crate) const MAX_OUTLINE_DEPTH: usize = 64;
+
+/// Extract the document outline with each entry mapped to its heading
+/// paragraph.
+///
+/// `paragraph_index` points into [`PDFDocument::document_paragraphs`], which
+/// is extracted (and cached) the first time the document is asked for its
+/// TOC. Use [`extract_outline`] to skip that.
+///
+/// Documents without an outline get one inferred from their headings with
+/// [`infer_toc`], unless the extraction options turn `infer_toc` off. Font
+/// weight is one of the cues, so inference reads font attributes even if the
+/// options turn `font_attributes` off, extracting the document a second time.
+///
+/// # Example
+/// ```no_run
+/// use voxpdf_core::PDFDocument;
+/// use voxpdf_core::extraction::extract_toc;
+///
+/// let doc = PDFDocument::open("book.pdf")?;
+/// let paragraphs = doc.document_paragraphs()?;
+/// for entry in extract_toc(&doc)? {
+///     println!("{} -> {}", entry.title, paragraphs[entry.paragraph_index].text);
+/// }
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
 pub fn extract_toc(doc: &PDFDocument) -> Result<Vec<TocEntry>> {
+    let mut entries = extract_outline(doc)?;
+    if !entries.is_empty() {
+        map_toc_to_paragraphs(&mut entries, doc.document_paragraphs()?);
+    } else if doc.extraction_options().infer_toc {
+        entries = infer_headings(doc)?;
+    }
+    Ok(entries)
+}
+
+/// [`infer_toc`] over the document's paragraphs, read with font attributes
+fn infer_headings(doc: &PDFDocument) -> Result<Vec<TocEntry>> {
+    let options = doc.extraction_options();
+    if options.font_attributes {
+        return Ok(infer_toc(doc.document_paragraphs()?, options));
+    }
+
+    // Paragraph breaks don't depend on fonts, so indices still point into
+    // `document_paragraphs`
+    let options = ExtractionOptions {
+        font_attributes: true,
+        ..options.clone()
+    };
+    let pages =
+        extract_pages_parallel_with_options(doc, (0..doc.page_count()).collect(), &options)?;
+    let paragraphs = stitch_paragraphs(pages.into_iter().map(|page| page.paragraphs), &options);
+    Ok(infer_toc(&paragraphs, &options))
+}
+
+/// Extract the document outline as a [`TocTree`], with parent/child links,
+/// open state and the page and paragraph range of every entry
+pub fn extract_toc_tree(doc: &PDFDocument) -> Result<TocTree> {
+    let entries = extract_toc(doc)?;
+    if entries.is_empty() {
+        return Ok(TocTree::default());
+    }
+    Ok(TocTree::new(entries, doc.document_paragraphs()?))
+}
+
+/// Extract the document outline without mapping entries to paragraphs
+///
+/// Every `paragraph_index` is 0; `page_number` and `dest_top` say where each
+/// entry points.
+pub fn extract_outline(doc: &PDFDocument) -> Result<Vec<TocEntry>> {
+    doc.ensure_unlocked()?;
+
     // Try mupdf first (fast, production-grade)
     let outlines = match doc.doc.outlines() {
         Ok(outlines) => outlines,
@@ -10,7 +92,7 @@ pub fn extract_toc(doc: &PDFDocument) -> Result<Vec<TocEntry>> {
             // mupdf failed - try lopdf fallback if available
             #[cfg(feature = "toc-fallback")]
             {
-                return extract_toc_lopdf_fallback(doc);
+                return doc.lopdf_outline();
             }
 
             #[cfg(not(feature = "toc-fallback"))]
@@ -24,133 +106,157 @@ pub fn extract_toc(doc: &PDFDocument) -> Result<Vec<TocEntry>> {
     let mut entries = Vec::new();
     flatten_outlines(&outlines, 0, &mut entries);
 
+    // MuPDF's outline doesn't say which entries are expanded; read `/Count`
+    // from the outline items, which it flattens in the same order
+    let open = outline_open_states(doc);
+    if open.len() == entries.len() {
+        for (entry, is_open) in entries.iter_mut().zip(open) {
+            entry.is_open = is_open;
+        }
+    }
+
     Ok(entries)
 }
 
-/// Fallback TOC extraction using lopdf (more lenient with corrupted outlines)
-#[cfg(feature = "toc-fallback")]
-fn extract_toc_lopdf_fallback(doc: &PDFDocument) -> Result<Vec<TocEntry>> {
-    use lopdf::Document as LoPdfDocument;
-
-    // Load PDF with lopdf
-    let lopdf_doc = LoPdfDocument::load(doc.path())?;
-
-    // Get catalog and outlines
-    let catalog = lopdf_doc.catalog()?;
-    let outlines_ref = match catalog.get(b"Outlines") {
-        Ok(r) => r,
-        Err(_) => return Ok(Vec::new()), // No outlines
-    };
-
-    let outlines_id = outlines_ref.as_reference()?;
-    let outlines_dict = lopdf_doc.get_dictionary(outlines_id)?;
+/// Whether each outline item, in pre-order, has a positive `/Count`
+fn outline_open_states(doc: &PDFDocument) -> Vec<bool> {
+    let mut states = Vec::new();
+    let first = doc
+        .doc
+        .catalog()
+        .ok()
+        .and_then(|catalog| catalog.get_dict("Outlines").ok().flatten())
+        .and_then(|outlines| outlines.get_dict("First").ok().flatten());
+    if let Some(first) = first {
+        collect_open_states(first, 0, &mut states);
+    }
+    states
+}
 
-    // Get first outline entry
-    let first_ref = match outlines_dict.get(b"First") {
-        Ok(r) => r,
-        Err(_) => return Ok(Vec::new()), // Empty outlines
-    };
+fn collect_open_states(mut item: PdfObject, depth: usize, states: &mut Vec<bool>) {
+    if depth > MAX_OUTLINE_DEPTH {
+        return;
+    }
 
-    let first_id = first_ref.as_reference()?;
+    while states.len() < MAX_OUTLINE_ITEMS {
+        let count = item
+            .get_dict("Count")
+            .ok()
+            .flatten()
+            .and_then(|count| count.as_int().ok())
+            .unwrap_or(0);
+        states.push(count > 0);
 
-    // Traverse outline tree
-    let mut entries = Vec::new();
-    traverse_lopdf_outline(&lopdf_doc, first_id, 0, &mut entries)?;
+        if let Some(first) = item.get_dict("First").ok().flatten() {
+            collect_open_states(first, depth + 1, states);
+        }
+        match item.get_dict("Next").ok().flatten() {
+            Some(next) => item = next,
+            None => break,
+        }
+    }
+}
 
-    Ok(entries)
+/// Point each entry's `paragraph_index` at its heading in `paragraphs`, a
+/// document-wide sequence such as
+/// [`extract_document_paragraphs`](crate::extraction::extract_document_paragraphs)
+/// returns.
+///
+/// Among the body paragraphs on the entry's page, the first one reaching
+/// below `dest_top` is the heading if its words match the title; otherwise
+/// the paragraph that best matches the title wins. Without either, the
+/// entry keeps the destination paragraph, or the page's first one.
+pub fn map_toc_to_paragraphs(entries: &mut [TocEntry], paragraphs: &[Paragraph]) {
+    for entry in entries {
+        entry.paragraph_index = resolve_paragraph(entry, paragraphs);
+    }
 }
 
-#[cfg(feature = "toc-fallback")]
-fn traverse_lopdf_outline(
-    doc: &lopdf::Document,
-    outline_id: lopdf::ObjectId,
-    level: u8,
-    entries: &mut Vec<TocEntry>,
-) -> Result<()> {
-    let outline_dict = doc.get_dictionary(outline_id)?;
+fn resolve_paragraph(entry: &TocEntry, paragraphs: &[Paragraph]) -> usize {
+    let page = entry.page_number;
+    let on_page: Vec<usize> = (0..paragraphs.len())
+        .filter(|&i| !paragraphs[i].is_running_text() && touches_page(&paragraphs[i], page))
+        .collect();
 
-    // Extract title
-    if let Ok(title_obj) = outline_dict.get(b"Title") {
-        if let Ok(title_bytes) = title_obj.as_str() {
-            let title = String::from_utf8_lossy(title_bytes).to_string();
+    let Some(&first) = on_page.first() else {
+        // Nothing to read on the page (a figure or blank page): land on
+        // whatever comes next
+        return paragraphs
+            .iter()
+            .position(|p| !p.is_running_text() && p.page_number >= page)
+            .unwrap_or(paragraphs.len().saturating_sub(1));
+    };
 
-            // Extract destination page (if available)
-            let page_number = extract_page_from_dest(doc, outline_dict).unwrap_or(0);
+    let title = tokens(&entry.title);
+    let similarity = |i: usize| title_similarity(&title, &paragraphs[i].text);
 
-            entries.push(TocEntry::new(title, level, page_number, 0));
-        }
-    }
+    let by_position = entry.dest_top.and_then(|top| {
+        on_page
+            .iter()
+            .copied()
+            .find(|&i| reaches_below(&paragraphs[i], page, top))
+    });
+    let by_title = on_page
+        .iter()
+        .map(|&i| (i, similarity(i)))
+        .filter(|&(_, score)| score >= MIN_TITLE_SIMILARITY)
+        // Earliest paragraph wins ties
+        .max_by(|a, b| a.1.total_cmp(&b.1).then(b.0.cmp(&a.0)));
 
-    // Traverse children (First)
-    if let Ok(first_ref) = outline_dict.get(b"First") {
-        if let Ok(first_id) = first_ref.as_reference() {
-            traverse_lopdf_outline(doc, first_id, level + 1, entries)?;
-        }
+    match (by_position, by_title) {
+        (Some(i), _) if similarity(i) >= MIN_TITLE_SIMILARITY => i,
+        (_, Some((i, _))) => i,
+        (Some(i), None) => i,
+        (None, None) => first,
     }
+}
 
-    // Traverse siblings (Next)
-    if let Ok(next_ref) = outline_dict.get(b"Next") {
-        if let Ok(next_id) = next_ref.as_reference() {
-            traverse_lopdf_outline(doc, next_id, level, entries)?;
-        }
+/// True if some of the paragraph's words are on `page`
+fn touches_page(paragraph: &Paragraph, page: u32) -> bool {
+    if paragraph.words.is_empty() {
+        return paragraph.page_number == page;
     }
+    paragraph.words.iter().any(|w| w.page_number == page)
+}
 
-    Ok(())
+/// True if the paragraph has words on `page` below the display y `top`
+fn reaches_below(paragraph: &Paragraph, page: u32, top: f32) -> bool {
+    paragraph
+        .words
+        .iter()
+        .filter(|w| w.page_number == page)
+        .any(|w| w.bounds.y + w.bounds.height > top)
 }
 
-#[cfg(feature = "toc-fallback")]
-fn extract_page_from_dest(doc: &lopdf::Document, outline_dict: &lopdf::Dictionary) -> Option<u32> {
-    // Try to get Dest entry
-    if let Ok(dest) = outline_dict.get(b"Dest") {
-        // Dest can be a name, string, or array
-        if let Ok(dest_array) = dest.as_array() {
-            if let Some(page_ref) = dest_array.first() {
-                if let Ok(page_id) = page_ref.as_reference() {
-                    // Look up page number from page ID
-                    let pages = doc.get_pages();
-                    for (page_num, &id) in pages.iter() {
-                        if id == page_id {
-                            return Some(page_num.saturating_sub(1)); // Convert to 0-indexed
-                        }
-                    }
-                }
-            }
-        }
-    }
+/// Lowercased alphanumeric words
+fn tokens(text: &str) -> HashSet<String> {
+    text.split(|c: char| !c.is_alphanumeric())
+        .filter(|t| !t.is_empty())
+        .map(str::to_lowercase)
+        .collect()
+}
 
-    // Try A (Action) dictionary as fallback
-    if let Ok(action) = outline_dict.get(b"A") {
-        if let Ok(action_dict) = action.as_dict() {
-            if let Ok(dest) = action_dict.get(b"D") {
-                if let Ok(dest_array) = dest.as_array() {
-                    if let Some(page_ref) = dest_array.first() {
-                        if let Ok(page_id) = page_ref.as_reference() {
-                            let pages = doc.get_pages();
-                            for (page_num, &id) in pages.iter() {
-                                if id == page_id {
-                                    return Some(page_num.saturating_sub(1));
-                                }
-                            }
-                        }
-                    }
-                }
-            }
-        }
+/// Dice coefficient of the title's words and the paragraph's words, so long
+/// paragraphs that merely mention the title score low
+fn title_similarity(title: &HashSet<String>, text: &str) -> f32 {
+    let words = tokens(text);
+    if title.is_empty() || words.is_empty() {
+        return 0.0;
     }
-
-    None
+    let common = title.intersection(&words).count();
+    2.0 * common as f32 / (title.len() + words.len()) as f32
 }
 
 /// Recursively flatten the outline tree into a flat list of TOC entries
 fn flatten_outlines(outlines: &[mupdf::Outline], level: u8, entries: &mut Vec<TocEntry>) {
     for outline in outlines {
         // Create TOC entry from this outline entry
-        let entry = TocEntry::new(
-            outline.title.clone(),
-            level,
-            outline.page.unwrap_or(0),
-            0, // TODO: Map to paragraph_index when we have full document extraction
-        );
+        let mut entry = TocEntry::new(outline.title.clone(), level, outline.page.unwrap_or(0), 0);
+        // MuPDF resolves the destination to display coordinates; it leaves
+        // NaN where the destination has no position
+        if outline.page.is_some() && outline.y.is_finite() {
+            entry.dest_top = Some(outline.y);
+        }
         entries.push(entry);
 
         // Recursively process children
@@ -162,8 +268,86 @@ fn flatten_outlines(outlines: &[mupdf::Outline], level: u8, entries: &mut Vec<To
 
 #[cfg(test)]
 mod tests {
+    use super::*;
+    use crate::models::ParagraphKind;
+    use crate::test_support::line_paragraph;
+
+    /// A paragraph whose lines have their baseline at `y`
+    fn entry(title: &str, page: u32, dest_top: Option<f32>) -> TocEntry {
+        let mut entry = TocEntry::new(title.to_string(), 0, page, 0);
+        entry.dest_top = dest_top;
+        entry
+    }
+
+    fn document() -> Vec<Paragraph> {
+        vec![
+            line_paragraph(0, 0, 100.0, 12.0, "Preface text that opens the book."),
+            line_paragraph(1, 1, 100.0, 12.0, "The end of the preface."),
+            line_paragraph(2, 1, 300.0, 12.0, "1 Getting Started"),
+            line_paragraph(3, 1, 340.0, 12.0, "Body of the first chapter."),
+            line_paragraph(4, 1, 500.0, 12.0, "1.1 Installing"),
+            line_paragraph(
+                5,
+                1,
+                540.0,
+                12.0,
+                "Run the installer and follow getting started steps.",
+            ),
+        ]
+    }
+
+    fn resolve(title: &str, page: u32, dest_top: Option<f32>) -> usize {
+        let mut entries = [entry(title, page, dest_top)];
+        map_toc_to_paragraphs(&mut entries, &document());
+        entries[0].paragraph_index
+    }
+
+    #[test]
+    fn test_destination_position_finds_heading() {
+        assert_eq!(resolve("Getting Started", 1, Some(280.0)), 2);
+        assert_eq!(resolve("Installing", 1, Some(480.0)), 4);
+    }
+
+    #[test]
+    fn test_title_match_without_position() {
+        assert_eq!(resolve("1.1 Installing", 1, None), 4);
+        assert_eq!(resolve("Getting started", 1, None), 2);
+    }
+
+    #[test]
+    fn test_title_overrides_imprecise_position() {
+        // Destination points at the top of the page
+        assert_eq!(resolve("1 Getting Started", 1, Some(0.0)), 2);
+    }
+
     #[test]
-    fn test_toc_extraction_unit() {
-        // Unit tests for outline parsing logic
+    fn test_falls_back_to_position_then_page() {
+        assert_eq!(resolve("Something Else", 1, Some(480.0)), 4);
+        assert_eq!(resolve("Something Else", 1, None), 1);
+        assert_eq!(resolve("Preface", 0, None), 0);
+    }
+
+    #[test]
+    fn test_skips_running_text_and_empty_pages() {
+        let mut header = line_paragraph(0, 0, 30.0, 12.0, "Getting Started");
+        header.kind = ParagraphKind::Header;
+        let paragraphs = vec![header, line_paragraph(1, 2, 100.0, 12.0, "Getting Started")];
+
+        let mut entries = [entry("Getting Started", 0, None), entry("Figure", 1, None)];
+        map_toc_to_paragraphs(&mut entries, &paragraphs);
+        assert_eq!(entries[0].paragraph_index, 1);
+        assert_eq!(entries[1].paragraph_index, 1);
+    }
+
+    #[test]
+    fn test_title_similarity() {
+        let title = tokens("Chapter 3: Methods");
+        assert_eq!(title_similarity(&title, "CHAPTER 3 - METHODS"), 1.0);
+        assert!(
+            title_similarity(
+                &title,
+                "The methods of chapter 3 are described below at length."
+            ) < MIN_TITLE_SIMILARITY
+        );
     }
 }
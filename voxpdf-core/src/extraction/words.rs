@@ -1,12 +1,26 @@
 use crate::error::{Result, VoxPDFError};
-use crate::models::{Rect, Word};
+use crate::extraction::fonts::PageStyles;
+use crate::extraction::{detect_column_layout, order_words, ColumnLayout, ExtractionOptions};
+use crate::models::{Glyph, Point, Quad, Rect, TextDirection, Word};
 use crate::pdf::PDFDocument;
-use mupdf::TextPageOptions;
+use mupdf::{TextPageOptions, WriteMode};
+use unicode_segmentation::UnicodeSegmentation;
 
 // Pre-allocation capacity estimates for performance optimization
 const ESTIMATED_WORDS_PER_PAGE: usize = 500;
 const ESTIMATED_CHARS_PER_WORD: usize = 8;
 
+/// Space width in ems when a page has no space characters to measure
+/// (Times has 0.25, Helvetica 0.278)
+const DEFAULT_SPACE_WIDTH: f32 = 0.25;
+
+/// Wider "spaces" are tabs or justification fill, not the font's space
+const MAX_SPACE_WIDTH: f32 = 1.0;
+
+/// Baselines within this many degrees of their writing mode's direction
+/// count as upright
+const UPRIGHT_TOLERANCE_DEGREES: f32 = 1.0;
+
 /// Extract word positions from a PDF page using MuPDF.
 ///
 /// Groups consecutive TextChar objects into words based on spacing.
@@ -19,132 +33,581 @@ const ESTIMATED_CHARS_PER_WORD: usize = 8;
 /// # Returns
 ///
 /// A vector of words with their bounding boxes.
+///
+/// Uses the document's [`ExtractionOptions`]; see
+/// [`extract_word_positions_with_options`] to override them.
 pub fn extract_word_positions(doc: &PDFDocument, page_num: u32) -> Result<Vec<Word>> {
+    extract_word_positions_with_options(doc, page_num, doc.extraction_options())
+}
+
+/// Extract word positions using explicit [`ExtractionOptions`].
+///
+/// A space character, or a gap along the baseline wider than
+/// `options.word_gap_space_ratio` times the font's space width, starts a new
+/// word; text in scripts written without spaces is further split at its
+/// Unicode word boundaries. With `options.detect_columns`, words come
+/// back in reading order (left column before right column).
+pub fn extract_word_positions_with_options(
+    doc: &PDFDocument,
+    page_num: u32,
+    options: &ExtractionOptions,
+) -> Result<Vec<Word>> {
+    Ok(extract_words_with_layout(doc, page_num, options)?.0)
+}
+
+/// Detect a page's columns using the document's [`ExtractionOptions`].
+///
+/// Region indices refer to the words returned by [`extract_word_positions`]
+/// for the same page and options.
+///
+/// # Example
+/// ```no_run
+/// use voxpdf_core::PDFDocument;
+/// use voxpdf_core::extraction::extract_column_layout;
+///
+/// let doc = PDFDocument::open("paper.pdf")?;
+/// let layout = extract_column_layout(&doc, 0)?;
+/// println!("{} columns, {} regions", layout.column_count, layout.regions.len());
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+pub fn extract_column_layout(doc: &PDFDocument, page_num: u32) -> Result<ColumnLayout> {
+    Ok(extract_words_with_layout(doc, page_num, doc.extraction_options())?.1)
+}
+
+/// Words in reading order plus the column layout they were ordered by
+pub(crate) fn extract_words_with_layout(
+    doc: &PDFDocument,
+    page_num: u32,
+    options: &ExtractionOptions,
+) -> Result<(Vec<Word>, ColumnLayout)> {
+    let words = extract_raw_words(doc, page_num, options)?;
+
+    if !options.detect_columns {
+        let layout = ColumnLayout::single_region(page_num, &words);
+        return Ok((words, layout));
+    }
+
+    let mut layout = detect_column_layout(&words, options);
+    layout.page_number = page_num;
+    let words = order_words(words, &layout);
+    Ok((words, layout.renumbered()))
+}
+
+/// Words in MuPDF block order
+fn extract_raw_words(
+    doc: &PDFDocument,
+    page_num: u32,
+    options: &ExtractionOptions,
+) -> Result<Vec<Word>> {
     // Get the page
-    let page = doc.doc.load_page(page_num as i32).map_err(|e| {
-        VoxPDFError::ExtractionError(format!("Failed to load page {}: {}", page_num, e))
-    })?;
+    let page = doc.load_page(page_num)?;
 
-    // Convert to text page
+    // Convert to text page. MuPDF would insert spaces at gaps of its own
+    // choosing; word breaks are decided here instead.
     let text_page = page
-        .to_text_page(TextPageOptions::empty())
+        .to_text_page(TextPageOptions::INHIBIT_SPACES)
         .map_err(|e| VoxPDFError::ExtractionError(format!("Failed to extract text: {}", e)))?;
 
-    // Extract characters and group into words
-    let mut words = Vec::with_capacity(ESTIMATED_WORDS_PER_PAGE);
-    let mut current_word = String::with_capacity(ESTIMATED_CHARS_PER_WORD);
-    let mut word_chars: Vec<(char, f32, f32, f32)> = Vec::with_capacity(ESTIMATED_CHARS_PER_WORD); // (char, x, y, size)
+    // Fonts and colours come from a second pass, in the same character order
+    let styles = if options.font_attributes {
+        Some(PageStyles::read(&page)?)
+    } else {
+        None
+    };
 
-    // Word spacing threshold: characters farther apart than this start a new word
-    const WORD_SPACING_THRESHOLD: f32 = 3.0;
+    // Collect each line's characters, spaces included
+    let mut lines: Vec<(Vec<PageChar>, bool)> = Vec::new();
+    // Position of each character in text page order, for `styles`
+    let mut char_index = 0;
 
     for block in text_page.blocks() {
         for line in block.lines() {
-            let mut prev_x: Option<f32> = None;
+            let vertical = line.wmode() == WriteMode::Vertical;
+            let mut chars = Vec::new();
 
             for text_char in line.chars() {
+                let index = char_index;
+                char_index += 1;
+
                 let c = match text_char.char() {
                     Some(c) => c,
                     None => continue,
                 };
 
-                // Skip control characters and excessive whitespace
+                // Skip control characters
                 if c.is_control() {
                     continue;
                 }
 
                 let origin = text_char.origin();
-                let size = text_char.size();
-                let quad = text_char.quad();
-                let x = origin.x;
-                let y = origin.y;
-                let char_width = quad.ur.x - quad.ul.x;
-
-                // Check if this character starts a new word
-                let is_space = c.is_whitespace();
-                let is_new_word = if let Some(prev) = prev_x {
-                    let gap = x - prev;
-                    gap > WORD_SPACING_THRESHOLD || is_space
-                } else {
-                    false
-                };
+                chars.push(PageChar {
+                    c,
+                    origin: Point {
+                        x: origin.x,
+                        y: origin.y,
+                    },
+                    size: text_char.size(),
+                    quad: to_quad(&text_char.quad()),
+                    style: index,
+                });
+            }
 
-                if is_new_word || is_space {
-                    // Finish current word
-                    if !current_word.trim().is_empty() {
-                        let word = create_word_from_chars(
-                            current_word.trim().to_string(),
-                            &word_chars,
-                            page_num,
-                        );
-                        words.push(word);
-                    }
-                    current_word.clear();
-                    word_chars.clear();
-
-                    // If this is not just whitespace, start new word with this char
-                    if !is_space {
-                        current_word.push(c);
-                        word_chars.push((c, x, y, size));
-                    }
-                } else {
-                    // Continue current word
-                    current_word.push(c);
-                    word_chars.push((c, x, y, size));
-                }
+            lines.push((chars, vertical));
+        }
+    }
 
-                // Use actual character width from quad instead of approximation
-                prev_x = Some(x + char_width);
-            }
+    let page_space = space_width(lines.iter().flat_map(|(chars, _)| chars));
 
-            // Finish word at end of line
-            if !current_word.trim().is_empty() {
-                let word =
-                    create_word_from_chars(current_word.trim().to_string(), &word_chars, page_num);
-                words.push(word);
-                current_word.clear();
+    // Extract characters and group into words
+    let mut words = Vec::with_capacity(ESTIMATED_WORDS_PER_PAGE);
+    let mut word_chars: Vec<PageChar> = Vec::with_capacity(ESTIMATED_CHARS_PER_WORD);
+
+    for (chars, vertical) in &lines {
+        // The line's own spaces measure its font best
+        let space = space_width(chars)
+            .or(page_space)
+            .unwrap_or(DEFAULT_SPACE_WIDTH);
+        let mut prev: Option<&PageChar> = None;
+
+        for page_char in chars {
+            // Check if this character starts a new word
+            let is_space = page_char.c.is_whitespace();
+            let is_new_word = prev.is_some_and(|prev| {
+                prev.gap_to(page_char.origin) > options.word_gap(page_char.size, space)
+            });
+
+            if is_new_word || is_space {
+                push_words(
+                    &mut words,
+                    &word_chars,
+                    page_num,
+                    *vertical,
+                    styles.as_ref(),
+                    options,
+                );
                 word_chars.clear();
             }
+            if !is_space {
+                word_chars.push(*page_char);
+            }
 
-            // prev_x is reset implicitly at the start of next line
+            prev = Some(page_char);
         }
+
+        // Finish word at end of line
+        push_words(
+            &mut words,
+            &word_chars,
+            page_num,
+            *vertical,
+            styles.as_ref(),
+            options,
+        );
+        word_chars.clear();
     }
 
     Ok(words)
 }
 
-/// Create a Word from a collection of characters
-fn create_word_from_chars(text: String, chars: &[(char, f32, f32, f32)], page_num: u32) -> Word {
+/// Split a run of characters without gaps into words and add them
+///
+/// Scripts written without spaces are split at their Unicode word
+/// boundaries (UAX #29): between ideographs, at changes between hiragana
+/// and katakana, and where Thai or Lao meets another script. Elsewhere the
+/// run is one word, punctuation included.
+fn push_words(
+    words: &mut Vec<Word>,
+    chars: &[PageChar],
+    page_num: u32,
+    vertical: bool,
+    styles: Option<&PageStyles>,
+    options: &ExtractionOptions,
+) {
     if chars.is_empty() {
-        return Word::new(text, Rect::new(0.0, 0.0, 0.0, 0.0), page_num, 0.0);
+        return;
     }
 
-    // Single pass calculation instead of multiple fold operations
-    let mut min_x = f32::INFINITY;
-    let mut min_y = f32::INFINITY;
-    let mut max_x = f32::NEG_INFINITY;
-    let mut max_y = f32::NEG_INFINITY;
-    let mut font_size_sum = 0.0;
+    let text: String = chars.iter().map(|ch| ch.c).collect();
+    let mut start = 0;
+    for (at, _) in text.split_word_bound_indices().skip(1) {
+        // Every character is one `PageChar`; turn the byte offset into an index
+        let end = text[..at].chars().count();
+        if is_word_break(chars[end - 1].c, chars[end].c) {
+            words.push(word_from_run(
+                &chars[start..end],
+                page_num,
+                vertical,
+                styles,
+                options,
+            ));
+            start = end;
+        }
+    }
+    words.push(word_from_run(
+        &chars[start..],
+        page_num,
+        vertical,
+        styles,
+        options,
+    ));
+}
+
+fn word_from_run(
+    chars: &[PageChar],
+    page_num: u32,
+    vertical: bool,
+    styles: Option<&PageStyles>,
+    options: &ExtractionOptions,
+) -> Word {
+    let text = chars.iter().map(|ch| ch.c).collect();
+    create_word_from_chars(text, chars, page_num, vertical, styles, options)
+}
 
-    for &(_, x, y, size) in chars {
-        min_x = min_x.min(x);
-        min_y = min_y.min(y);
-        max_x = max_x.max(x + size * 0.6);
-        max_y = max_y.max(y + size);
-        font_size_sum += size;
+/// Median advance of the space characters among `chars`, in ems
+fn space_width<'a>(chars: impl IntoIterator<Item = &'a PageChar>) -> Option<f32> {
+    let mut widths: Vec<f32> = chars
+        .into_iter()
+        .filter(|ch| ch.c == ' ' && ch.size > 0.0)
+        .map(|ch| ch.advance() / ch.size)
+        .filter(|&em| em > 0.0 && em < MAX_SPACE_WIDTH)
+        .collect();
+    if widths.is_empty() {
+        return None;
     }
+    widths.sort_by(f32::total_cmp);
+    Some(widths[widths.len() / 2])
+}
+
+/// True if a Unicode word boundary between `a` and `b` separates two words
+///
+/// Letters and digits on both sides are required, so punctuation stays
+/// attached to its word ("日本。", "(see"). Next to ideographs, closing
+/// punctuation ends a word ("本。|次") and opening punctuation starts one
+/// ("次|「語").
+fn is_word_break(a: char, b: char) -> bool {
+    if !a.is_alphanumeric() {
+        return !a.is_whitespace() && !is_opening(a) && is_ideographic(b);
+    }
+    if is_opening(b) {
+        return is_ideographic(a);
+    }
+    b.is_alphanumeric()
+        && (is_ideographic(a)
+            || is_ideographic(b)
+            || is_complex_context(a) != is_complex_context(b))
+}
 
-    let width = max_x - min_x;
-    let height = max_y - min_y;
+/// Brackets and quotes that open, and so belong to the word after them
+fn is_opening(c: char) -> bool {
+    matches!(
+        c,
+        '(' | '['
+            | '{'
+            | '“'
+            | '‘'
+            | '„'
+            | '«'
+            | '‹'
+            | '¿'
+            | '¡'
+            | '「'
+            | '『'
+            | '（'
+            | '【'
+            | '〈'
+            | '《'
+            | '〔'
+            | '［'
+            | '｛'
+    )
+}
+
+/// Han ideographs and Japanese kana, written without spaces between words
+fn is_ideographic(c: char) -> bool {
+    matches!(c,
+        '\u{2E80}'..='\u{2FDF}'       // CJK and Kangxi radicals
+        | '\u{3005}'..='\u{3007}'     // Iteration mark, closing mark, zero
+        | '\u{3040}'..='\u{30FF}'     // Hiragana and katakana
+        | '\u{31F0}'..='\u{31FF}'     // Katakana phonetic extensions
+        | '\u{3400}'..='\u{4DBF}'     // Extension A
+        | '\u{4E00}'..='\u{9FFF}'     // Unified ideographs
+        | '\u{F900}'..='\u{FAFF}'     // Compatibility ideographs
+        | '\u{FF66}'..='\u{FF9F}'     // Halfwidth katakana
+        | '\u{20000}'..='\u{3FFFF}') // Supplementary ideographic planes
+}
+
+/// Thai, Lao, Myanmar and Khmer: scripts whose words need a dictionary to
+/// find. A spaceless run of them is kept whole, as a phrase.
+fn is_complex_context(c: char) -> bool {
+    matches!(c,
+        '\u{0E00}'..='\u{0EFF}' | '\u{1000}'..='\u{109F}' | '\u{1780}'..='\u{17FF}')
+}
+
+/// A character of the word being built
+#[derive(Clone, Copy)]
+struct PageChar {
+    c: char,
+    origin: Point,
+    size: f32,
+    /// MuPDF's glyph box: the advance width along the baseline, from the
+    /// font's descender to its ascender
+    quad: Quad,
+    /// Index into the page's [`PageStyles`]
+    style: usize,
+}
+
+impl PageChar {
+    /// Length of the character's advance along the baseline
+    fn advance(&self) -> f32 {
+        (self.quad.ur.x - self.quad.ul.x).hypot(self.quad.ur.y - self.quad.ul.y)
+    }
+
+    /// Distance along the baseline from the end of this character's
+    /// advance to `next`, negative if they overlap
+    fn gap_to(&self, next: Point) -> f32 {
+        let dx = self.quad.ur.x - self.quad.ul.x;
+        let dy = self.quad.ur.y - self.quad.ul.y;
+        let ex = next.x - (self.origin.x + dx);
+        let ey = next.y - (self.origin.y + dy);
+
+        let advance = self.advance();
+        if advance == 0.0 {
+            return ex;
+        }
+        (ex * dx + ey * dy) / advance
+    }
+}
+
+/// Create a Word from a collection of characters
+fn create_word_from_chars(
+    text: String,
+    chars: &[PageChar],
+    page_num: u32,
+    vertical: bool,
+    styles: Option<&PageStyles>,
+    options: &ExtractionOptions,
+) -> Word {
+    let Some(first) = chars.first() else {
+        return Word::new(text, Rect::new(0.0, 0.0, 0.0, 0.0), page_num, 0.0);
+    };
+
+    let mut bounds = first.quad.bounds();
+    let mut font_size_sum = 0.0;
+    for ch in chars {
+        bounds = bounds.union(&ch.quad.bounds());
+        font_size_sum += ch.size;
+    }
     let avg_font_size = font_size_sum / chars.len() as f32;
 
-    Word::new(text, Rect::new(min_x, min_y, width, height), page_num, avg_font_size)
+    let mut word = Word::new(text, bounds, page_num, avg_font_size);
+    if !is_upright(&first.quad, vertical) {
+        let last = chars.last().unwrap_or(first);
+        word.quad = Some(Quad {
+            ul: first.quad.ul,
+            ur: last.quad.ur,
+            ll: first.quad.ll,
+            lr: last.quad.lr,
+        });
+    }
+    word.origin = Some(first.origin);
+    word.direction = if vertical {
+        TextDirection::TopToBottom
+    } else if is_right_to_left(chars.iter().map(|ch| ch.c)) {
+        TextDirection::RightToLeft
+    } else {
+        TextDirection::LeftToRight
+    };
+
+    if let Some((font, color)) = styles.and_then(|styles| styles.get(first.style)) {
+        word.font = Some(font.clone());
+        word.color = Some(color);
+    }
+
+    if options.glyph_boxes {
+        word.glyphs = chars
+            .iter()
+            .map(|ch| Glyph {
+                c: ch.c,
+                bounds: ch.quad.bounds(),
+            })
+            .collect();
+    }
+
+    word
+}
+
+fn to_quad(quad: &mupdf::Quad) -> Quad {
+    let point = |p: &mupdf::Point| Point { x: p.x, y: p.y };
+    Quad {
+        ul: point(&quad.ul),
+        ur: point(&quad.ur),
+        ll: point(&quad.ll),
+        lr: point(&quad.lr),
+    }
+}
+
+/// True if a character's baseline runs left to right, or top to bottom in
+/// vertical writing mode
+fn is_upright(quad: &Quad, vertical: bool) -> bool {
+    let expected = if vertical { 90.0 } else { 0.0 };
+    // A zero advance has no direction to judge by
+    quad.ul == quad.ur || (quad.angle() - expected).abs() <= UPRIGHT_TOLERANCE_DEGREES
+}
+
+/// True if the first strongly directional character is from a
+/// right-to-left script (Hebrew, Arabic, Syriac, Thaana, N'Ko and their
+/// presentation forms)
+fn is_right_to_left(chars: impl IntoIterator<Item = char>) -> bool {
+    chars
+        .into_iter()
+        .find(|c| c.is_alphabetic())
+        .is_some_and(|c| {
+            matches!(c,
+                '\u{0590}'..='\u{08FF}' | '\u{FB1D}'..='\u{FDFF}' | '\u{FE70}'..='\u{FEFF}')
+        })
 }
 
 #[cfg(test)]
 mod tests {
+    use super::*;
+
     #[test]
     fn test_parse_operations() {
         // Integration tests in tests/word_positions.rs provide coverage
     }
+
+    #[test]
+    fn test_right_to_left_detection() {
+        assert!(is_right_to_left("שלום".chars()));
+        assert!(is_right_to_left("«مرحبا»".chars()));
+        assert!(!is_right_to_left("hello".chars()));
+        assert!(!is_right_to_left("(12)".chars()));
+    }
+
+    fn char_at(x: f32, y: f32, dx: f32, dy: f32) -> PageChar {
+        let p = |x, y| Point { x, y };
+        PageChar {
+            c: 'a',
+            origin: p(x, y),
+            size: 10.0,
+            quad: Quad {
+                ul: p(x, y - 8.0),
+                ur: p(x + dx, y + dy - 8.0),
+                ll: p(x, y + 2.0),
+                lr: p(x + dx, y + dy + 2.0),
+            },
+            style: 0,
+        }
+    }
+
+    fn run(text: &str) -> Vec<PageChar> {
+        text.chars()
+            .enumerate()
+            .map(|(i, c)| PageChar {
+                c,
+                ..char_at(i as f32 * 3.0, 0.0, 3.0, 0.0)
+            })
+            .collect()
+    }
+
+    fn segment(text: &str) -> Vec<String> {
+        let mut words = Vec::new();
+        let options = ExtractionOptions::default();
+        push_words(&mut words, &run(text), 0, false, None, &options);
+        words.into_iter().map(|w| w.text).collect()
+    }
+
+    #[test]
+    fn test_segmentation_without_spaces() {
+        // Latin runs stay whole, punctuation and all
+        assert_eq!(segment("don't,"), ["don't,"]);
+        assert_eq!(segment("e.g."), ["e.g."]);
+        // Ideographs split; katakana runs and trailing punctuation stay
+        assert_eq!(segment("日本語"), ["日", "本", "語"]);
+        assert_eq!(segment("コンピュータ。"), ["コンピュータ。"]);
+        assert_eq!(segment("日本。次「語」"), ["日", "本。", "次", "「語」"]);
+        assert_eq!(segment("iPhone用"), ["iPhone", "用"]);
+        // Thai can't be split without a dictionary, but script changes can
+        assert_eq!(segment("ภาษาไทย"), ["ภาษาไทย"]);
+        assert_eq!(segment("ภาษาไทยOK"), ["ภาษาไทย", "OK"]);
+    }
+
+    #[test]
+    fn test_space_width() {
+        // 3pt advances at 10pt
+        let mut chars = run("a b  c");
+        assert_eq!(space_width(&chars), Some(0.3));
+        chars[1].quad.ur.x += 2.0;
+        chars[3].quad.ur.x -= 1.0;
+        // Median of 0.5, 0.2 and 0.3 ems
+        assert_eq!(space_width(&chars), Some(0.3));
+        // A tab stop is no measure of the font
+        chars[1].quad.ur.x += 20.0;
+        chars[4].quad.ur.x += 20.0;
+        assert_eq!(space_width(&chars), Some(0.2));
+        assert_eq!(space_width(&run("abc")), None);
+    }
+
+    #[test]
+    fn test_gap_along_baseline() {
+        let a = char_at(100.0, 50.0, 6.0, 0.0);
+        assert_eq!(a.gap_to(Point { x: 106.0, y: 50.0 }), 0.0);
+        assert_eq!(a.gap_to(Point { x: 110.0, y: 50.0 }), 4.0);
+
+        // Running down the page, the x coordinate stays put
+        let b = char_at(100.0, 50.0, 0.0, 6.0);
+        assert_eq!(b.gap_to(Point { x: 100.0, y: 56.0 }), 0.0);
+        assert_eq!(b.gap_to(Point { x: 100.0, y: 60.0 }), 4.0);
+    }
+
+    #[test]
+    fn test_upright_quads() {
+        assert!(is_upright(&char_at(0.0, 0.0, 6.0, 0.0).quad, false));
+        assert!(is_upright(&char_at(0.0, 0.0, 6.0, 0.05).quad, false));
+        assert!(!is_upright(&char_at(0.0, 0.0, 6.0, 6.0).quad, false));
+        assert!(!is_upright(&char_at(0.0, 0.0, -6.0, 0.0).quad, false));
+        assert!(is_upright(&char_at(0.0, 0.0, 0.0, 6.0).quad, true));
+        assert!(is_upright(&char_at(0.0, 0.0, 0.0, 0.0).quad, false));
+    }
+
+    #[test]
+    fn test_word_bounds_from_quads() {
+        let chars = [
+            char_at(100.0, 50.0, 6.0, 0.0),
+            char_at(106.0, 50.0, 4.0, 0.0),
+        ];
+        let word = create_word_from_chars(
+            "ab".to_string(),
+            &chars,
+            0,
+            false,
+            None,
+            &ExtractionOptions::default(),
+        );
+        // From the ascender of the first to the descender of the last
+        assert_eq!(word.bounds, Rect::new(100.0, 42.0, 10.0, 10.0));
+        assert_eq!(word.quad, None);
+    }
+
+    #[test]
+    fn test_rotated_word_keeps_its_quad() {
+        // 45 degrees, each character advancing (3, 3)
+        let chars = [
+            char_at(100.0, 50.0, 3.0, 3.0),
+            char_at(103.0, 53.0, 3.0, 3.0),
+        ];
+        let word = create_word_from_chars(
+            "ab".to_string(),
+            &chars,
+            0,
+            false,
+            None,
+            &ExtractionOptions::default(),
+        );
+        let quad = word.quad.unwrap();
+        assert_eq!(quad.ul, Point { x: 100.0, y: 42.0 });
+        assert_eq!(quad.lr, Point { x: 106.0, y: 58.0 });
+        assert_eq!(quad.angle(), 45.0);
+        assert_eq!(word.bounds, quad.bounds());
+    }
 }
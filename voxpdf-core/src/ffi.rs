@@ -1,5 +1,12 @@
 use crate::error::VoxPDFError;
+use crate::extraction::{ExtractionOptions, ExtractionProfile};
+use crate::models::{
+    CoordinateSpace, Language, LanguageSource, PageInfo, Paragraph, ParagraphKind, Quad, Rect,
+    Sentence, TextDirection, TextRange, Word,
+};
+use crate::normalization::NormalizedText;
 use crate::pdf::PDFDocument;
+use crate::ssml::{SsmlOptions, SsmlRenderer};
 use std::ffi::{CStr, CString};
 use std::os::raw::c_char;
 
@@ -13,6 +20,11 @@ pub enum CVoxPDFError {
     IoError = 3,
     OutOfMemory = 4,
     InvalidText = 5,
+    PasswordRequired = 6,
+    /// An enum argument passed as an integer had no matching value
+    InvalidArgument = 7,
+    /// A paragraph, sentence, word or other item index past the end
+    IndexOutOfRange = 8,
 }
 
 impl From<VoxPDFError> for CVoxPDFError {
@@ -21,11 +33,147 @@ impl From<VoxPDFError> for CVoxPDFError {
             VoxPDFError::InvalidPDF(_) => CVoxPDFError::InvalidPDF,
             VoxPDFError::PageNotFound(_, _) => CVoxPDFError::PageNotFound,
             VoxPDFError::Io(_) => CVoxPDFError::IoError,
+            VoxPDFError::PasswordRequired => CVoxPDFError::PasswordRequired,
             VoxPDFError::ExtractionError(_) => CVoxPDFError::InvalidPDF,
         }
     }
 }
 
+/// Document metadata fields readable through `voxpdf_get_metadata_field`.
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CMetadataField {
+    /// Info `/Title`, falling back to XMP `dc:title`
+    Title = 0,
+    /// Info `/Author`, falling back to XMP `dc:creator`
+    Author = 1,
+    Subject = 2,
+    Keywords = 3,
+    Creator = 4,
+    Producer = 5,
+    /// ISO 8601
+    CreationDate = 6,
+    /// ISO 8601
+    ModificationDate = 7,
+    /// Catalog `/Lang`, falling back to XMP `dc:language`
+    Language = 8,
+}
+
+/// C enums arrive as plain integers; an out-of-range value can't be a Rust
+/// enum, so validate before matching.
+impl TryFrom<u32> for CMetadataField {
+    type Error = CVoxPDFError;
+
+    fn try_from(value: u32) -> Result<Self, Self::Error> {
+        Ok(match value {
+            0 => CMetadataField::Title,
+            1 => CMetadataField::Author,
+            2 => CMetadataField::Subject,
+            3 => CMetadataField::Keywords,
+            4 => CMetadataField::Creator,
+            5 => CMetadataField::Producer,
+            6 => CMetadataField::CreationDate,
+            7 => CMetadataField::ModificationDate,
+            8 => CMetadataField::Language,
+            _ => return Err(CVoxPDFError::InvalidArgument),
+        })
+    }
+}
+
+/// Built-in extraction profiles for `voxpdf_apply_profile`.
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CExtractionProfile {
+    Novel = 0,
+    Academic = 1,
+    Textbook = 2,
+    Legal = 3,
+    Slides = 4,
+    Magazine = 5,
+}
+
+impl TryFrom<u32> for CExtractionProfile {
+    type Error = CVoxPDFError;
+
+    fn try_from(value: u32) -> Result<Self, Self::Error> {
+        Ok(match value {
+            0 => CExtractionProfile::Novel,
+            1 => CExtractionProfile::Academic,
+            2 => CExtractionProfile::Textbook,
+            3 => CExtractionProfile::Legal,
+            4 => CExtractionProfile::Slides,
+            5 => CExtractionProfile::Magazine,
+            _ => return Err(CVoxPDFError::InvalidArgument),
+        })
+    }
+}
+
+impl From<CExtractionProfile> for ExtractionProfile {
+    fn from(profile: CExtractionProfile) -> Self {
+        match profile {
+            CExtractionProfile::Novel => ExtractionProfile::Novel,
+            CExtractionProfile::Academic => ExtractionProfile::Academic,
+            CExtractionProfile::Textbook => ExtractionProfile::Textbook,
+            CExtractionProfile::Legal => ExtractionProfile::Legal,
+            CExtractionProfile::Slides => ExtractionProfile::Slides,
+            CExtractionProfile::Magazine => ExtractionProfile::Magazine,
+        }
+    }
+}
+
+impl From<ExtractionProfile> for CExtractionProfile {
+    fn from(profile: ExtractionProfile) -> Self {
+        match profile {
+            ExtractionProfile::Novel => CExtractionProfile::Novel,
+            ExtractionProfile::Academic => CExtractionProfile::Academic,
+            ExtractionProfile::Textbook => CExtractionProfile::Textbook,
+            ExtractionProfile::Legal => CExtractionProfile::Legal,
+            ExtractionProfile::Slides => CExtractionProfile::Slides,
+            ExtractionProfile::Magazine => CExtractionProfile::Magazine,
+        }
+    }
+}
+
+/// What a paragraph is, as reported in `CParagraph::kind`.
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CParagraphKind {
+    Body = 0,
+    Header = 1,
+    Footer = 2,
+    PageNumber = 3,
+}
+
+impl From<ParagraphKind> for CParagraphKind {
+    fn from(kind: ParagraphKind) -> Self {
+        match kind {
+            ParagraphKind::Body => CParagraphKind::Body,
+            ParagraphKind::Header => CParagraphKind::Header,
+            ParagraphKind::Footer => CParagraphKind::Footer,
+            ParagraphKind::PageNumber => CParagraphKind::PageNumber,
+        }
+    }
+}
+
+/// Where a language came from, as reported in `CLanguage::source`.
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CLanguageSource {
+    Document = 0,
+    Tagged = 1,
+    Detected = 2,
+}
+
+impl From<LanguageSource> for CLanguageSource {
+    fn from(source: LanguageSource) -> Self {
+        match source {
+            LanguageSource::Document => CLanguageSource::Document,
+            LanguageSource::Tagged => CLanguageSource::Tagged,
+            LanguageSource::Detected => CLanguageSource::Detected,
+        }
+    }
+}
+
 // Opaque pointer for PDFDocument
 #[repr(C)]
 pub struct CVoxPDFDocument {
@@ -44,6 +192,116 @@ pub struct CWordPosition {
     pub font_size: f32,
 }
 
+/// Direction a word's characters advance in, as reported in
+/// `CWordStyle::direction`.
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CTextDirection {
+    LeftToRight = 0,
+    RightToLeft = 1,
+    TopToBottom = 2,
+}
+
+impl From<TextDirection> for CTextDirection {
+    fn from(direction: TextDirection) -> Self {
+        match direction {
+            TextDirection::LeftToRight => CTextDirection::LeftToRight,
+            TextDirection::RightToLeft => CTextDirection::RightToLeft,
+            TextDirection::TopToBottom => CTextDirection::TopToBottom,
+        }
+    }
+}
+
+/// C-compatible word style.
+#[repr(C)]
+#[derive(Debug, Clone, Copy)]
+pub struct CWordStyle {
+    /// Baseline origin of the first character
+    pub origin_x: f32,
+    pub origin_y: f32,
+    pub direction: CTextDirection,
+    /// Font fields and `color` are only set when the document's options
+    /// set `font_attributes`
+    pub has_font: bool,
+    pub bold: bool,
+    pub italic: bool,
+    pub monospace: bool,
+    /// Fill colour as 0xRRGGBB
+    pub color: u32,
+    /// Character boxes available through `voxpdf_get_word_glyph`; 0 unless
+    /// the document's options set `glyph_boxes`
+    pub glyph_count: usize,
+    /// Set for text that isn't upright on the page, whose word position is
+    /// then the axis-aligned box around `quad`
+    pub has_quad: bool,
+    pub quad: CQuad,
+}
+
+/// C-compatible quadrilateral: the upper and lower corners at the start
+/// and end of a word.
+#[repr(C)]
+#[derive(Debug, Clone, Copy, Default)]
+pub struct CQuad {
+    pub ul_x: f32,
+    pub ul_y: f32,
+    pub ur_x: f32,
+    pub ur_y: f32,
+    pub ll_x: f32,
+    pub ll_y: f32,
+    pub lr_x: f32,
+    pub lr_y: f32,
+}
+
+impl From<Quad> for CQuad {
+    fn from(quad: Quad) -> Self {
+        Self {
+            ul_x: quad.ul.x,
+            ul_y: quad.ul.y,
+            ur_x: quad.ur.x,
+            ur_y: quad.ur.y,
+            ll_x: quad.ll.x,
+            ll_y: quad.ll.y,
+            lr_x: quad.lr.x,
+            lr_y: quad.lr.y,
+        }
+    }
+}
+
+impl From<&Word> for CWordStyle {
+    fn from(word: &Word) -> Self {
+        let origin = word.origin.unwrap_or(crate::models::Point {
+            x: word.bounds.x,
+            y: word.bounds.y,
+        });
+        let font = word.font.as_ref();
+        Self {
+            origin_x: origin.x,
+            origin_y: origin.y,
+            direction: word.direction.into(),
+            has_font: font.is_some(),
+            bold: font.is_some_and(|f| f.bold),
+            italic: font.is_some_and(|f| f.italic),
+            monospace: font.is_some_and(|f| f.monospace),
+            color: word.color.unwrap_or(0),
+            glyph_count: word.glyphs.len(),
+            has_quad: word.quad.is_some(),
+            quad: word.quad.map(CQuad::from).unwrap_or_default(),
+        }
+    }
+}
+
+/// C-compatible character box.
+#[repr(C)]
+#[derive(Debug, Clone, Copy)]
+pub struct CGlyph {
+    /// Unicode scalar value
+    pub codepoint: u32,
+    pub x: f32,
+    pub y: f32,
+    pub width: f32,
+    pub height: f32,
+}
+
 /// C-compatible paragraph structure.
 #[repr(C)]
 #[derive(Debug, Clone, Copy)]
@@ -51,6 +309,58 @@ pub struct CParagraph {
     pub index: usize,
     pub page_number: u32,
     pub word_count: usize,
+    /// Running headers, footers and page numbers are only returned when the
+    /// document's options set `keep_running_text`
+    pub kind: CParagraphKind,
+}
+
+/// C-compatible position of a word or sentence in its paragraph's text, as
+/// `start..end` offsets in UTF-8 bytes and in UTF-16 code units.
+#[repr(C)]
+#[derive(Debug, Clone, Copy, Default)]
+pub struct CTextRange {
+    pub utf8_start: usize,
+    pub utf8_end: usize,
+    pub utf16_start: usize,
+    pub utf16_end: usize,
+}
+
+impl From<&TextRange> for CTextRange {
+    fn from(range: &TextRange) -> Self {
+        CTextRange {
+            utf8_start: range.utf8.start,
+            utf8_end: range.utf8.end,
+            utf16_start: range.utf16.start,
+            utf16_end: range.utf16.end,
+        }
+    }
+}
+
+/// C-compatible sentence of a document paragraph.
+#[repr(C)]
+#[derive(Debug, Clone, Copy, Default)]
+pub struct CSentence {
+    pub index: usize,
+    /// Offsets into the paragraph's text
+    pub text_range: CTextRange,
+    /// Indices into the paragraph's words, `word_start..word_end`
+    pub word_start: usize,
+    pub word_end: usize,
+    /// One box per printed line, see `voxpdf_get_sentence_rect`
+    pub rect_count: usize,
+    /// Page the sentence starts on
+    pub page_number: u32,
+}
+
+/// C-compatible language of a paragraph or sentence. The BCP 47 tag is
+/// returned separately as a string.
+#[repr(C)]
+#[derive(Debug, Clone, Copy)]
+pub struct CLanguage {
+    /// 0–1: 1 for tagged text, otherwise how clearly the text points at
+    /// this language
+    pub confidence: f32,
+    pub source: CLanguageSource,
 }
 
 /// C-compatible TOC entry structure.
@@ -60,6 +370,136 @@ pub struct CTocEntry {
     pub level: u8,
     pub page_number: u32,
     pub paragraph_index: usize,
+    /// Inferred from heading typography because the document has no outline
+    pub inferred: bool,
+    /// 1.0 for outline entries
+    pub confidence: f32,
+}
+
+/// C-compatible TOC tree node.
+///
+/// Node ids are TOC entry indices. Links that don't exist are flagged by the
+/// matching `has_*` field (or `child_count == 0`) and left as 0.
+#[repr(C)]
+#[derive(Debug, Clone, Copy)]
+pub struct CTocNode {
+    pub id: usize,
+    pub has_parent: bool,
+    pub parent: usize,
+    pub child_count: usize,
+    pub first_child: usize,
+    pub has_next_sibling: bool,
+    pub next_sibling: usize,
+    pub has_prev_sibling: bool,
+    pub prev_sibling: usize,
+    /// Children are shown expanded in the outline
+    pub is_open: bool,
+    /// Document paragraphs covered, `paragraph_start..paragraph_end`
+    pub paragraph_start: usize,
+    pub paragraph_end: usize,
+    /// Pages covered, inclusive
+    pub page_start: u32,
+    pub page_end: u32,
+}
+
+impl CTocNode {
+    fn new(tree: &crate::models::TocTree, node: &crate::models::TocNode) -> Self {
+        let next = tree.next_sibling(node.id).map(|n| n.id);
+        let prev = tree.prev_sibling(node.id).map(|n| n.id);
+        CTocNode {
+            id: node.id,
+            has_parent: node.parent.is_some(),
+            parent: node.parent.unwrap_or(0),
+            child_count: node.children.len(),
+            first_child: node.children.first().copied().unwrap_or(0),
+            has_next_sibling: next.is_some(),
+            next_sibling: next.unwrap_or(0),
+            has_prev_sibling: prev.is_some(),
+            prev_sibling: prev.unwrap_or(0),
+            is_open: node.entry.is_open,
+            paragraph_start: node.paragraph_range.start,
+            paragraph_end: node.paragraph_range.end,
+            page_start: *node.page_range.start(),
+            page_end: *node.page_range.end(),
+        }
+    }
+}
+
+/// C-compatible rectangle (origin plus size).
+#[repr(C)]
+#[derive(Debug, Clone, Copy)]
+pub struct CRect {
+    pub x: f32,
+    pub y: f32,
+    pub width: f32,
+    pub height: f32,
+}
+
+impl From<&Rect> for CRect {
+    fn from(rect: &Rect) -> Self {
+        CRect {
+            x: rect.x,
+            y: rect.y,
+            width: rect.width,
+            height: rect.height,
+        }
+    }
+}
+
+impl From<CRect> for Rect {
+    fn from(rect: CRect) -> Self {
+        Rect::new(rect.x, rect.y, rect.width, rect.height)
+    }
+}
+
+/// C-compatible page geometry. Boxes are in PDF user space (bottom-left origin).
+#[repr(C)]
+#[derive(Debug, Clone, Copy)]
+pub struct CPageInfo {
+    pub index: u32,
+    pub media_box: CRect,
+    pub crop_box: CRect,
+    pub rotation: u16,
+    pub user_unit: f32,
+    /// Displayed width in points (after rotation)
+    pub width: f32,
+    /// Displayed height in points (after rotation)
+    pub height: f32,
+}
+
+/// Target coordinate space for `voxpdf_transform_rect`.
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CCoordinateSpace {
+    /// Rotated, top-left origin, y down (word bounds are already in this space)
+    TopLeft = 0,
+    /// PDF default user space: unrotated, bottom-left origin, y up
+    BottomLeft = 1,
+    /// Top-left space scaled to 0..1 of the displayed page
+    Normalized = 2,
+}
+
+impl TryFrom<u32> for CCoordinateSpace {
+    type Error = CVoxPDFError;
+
+    fn try_from(value: u32) -> Result<Self, Self::Error> {
+        Ok(match value {
+            0 => CCoordinateSpace::TopLeft,
+            1 => CCoordinateSpace::BottomLeft,
+            2 => CCoordinateSpace::Normalized,
+            _ => return Err(CVoxPDFError::InvalidArgument),
+        })
+    }
+}
+
+impl From<CCoordinateSpace> for CoordinateSpace {
+    fn from(space: CCoordinateSpace) -> Self {
+        match space {
+            CCoordinateSpace::TopLeft => CoordinateSpace::TopLeft,
+            CCoordinateSpace::BottomLeft => CoordinateSpace::BottomLeft,
+            CCoordinateSpace::Normalized => CoordinateSpace::Normalized,
+        }
+    }
 }
 
 /// Open a PDF document from a file path.
@@ -98,6 +538,40 @@ pub unsafe extern "C" fn voxpdf_open(
     }
 }
 
+/// Open a PDF document from an in-memory buffer.
+///
+/// The bytes are copied, so the caller may release `data` as soon as this
+/// function returns.
+///
+/// # Safety
+///
+/// - `data` must be a valid pointer to at least `len` readable bytes
+/// - `error_out` must be a valid mutable pointer to CVoxPDFError
+/// - Caller must eventually call `voxpdf_free_document` on the returned pointer
+#[no_mangle]
+pub unsafe extern "C" fn voxpdf_open_bytes(
+    data: *const u8,
+    len: usize,
+    error_out: *mut CVoxPDFError,
+) -> *mut CVoxPDFDocument {
+    if data.is_null() || error_out.is_null() {
+        return std::ptr::null_mut();
+    }
+
+    let bytes = std::slice::from_raw_parts(data, len);
+
+    match PDFDocument::from_bytes(bytes) {
+        Ok(doc) => {
+            *error_out = CVoxPDFError::Ok;
+            Box::into_raw(Box::new(doc)) as *mut CVoxPDFDocument
+        }
+        Err(e) => {
+            *error_out = e.into();
+            std::ptr::null_mut()
+        }
+    }
+}
+
 /// Get the number of pages in a PDF document.
 ///
 /// # Safety
@@ -114,6 +588,69 @@ pub unsafe extern "C" fn voxpdf_get_page_count(doc: *const CVoxPDFDocument) -> u
     doc.page_count() as usize
 }
 
+/// Check whether a PDF document is encrypted and still locked.
+///
+/// # Safety
+///
+/// - `doc` must be a valid pointer returned from `voxpdf_open`
+/// - `doc` must not have been freed with `voxpdf_free_document`
+#[no_mangle]
+pub unsafe extern "C" fn voxpdf_needs_password(doc: *const CVoxPDFDocument) -> bool {
+    if doc.is_null() {
+        return false;
+    }
+
+    let doc = &*(doc as *const PDFDocument);
+    doc.needs_password()
+}
+
+/// Unlock an encrypted PDF document with a user or owner password.
+///
+/// Returns true if the document is readable afterwards. A rejected password
+/// sets `error_out` to `PasswordRequired`.
+///
+/// # Safety
+///
+/// - `doc` must be a valid pointer returned from `voxpdf_open`
+/// - `doc` must not have been freed with `voxpdf_free_document`
+/// - `password` must be a valid null-terminated C string pointer
+/// - `error_out` must be a valid mutable pointer to CVoxPDFError
+#[no_mangle]
+pub unsafe extern "C" fn voxpdf_authenticate(
+    doc: *mut CVoxPDFDocument,
+    password: *const c_char,
+    error_out: *mut CVoxPDFError,
+) -> bool {
+    if doc.is_null() || password.is_null() || error_out.is_null() {
+        return false;
+    }
+
+    let doc = &mut *(doc as *mut PDFDocument);
+
+    let password = match CStr::from_ptr(password).to_str() {
+        Ok(s) => s,
+        Err(_) => {
+            *error_out = CVoxPDFError::InvalidText;
+            return false;
+        }
+    };
+
+    match doc.authenticate(password) {
+        Ok(true) => {
+            *error_out = CVoxPDFError::Ok;
+            true
+        }
+        Ok(false) => {
+            *error_out = CVoxPDFError::PasswordRequired;
+            false
+        }
+        Err(e) => {
+            *error_out = e.into();
+            false
+        }
+    }
+}
+
 /// Free a PDF document, releasing all associated resources.
 ///
 /// # Safety
@@ -274,91 +811,109 @@ pub unsafe extern "C" fn voxpdf_get_word(
     }
 }
 
-/// Get the number of paragraphs on a page.
+/// Get the style of a word: baseline origin, direction and, if the
+/// document's options ask for them, font, colour and glyph count.
+///
+/// `font_name_out` is set to the font's name, or to null when the font
+/// isn't known.
 ///
 /// # Safety
 ///
 /// - `doc` must be a valid pointer returned from `voxpdf_open`
 /// - `doc` must not have been freed with `voxpdf_free_document`
+/// - `style_out` must be a valid mutable pointer to CWordStyle
+/// - `font_name_out` must be a valid mutable pointer
 /// - `error_out` must be a valid mutable pointer to CVoxPDFError
+/// - Caller must eventually call `voxpdf_free_string` on a non-null font name
 #[no_mangle]
-pub unsafe extern "C" fn voxpdf_get_paragraph_count(
+pub unsafe extern "C" fn voxpdf_get_word_style(
     doc: *const CVoxPDFDocument,
     page: u32,
+    index: usize,
+    style_out: *mut CWordStyle,
+    font_name_out: *mut *const c_char,
     error_out: *mut CVoxPDFError,
-) -> usize {
-    if doc.is_null() || error_out.is_null() {
-        return 0;
+) -> bool {
+    if doc.is_null() || style_out.is_null() || font_name_out.is_null() || error_out.is_null() {
+        return false;
     }
 
     let doc = &*(doc as *const PDFDocument);
 
-    // Extract words and detect paragraphs
     match crate::extraction::extract_word_positions(doc, page) {
         Ok(words) => {
-            let paragraphs = crate::extraction::detect_paragraphs(words);
+            let Some(word) = words.get(index) else {
+                *error_out = CVoxPDFError::IndexOutOfRange;
+                return false;
+            };
+
+            *font_name_out = std::ptr::null();
+            if let Some(font) = &word.font {
+                match CString::new(font.name.clone()) {
+                    Ok(c_str) => *font_name_out = c_str.into_raw(),
+                    Err(_) => {
+                        *error_out = CVoxPDFError::InvalidText;
+                        return false;
+                    }
+                }
+            }
+
+            *style_out = word.into();
             *error_out = CVoxPDFError::Ok;
-            paragraphs.len()
+            true
         }
         Err(e) => {
             *error_out = e.into();
-            0
+            false
         }
     }
 }
 
-/// Get a specific paragraph by index from a page.
+/// Get the box of one character of a word.
+///
+/// Words only have glyphs when the document's options set `glyph_boxes`;
+/// `CWordStyle::glyph_count` says how many.
 ///
 /// # Safety
 ///
 /// - `doc` must be a valid pointer returned from `voxpdf_open`
 /// - `doc` must not have been freed with `voxpdf_free_document`
-/// - `para_out` must be a valid mutable pointer to CParagraph
-/// - `text_out` must be a valid mutable pointer
+/// - `glyph_out` must be a valid mutable pointer to CGlyph
 /// - `error_out` must be a valid mutable pointer to CVoxPDFError
-/// - Caller must eventually call `voxpdf_free_string` on the returned text pointer
 #[no_mangle]
-pub unsafe extern "C" fn voxpdf_get_paragraph(
+pub unsafe extern "C" fn voxpdf_get_word_glyph(
     doc: *const CVoxPDFDocument,
     page: u32,
-    index: usize,
-    para_out: *mut CParagraph,
-    text_out: *mut *const c_char,
+    word_index: usize,
+    glyph_index: usize,
+    glyph_out: *mut CGlyph,
     error_out: *mut CVoxPDFError,
 ) -> bool {
-    if doc.is_null() || para_out.is_null() || text_out.is_null() || error_out.is_null() {
+    if doc.is_null() || glyph_out.is_null() || error_out.is_null() {
         return false;
     }
 
     let doc = &*(doc as *const PDFDocument);
 
-    // Extract words and detect paragraphs
     match crate::extraction::extract_word_positions(doc, page) {
         Ok(words) => {
-            let paragraphs = crate::extraction::detect_paragraphs(words);
-
-            if let Some(para) = paragraphs.get(index) {
-                *para_out = CParagraph {
-                    index: para.index,
-                    page_number: para.page_number,
-                    word_count: para.word_count(),
-                };
-
-                match CString::new(para.text.clone()) {
-                    Ok(c_str) => {
-                        *text_out = c_str.into_raw();
-                        *error_out = CVoxPDFError::Ok;
-                        return true;
-                    }
-                    Err(_) => {
-                        *error_out = CVoxPDFError::InvalidText;
-                        return false;
-                    }
-                }
-            }
+            let glyph = words
+                .get(word_index)
+                .and_then(|word| word.glyphs.get(glyph_index));
+            let Some(glyph) = glyph else {
+                *error_out = CVoxPDFError::IndexOutOfRange;
+                return false;
+            };
 
-            *error_out = CVoxPDFError::PageNotFound;
-            false
+            *glyph_out = CGlyph {
+                codepoint: glyph.c as u32,
+                x: glyph.bounds.x,
+                y: glyph.bounds.y,
+                width: glyph.bounds.width,
+                height: glyph.bounds.height,
+            };
+            *error_out = CVoxPDFError::Ok;
+            true
         }
         Err(e) => {
             *error_out = e.into();
@@ -367,7 +922,23 @@ pub unsafe extern "C" fn voxpdf_get_paragraph(
     }
 }
 
-/// Get the number of TOC entries in the document.
+/// Paragraphs of a page with the document's options and running text applied
+fn page_paragraphs(doc: &PDFDocument, page: u32) -> crate::error::Result<Vec<Paragraph>> {
+    let options = doc.extraction_options();
+    let words = crate::extraction::extract_word_positions(doc, page)?;
+    let paragraphs = crate::extraction::detect_paragraphs_with_options(words, options);
+    let height = doc.page_info(page)?.height();
+    let mut paragraphs = doc.running_text()?.apply(paragraphs, height, options);
+    if options.detect_language {
+        crate::extraction::detect_paragraph_languages(doc, &mut paragraphs)?;
+    }
+    Ok(paragraphs)
+}
+
+/// Get the number of paragraphs on a page.
+///
+/// Running headers, footers and page numbers are left out unless the
+/// document's extraction options set `keep_running_text`.
 ///
 /// # Safety
 ///
@@ -375,8 +946,9 @@ pub unsafe extern "C" fn voxpdf_get_paragraph(
 /// - `doc` must not have been freed with `voxpdf_free_document`
 /// - `error_out` must be a valid mutable pointer to CVoxPDFError
 #[no_mangle]
-pub unsafe extern "C" fn voxpdf_get_toc_count(
+pub unsafe extern "C" fn voxpdf_get_paragraph_count(
     doc: *const CVoxPDFDocument,
+    page: u32,
     error_out: *mut CVoxPDFError,
 ) -> usize {
     if doc.is_null() || error_out.is_null() {
@@ -385,10 +957,10 @@ pub unsafe extern "C" fn voxpdf_get_toc_count(
 
     let doc = &*(doc as *const PDFDocument);
 
-    match crate::extraction::extract_toc(doc) {
-        Ok(toc_entries) => {
+    match page_paragraphs(doc, page) {
+        Ok(paragraphs) => {
             *error_out = CVoxPDFError::Ok;
-            toc_entries.len()
+            paragraphs.len()
         }
         Err(e) => {
             *error_out = e.into();
@@ -397,54 +969,1387 @@ pub unsafe extern "C" fn voxpdf_get_toc_count(
     }
 }
 
-/// Get a specific TOC entry by index.
+/// Get a specific paragraph by index from a page.
 ///
 /// # Safety
 ///
 /// - `doc` must be a valid pointer returned from `voxpdf_open`
 /// - `doc` must not have been freed with `voxpdf_free_document`
-/// - `toc_out` must be a valid mutable pointer to CTocEntry
-/// - `title_out` must be a valid mutable pointer
+/// - `para_out` must be a valid mutable pointer to CParagraph
+/// - `text_out` must be a valid mutable pointer
 /// - `error_out` must be a valid mutable pointer to CVoxPDFError
-/// - Caller must eventually call `voxpdf_free_string` on the returned title pointer
+/// - Caller must eventually call `voxpdf_free_string` on the returned text pointer
 #[no_mangle]
-pub unsafe extern "C" fn voxpdf_get_toc_entry(
+pub unsafe extern "C" fn voxpdf_get_paragraph(
     doc: *const CVoxPDFDocument,
+    page: u32,
     index: usize,
-    toc_out: *mut CTocEntry,
-    title_out: *mut *const c_char,
+    para_out: *mut CParagraph,
+    text_out: *mut *const c_char,
     error_out: *mut CVoxPDFError,
 ) -> bool {
-    if doc.is_null() || toc_out.is_null() || title_out.is_null() || error_out.is_null() {
+    if doc.is_null() || para_out.is_null() || text_out.is_null() || error_out.is_null() {
         return false;
     }
 
     let doc = &*(doc as *const PDFDocument);
 
-    match crate::extraction::extract_toc(doc) {
-        Ok(toc_entries) => {
-            if let Some(entry) = toc_entries.get(index) {
-                *toc_out = CTocEntry {
-                    level: entry.level,
-                    page_number: entry.page_number,
-                    paragraph_index: entry.paragraph_index,
+    match page_paragraphs(doc, page) {
+        Ok(paragraphs) => {
+            if let Some(para) = paragraphs.get(index) {
+                *para_out = CParagraph {
+                    index: para.index,
+                    page_number: para.page_number,
+                    word_count: para.word_count(),
+                    kind: para.kind.into(),
+                };
+
+                match CString::new(para.text.clone()) {
+                    Ok(c_str) => {
+                        *text_out = c_str.into_raw();
+                        *error_out = CVoxPDFError::Ok;
+                        return true;
+                    }
+                    Err(_) => {
+                        *error_out = CVoxPDFError::InvalidText;
+                        return false;
+                    }
+                }
+            }
+
+            *error_out = CVoxPDFError::PageNotFound;
+            false
+        }
+        Err(e) => {
+            *error_out = e.into();
+            false
+        }
+    }
+}
+
+/// Get the number of paragraphs in the whole document, stitched across page
+/// breaks.
+///
+/// Extracts every page on first use; the result is cached on the document.
+///
+/// # Safety
+///
+/// - `doc` must be a valid pointer returned from `voxpdf_open`
+/// - `doc` must not have been freed with `voxpdf_free_document`
+/// - `error_out` must be a valid mutable pointer to CVoxPDFError
+#[no_mangle]
+pub unsafe extern "C" fn voxpdf_get_document_paragraph_count(
+    doc: *const CVoxPDFDocument,
+    error_out: *mut CVoxPDFError,
+) -> usize {
+    if doc.is_null() || error_out.is_null() {
+        return 0;
+    }
+
+    let doc = &*(doc as *const PDFDocument);
+
+    match doc.document_paragraphs() {
+        Ok(paragraphs) => {
+            *error_out = CVoxPDFError::Ok;
+            paragraphs.len()
+        }
+        Err(e) => {
+            *error_out = e.into();
+            0
+        }
+    }
+}
+
+/// Get a paragraph by its index in the whole document.
+///
+/// Indices match `CTocEntry.paragraph_index`. A paragraph continued from one
+/// page to the next reports the page it starts on.
+///
+/// # Safety
+///
+/// - `doc` must be a valid pointer returned from `voxpdf_open`
+/// - `doc` must not have been freed with `voxpdf_free_document`
+/// - `para_out` must be a valid mutable pointer to CParagraph
+/// - `text_out` must be a valid mutable pointer
+/// - `error_out` must be a valid mutable pointer to CVoxPDFError
+/// - Caller must eventually call `voxpdf_free_string` on the returned text pointer
+#[no_mangle]
+pub unsafe extern "C" fn voxpdf_get_document_paragraph(
+    doc: *const CVoxPDFDocument,
+    index: usize,
+    para_out: *mut CParagraph,
+    text_out: *mut *const c_char,
+    error_out: *mut CVoxPDFError,
+) -> bool {
+    if doc.is_null() || para_out.is_null() || text_out.is_null() || error_out.is_null() {
+        return false;
+    }
+
+    let doc = &*(doc as *const PDFDocument);
+
+    match doc.document_paragraphs() {
+        Ok(paragraphs) => {
+            if let Some(para) = paragraphs.get(index) {
+                *para_out = CParagraph {
+                    index: para.index,
+                    page_number: para.page_number,
+                    word_count: para.word_count(),
+                    kind: para.kind.into(),
                 };
 
-                match CString::new(entry.title.clone()) {
-                    Ok(c_str) => {
-                        *title_out = c_str.into_raw();
-                        *error_out = CVoxPDFError::Ok;
-                        return true;
-                    }
-                    Err(_) => {
-                        *error_out = CVoxPDFError::InvalidText;
-                        return false;
-                    }
-                }
-            }
+                match CString::new(para.text.clone()) {
+                    Ok(c_str) => {
+                        *text_out = c_str.into_raw();
+                        *error_out = CVoxPDFError::Ok;
+                        return true;
+                    }
+                    Err(_) => {
+                        *error_out = CVoxPDFError::InvalidText;
+                        return false;
+                    }
+                }
+            }
+
+            *error_out = CVoxPDFError::IndexOutOfRange;
+            false
+        }
+        Err(e) => {
+            *error_out = e.into();
+            false
+        }
+    }
+}
+
+/// Get where a word of a document paragraph sits in the paragraph's text.
+///
+/// # Safety
+///
+/// - `doc` must be a valid pointer returned from `voxpdf_open`
+/// - `doc` must not have been freed with `voxpdf_free_document`
+/// - `range_out` must be a valid mutable pointer to CTextRange
+/// - `error_out` must be a valid mutable pointer to CVoxPDFError
+#[no_mangle]
+pub unsafe extern "C" fn voxpdf_get_paragraph_word_range(
+    doc: *const CVoxPDFDocument,
+    paragraph_index: usize,
+    word_index: usize,
+    range_out: *mut CTextRange,
+    error_out: *mut CVoxPDFError,
+) -> bool {
+    if doc.is_null() || range_out.is_null() || error_out.is_null() {
+        return false;
+    }
+
+    let doc = &*(doc as *const PDFDocument);
+
+    match doc.document_paragraphs() {
+        Ok(paragraphs) => {
+            let range = paragraphs
+                .get(paragraph_index)
+                .and_then(|para| para.word_ranges.get(word_index));
+            let Some(range) = range else {
+                *error_out = CVoxPDFError::IndexOutOfRange;
+                return false;
+            };
+
+            *range_out = range.into();
+            *error_out = CVoxPDFError::Ok;
+            true
+        }
+        Err(e) => {
+            *error_out = e.into();
+            false
+        }
+    }
+}
+
+/// Find the word of a document paragraph at a UTF-16 offset into its text,
+/// such as the start of the range a speech synthesizer reports.
+///
+/// An offset between words gives the next word. Returns false with
+/// `CVoxPDFError::Ok` if the offset is past the last word.
+///
+/// # Safety
+///
+/// - `doc` must be a valid pointer returned from `voxpdf_open`
+/// - `doc` must not have been freed with `voxpdf_free_document`
+/// - `word_index_out` must be a valid mutable pointer
+/// - `error_out` must be a valid mutable pointer to CVoxPDFError
+#[no_mangle]
+pub unsafe extern "C" fn voxpdf_get_paragraph_word_at_utf16(
+    doc: *const CVoxPDFDocument,
+    paragraph_index: usize,
+    utf16_offset: usize,
+    word_index_out: *mut usize,
+    error_out: *mut CVoxPDFError,
+) -> bool {
+    if doc.is_null() || word_index_out.is_null() || error_out.is_null() {
+        return false;
+    }
+
+    let doc = &*(doc as *const PDFDocument);
+
+    match doc.document_paragraphs() {
+        Ok(paragraphs) => {
+            let Some(para) = paragraphs.get(paragraph_index) else {
+                *error_out = CVoxPDFError::IndexOutOfRange;
+                return false;
+            };
+
+            *error_out = CVoxPDFError::Ok;
+            match para.word_at_utf16(utf16_offset) {
+                Some(index) => {
+                    *word_index_out = index;
+                    true
+                }
+                None => false,
+            }
+        }
+        Err(e) => {
+            *error_out = e.into();
+            false
+        }
+    }
+}
+
+/// Sentences of a document paragraph, or `None` if there is no such paragraph
+fn document_sentences(
+    doc: &PDFDocument,
+    paragraph_index: usize,
+) -> crate::error::Result<Option<Vec<Sentence>>> {
+    let splitter = doc.sentence_splitter()?;
+    let paragraphs = doc.document_paragraphs()?;
+    let Some(para) = paragraphs.get(paragraph_index) else {
+        return Ok(None);
+    };
+
+    let mut sentences = splitter.split(para);
+    if doc.extraction_options().detect_language {
+        doc.language_detector()?.tag_sentences(para, &mut sentences);
+    }
+    Ok(Some(sentences))
+}
+
+/// Get the number of sentences in a document paragraph.
+///
+/// Sentence rules follow the document's language (`/Lang`).
+///
+/// # Safety
+///
+/// - `doc` must be a valid pointer returned from `voxpdf_open`
+/// - `doc` must not have been freed with `voxpdf_free_document`
+/// - `error_out` must be a valid mutable pointer to CVoxPDFError
+#[no_mangle]
+pub unsafe extern "C" fn voxpdf_get_sentence_count(
+    doc: *const CVoxPDFDocument,
+    paragraph_index: usize,
+    error_out: *mut CVoxPDFError,
+) -> usize {
+    if doc.is_null() || error_out.is_null() {
+        return 0;
+    }
+
+    let doc = &*(doc as *const PDFDocument);
+
+    match document_sentences(doc, paragraph_index) {
+        Ok(Some(sentences)) => {
+            *error_out = CVoxPDFError::Ok;
+            sentences.len()
+        }
+        Ok(None) => {
+            *error_out = CVoxPDFError::IndexOutOfRange;
+            0
+        }
+        Err(e) => {
+            *error_out = e.into();
+            0
+        }
+    }
+}
+
+/// Get a sentence of a document paragraph.
+///
+/// # Safety
+///
+/// - `doc` must be a valid pointer returned from `voxpdf_open`
+/// - `doc` must not have been freed with `voxpdf_free_document`
+/// - `sentence_out` must be a valid mutable pointer to CSentence
+/// - `text_out` must be a valid mutable pointer
+/// - `error_out` must be a valid mutable pointer to CVoxPDFError
+/// - Caller must eventually call `voxpdf_free_string` on the returned text pointer
+#[no_mangle]
+pub unsafe extern "C" fn voxpdf_get_sentence(
+    doc: *const CVoxPDFDocument,
+    paragraph_index: usize,
+    sentence_index: usize,
+    sentence_out: *mut CSentence,
+    text_out: *mut *const c_char,
+    error_out: *mut CVoxPDFError,
+) -> bool {
+    if doc.is_null() || sentence_out.is_null() || text_out.is_null() || error_out.is_null() {
+        return false;
+    }
+
+    let doc = &*(doc as *const PDFDocument);
+
+    let sentences = match document_sentences(doc, paragraph_index) {
+        Ok(sentences) => sentences.unwrap_or_default(),
+        Err(e) => {
+            *error_out = e.into();
+            return false;
+        }
+    };
+    let Some(sentence) = sentences.get(sentence_index) else {
+        *error_out = CVoxPDFError::IndexOutOfRange;
+        return false;
+    };
+
+    *sentence_out = CSentence {
+        index: sentence.index,
+        text_range: (&sentence.text_range).into(),
+        word_start: sentence.word_range.start,
+        word_end: sentence.word_range.end,
+        rect_count: sentence.rects.len(),
+        page_number: sentence.page_number().unwrap_or(0),
+    };
+
+    match CString::new(sentence.text.clone()) {
+        Ok(c_str) => {
+            *text_out = c_str.into_raw();
+            *error_out = CVoxPDFError::Ok;
+            true
+        }
+        Err(_) => {
+            *error_out = CVoxPDFError::InvalidText;
+            false
+        }
+    }
+}
+
+/// Get the box around one printed line of a sentence, for highlighting.
+///
+/// `CSentence::rect_count` says how many there are. Boxes use the word
+/// coordinate system (top-left origin); see `voxpdf_transform_rect`.
+///
+/// # Safety
+///
+/// - `doc` must be a valid pointer returned from `voxpdf_open`
+/// - `doc` must not have been freed with `voxpdf_free_document`
+/// - `rect_out` must be a valid mutable pointer to CRect
+/// - `page_out` must be a valid mutable pointer
+/// - `error_out` must be a valid mutable pointer to CVoxPDFError
+#[no_mangle]
+pub unsafe extern "C" fn voxpdf_get_sentence_rect(
+    doc: *const CVoxPDFDocument,
+    paragraph_index: usize,
+    sentence_index: usize,
+    rect_index: usize,
+    rect_out: *mut CRect,
+    page_out: *mut u32,
+    error_out: *mut CVoxPDFError,
+) -> bool {
+    if doc.is_null() || rect_out.is_null() || page_out.is_null() || error_out.is_null() {
+        return false;
+    }
+
+    let doc = &*(doc as *const PDFDocument);
+
+    match document_sentences(doc, paragraph_index) {
+        Ok(sentences) => {
+            let rect = sentences
+                .as_ref()
+                .and_then(|sentences| sentences.get(sentence_index))
+                .and_then(|sentence| sentence.rects.get(rect_index));
+            let Some((page, rect)) = rect else {
+                *error_out = CVoxPDFError::IndexOutOfRange;
+                return false;
+            };
+
+            *rect_out = CRect::from(rect);
+            *page_out = *page;
+            *error_out = CVoxPDFError::Ok;
+            true
+        }
+        Err(e) => {
+            *error_out = e.into();
+            false
+        }
+    }
+}
+
+/// Write `language` out, returning false (with no error) if there is none
+unsafe fn write_language(
+    language: Option<&Language>,
+    language_out: *mut CLanguage,
+    tag_out: *mut *const c_char,
+    error_out: *mut CVoxPDFError,
+) -> bool {
+    let Some(language) = language else {
+        *error_out = CVoxPDFError::Ok;
+        return false;
+    };
+
+    match CString::new(language.tag.clone()) {
+        Ok(c_str) => {
+            *language_out = CLanguage {
+                confidence: language.confidence,
+                source: language.source.into(),
+            };
+            *tag_out = c_str.into_raw();
+            *error_out = CVoxPDFError::Ok;
+            true
+        }
+        Err(_) => {
+            *error_out = CVoxPDFError::InvalidText;
+            false
+        }
+    }
+}
+
+/// Get the language of a document paragraph, as a BCP 47 tag ("fr-FR").
+///
+/// Returns false with `CVoxPDFError::Ok` if the paragraph has no language:
+/// language detection is off in the document's options, or the text is too
+/// short to identify and the document declares none.
+///
+/// # Safety
+///
+/// - `doc` must be a valid pointer returned from `voxpdf_open`
+/// - `doc` must not have been freed with `voxpdf_free_document`
+/// - `language_out` must be a valid mutable pointer to CLanguage
+/// - `tag_out` must be a valid mutable pointer
+/// - `error_out` must be a valid mutable pointer to CVoxPDFError
+/// - Caller must eventually call `voxpdf_free_string` on the returned tag pointer
+#[no_mangle]
+pub unsafe extern "C" fn voxpdf_get_paragraph_language(
+    doc: *const CVoxPDFDocument,
+    paragraph_index: usize,
+    language_out: *mut CLanguage,
+    tag_out: *mut *const c_char,
+    error_out: *mut CVoxPDFError,
+) -> bool {
+    if doc.is_null() || language_out.is_null() || tag_out.is_null() || error_out.is_null() {
+        return false;
+    }
+
+    let doc = &*(doc as *const PDFDocument);
+
+    match doc.document_paragraphs() {
+        Ok(paragraphs) => {
+            let Some(para) = paragraphs.get(paragraph_index) else {
+                *error_out = CVoxPDFError::IndexOutOfRange;
+                return false;
+            };
+            write_language(para.language.as_ref(), language_out, tag_out, error_out)
+        }
+        Err(e) => {
+            *error_out = e.into();
+            false
+        }
+    }
+}
+
+/// Get the language of a sentence of a document paragraph, as a BCP 47
+/// tag. A sentence has its paragraph's language unless it is clearly in
+/// another.
+///
+/// Returns false with `CVoxPDFError::Ok` if the sentence has no language.
+///
+/// # Safety
+///
+/// - `doc` must be a valid pointer returned from `voxpdf_open`
+/// - `doc` must not have been freed with `voxpdf_free_document`
+/// - `language_out` must be a valid mutable pointer to CLanguage
+/// - `tag_out` must be a valid mutable pointer
+/// - `error_out` must be a valid mutable pointer to CVoxPDFError
+/// - Caller must eventually call `voxpdf_free_string` on the returned tag pointer
+#[no_mangle]
+pub unsafe extern "C" fn voxpdf_get_sentence_language(
+    doc: *const CVoxPDFDocument,
+    paragraph_index: usize,
+    sentence_index: usize,
+    language_out: *mut CLanguage,
+    tag_out: *mut *const c_char,
+    error_out: *mut CVoxPDFError,
+) -> bool {
+    if doc.is_null() || language_out.is_null() || tag_out.is_null() || error_out.is_null() {
+        return false;
+    }
+
+    let doc = &*(doc as *const PDFDocument);
+
+    match document_sentences(doc, paragraph_index) {
+        Ok(sentences) => {
+            let sentence = sentences
+                .as_ref()
+                .and_then(|sentences| sentences.get(sentence_index));
+            let Some(sentence) = sentence else {
+                *error_out = CVoxPDFError::IndexOutOfRange;
+                return false;
+            };
+            write_language(sentence.language.as_ref(), language_out, tag_out, error_out)
+        }
+        Err(e) => {
+            *error_out = e.into();
+            false
+        }
+    }
+}
+
+/// A document paragraph and its text normalized for speech, or `None` if
+/// there is no such paragraph
+fn document_normalized(
+    doc: &PDFDocument,
+    paragraph_index: usize,
+) -> crate::error::Result<Option<(&Paragraph, NormalizedText)>> {
+    let normalizer = doc.normalizer()?;
+    let paragraphs = doc.document_paragraphs()?;
+    Ok(paragraphs
+        .get(paragraph_index)
+        .map(|para| (para, normalizer.normalize_paragraph(para))))
+}
+
+/// Get a document paragraph's text normalized for speech: ligatures and
+/// quotes folded, symbols, abbreviations, units and number ranges read as
+/// words, following the document's language (`/Lang`).
+///
+/// # Safety
+///
+/// - `doc` must be a valid pointer returned from `voxpdf_open`
+/// - `doc` must not have been freed with `voxpdf_free_document`
+/// - `text_out` must be a valid mutable pointer
+/// - `error_out` must be a valid mutable pointer to CVoxPDFError
+/// - Caller must eventually call `voxpdf_free_string` on the returned string
+#[no_mangle]
+pub unsafe extern "C" fn voxpdf_normalize_paragraph(
+    doc: *const CVoxPDFDocument,
+    paragraph_index: usize,
+    text_out: *mut *const c_char,
+    error_out: *mut CVoxPDFError,
+) -> bool {
+    if doc.is_null() || text_out.is_null() || error_out.is_null() {
+        return false;
+    }
+
+    let doc = &*(doc as *const PDFDocument);
+
+    match document_normalized(doc, paragraph_index) {
+        Ok(Some((_, normalized))) => match CString::new(normalized.text) {
+            Ok(c_str) => {
+                *text_out = c_str.into_raw();
+                *error_out = CVoxPDFError::Ok;
+                true
+            }
+            Err(_) => {
+                *error_out = CVoxPDFError::InvalidText;
+                false
+            }
+        },
+        Ok(None) => {
+            *error_out = CVoxPDFError::IndexOutOfRange;
+            false
+        }
+        Err(e) => {
+            *error_out = e.into();
+            false
+        }
+    }
+}
+
+/// Get where a word of a document paragraph sits in the paragraph's
+/// normalized text (see `voxpdf_normalize_paragraph`).
+///
+/// A word partly replaced covers the whole replacement: "~5%" becomes
+/// "approximately 5 percent".
+///
+/// # Safety
+///
+/// - `doc` must be a valid pointer returned from `voxpdf_open`
+/// - `doc` must not have been freed with `voxpdf_free_document`
+/// - `range_out` must be a valid mutable pointer to CTextRange
+/// - `error_out` must be a valid mutable pointer to CVoxPDFError
+#[no_mangle]
+pub unsafe extern "C" fn voxpdf_get_normalized_word_range(
+    doc: *const CVoxPDFDocument,
+    paragraph_index: usize,
+    word_index: usize,
+    range_out: *mut CTextRange,
+    error_out: *mut CVoxPDFError,
+) -> bool {
+    if doc.is_null() || range_out.is_null() || error_out.is_null() {
+        return false;
+    }
+
+    let doc = &*(doc as *const PDFDocument);
+
+    match document_normalized(doc, paragraph_index) {
+        Ok(Some((para, normalized))) => {
+            let ranges = normalized.word_ranges(para);
+            let Some(range) = ranges.get(word_index) else {
+                *error_out = CVoxPDFError::IndexOutOfRange;
+                return false;
+            };
+
+            *range_out = range.into();
+            *error_out = CVoxPDFError::Ok;
+            true
+        }
+        Ok(None) => {
+            *error_out = CVoxPDFError::IndexOutOfRange;
+            false
+        }
+        Err(e) => {
+            *error_out = e.into();
+            false
+        }
+    }
+}
+
+/// Find the word of a document paragraph at a UTF-16 offset into its
+/// normalized text (see `voxpdf_normalize_paragraph`), such as the start of
+/// the range a speech synthesizer reports while reading it.
+///
+/// Returns false with `CVoxPDFError::Ok` if the offset is past the last
+/// word.
+///
+/// # Safety
+///
+/// - `doc` must be a valid pointer returned from `voxpdf_open`
+/// - `doc` must not have been freed with `voxpdf_free_document`
+/// - `word_index_out` must be a valid mutable pointer
+/// - `error_out` must be a valid mutable pointer to CVoxPDFError
+#[no_mangle]
+pub unsafe extern "C" fn voxpdf_get_normalized_word_at_utf16(
+    doc: *const CVoxPDFDocument,
+    paragraph_index: usize,
+    utf16_offset: usize,
+    word_index_out: *mut usize,
+    error_out: *mut CVoxPDFError,
+) -> bool {
+    if doc.is_null() || word_index_out.is_null() || error_out.is_null() {
+        return false;
+    }
+
+    let doc = &*(doc as *const PDFDocument);
+
+    match document_normalized(doc, paragraph_index) {
+        Ok(Some((para, normalized))) => {
+            *error_out = CVoxPDFError::Ok;
+            match normalized.word_at_utf16(para, utf16_offset) {
+                Some(index) => {
+                    *word_index_out = index;
+                    true
+                }
+                None => false,
+            }
+        }
+        Ok(None) => {
+            *error_out = CVoxPDFError::IndexOutOfRange;
+            false
+        }
+        Err(e) => {
+            *error_out = e.into();
+            false
+        }
+    }
+}
+
+/// Render document paragraphs `paragraph_start..paragraph_end` as an SSML
+/// `<speak>` document.
+///
+/// `options_json` may be null for the defaults, or partial JSON such as
+/// `{"marks": false}`. Mark names are "p{paragraph}w{word}", indexing
+/// document paragraphs and their words. Sets `InvalidText` if the JSON
+/// doesn't describe valid options and `IndexOutOfRange` if the range is out
+/// of bounds.
+///
+/// # Safety
+///
+/// - `doc` must be a valid pointer returned from `voxpdf_open`
+/// - `doc` must not have been freed with `voxpdf_free_document`
+/// - `options_json` must be null or a valid null-terminated UTF-8 string
+/// - `ssml_out` must be a valid mutable pointer
+/// - `error_out` must be a valid mutable pointer to CVoxPDFError
+/// - Caller must eventually call `voxpdf_free_string` on the returned string
+#[no_mangle]
+pub unsafe extern "C" fn voxpdf_render_ssml(
+    doc: *const CVoxPDFDocument,
+    paragraph_start: usize,
+    paragraph_end: usize,
+    options_json: *const c_char,
+    ssml_out: *mut *const c_char,
+    error_out: *mut CVoxPDFError,
+) -> bool {
+    if doc.is_null() || ssml_out.is_null() || error_out.is_null() {
+        return false;
+    }
+
+    let doc = &*(doc as *const PDFDocument);
+
+    let options = if options_json.is_null() {
+        Some(SsmlOptions::default())
+    } else {
+        CStr::from_ptr(options_json)
+            .to_str()
+            .ok()
+            .and_then(|json| serde_json::from_str::<SsmlOptions>(json).ok())
+    };
+    let Some(options) = options else {
+        *error_out = CVoxPDFError::InvalidText;
+        return false;
+    };
+
+    let rendered = doc.document_paragraphs().and_then(|paragraphs| {
+        let renderer = SsmlRenderer::for_document(doc)?.with_options(options);
+        Ok(paragraphs
+            .get(paragraph_start..paragraph_end)
+            .map(|range| renderer.render(range)))
+    });
+
+    match rendered {
+        Ok(Some(ssml)) => match CString::new(ssml) {
+            Ok(c_str) => {
+                *ssml_out = c_str.into_raw();
+                *error_out = CVoxPDFError::Ok;
+                true
+            }
+            Err(_) => {
+                *error_out = CVoxPDFError::InvalidText;
+                false
+            }
+        },
+        Ok(None) => {
+            *error_out = CVoxPDFError::IndexOutOfRange;
+            false
+        }
+        Err(e) => {
+            *error_out = e.into();
+            false
+        }
+    }
+}
+
+/// Get the number of TOC entries in the document.
+///
+/// Documents without an outline get entries inferred from their headings
+/// (flagged `inferred`), so this extracts every page on first use.
+///
+/// # Safety
+///
+/// - `doc` must be a valid pointer returned from `voxpdf_open`
+/// - `doc` must not have been freed with `voxpdf_free_document`
+/// - `error_out` must be a valid mutable pointer to CVoxPDFError
+#[no_mangle]
+pub unsafe extern "C" fn voxpdf_get_toc_count(
+    doc: *const CVoxPDFDocument,
+    error_out: *mut CVoxPDFError,
+) -> usize {
+    if doc.is_null() || error_out.is_null() {
+        return 0;
+    }
+
+    let doc = &*(doc as *const PDFDocument);
+
+    match doc.toc_tree() {
+        Ok(tree) => {
+            *error_out = CVoxPDFError::Ok;
+            tree.len()
+        }
+        Err(e) => {
+            *error_out = e.into();
+            0
+        }
+    }
+}
+
+/// Get a specific TOC entry by index.
+///
+/// `paragraph_index` is the entry's heading in the document-wide paragraph
+/// sequence read with `voxpdf_get_document_paragraph`. The first call
+/// extracts every page to find it.
+///
+/// # Safety
+///
+/// - `doc` must be a valid pointer returned from `voxpdf_open`
+/// - `doc` must not have been freed with `voxpdf_free_document`
+/// - `toc_out` must be a valid mutable pointer to CTocEntry
+/// - `title_out` must be a valid mutable pointer
+/// - `error_out` must be a valid mutable pointer to CVoxPDFError
+/// - Caller must eventually call `voxpdf_free_string` on the returned title pointer
+#[no_mangle]
+pub unsafe extern "C" fn voxpdf_get_toc_entry(
+    doc: *const CVoxPDFDocument,
+    index: usize,
+    toc_out: *mut CTocEntry,
+    title_out: *mut *const c_char,
+    error_out: *mut CVoxPDFError,
+) -> bool {
+    if doc.is_null() || toc_out.is_null() || title_out.is_null() || error_out.is_null() {
+        return false;
+    }
+
+    let doc = &*(doc as *const PDFDocument);
+
+    match doc.toc_tree() {
+        Ok(tree) => {
+            if let Some(entry) = tree.get(index).map(|node| &node.entry) {
+                *toc_out = CTocEntry {
+                    level: entry.level,
+                    page_number: entry.page_number,
+                    paragraph_index: entry.paragraph_index,
+                    inferred: entry.inferred,
+                    confidence: entry.confidence,
+                };
+
+                match CString::new(entry.title.clone()) {
+                    Ok(c_str) => {
+                        *title_out = c_str.into_raw();
+                        *error_out = CVoxPDFError::Ok;
+                        return true;
+                    }
+                    Err(_) => {
+                        *error_out = CVoxPDFError::InvalidText;
+                        return false;
+                    }
+                }
+            }
+
+            *error_out = CVoxPDFError::PageNotFound;
+            false
+        }
+        Err(e) => {
+            *error_out = e.into();
+            false
+        }
+    }
+}
+
+/// Get a node of the TOC tree by id (the TOC entry index).
+///
+/// # Safety
+///
+/// - `doc` must be a valid pointer returned from `voxpdf_open`
+/// - `doc` must not have been freed with `voxpdf_free_document`
+/// - `node_out` must be a valid mutable pointer to CTocNode
+/// - `error_out` must be a valid mutable pointer to CVoxPDFError
+#[no_mangle]
+pub unsafe extern "C" fn voxpdf_get_toc_node(
+    doc: *const CVoxPDFDocument,
+    id: usize,
+    node_out: *mut CTocNode,
+    error_out: *mut CVoxPDFError,
+) -> bool {
+    if doc.is_null() || node_out.is_null() || error_out.is_null() {
+        return false;
+    }
+
+    let doc = &*(doc as *const PDFDocument);
+
+    match doc.toc_tree() {
+        Ok(tree) => match tree.get(id) {
+            Some(node) => {
+                *node_out = CTocNode::new(tree, node);
+                *error_out = CVoxPDFError::Ok;
+                true
+            }
+            None => {
+                *error_out = CVoxPDFError::IndexOutOfRange;
+                false
+            }
+        },
+        Err(e) => {
+            *error_out = e.into();
+            false
+        }
+    }
+}
+
+/// Find the most deeply nested TOC node containing a document paragraph.
+///
+/// Returns false with `CVoxPDFError::Ok` if the paragraph comes before the
+/// first entry or the document has no outline. Follow `parent` links to get
+/// the chapter.
+///
+/// # Safety
+///
+/// - `doc` must be a valid pointer returned from `voxpdf_open`
+/// - `doc` must not have been freed with `voxpdf_free_document`
+/// - `node_out` must be a valid mutable pointer to CTocNode
+/// - `error_out` must be a valid mutable pointer to CVoxPDFError
+#[no_mangle]
+pub unsafe extern "C" fn voxpdf_get_toc_node_for_paragraph(
+    doc: *const CVoxPDFDocument,
+    paragraph_index: usize,
+    node_out: *mut CTocNode,
+    error_out: *mut CVoxPDFError,
+) -> bool {
+    if doc.is_null() || node_out.is_null() || error_out.is_null() {
+        return false;
+    }
+
+    let doc = &*(doc as *const PDFDocument);
+
+    match doc.toc_tree() {
+        Ok(tree) => {
+            *error_out = CVoxPDFError::Ok;
+            match tree.node_for_paragraph(paragraph_index) {
+                Some(node) => {
+                    *node_out = CTocNode::new(tree, node);
+                    true
+                }
+                None => false,
+            }
+        }
+        Err(e) => {
+            *error_out = e.into();
+            false
+        }
+    }
+}
+
+/// Get a single document metadata field.
+///
+/// `field` is a `CMetadataField` value; anything else fails with
+/// `InvalidArgument`. On success `*value_out` is set to a newly allocated
+/// string, or to null if the document doesn't define the field.
+///
+/// # Safety
+///
+/// - `doc` must be a valid pointer returned from `voxpdf_open`
+/// - `doc` must not have been freed with `voxpdf_free_document`
+/// - `value_out` must be a valid mutable pointer
+/// - `error_out` must be a valid mutable pointer to CVoxPDFError
+/// - Caller must eventually call `voxpdf_free_string` on a non-null returned value
+#[no_mangle]
+pub unsafe extern "C" fn voxpdf_get_metadata_field(
+    doc: *const CVoxPDFDocument,
+    field: u32,
+    value_out: *mut *const c_char,
+    error_out: *mut CVoxPDFError,
+) -> bool {
+    if doc.is_null() || value_out.is_null() || error_out.is_null() {
+        return false;
+    }
+
+    let field = match CMetadataField::try_from(field) {
+        Ok(field) => field,
+        Err(e) => {
+            *error_out = e;
+            return false;
+        }
+    };
+
+    let doc = &*(doc as *const PDFDocument);
+
+    let meta = match doc.metadata() {
+        Ok(meta) => meta,
+        Err(e) => {
+            *error_out = e.into();
+            return false;
+        }
+    };
+
+    let value = match field {
+        CMetadataField::Title => meta.display_title().map(str::to_string),
+        CMetadataField::Author => meta.display_author(),
+        CMetadataField::Subject => meta.subject,
+        CMetadataField::Keywords => meta.keywords,
+        CMetadataField::Creator => meta.creator,
+        CMetadataField::Producer => meta.producer,
+        CMetadataField::CreationDate => meta.creation_date.map(|d| d.to_iso8601()),
+        CMetadataField::ModificationDate => meta.modification_date.map(|d| d.to_iso8601()),
+        CMetadataField::Language => meta.display_language().map(str::to_string),
+    };
+
+    match value {
+        Some(value) => match CString::new(value) {
+            Ok(c_str) => {
+                *value_out = c_str.into_raw();
+                *error_out = CVoxPDFError::Ok;
+                true
+            }
+            Err(_) => {
+                *error_out = CVoxPDFError::InvalidText;
+                false
+            }
+        },
+        None => {
+            *value_out = std::ptr::null();
+            *error_out = CVoxPDFError::Ok;
+            true
+        }
+    }
+}
+
+/// Get all document metadata (including XMP fields) as a JSON object.
+///
+/// # Safety
+///
+/// - `doc` must be a valid pointer returned from `voxpdf_open`
+/// - `doc` must not have been freed with `voxpdf_free_document`
+/// - `json_out` must be a valid mutable pointer
+/// - `error_out` must be a valid mutable pointer to CVoxPDFError
+/// - Caller must eventually call `voxpdf_free_string` on the returned JSON pointer
+#[no_mangle]
+pub unsafe extern "C" fn voxpdf_get_metadata_json(
+    doc: *const CVoxPDFDocument,
+    json_out: *mut *const c_char,
+    error_out: *mut CVoxPDFError,
+) -> bool {
+    if doc.is_null() || json_out.is_null() || error_out.is_null() {
+        return false;
+    }
+
+    let doc = &*(doc as *const PDFDocument);
+
+    let json = match doc.metadata() {
+        Ok(meta) => serde_json::to_string(&meta).unwrap_or_default(),
+        Err(e) => {
+            *error_out = e.into();
+            return false;
+        }
+    };
+
+    match CString::new(json) {
+        Ok(c_str) => {
+            *json_out = c_str.into_raw();
+            *error_out = CVoxPDFError::Ok;
+            true
+        }
+        Err(_) => {
+            *error_out = CVoxPDFError::InvalidText;
+            false
+        }
+    }
+}
+
+/// Get a page's geometry (boxes, rotation and user unit).
+///
+/// # Safety
+///
+/// - `doc` must be a valid pointer returned from `voxpdf_open`
+/// - `doc` must not have been freed with `voxpdf_free_document`
+/// - `info_out` must be a valid mutable pointer to CPageInfo
+/// - `error_out` must be a valid mutable pointer to CVoxPDFError
+#[no_mangle]
+pub unsafe extern "C" fn voxpdf_get_page_info(
+    doc: *const CVoxPDFDocument,
+    page: u32,
+    info_out: *mut CPageInfo,
+    error_out: *mut CVoxPDFError,
+) -> bool {
+    if doc.is_null() || info_out.is_null() || error_out.is_null() {
+        return false;
+    }
+
+    let doc = &*(doc as *const PDFDocument);
+
+    match doc.page_info(page) {
+        Ok(info) => {
+            *info_out = CPageInfo {
+                index: info.index,
+                media_box: (&info.media_box).into(),
+                crop_box: (&info.crop_box).into(),
+                rotation: info.rotation,
+                user_unit: info.user_unit,
+                width: info.width(),
+                height: info.height(),
+            };
+            *error_out = CVoxPDFError::Ok;
+            true
+        }
+        Err(e) => {
+            *error_out = e.into();
+            false
+        }
+    }
+}
+
+/// Convert a word or paragraph rectangle (top-left space) to another
+/// coordinate space, using geometry from `voxpdf_get_page_info`.
+///
+/// `space` is a `CCoordinateSpace` value; anything else fails with
+/// `InvalidArgument`.
+///
+/// # Safety
+///
+/// - `info` must be a valid pointer to CPageInfo
+/// - `rect_out` must be a valid mutable pointer to CRect
+/// - `error_out` must be a valid mutable pointer to CVoxPDFError
+#[no_mangle]
+pub unsafe extern "C" fn voxpdf_transform_rect(
+    info: *const CPageInfo,
+    rect: CRect,
+    space: u32,
+    rect_out: *mut CRect,
+    error_out: *mut CVoxPDFError,
+) -> bool {
+    if info.is_null() || rect_out.is_null() || error_out.is_null() {
+        return false;
+    }
+
+    let space = match CCoordinateSpace::try_from(space) {
+        Ok(space) => space,
+        Err(e) => {
+            *error_out = e;
+            return false;
+        }
+    };
+
+    let info = &*info;
+    let page = PageInfo {
+        index: info.index,
+        media_box: info.media_box.into(),
+        crop_box: info.crop_box.into(),
+        rotation: info.rotation,
+        user_unit: info.user_unit,
+        label: None,
+    };
+
+    *rect_out = (&page.transform_rect(&rect.into(), space.into())).into();
+    *error_out = CVoxPDFError::Ok;
+    true
+}
+
+/// Get the printed label of a page (e.g. "xii" or "17").
+///
+/// On success `*label_out` is set to a newly allocated string, or to null if the
+/// document doesn't label this page.
+///
+/// # Safety
+///
+/// - `doc` must be a valid pointer returned from `voxpdf_open`
+/// - `doc` must not have been freed with `voxpdf_free_document`
+/// - `label_out` must be a valid mutable pointer
+/// - `error_out` must be a valid mutable pointer to CVoxPDFError
+/// - Caller must eventually call `voxpdf_free_string` on a non-null returned label
+#[no_mangle]
+pub unsafe extern "C" fn voxpdf_get_page_label(
+    doc: *const CVoxPDFDocument,
+    page: u32,
+    label_out: *mut *const c_char,
+    error_out: *mut CVoxPDFError,
+) -> bool {
+    if doc.is_null() || label_out.is_null() || error_out.is_null() {
+        return false;
+    }
+
+    let doc = &*(doc as *const PDFDocument);
+
+    match doc.page_label(page) {
+        Ok(Some(label)) => match CString::new(label) {
+            Ok(c_str) => {
+                *label_out = c_str.into_raw();
+                *error_out = CVoxPDFError::Ok;
+                true
+            }
+            Err(_) => {
+                *error_out = CVoxPDFError::InvalidText;
+                false
+            }
+        },
+        Ok(None) => {
+            *label_out = std::ptr::null();
+            *error_out = CVoxPDFError::Ok;
+            true
+        }
+        Err(e) => {
+            *error_out = e.into();
+            false
+        }
+    }
+}
+
+/// Find the physical page (0-indexed) with a printed label.
+///
+/// Returns false with `PageNotFound` if no page has the label.
+///
+/// # Safety
+///
+/// - `doc` must be a valid pointer returned from `voxpdf_open`
+/// - `doc` must not have been freed with `voxpdf_free_document`
+/// - `label` must be a valid null-terminated UTF-8 string
+/// - `page_out` must be a valid mutable pointer
+/// - `error_out` must be a valid mutable pointer to CVoxPDFError
+#[no_mangle]
+pub unsafe extern "C" fn voxpdf_find_page_by_label(
+    doc: *const CVoxPDFDocument,
+    label: *const c_char,
+    page_out: *mut u32,
+    error_out: *mut CVoxPDFError,
+) -> bool {
+    if doc.is_null() || label.is_null() || page_out.is_null() || error_out.is_null() {
+        return false;
+    }
+
+    let doc = &*(doc as *const PDFDocument);
+
+    let label = match CStr::from_ptr(label).to_str() {
+        Ok(s) => s,
+        Err(_) => {
+            *error_out = CVoxPDFError::InvalidText;
+            return false;
+        }
+    };
+
+    match doc.page_index_for_label(label) {
+        Ok(Some(page)) => {
+            *page_out = page;
+            *error_out = CVoxPDFError::Ok;
+            true
+        }
+        Ok(None) => {
+            *error_out = CVoxPDFError::PageNotFound;
+            false
+        }
+        Err(e) => {
+            *error_out = e.into();
+            false
+        }
+    }
+}
+
+/// Set the extraction options used for this document, as JSON.
+///
+/// Missing fields keep their defaults, e.g. `{"word_gap_space_ratio": 0.8}`. Sets
+/// `InvalidText` if the JSON doesn't describe valid options.
+///
+/// # Safety
+///
+/// - `doc` must be a valid pointer returned from `voxpdf_open`
+/// - `doc` must not have been freed with `voxpdf_free_document`
+/// - `options_json` must be a valid null-terminated UTF-8 string
+/// - `error_out` must be a valid mutable pointer to CVoxPDFError
+#[no_mangle]
+pub unsafe extern "C" fn voxpdf_set_extraction_options(
+    doc: *mut CVoxPDFDocument,
+    options_json: *const c_char,
+    error_out: *mut CVoxPDFError,
+) -> bool {
+    if doc.is_null() || options_json.is_null() || error_out.is_null() {
+        return false;
+    }
+
+    let doc = &mut *(doc as *mut PDFDocument);
+
+    let options = CStr::from_ptr(options_json)
+        .to_str()
+        .ok()
+        .and_then(|json| serde_json::from_str::<ExtractionOptions>(json).ok());
+
+    match options {
+        Some(options) => {
+            doc.set_extraction_options(options);
+            *error_out = CVoxPDFError::Ok;
+            true
+        }
+        None => {
+            *error_out = CVoxPDFError::InvalidText;
+            false
+        }
+    }
+}
+
+/// Get the extraction options used for this document, as JSON.
+///
+/// # Safety
+///
+/// - `doc` must be a valid pointer returned from `voxpdf_open`
+/// - `doc` must not have been freed with `voxpdf_free_document`
+/// - `json_out` must be a valid mutable pointer
+/// - `error_out` must be a valid mutable pointer to CVoxPDFError
+/// - Caller must eventually call `voxpdf_free_string` on the returned JSON pointer
+#[no_mangle]
+pub unsafe extern "C" fn voxpdf_get_extraction_options(
+    doc: *const CVoxPDFDocument,
+    json_out: *mut *const c_char,
+    error_out: *mut CVoxPDFError,
+) -> bool {
+    if doc.is_null() || json_out.is_null() || error_out.is_null() {
+        return false;
+    }
+
+    let doc = &*(doc as *const PDFDocument);
+
+    let json = serde_json::to_string(doc.extraction_options()).unwrap_or_default();
+    match CString::new(json) {
+        Ok(c_str) => {
+            *json_out = c_str.into_raw();
+            *error_out = CVoxPDFError::Ok;
+            true
+        }
+        Err(_) => {
+            *error_out = CVoxPDFError::InvalidText;
+            false
+        }
+    }
+}
+
+/// Replace this document's extraction options with a built-in profile.
+///
+/// `profile` is a `CExtractionProfile` value; anything else fails with
+/// `InvalidArgument` and leaves the options unchanged.
+///
+/// # Safety
+///
+/// - `doc` must be a valid pointer returned from `voxpdf_open`
+/// - `doc` must not have been freed with `voxpdf_free_document`
+/// - `error_out` must be a valid mutable pointer to CVoxPDFError
+#[no_mangle]
+pub unsafe extern "C" fn voxpdf_apply_profile(
+    doc: *mut CVoxPDFDocument,
+    profile: u32,
+    error_out: *mut CVoxPDFError,
+) -> bool {
+    if doc.is_null() || error_out.is_null() {
+        return false;
+    }
+
+    let profile = match CExtractionProfile::try_from(profile) {
+        Ok(profile) => profile,
+        Err(e) => {
+            *error_out = e;
+            return false;
+        }
+    };
+
+    let doc = &mut *(doc as *mut PDFDocument);
+    doc.set_extraction_options(ExtractionProfile::from(profile).options());
+    *error_out = CVoxPDFError::Ok;
+    true
+}
+
+/// Sample a few pages and report the best-fit extraction profile.
+///
+/// Doesn't change the document's options; pass the result to
+/// `voxpdf_apply_profile` to use it.
+///
+/// # Safety
+///
+/// - `doc` must be a valid pointer returned from `voxpdf_open`
+/// - `doc` must not have been freed with `voxpdf_free_document`
+/// - `profile_out` must be a valid mutable pointer to CExtractionProfile
+/// - `confidence_out` must be a valid mutable pointer
+/// - `error_out` must be a valid mutable pointer to CVoxPDFError
+#[no_mangle]
+pub unsafe extern "C" fn voxpdf_detect_profile(
+    doc: *const CVoxPDFDocument,
+    profile_out: *mut CExtractionProfile,
+    confidence_out: *mut f32,
+    error_out: *mut CVoxPDFError,
+) -> bool {
+    if doc.is_null() || profile_out.is_null() || confidence_out.is_null() || error_out.is_null() {
+        return false;
+    }
+
+    let doc = &*(doc as *const PDFDocument);
 
-            *error_out = CVoxPDFError::PageNotFound;
-            false
+    match crate::extraction::detect_profile(doc) {
+        Ok(detected) => {
+            *profile_out = detected.profile.into();
+            *confidence_out = detected.confidence;
+            *error_out = CVoxPDFError::Ok;
+            true
         }
         Err(e) => {
             *error_out = e.into();
@@ -475,6 +2380,51 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_ffi_open_bytes() {
+        let bytes = std::fs::read("tests/fixtures/simple.pdf").unwrap();
+        let mut error = CVoxPDFError::Ok;
+
+        unsafe {
+            let doc = voxpdf_open_bytes(bytes.as_ptr(), bytes.len(), &mut error);
+            assert!(!doc.is_null());
+            assert_eq!(error, CVoxPDFError::Ok);
+
+            // Buffer is copied, so the document outlives the caller's bytes
+            drop(bytes);
+            assert_eq!(voxpdf_get_page_count(doc), 1);
+
+            voxpdf_free_document(doc);
+        }
+    }
+
+    #[test]
+    fn test_ffi_encrypted_document() {
+        let path = CString::new("tests/fixtures/encrypted.pdf").unwrap();
+        let mut error = CVoxPDFError::Ok;
+
+        unsafe {
+            let doc = voxpdf_open(path.as_ptr(), &mut error);
+            assert!(!doc.is_null());
+            assert!(voxpdf_needs_password(doc));
+
+            let mut text_ptr: *const c_char = std::ptr::null();
+            assert!(!voxpdf_extract_page_text(doc, 0, &mut text_ptr, &mut error));
+            assert_eq!(error, CVoxPDFError::PasswordRequired);
+
+            let wrong = CString::new("wrong").unwrap();
+            assert!(!voxpdf_authenticate(doc, wrong.as_ptr(), &mut error));
+            assert_eq!(error, CVoxPDFError::PasswordRequired);
+
+            let password = CString::new("secret").unwrap();
+            assert!(voxpdf_authenticate(doc, password.as_ptr(), &mut error));
+            assert_eq!(error, CVoxPDFError::Ok);
+            assert!(!voxpdf_needs_password(doc));
+
+            voxpdf_free_document(doc);
+        }
+    }
+
     #[test]
     fn test_ffi_text_extraction() {
         let path = CString::new("tests/fixtures/simple.pdf").unwrap();
@@ -549,6 +2499,7 @@ mod tests {
                 index: 0,
                 page_number: 0,
                 word_count: 0,
+                kind: CParagraphKind::Body,
             };
             let mut text_ptr: *const c_char = std::ptr::null();
 
@@ -579,4 +2530,616 @@ mod tests {
             voxpdf_free_document(doc);
         }
     }
+
+    #[test]
+    fn test_ffi_metadata() {
+        let path = CString::new("tests/fixtures/simple.pdf").unwrap();
+        let mut error = CVoxPDFError::Ok;
+
+        unsafe {
+            let doc = voxpdf_open(path.as_ptr(), &mut error);
+            assert!(!doc.is_null());
+
+            let mut json_ptr: *const c_char = std::ptr::null();
+            assert!(voxpdf_get_metadata_json(doc, &mut json_ptr, &mut error));
+            assert_eq!(error, CVoxPDFError::Ok);
+            let json = CStr::from_ptr(json_ptr).to_string_lossy().into_owned();
+            let meta: crate::models::DocumentMetadata = serde_json::from_str(&json).unwrap();
+            voxpdf_free_string(json_ptr as *mut c_char);
+
+            let mut value_ptr: *const c_char = std::ptr::null();
+            assert!(voxpdf_get_metadata_field(
+                doc,
+                CMetadataField::Producer as u32,
+                &mut value_ptr,
+                &mut error
+            ));
+            assert_eq!(error, CVoxPDFError::Ok);
+            assert_eq!(value_ptr.is_null(), meta.producer.is_none());
+            if !value_ptr.is_null() {
+                voxpdf_free_string(value_ptr as *mut c_char);
+            }
+
+            assert!(!voxpdf_get_metadata_field(
+                doc,
+                99,
+                &mut value_ptr,
+                &mut error
+            ));
+            assert_eq!(error, CVoxPDFError::InvalidArgument);
+
+            voxpdf_free_document(doc);
+        }
+    }
+
+    #[test]
+    fn test_ffi_page_info_and_transform() {
+        let path = CString::new("tests/fixtures/rotated.pdf").unwrap();
+        let mut error = CVoxPDFError::Ok;
+
+        unsafe {
+            let doc = voxpdf_open(path.as_ptr(), &mut error);
+            assert!(!doc.is_null());
+
+            let mut info = std::mem::zeroed::<CPageInfo>();
+            assert!(voxpdf_get_page_info(doc, 0, &mut info, &mut error));
+            assert_eq!(error, CVoxPDFError::Ok);
+            assert_eq!(info.rotation, 90);
+            assert_eq!((info.width, info.height), (792.0, 612.0));
+
+            let rect = CRect {
+                x: 0.0,
+                y: 0.0,
+                width: 10.0,
+                height: 20.0,
+            };
+            let mut out = rect;
+            assert!(voxpdf_transform_rect(
+                &info,
+                rect,
+                CCoordinateSpace::BottomLeft as u32,
+                &mut out,
+                &mut error
+            ));
+            assert_eq!(error, CVoxPDFError::Ok);
+            assert_eq!(
+                (out.x, out.y, out.width, out.height),
+                (0.0, 0.0, 20.0, 10.0)
+            );
+
+            assert!(!voxpdf_transform_rect(&info, rect, 3, &mut out, &mut error));
+            assert_eq!(error, CVoxPDFError::InvalidArgument);
+
+            assert!(!voxpdf_get_page_info(doc, 3, &mut info, &mut error));
+            assert_eq!(error, CVoxPDFError::PageNotFound);
+
+            voxpdf_free_document(doc);
+        }
+    }
+
+    #[test]
+    fn test_ffi_page_labels() {
+        let path = CString::new("tests/fixtures/page-labels.pdf").unwrap();
+        let mut error = CVoxPDFError::Ok;
+
+        unsafe {
+            let doc = voxpdf_open(path.as_ptr(), &mut error);
+            assert!(!doc.is_null());
+
+            let mut label_ptr: *const c_char = std::ptr::null();
+            assert!(voxpdf_get_page_label(doc, 2, &mut label_ptr, &mut error));
+            assert_eq!(CStr::from_ptr(label_ptr).to_str().unwrap(), "iii");
+            voxpdf_free_string(label_ptr as *mut c_char);
+
+            let label = CString::new("App-A").unwrap();
+            let mut page = 0;
+            assert!(voxpdf_find_page_by_label(
+                doc,
+                label.as_ptr(),
+                &mut page,
+                &mut error
+            ));
+            assert_eq!(page, 6);
+
+            let missing = CString::new("xlii").unwrap();
+            assert!(!voxpdf_find_page_by_label(
+                doc,
+                missing.as_ptr(),
+                &mut page,
+                &mut error
+            ));
+            assert_eq!(error, CVoxPDFError::PageNotFound);
+
+            voxpdf_free_document(doc);
+        }
+    }
+
+    #[test]
+    fn test_ffi_extraction_options() {
+        let path = CString::new("tests/fixtures/simple.pdf").unwrap();
+        let mut error = CVoxPDFError::Ok;
+
+        unsafe {
+            let doc = voxpdf_open(path.as_ptr(), &mut error);
+            assert!(!doc.is_null());
+
+            let json = CString::new(r#"{"word_gap_space_ratio": 0.8}"#).unwrap();
+            assert!(voxpdf_set_extraction_options(
+                doc,
+                json.as_ptr(),
+                &mut error
+            ));
+            assert_eq!(error, CVoxPDFError::Ok);
+
+            let mut json_ptr: *const c_char = std::ptr::null();
+            assert!(voxpdf_get_extraction_options(
+                doc,
+                &mut json_ptr,
+                &mut error
+            ));
+            let options: ExtractionOptions =
+                serde_json::from_str(CStr::from_ptr(json_ptr).to_str().unwrap()).unwrap();
+            voxpdf_free_string(json_ptr as *mut c_char);
+            assert_eq!(options.word_gap_space_ratio, 0.8);
+
+            let invalid = CString::new("{not json").unwrap();
+            assert!(!voxpdf_set_extraction_options(
+                doc,
+                invalid.as_ptr(),
+                &mut error
+            ));
+            assert_eq!(error, CVoxPDFError::InvalidText);
+
+            voxpdf_free_document(doc);
+        }
+    }
+
+    #[test]
+    fn test_ffi_profiles() {
+        let path = CString::new("tests/fixtures/simple.pdf").unwrap();
+        let mut error = CVoxPDFError::Ok;
+
+        unsafe {
+            let doc = voxpdf_open(path.as_ptr(), &mut error);
+            assert!(!doc.is_null());
+
+            let mut profile = CExtractionProfile::Slides;
+            let mut confidence = -1.0;
+            assert!(voxpdf_detect_profile(
+                doc,
+                &mut profile,
+                &mut confidence,
+                &mut error
+            ));
+            assert_eq!(error, CVoxPDFError::Ok);
+            assert!((0.0..=1.0).contains(&confidence));
+
+            assert!(voxpdf_apply_profile(
+                doc,
+                CExtractionProfile::Legal as u32,
+                &mut error
+            ));
+            assert_eq!(error, CVoxPDFError::Ok);
+            let doc_ref = &*(doc as *const PDFDocument);
+            assert_eq!(
+                doc_ref.extraction_options(),
+                &ExtractionProfile::Legal.options()
+            );
+
+            assert!(!voxpdf_apply_profile(doc, 6, &mut error));
+            assert_eq!(error, CVoxPDFError::InvalidArgument);
+            assert_eq!(
+                doc_ref.extraction_options(),
+                &ExtractionProfile::Legal.options()
+            );
+
+            voxpdf_free_document(doc);
+        }
+    }
+
+    #[test]
+    fn test_ffi_paragraph_kinds() {
+        let path = CString::new("tests/fixtures/running-text.pdf").unwrap();
+        let keep = CString::new(r#"{"keep_running_text": true}"#).unwrap();
+        let mut error = CVoxPDFError::Ok;
+
+        unsafe {
+            let doc = voxpdf_open(path.as_ptr(), &mut error);
+            assert!(!doc.is_null());
+
+            // Header and page number are stripped by default
+            assert_eq!(voxpdf_get_paragraph_count(doc, 0, &mut error), 2);
+
+            assert!(voxpdf_set_extraction_options(
+                doc,
+                keep.as_ptr(),
+                &mut error
+            ));
+            assert_eq!(voxpdf_get_paragraph_count(doc, 0, &mut error), 4);
+
+            let mut para = CParagraph {
+                index: 0,
+                page_number: 0,
+                word_count: 0,
+                kind: CParagraphKind::Body,
+            };
+            let mut text_ptr: *const c_char = std::ptr::null();
+            assert!(voxpdf_get_paragraph(
+                doc,
+                0,
+                3,
+                &mut para,
+                &mut text_ptr,
+                &mut error
+            ));
+            assert_eq!(para.kind, CParagraphKind::PageNumber);
+
+            voxpdf_free_string(text_ptr as *mut c_char);
+            voxpdf_free_document(doc);
+        }
+    }
+
+    #[test]
+    fn test_ffi_toc_paragraph_index() {
+        let path = CString::new("tests/fixtures/toc-positions.pdf").unwrap();
+        let mut error = CVoxPDFError::Ok;
+
+        unsafe {
+            let doc = voxpdf_open(path.as_ptr(), &mut error);
+            assert!(!doc.is_null());
+
+            let count = voxpdf_get_toc_count(doc, &mut error);
+            assert_eq!(error, CVoxPDFError::Ok);
+
+            let paragraph_count = voxpdf_get_document_paragraph_count(doc, &mut error);
+            assert_eq!(error, CVoxPDFError::Ok);
+
+            let headings = ["1 Getting Started", "1.1 Installing", "2 Next Steps"];
+            assert_eq!(count, headings.len());
+
+            for (index, heading) in headings.into_iter().enumerate() {
+                let mut toc = std::mem::zeroed::<CTocEntry>();
+                let mut title_ptr: *const c_char = std::ptr::null();
+                assert!(voxpdf_get_toc_entry(
+                    doc,
+                    index,
+                    &mut toc,
+                    &mut title_ptr,
+                    &mut error
+                ));
+                voxpdf_free_string(title_ptr as *mut c_char);
+                assert!(toc.paragraph_index < paragraph_count);
+                assert!(!toc.inferred);
+
+                let mut para = std::mem::zeroed::<CParagraph>();
+                let mut text_ptr: *const c_char = std::ptr::null();
+                assert!(voxpdf_get_document_paragraph(
+                    doc,
+                    toc.paragraph_index,
+                    &mut para,
+                    &mut text_ptr,
+                    &mut error
+                ));
+                let text = CStr::from_ptr(text_ptr).to_string_lossy().into_owned();
+                voxpdf_free_string(text_ptr as *mut c_char);
+
+                assert_eq!(para.index, toc.paragraph_index);
+                assert_eq!(para.page_number, toc.page_number);
+                assert_eq!(text, heading);
+            }
+
+            voxpdf_free_document(doc);
+        }
+    }
+
+    #[test]
+    fn test_ffi_toc_tree() {
+        let path = CString::new("tests/fixtures/toc-positions.pdf").unwrap();
+        let mut error = CVoxPDFError::Ok;
+
+        unsafe {
+            let doc = voxpdf_open(path.as_ptr(), &mut error);
+            assert!(!doc.is_null());
+
+            let mut node = std::mem::zeroed::<CTocNode>();
+            assert!(voxpdf_get_toc_node(doc, 0, &mut node, &mut error));
+            assert_eq!(error, CVoxPDFError::Ok);
+            assert!(!node.has_parent);
+            assert!(node.is_open);
+            assert_eq!((node.child_count, node.first_child), (1, 1));
+            assert!(node.has_next_sibling);
+            assert_eq!(node.next_sibling, 2);
+
+            let child = node.first_child;
+            let mut found = std::mem::zeroed::<CTocNode>();
+            assert!(voxpdf_get_toc_node(doc, child, &mut node, &mut error));
+            assert!(voxpdf_get_toc_node_for_paragraph(
+                doc,
+                node.paragraph_start,
+                &mut found,
+                &mut error
+            ));
+            assert_eq!(found.id, child);
+            assert_eq!((found.has_parent, found.parent), (true, 0));
+
+            assert!(!voxpdf_get_toc_node(doc, 99, &mut node, &mut error));
+            assert_eq!(error, CVoxPDFError::IndexOutOfRange);
+
+            voxpdf_free_document(doc);
+        }
+    }
+
+    #[test]
+    fn test_ffi_word_style() {
+        let path = CString::new("tests/fixtures/styled-text.pdf").unwrap();
+        let options = CString::new(r#"{"font_attributes": true, "glyph_boxes": true}"#).unwrap();
+        let mut error = CVoxPDFError::Ok;
+
+        unsafe {
+            let doc = voxpdf_open(path.as_ptr(), &mut error);
+            assert!(!doc.is_null());
+            assert!(voxpdf_set_extraction_options(
+                doc,
+                options.as_ptr(),
+                &mut error
+            ));
+
+            // "Bold" in red Helvetica-Bold is the second word
+            let mut style = std::mem::zeroed::<CWordStyle>();
+            let mut font_name: *const c_char = std::ptr::null();
+            assert!(voxpdf_get_word_style(
+                doc,
+                0,
+                1,
+                &mut style,
+                &mut font_name,
+                &mut error
+            ));
+            assert_eq!(error, CVoxPDFError::Ok);
+            assert!(style.has_font && style.bold && !style.italic);
+            assert_eq!(style.color, 0xFF0000);
+            assert_eq!(style.direction, CTextDirection::LeftToRight);
+            assert_eq!(style.glyph_count, 4);
+            assert!(!style.has_quad);
+            // MuPDF may name the substitute it draws Helvetica-Bold with
+            assert!(CStr::from_ptr(font_name).to_str().unwrap().contains("Bold"));
+            voxpdf_free_string(font_name as *mut c_char);
+
+            let mut glyph = std::mem::zeroed::<CGlyph>();
+            assert!(voxpdf_get_word_glyph(doc, 0, 1, 0, &mut glyph, &mut error));
+            assert_eq!(glyph.codepoint, 'B' as u32);
+            assert!(glyph.width > 0.0);
+            assert!(!voxpdf_get_word_glyph(doc, 0, 1, 4, &mut glyph, &mut error));
+            assert_eq!(error, CVoxPDFError::IndexOutOfRange);
+
+            voxpdf_free_document(doc);
+        }
+    }
+
+    #[test]
+    fn test_ffi_sentences() {
+        let path = CString::new("tests/fixtures/sentences.pdf").unwrap();
+        let mut error = CVoxPDFError::Ok;
+
+        unsafe {
+            let doc = voxpdf_open(path.as_ptr(), &mut error);
+            assert!(!doc.is_null());
+
+            assert_eq!(voxpdf_get_sentence_count(doc, 0, &mut error), 3);
+            assert_eq!(error, CVoxPDFError::Ok);
+
+            let mut sentence = std::mem::zeroed::<CSentence>();
+            let mut text: *const c_char = std::ptr::null();
+            assert!(voxpdf_get_sentence(
+                doc,
+                0,
+                1,
+                &mut sentence,
+                &mut text,
+                &mut error
+            ));
+            assert_eq!(
+                CStr::from_ptr(text).to_str().unwrap(),
+                "Er blieb bis zum 1. Mai."
+            );
+            voxpdf_free_string(text as *mut c_char);
+            assert_eq!(sentence.index, 1);
+            let range = sentence.text_range;
+            assert_eq!((range.utf8_start, range.utf8_end), (68, 92));
+            assert_eq!((range.utf16_start, range.utf16_end), (68, 92));
+            assert_eq!((sentence.word_start, sentence.word_end), (14, 20));
+            assert_eq!((sentence.rect_count, sentence.page_number), (1, 0));
+
+            let mut rect = std::mem::zeroed::<CRect>();
+            let mut page = 99;
+            assert!(voxpdf_get_sentence_rect(
+                doc, 0, 1, 0, &mut rect, &mut page, &mut error
+            ));
+            assert_eq!(page, 0);
+            assert!(rect.width > 0.0 && rect.height > 0.0);
+            assert!(!voxpdf_get_sentence_rect(
+                doc, 0, 1, 1, &mut rect, &mut page, &mut error
+            ));
+            assert_eq!(error, CVoxPDFError::IndexOutOfRange);
+
+            assert_eq!(voxpdf_get_sentence_count(doc, 5, &mut error), 0);
+            assert_eq!(error, CVoxPDFError::IndexOutOfRange);
+
+            voxpdf_free_document(doc);
+        }
+    }
+
+    #[test]
+    fn test_ffi_paragraph_word_ranges() {
+        let path = CString::new("tests/fixtures/page-continuation.pdf").unwrap();
+        let mut error = CVoxPDFError::Ok;
+
+        unsafe {
+            let doc = voxpdf_open(path.as_ptr(), &mut error);
+            assert!(!doc.is_null());
+
+            // "The opening paragraph is complete."
+            let mut range = std::mem::zeroed::<CTextRange>();
+            assert!(voxpdf_get_paragraph_word_range(
+                doc, 0, 1, &mut range, &mut error
+            ));
+            assert_eq!(error, CVoxPDFError::Ok);
+            assert_eq!((range.utf8_start, range.utf8_end), (4, 11));
+            assert_eq!((range.utf16_start, range.utf16_end), (4, 11));
+            assert!(!voxpdf_get_paragraph_word_range(
+                doc, 0, 5, &mut range, &mut error
+            ));
+            assert_eq!(error, CVoxPDFError::IndexOutOfRange);
+
+            let mut word = 99;
+            assert!(voxpdf_get_paragraph_word_at_utf16(
+                doc, 0, 12, &mut word, &mut error
+            ));
+            assert_eq!(word, 2);
+            // Past the end: not found, but not an error
+            assert!(!voxpdf_get_paragraph_word_at_utf16(
+                doc, 0, 34, &mut word, &mut error
+            ));
+            assert_eq!(error, CVoxPDFError::Ok);
+
+            voxpdf_free_document(doc);
+        }
+    }
+
+    #[test]
+    fn test_ffi_normalized_paragraph() {
+        let path = CString::new("tests/fixtures/speech-normalization.pdf").unwrap();
+        let mut error = CVoxPDFError::Ok;
+
+        unsafe {
+            let doc = voxpdf_open(path.as_ptr(), &mut error);
+            assert!(!doc.is_null());
+
+            let mut text: *const c_char = std::ptr::null();
+            assert!(voxpdf_normalize_paragraph(doc, 0, &mut text, &mut error));
+            assert_eq!(error, CVoxPDFError::Ok);
+            let spoken = CStr::from_ptr(text).to_str().unwrap().to_string();
+            voxpdf_free_string(text as *mut c_char);
+            assert!(spoken.starts_with("The final figure, Figure 2, covers section 3 a:"));
+            assert!(!voxpdf_normalize_paragraph(doc, 1, &mut text, &mut error));
+            assert_eq!(error, CVoxPDFError::IndexOutOfRange);
+
+            // "Fig." is read as "Figure"
+            let mut range = std::mem::zeroed::<CTextRange>();
+            assert!(voxpdf_get_normalized_word_range(
+                doc, 0, 3, &mut range, &mut error
+            ));
+            assert_eq!((range.utf16_start, range.utf16_end), (18, 24));
+
+            let mut word = 99;
+            assert!(voxpdf_get_normalized_word_at_utf16(
+                doc, 0, 101, &mut word, &mut error
+            ));
+            assert_eq!(word, 12);
+            assert!(!voxpdf_get_normalized_word_at_utf16(
+                doc, 0, 109, &mut word, &mut error
+            ));
+            assert_eq!(error, CVoxPDFError::Ok);
+
+            voxpdf_free_document(doc);
+        }
+    }
+
+    #[test]
+    fn test_ffi_render_ssml() {
+        let path = CString::new("tests/fixtures/toc-positions.pdf").unwrap();
+        let options = CString::new(r#"{"marks": false}"#).unwrap();
+        let mut error = CVoxPDFError::Ok;
+
+        unsafe {
+            let doc = voxpdf_open(path.as_ptr(), &mut error);
+            assert!(!doc.is_null());
+
+            let mut ssml: *const c_char = std::ptr::null();
+            assert!(voxpdf_render_ssml(
+                doc,
+                1,
+                2,
+                options.as_ptr(),
+                &mut ssml,
+                &mut error
+            ));
+            assert_eq!(error, CVoxPDFError::Ok);
+            let text = CStr::from_ptr(ssml).to_str().unwrap();
+            assert!(text.contains("\n<p><s>1 Getting Started</s></p>\n</speak>"));
+            voxpdf_free_string(ssml as *mut c_char);
+
+            assert!(!voxpdf_render_ssml(
+                doc,
+                0,
+                99,
+                std::ptr::null(),
+                &mut ssml,
+                &mut error
+            ));
+            assert_eq!(error, CVoxPDFError::IndexOutOfRange);
+
+            let bad = CString::new(r#"{"marks": "yes"}"#).unwrap();
+            assert!(!voxpdf_render_ssml(
+                doc,
+                0,
+                1,
+                bad.as_ptr(),
+                &mut ssml,
+                &mut error
+            ));
+            assert_eq!(error, CVoxPDFError::InvalidText);
+
+            voxpdf_free_document(doc);
+        }
+    }
+
+    #[test]
+    fn test_ffi_languages() {
+        let path = CString::new("tests/fixtures/languages.pdf").unwrap();
+        let mut error = CVoxPDFError::Ok;
+
+        unsafe {
+            let doc = voxpdf_open(path.as_ptr(), &mut error);
+            assert!(!doc.is_null());
+
+            let mut language = std::mem::zeroed::<CLanguage>();
+            let mut tag: *const c_char = std::ptr::null();
+            assert!(voxpdf_get_paragraph_language(
+                doc,
+                1,
+                &mut language,
+                &mut tag,
+                &mut error
+            ));
+            assert_eq!(CStr::from_ptr(tag).to_str().unwrap(), "fr-FR");
+            voxpdf_free_string(tag as *mut c_char);
+            assert_eq!(language.source, CLanguageSource::Tagged);
+            assert_eq!(language.confidence, 1.0);
+
+            // The French sentence ending an English paragraph
+            assert!(voxpdf_get_sentence_language(
+                doc,
+                4,
+                1,
+                &mut language,
+                &mut tag,
+                &mut error
+            ));
+            assert_eq!(CStr::from_ptr(tag).to_str().unwrap(), "fr");
+            voxpdf_free_string(tag as *mut c_char);
+            assert_eq!(language.source, CLanguageSource::Detected);
+
+            assert!(!voxpdf_get_paragraph_language(
+                doc,
+                5,
+                &mut language,
+                &mut tag,
+                &mut error
+            ));
+            assert_eq!(error, CVoxPDFError::IndexOutOfRange);
+
+            voxpdf_free_document(doc);
+        }
+    }
 }
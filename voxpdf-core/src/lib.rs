@@ -22,8 +22,15 @@ pub mod extraction;
 pub mod ffi;
 pub mod memory_pool;
 pub mod models;
+pub mod normalization;
 pub mod pdf;
+pub mod ssml;
+
+#[cfg(test)]
+pub(crate) mod test_support;
 
 pub use error::{Result, VoxPDFError};
-pub use models::{Paragraph, Rect, TocEntry, Word};
-pub use pdf::PDFDocument;
+pub use models::{
+    CoordinateSpace, DocumentMetadata, PageInfo, PageLabels, Paragraph, Rect, TocEntry, Word,
+};
+pub use pdf::{DocumentSource, PDFDocument};
@@ -0,0 +1,62 @@
+use serde::{Deserialize, Serialize};
+
+/// Where a passage's [`Language`] came from.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum LanguageSource {
+    /// The document's language (catalog `/Lang` or XMP `dc:language`),
+    /// which the text agrees with or is too short to contradict
+    Document,
+    /// A `/Lang` on the marked content or structure element the text was
+    /// drawn under
+    Tagged,
+    /// Identified from the text itself
+    Detected,
+}
+
+/// The language of a paragraph or sentence, for picking a TTS voice.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Language {
+    /// BCP 47 tag ("en-US", "fr")
+    pub tag: String,
+    /// 0–1: 1 for tagged text, otherwise how clearly the text itself
+    /// points at this language
+    pub confidence: f32,
+    pub source: LanguageSource,
+}
+
+impl Language {
+    pub fn new(tag: impl Into<String>, confidence: f32, source: LanguageSource) -> Self {
+        Self {
+            tag: tag.into(),
+            confidence,
+            source,
+        }
+    }
+
+    /// Lowercase primary subtag: "pt" for "pt-BR"
+    pub fn primary(&self) -> String {
+        primary_subtag(&self.tag)
+    }
+}
+
+/// Lowercase primary subtag of a BCP 47 tag
+pub(crate) fn primary_subtag(tag: &str) -> String {
+    tag.split(['-', '_'])
+        .next()
+        .unwrap_or("")
+        .trim()
+        .to_ascii_lowercase()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_primary_subtag() {
+        let language = Language::new("pt-BR", 0.9, LanguageSource::Detected);
+        assert_eq!(language.primary(), "pt");
+        assert_eq!(primary_subtag("zh_Hant_TW"), "zh");
+        assert_eq!(primary_subtag(" EN "), "en");
+    }
+}
@@ -0,0 +1,261 @@
+use serde::{Deserialize, Serialize};
+
+/// Document-level metadata from the Info dictionary, catalog and XMP packet.
+///
+/// Every field is optional; PDFs in the wild fill in any subset of them.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct DocumentMetadata {
+    /// Info dictionary `/Title`
+    pub title: Option<String>,
+
+    /// Info dictionary `/Author`
+    pub author: Option<String>,
+
+    /// Info dictionary `/Subject`
+    pub subject: Option<String>,
+
+    /// Info dictionary `/Keywords`
+    pub keywords: Option<String>,
+
+    /// Info dictionary `/Creator` (the authoring application)
+    pub creator: Option<String>,
+
+    /// Info dictionary `/Producer` (the PDF generator)
+    pub producer: Option<String>,
+
+    /// Info dictionary `/CreationDate`
+    pub creation_date: Option<PdfDate>,
+
+    /// Info dictionary `/ModDate`
+    pub modification_date: Option<PdfDate>,
+
+    /// Natural language from the catalog `/Lang` entry (BCP-47, e.g. "en-US")
+    pub language: Option<String>,
+
+    /// Fields from the catalog `/Metadata` XMP stream, if present
+    pub xmp: Option<XmpMetadata>,
+}
+
+/// Selected fields from an XMP metadata packet.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct XmpMetadata {
+    /// `dc:title` (default language alternative)
+    pub title: Option<String>,
+
+    /// `dc:creator` entries, in order
+    pub creators: Vec<String>,
+
+    /// `dc:description`
+    pub description: Option<String>,
+
+    /// `dc:subject` entries
+    pub subjects: Vec<String>,
+
+    /// `dc:language` entries
+    pub languages: Vec<String>,
+
+    /// `xmp:CreatorTool`
+    pub creator_tool: Option<String>,
+
+    /// `xmp:CreateDate` (ISO 8601, as written)
+    pub create_date: Option<String>,
+
+    /// `xmp:ModifyDate` (ISO 8601, as written)
+    pub modify_date: Option<String>,
+
+    /// `pdf:Producer`
+    pub producer: Option<String>,
+
+    /// `pdf:Keywords`
+    pub keywords: Option<String>,
+}
+
+/// A date in PDF format (`D:YYYYMMDDHHmmSSOHH'mm'`).
+///
+/// Only the year is required by the spec; missing parts default to the
+/// earliest valid value.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PdfDate {
+    pub year: u16,
+    pub month: u8,
+    pub day: u8,
+    pub hour: u8,
+    pub minute: u8,
+    pub second: u8,
+
+    /// Offset from UTC in minutes, if the date carries a time zone
+    pub utc_offset_minutes: Option<i16>,
+}
+
+impl DocumentMetadata {
+    /// Best available title: Info `/Title`, falling back to XMP `dc:title`
+    pub fn display_title(&self) -> Option<&str> {
+        non_empty(self.title.as_deref()).or_else(|| {
+            self.xmp
+                .as_ref()
+                .and_then(|x| non_empty(x.title.as_deref()))
+        })
+    }
+
+    /// Best available author: Info `/Author`, falling back to XMP `dc:creator`
+    pub fn display_author(&self) -> Option<String> {
+        if let Some(author) = non_empty(self.author.as_deref()) {
+            return Some(author.to_string());
+        }
+
+        let creators = &self.xmp.as_ref()?.creators;
+        if creators.is_empty() {
+            None
+        } else {
+            Some(creators.join(", "))
+        }
+    }
+
+    /// Best available language: catalog `/Lang`, falling back to XMP `dc:language`
+    pub fn display_language(&self) -> Option<&str> {
+        non_empty(self.language.as_deref()).or_else(|| {
+            self.xmp
+                .as_ref()
+                .and_then(|x| x.languages.first())
+                .map(String::as_str)
+        })
+    }
+}
+
+impl PdfDate {
+    /// Parse a PDF date string such as `D:20240131120000+01'00'`.
+    ///
+    /// The `D:` prefix is optional. Returns `None` if the year is missing or any
+    /// present component is out of range.
+    pub fn parse(s: &str) -> Option<Self> {
+        let s = s.trim();
+        let s = s.strip_prefix("D:").unwrap_or(s);
+        let bytes = s.as_bytes();
+
+        let digits = |start: usize, len: usize| -> Option<u32> {
+            let part = bytes.get(start..start + len)?;
+            if !part.iter().all(u8::is_ascii_digit) {
+                return None;
+            }
+            std::str::from_utf8(part).ok()?.parse().ok()
+        };
+
+        let year = digits(0, 4)? as u16;
+        let month = digits(4, 2).unwrap_or(1) as u8;
+        let day = digits(6, 2).unwrap_or(1) as u8;
+        let hour = digits(8, 2).unwrap_or(0) as u8;
+        let minute = digits(10, 2).unwrap_or(0) as u8;
+        let second = digits(12, 2).unwrap_or(0) as u8;
+
+        if !(1..=12).contains(&month)
+            || !(1..=31).contains(&day)
+            || hour > 23
+            || minute > 59
+            || second > 59
+        {
+            return None;
+        }
+
+        // Time zone: Z, or +HH'mm' / -HH'mm' (apostrophes optional)
+        let tz_start = (4..=14)
+            .step_by(2)
+            .find(|&i| digits(i, 2).is_none())
+            .unwrap_or(14);
+        let utc_offset_minutes = match bytes.get(tz_start) {
+            Some(b'Z') => Some(0),
+            Some(&sign @ (b'+' | b'-')) => {
+                let rest: String = s[tz_start + 1..]
+                    .chars()
+                    .filter(char::is_ascii_digit)
+                    .collect();
+                let hours: i16 = rest.get(0..2)?.parse().ok()?;
+                let minutes: i16 = rest.get(2..4).and_then(|m| m.parse().ok()).unwrap_or(0);
+                let offset = hours * 60 + minutes;
+                Some(if sign == b'-' { -offset } else { offset })
+            }
+            _ => None,
+        };
+
+        Some(PdfDate {
+            year,
+            month,
+            day,
+            hour,
+            minute,
+            second,
+            utc_offset_minutes,
+        })
+    }
+
+    /// Format as ISO 8601 (`2024-01-31T12:00:00+01:00`)
+    pub fn to_iso8601(&self) -> String {
+        let mut out = format!(
+            "{:04}-{:02}-{:02}T{:02}:{:02}:{:02}",
+            self.year, self.month, self.day, self.hour, self.minute, self.second
+        );
+
+        match self.utc_offset_minutes {
+            Some(0) => out.push('Z'),
+            Some(offset) => {
+                let sign = if offset < 0 { '-' } else { '+' };
+                let offset = offset.abs();
+                out.push_str(&format!("{}{:02}:{:02}", sign, offset / 60, offset % 60));
+            }
+            None => {}
+        }
+
+        out
+    }
+}
+
+fn non_empty(s: Option<&str>) -> Option<&str> {
+    s.map(str::trim).filter(|s| !s.is_empty())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_full_pdf_date() {
+        let date = PdfDate::parse("D:20240131120500+01'30'").unwrap();
+        assert_eq!(date.year, 2024);
+        assert_eq!(date.month, 1);
+        assert_eq!(date.day, 31);
+        assert_eq!(date.hour, 12);
+        assert_eq!(date.minute, 5);
+        assert_eq!(date.utc_offset_minutes, Some(90));
+        assert_eq!(date.to_iso8601(), "2024-01-31T12:05:00+01:30");
+    }
+
+    #[test]
+    fn test_parse_partial_pdf_date() {
+        let date = PdfDate::parse("D:1999").unwrap();
+        assert_eq!((date.year, date.month, date.day), (1999, 1, 1));
+        assert_eq!(date.utc_offset_minutes, None);
+
+        let utc = PdfDate::parse("20200102030405Z").unwrap();
+        assert_eq!(utc.to_iso8601(), "2020-01-02T03:04:05Z");
+
+        assert!(PdfDate::parse("D:20241301").is_none());
+        assert!(PdfDate::parse("yesterday").is_none());
+    }
+
+    #[test]
+    fn test_display_fields_fall_back_to_xmp() {
+        let meta = DocumentMetadata {
+            title: Some("  ".to_string()),
+            xmp: Some(XmpMetadata {
+                title: Some("Moby-Dick".to_string()),
+                creators: vec!["Herman Melville".to_string()],
+                languages: vec!["en".to_string()],
+                ..Default::default()
+            }),
+            ..Default::default()
+        };
+
+        assert_eq!(meta.display_title(), Some("Moby-Dick"));
+        assert_eq!(meta.display_author().as_deref(), Some("Herman Melville"));
+        assert_eq!(meta.display_language(), Some("en"));
+    }
+}
@@ -1,7 +1,22 @@
+mod language;
+mod metadata;
+mod page_info;
+mod page_labels;
 mod paragraph;
+mod sentence;
 mod toc_entry;
+mod toc_tree;
 mod word;
 
-pub use paragraph::Paragraph;
+pub(crate) use language::primary_subtag;
+pub use language::{Language, LanguageSource};
+pub use metadata::{DocumentMetadata, PdfDate, XmpMetadata};
+pub use page_info::{CoordinateSpace, PageInfo};
+pub(crate) use page_labels::to_roman;
+pub use page_labels::{PageLabelRange, PageLabelStyle, PageLabels};
+pub(crate) use paragraph::locate_words;
+pub use paragraph::{Paragraph, ParagraphKind, TextRange};
+pub use sentence::Sentence;
 pub use toc_entry::TocEntry;
-pub use word::{Rect, Word};
+pub use toc_tree::{TocNode, TocNodeId, TocTree};
+pub use word::{FontInfo, Glyph, Point, Quad, Rect, TextDirection, Word, WordFragment};
@@ -0,0 +1,245 @@
+use super::Rect;
+use serde::{Deserialize, Serialize};
+
+/// Page geometry: boxes, rotation and scale.
+///
+/// Boxes are in PDF default user space (origin bottom-left, y up, unrotated).
+/// Word and paragraph bounds, on the other hand, come from MuPDF in
+/// [`CoordinateSpace::TopLeft`]; use [`PageInfo::transform_rect`] to convert.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct PageInfo {
+    /// Page index (0-indexed)
+    pub index: u32,
+
+    /// `/MediaBox` (inherited from parent page tree nodes if needed)
+    pub media_box: Rect,
+
+    /// `/CropBox` clipped to the media box; equals the media box if absent
+    pub crop_box: Rect,
+
+    /// Clockwise display rotation from `/Rotate`: 0, 90, 180 or 270
+    pub rotation: u16,
+
+    /// `/UserUnit` size of one user-space unit in points (default 1.0)
+    pub user_unit: f32,
+
+    /// Printed page label from `/PageLabels`, if the document defines one
+    pub label: Option<String>,
+}
+
+/// Coordinate systems for [`PageInfo::transform_rect`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum CoordinateSpace {
+    /// The page as displayed: rotation applied, origin at the top-left of the
+    /// crop box, y down, scaled by `/UserUnit`. This is what MuPDF (and so
+    /// every `Word` and `Paragraph`) reports.
+    TopLeft,
+
+    /// PDF default user space: unrotated, origin bottom-left, y up
+    BottomLeft,
+
+    /// [`TopLeft`](Self::TopLeft) divided by the displayed page size, so the
+    /// page spans 0..1 on both axes
+    Normalized,
+}
+
+impl PageInfo {
+    /// Width of the page as displayed (after rotation), in points
+    pub fn width(&self) -> f32 {
+        if !self.is_sideways() {
+            self.crop_box.width * self.user_unit
+        } else {
+            self.crop_box.height * self.user_unit
+        }
+    }
+
+    /// Height of the page as displayed (after rotation), in points
+    pub fn height(&self) -> f32 {
+        if !self.is_sideways() {
+            self.crop_box.height * self.user_unit
+        } else {
+            self.crop_box.width * self.user_unit
+        }
+    }
+
+    /// True if the page is displayed rotated by a quarter turn
+    fn is_sideways(&self) -> bool {
+        self.rotation == 90 || self.rotation == 270
+    }
+
+    /// Convert a rectangle from [`CoordinateSpace::TopLeft`] (word bounds) to
+    /// another coordinate space.
+    ///
+    /// # Example
+    /// ```
+    /// use voxpdf_core::models::{CoordinateSpace, PageInfo, Rect};
+    ///
+    /// let page = PageInfo {
+    ///     index: 0,
+    ///     media_box: Rect::new(0.0, 0.0, 612.0, 792.0),
+    ///     crop_box: Rect::new(0.0, 0.0, 612.0, 792.0),
+    ///     rotation: 0,
+    ///     user_unit: 1.0,
+    ///     label: None,
+    /// };
+    ///
+    /// // A word 100pt from the left and 200pt from the top
+    /// let word = Rect::new(100.0, 200.0, 30.0, 12.0);
+    /// let native = page.transform_rect(&word, CoordinateSpace::BottomLeft);
+    /// assert_eq!(native, Rect::new(100.0, 580.0, 30.0, 12.0));
+    /// ```
+    pub fn transform_rect(&self, rect: &Rect, to: CoordinateSpace) -> Rect {
+        match to {
+            CoordinateSpace::TopLeft => rect.clone(),
+            CoordinateSpace::Normalized => {
+                let (w, h) = (self.width(), self.height());
+                if w <= 0.0 || h <= 0.0 {
+                    return Rect::new(0.0, 0.0, 0.0, 0.0);
+                }
+                Rect::new(rect.x / w, rect.y / h, rect.width / w, rect.height / h)
+            }
+            CoordinateSpace::BottomLeft => {
+                let (x0, y0) = self.top_left_to_user_space(rect.x, rect.y);
+                let (x1, y1) =
+                    self.top_left_to_user_space(rect.x + rect.width, rect.y + rect.height);
+                Rect::new(x0.min(x1), y0.min(y1), (x1 - x0).abs(), (y1 - y0).abs())
+            }
+        }
+    }
+
+    /// Map a point in unrotated PDF user space (such as a `/XYZ` destination)
+    /// to [`CoordinateSpace::TopLeft`]
+    pub fn user_space_to_top_left(&self, x: f32, y: f32) -> (f32, f32) {
+        let unit = if self.user_unit > 0.0 {
+            self.user_unit
+        } else {
+            1.0
+        };
+
+        let crop = &self.crop_box;
+        let (left, bottom) = (crop.x, crop.y);
+        let (right, top) = (crop.x + crop.width, crop.y + crop.height);
+
+        let (x, y) = match self.rotation {
+            90 => (y - bottom, x - left),
+            180 => (right - x, y - bottom),
+            270 => (top - y, right - x),
+            _ => (x - left, top - y),
+        };
+        (x * unit, y * unit)
+    }
+
+    /// Map a display-space point back into unrotated PDF user space.
+    ///
+    /// Inverse of MuPDF's page transform: scale by `/UserUnit`, flip y, rotate
+    /// clockwise by `/Rotate`, and move the crop box origin to (0, 0).
+    fn top_left_to_user_space(&self, x: f32, y: f32) -> (f32, f32) {
+        let unit = if self.user_unit > 0.0 {
+            self.user_unit
+        } else {
+            1.0
+        };
+        let (x, y) = (x / unit, y / unit);
+
+        let crop = &self.crop_box;
+        let (left, bottom) = (crop.x, crop.y);
+        let (right, top) = (crop.x + crop.width, crop.y + crop.height);
+
+        match self.rotation {
+            90 => (left + y, bottom + x),
+            180 => (right - x, bottom + y),
+            270 => (right - y, top - x),
+            _ => (left + x, top - y),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn letter(rotation: u16) -> PageInfo {
+        PageInfo {
+            index: 0,
+            media_box: Rect::new(0.0, 0.0, 612.0, 792.0),
+            crop_box: Rect::new(0.0, 0.0, 612.0, 792.0),
+            rotation,
+            user_unit: 1.0,
+            label: None,
+        }
+    }
+
+    #[test]
+    fn test_rotated_page_size() {
+        assert_eq!((letter(0).width(), letter(0).height()), (612.0, 792.0));
+        assert_eq!((letter(90).width(), letter(90).height()), (792.0, 612.0));
+    }
+
+    #[test]
+    fn test_bottom_left_with_rotation() {
+        // Displayed top-left corner of a page rotated 90° clockwise is the
+        // unrotated page's bottom-left corner
+        let page = letter(90);
+        let rect = page.transform_rect(
+            &Rect::new(0.0, 0.0, 10.0, 20.0),
+            CoordinateSpace::BottomLeft,
+        );
+        assert_eq!(rect, Rect::new(0.0, 0.0, 20.0, 10.0));
+
+        let page = letter(180);
+        let rect = page.transform_rect(
+            &Rect::new(0.0, 0.0, 10.0, 20.0),
+            CoordinateSpace::BottomLeft,
+        );
+        assert_eq!(rect, Rect::new(602.0, 0.0, 10.0, 20.0));
+
+        let page = letter(270);
+        let rect = page.transform_rect(
+            &Rect::new(0.0, 0.0, 10.0, 20.0),
+            CoordinateSpace::BottomLeft,
+        );
+        assert_eq!(rect, Rect::new(592.0, 782.0, 20.0, 10.0));
+    }
+
+    #[test]
+    fn test_bottom_left_with_crop_box_and_user_unit() {
+        let page = PageInfo {
+            crop_box: Rect::new(50.0, 100.0, 500.0, 600.0),
+            user_unit: 2.0,
+            ..letter(0)
+        };
+        let rect = page.transform_rect(
+            &Rect::new(20.0, 40.0, 10.0, 10.0),
+            CoordinateSpace::BottomLeft,
+        );
+        assert_eq!(rect, Rect::new(60.0, 675.0, 5.0, 5.0));
+    }
+
+    #[test]
+    fn test_user_space_round_trip() {
+        for rotation in [0, 90, 180, 270] {
+            let page = PageInfo {
+                crop_box: Rect::new(50.0, 100.0, 500.0, 600.0),
+                user_unit: 2.0,
+                ..letter(rotation)
+            };
+            let (x, y) = page.user_space_to_top_left(120.0, 640.0);
+            assert_eq!(page.top_left_to_user_space(x, y), (120.0, 640.0));
+        }
+
+        assert_eq!(letter(0).user_space_to_top_left(72.0, 700.0), (72.0, 92.0));
+    }
+
+    #[test]
+    fn test_normalized() {
+        let page = letter(90);
+        let rect = page.transform_rect(
+            &Rect::new(396.0, 306.0, 79.2, 61.2),
+            CoordinateSpace::Normalized,
+        );
+        assert!((rect.x - 0.5).abs() < 1e-5);
+        assert!((rect.y - 0.5).abs() < 1e-5);
+        assert!((rect.width - 0.1).abs() < 1e-5);
+        assert!((rect.height - 0.1).abs() < 1e-5);
+    }
+}
@@ -0,0 +1,243 @@
+use serde::{Deserialize, Serialize};
+
+/// Numbering style of a page label range (`/S` in a page label dictionary).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum PageLabelStyle {
+    /// `/D`: 1, 2, 3
+    Decimal,
+    /// `/R`: I, II, III
+    UpperRoman,
+    /// `/r`: i, ii, iii
+    LowerRoman,
+    /// `/A`: A..Z, AA..ZZ
+    UpperLetters,
+    /// `/a`: a..z, aa..zz
+    LowerLetters,
+    /// No `/S`: labels are the prefix alone
+    None,
+}
+
+/// A run of pages sharing one labelling scheme, starting at `start_page`.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PageLabelRange {
+    /// First physical page (0-indexed) of the range
+    pub start_page: u32,
+
+    pub style: PageLabelStyle,
+
+    /// `/P`, prepended to every label in the range
+    pub prefix: String,
+
+    /// `/St`, the number of the first page in the range (default 1)
+    pub first_number: u32,
+}
+
+/// Printed page labels ("xii", "17", "A-3") from the `/PageLabels` number tree.
+///
+/// Documents without `/PageLabels` have no ranges; [`label`](Self::label)
+/// then returns `None` and [`find`](Self::find) accepts plain 1-based page
+/// numbers, matching what PDF viewers show.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PageLabels {
+    /// Ranges sorted by `start_page`
+    pub ranges: Vec<PageLabelRange>,
+
+    /// Number of pages in the document
+    pub page_count: u32,
+}
+
+impl PageLabels {
+    /// Build labels from (possibly unsorted) ranges.
+    pub fn new(mut ranges: Vec<PageLabelRange>, page_count: u32) -> Self {
+        ranges.sort_by_key(|r| r.start_page);
+        ranges.dedup_by_key(|r| r.start_page);
+        Self { ranges, page_count }
+    }
+
+    /// True if the document defines no page labels
+    pub fn is_empty(&self) -> bool {
+        self.ranges.is_empty()
+    }
+
+    /// Label of a physical page (0-indexed), or `None` if the page is out of
+    /// range or precedes the first labelled range.
+    ///
+    /// # Example
+    /// ```
+    /// use voxpdf_core::models::{PageLabelRange, PageLabelStyle, PageLabels};
+    ///
+    /// let labels = PageLabels::new(
+    ///     vec![
+    ///         PageLabelRange { start_page: 0, style: PageLabelStyle::LowerRoman, prefix: String::new(), first_number: 1 },
+    ///         PageLabelRange { start_page: 4, style: PageLabelStyle::Decimal, prefix: String::new(), first_number: 1 },
+    ///     ],
+    ///     10,
+    /// );
+    /// assert_eq!(labels.label(2).as_deref(), Some("iii"));
+    /// assert_eq!(labels.label(4).as_deref(), Some("1"));
+    /// assert_eq!(labels.find("iv"), Some(3));
+    /// ```
+    pub fn label(&self, page: u32) -> Option<String> {
+        if page >= self.page_count {
+            return None;
+        }
+
+        let range = self.ranges.iter().rev().find(|r| r.start_page <= page)?;
+        let number = range.first_number + (page - range.start_page);
+
+        let mut label = range.prefix.clone();
+        label.push_str(&format_number(number, range.style));
+        Some(label)
+    }
+
+    /// Physical page index (0-indexed) for a printed label.
+    ///
+    /// Exact matches win; otherwise the comparison ignores case and
+    /// surrounding whitespace, so "XII" finds page "xii". When the same label
+    /// appears more than once (e.g. two appendices both numbered from 1), the
+    /// first page wins.
+    pub fn find(&self, label: &str) -> Option<u32> {
+        let label = label.trim();
+        if label.is_empty() {
+            return None;
+        }
+
+        if self.is_empty() {
+            let number: u32 = label.parse().ok()?;
+            return (1..=self.page_count).contains(&number).then(|| number - 1);
+        }
+
+        let labels: Vec<(u32, String)> = (0..self.page_count)
+            .filter_map(|page| self.label(page).map(|l| (page, l)))
+            .collect();
+
+        labels
+            .iter()
+            .find(|(_, l)| l == label)
+            .or_else(|| labels.iter().find(|(_, l)| l.eq_ignore_ascii_case(label)))
+            .map(|(page, _)| *page)
+    }
+}
+
+fn format_number(number: u32, style: PageLabelStyle) -> String {
+    match style {
+        PageLabelStyle::Decimal => number.to_string(),
+        PageLabelStyle::UpperRoman => to_roman(number),
+        PageLabelStyle::LowerRoman => to_roman(number).to_lowercase(),
+        PageLabelStyle::UpperLetters => to_letters(number),
+        PageLabelStyle::LowerLetters => to_letters(number).to_lowercase(),
+        PageLabelStyle::None => String::new(),
+    }
+}
+
+/// Roman numerals; zero has none, so it falls back to decimal.
+pub(crate) fn to_roman(mut number: u32) -> String {
+    const NUMERALS: [(u32, &str); 13] = [
+        (1000, "M"),
+        (900, "CM"),
+        (500, "D"),
+        (400, "CD"),
+        (100, "C"),
+        (90, "XC"),
+        (50, "L"),
+        (40, "XL"),
+        (10, "X"),
+        (9, "IX"),
+        (5, "V"),
+        (4, "IV"),
+        (1, "I"),
+    ];
+
+    if number == 0 {
+        return "0".to_string();
+    }
+
+    let mut out = String::new();
+    for &(value, numeral) in &NUMERALS {
+        while number >= value {
+            out.push_str(numeral);
+            number -= value;
+        }
+    }
+    out
+}
+
+/// Letter labels per the PDF spec: A..Z, then AA..ZZ, AAA..ZZZ, and so on.
+fn to_letters(number: u32) -> String {
+    if number == 0 {
+        return String::new();
+    }
+
+    let letter = (b'A' + ((number - 1) % 26) as u8) as char;
+    let repeat = ((number - 1) / 26 + 1) as usize;
+    letter.to_string().repeat(repeat)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn range(start_page: u32, style: PageLabelStyle, prefix: &str, first: u32) -> PageLabelRange {
+        PageLabelRange {
+            start_page,
+            style,
+            prefix: prefix.to_string(),
+            first_number: first,
+        }
+    }
+
+    #[test]
+    fn test_roman_and_letters() {
+        assert_eq!(to_roman(4), "IV");
+        assert_eq!(to_roman(1994), "MCMXCIV");
+        assert_eq!(to_letters(1), "A");
+        assert_eq!(to_letters(26), "Z");
+        assert_eq!(to_letters(27), "AA");
+        assert_eq!(to_letters(53), "AAA");
+    }
+
+    #[test]
+    fn test_book_labels() {
+        // Cover, roman front matter, body from 1, appendix "A-1".. style
+        let labels = PageLabels::new(
+            vec![
+                range(20, PageLabelStyle::Decimal, "A-", 1),
+                range(0, PageLabelStyle::None, "Cover", 1),
+                range(1, PageLabelStyle::LowerRoman, "", 1),
+                range(5, PageLabelStyle::Decimal, "", 1),
+            ],
+            22,
+        );
+
+        assert_eq!(labels.label(0).as_deref(), Some("Cover"));
+        assert_eq!(labels.label(1).as_deref(), Some("i"));
+        assert_eq!(labels.label(4).as_deref(), Some("iv"));
+        assert_eq!(labels.label(5).as_deref(), Some("1"));
+        assert_eq!(labels.label(19).as_deref(), Some("15"));
+        assert_eq!(labels.label(21).as_deref(), Some("A-2"));
+        assert_eq!(labels.label(22), None);
+
+        assert_eq!(labels.find("iii"), Some(3));
+        assert_eq!(labels.find("III"), Some(3));
+        assert_eq!(labels.find("12"), Some(16));
+        assert_eq!(labels.find("A-1"), Some(20));
+        assert_eq!(labels.find("xx"), None);
+    }
+
+    #[test]
+    fn test_pages_before_first_range_have_no_label() {
+        let labels = PageLabels::new(vec![range(2, PageLabelStyle::Decimal, "", 1)], 4);
+        assert_eq!(labels.label(1), None);
+        assert_eq!(labels.label(2).as_deref(), Some("1"));
+    }
+
+    #[test]
+    fn test_unlabelled_document_uses_page_numbers() {
+        let labels = PageLabels::new(Vec::new(), 3);
+        assert_eq!(labels.label(0), None);
+        assert_eq!(labels.find("1"), Some(0));
+        assert_eq!(labels.find("3"), Some(2));
+        assert_eq!(labels.find("4"), None);
+        assert_eq!(labels.find("0"), None);
+    }
+}
@@ -1,5 +1,31 @@
-use super::Word;
+use super::{Language, Word};
 use serde::{Deserialize, Serialize};
+use std::ops::Range;
+
+/// What a paragraph is on the page, as far as reading it aloud is concerned.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub enum ParagraphKind {
+    /// Body text (including headings)
+    #[default]
+    Body,
+    /// Running header repeated in the top margin across pages
+    Header,
+    /// Running footer repeated in the bottom margin across pages
+    Footer,
+    /// A folio such as "17", "xii" or "Page 3 of 20" in either margin
+    PageNumber,
+}
+
+/// Where a word sits in its paragraph's text.
+///
+/// Offsets are in UTF-8 bytes, for slicing the Rust string, and in UTF-16
+/// code units, as speech synthesizers on Apple platforms report them
+/// (`NSRange`).
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TextRange {
+    pub utf8: Range<usize>,
+    pub utf16: Range<usize>,
+}
 
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct Paragraph {
@@ -7,21 +33,94 @@ pub struct Paragraph {
     pub text: String,
     pub page_number: u32,
     pub words: Vec<Word>,
+    /// Range of each of `words` in `text`. Kept up to date by extraction;
+    /// call [`update_word_ranges`](Self::update_word_ranges) after editing
+    /// either by hand.
+    #[serde(default)]
+    pub word_ranges: Vec<TextRange>,
+    #[serde(default)]
+    pub kind: ParagraphKind,
+    /// Language of the text, when
+    /// [`ExtractionOptions::detect_language`](crate::extraction::ExtractionOptions::detect_language)
+    /// is set (the default)
+    #[serde(default)]
+    pub language: Option<Language>,
 }
 
 impl Paragraph {
     pub fn new(index: usize, text: String, page_number: u32, words: Vec<Word>) -> Self {
+        let word_ranges = locate_words(&text, &words);
         Self {
             index,
             text,
             page_number,
             words,
+            word_ranges,
+            kind: ParagraphKind::Body,
+            language: None,
         }
     }
 
+    /// Find the words in the text again, after either has changed
+    pub fn update_word_ranges(&mut self) {
+        self.word_ranges = locate_words(&self.text, &self.words);
+    }
+
+    /// Index of the word at a UTF-8 byte offset into `text`. An offset
+    /// between words gives the next one.
+    pub fn word_at_utf8(&self, offset: usize) -> Option<usize> {
+        let index = self.word_ranges.partition_point(|r| r.utf8.end <= offset);
+        (index < self.word_ranges.len()).then_some(index)
+    }
+
+    /// Index of the word at a UTF-16 offset into `text`, such as the start
+    /// of the range a speech synthesizer is about to speak. An offset
+    /// between words gives the next one.
+    pub fn word_at_utf16(&self, offset: usize) -> Option<usize> {
+        let index = self.word_ranges.partition_point(|r| r.utf16.end <= offset);
+        (index < self.word_ranges.len()).then_some(index)
+    }
+
     pub fn word_count(&self) -> usize {
         self.words.len()
     }
+
+    /// Page the paragraph ends on; later than `page_number` for paragraphs
+    /// joined across a page break
+    pub fn end_page_number(&self) -> u32 {
+        self.words
+            .last()
+            .map(|w| w.page_number)
+            .unwrap_or(self.page_number)
+    }
+
+    /// True for running headers, footers and page numbers
+    pub fn is_running_text(&self) -> bool {
+        self.kind != ParagraphKind::Body
+    }
+}
+
+/// Range of each word in `text`, searched for in order. A word that isn't
+/// there gets an empty range where it would have been.
+pub(crate) fn locate_words(text: &str, words: &[Word]) -> Vec<TextRange> {
+    let mut utf8 = 0;
+    let mut utf16 = 0;
+    let mut ranges = Vec::with_capacity(words.len());
+    for word in words {
+        let (start, end) = match text[utf8..].find(word.text.as_str()) {
+            Some(at) => (utf8 + at, utf8 + at + word.text.len()),
+            None => (utf8, utf8),
+        };
+        let start16 = utf16 + text[utf8..start].encode_utf16().count();
+        let end16 = start16 + text[start..end].encode_utf16().count();
+        ranges.push(TextRange {
+            utf8: start..end,
+            utf16: start16..end16,
+        });
+        utf8 = end;
+        utf16 = end16;
+    }
+    ranges
 }
 
 #[cfg(test)]
@@ -38,5 +137,44 @@ mod tests {
         assert_eq!(para.index, 0);
         assert_eq!(para.text, "test");
         assert_eq!(para.word_count(), 1);
+        assert_eq!(para.kind, ParagraphKind::Body);
+        assert!(!para.is_running_text());
+    }
+
+    #[test]
+    fn test_word_ranges() {
+        // "é" is two UTF-8 bytes, "𝑥" two UTF-16 units
+        let words: Vec<Word> = ["Café", "𝑥", "costs", "€3."]
+            .iter()
+            .map(|w| Word::new(*w, Rect::new(0.0, 0.0, 10.0, 10.0), 0, 12.0))
+            .collect();
+        let mut para = Paragraph::new(0, "Café 𝑥 costs €3.".to_string(), 0, words);
+
+        let utf8: Vec<_> = para.word_ranges.iter().map(|r| r.utf8.clone()).collect();
+        let utf16: Vec<_> = para.word_ranges.iter().map(|r| r.utf16.clone()).collect();
+        assert_eq!(utf8, [0..5, 6..10, 11..16, 17..22]);
+        assert_eq!(utf16, [0..4, 5..7, 8..13, 14..17]);
+        for (word, range) in para.words.iter().zip(&para.word_ranges) {
+            assert_eq!(&para.text[range.utf8.clone()], word.text);
+        }
+
+        assert_eq!(para.word_at_utf16(0), Some(0));
+        assert_eq!(para.word_at_utf16(6), Some(1));
+        // A space belongs to the word after it
+        assert_eq!(para.word_at_utf16(7), Some(2));
+        assert_eq!(para.word_at_utf16(17), None);
+        assert_eq!(para.word_at_utf8(12), Some(2));
+
+        // Editing text and words by hand
+        para.text = "Café costs €3.".to_string();
+        para.words.remove(1);
+        para.update_word_ranges();
+        assert_eq!(para.word_ranges[1].utf16, 5..10);
+
+        // A word missing from the text is an empty range in place
+        para.text = "Café €3.".to_string();
+        para.update_word_ranges();
+        assert_eq!(para.word_ranges[1].utf8, 5..5);
+        assert_eq!(para.word_ranges[2].utf8, 6..11);
     }
 }
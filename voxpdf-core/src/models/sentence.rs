@@ -0,0 +1,65 @@
+use super::{Language, Rect, TextRange};
+use serde::{Deserialize, Serialize};
+use std::ops::Range;
+
+/// A sentence of a [`Paragraph`](super::Paragraph), sized for one TTS
+/// utterance.
+///
+/// Produced by [`SentenceSplitter`](crate::extraction::SentenceSplitter).
+/// Sentences cover whole words: a paragraph's sentences partition its words
+/// and, apart from the spaces between them, its text.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Sentence {
+    /// Position within the paragraph
+    pub index: usize,
+    pub text: String,
+    /// Offsets into the paragraph's text
+    pub text_range: TextRange,
+    /// Indices into the paragraph's words
+    pub word_range: Range<usize>,
+    /// One box per printed line the sentence touches, with its page
+    /// number, in reading order
+    pub rects: Vec<(u32, Rect)>,
+    /// The paragraph's language, unless
+    /// [`LanguageDetector::tag_sentences`](crate::extraction::LanguageDetector::tag_sentences)
+    /// found the sentence to be in another
+    #[serde(default)]
+    pub language: Option<Language>,
+}
+
+impl Sentence {
+    pub fn word_count(&self) -> usize {
+        self.word_range.len()
+    }
+
+    /// Page the sentence starts on
+    pub fn page_number(&self) -> Option<u32> {
+        self.rects.first().map(|(page, _)| *page)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_sentence_pages() {
+        let sentence = Sentence {
+            index: 0,
+            text: "Over the page.".to_string(),
+            text_range: TextRange {
+                utf8: 0..14,
+                utf16: 0..14,
+            },
+            word_range: 0..3,
+            rects: vec![
+                (2, Rect::new(72.0, 700.0, 40.0, 12.0)),
+                (3, Rect::new(72.0, 72.0, 40.0, 12.0)),
+            ],
+            language: None,
+        };
+
+        assert_eq!(sentence.word_count(), 3);
+        assert_eq!(sentence.page_number(), Some(2));
+    }
+}
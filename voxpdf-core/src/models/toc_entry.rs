@@ -18,8 +18,38 @@ pub struct TocEntry {
     /// Page number where this entry points (0-indexed)
     pub page_number: u32,
 
-    /// Index into the paragraph array (for navigation)
+    /// Index of the entry's heading in the document-wide paragraph sequence
+    /// from [`extract_document_paragraphs`](crate::extraction::extract_document_paragraphs)
     pub paragraph_index: usize,
+
+    /// Vertical position the destination scrolls to on its page, in display
+    /// coordinates (top-left origin), if the outline specifies one
+    #[serde(default)]
+    pub dest_top: Option<f32>,
+
+    /// True if the outline shows this entry's children expanded (positive
+    /// `/Count`)
+    #[serde(default)]
+    pub is_open: bool,
+
+    /// True if the entry was inferred from heading typography rather than
+    /// read from the document outline
+    #[serde(default)]
+    pub inferred: bool,
+
+    /// How sure we are this is a heading: 1.0 for outline entries, lower for
+    /// inferred ones
+    #[serde(default = "full_confidence")]
+    pub confidence: f32,
+
+    /// Page number as printed in a contents page ("17", "xii"), for entries
+    /// read from one
+    #[serde(default)]
+    pub printed_page: Option<String>,
+}
+
+fn full_confidence() -> f32 {
+    1.0
 }
 
 impl TocEntry {
@@ -29,6 +59,11 @@ impl TocEntry {
             level,
             page_number,
             paragraph_index,
+            dest_top: None,
+            is_open: false,
+            inferred: false,
+            confidence: 1.0,
+            printed_page: None,
         }
     }
 
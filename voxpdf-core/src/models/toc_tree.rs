@@ -0,0 +1,336 @@
+use super::{Paragraph, TocEntry};
+use serde::{Deserialize, Serialize};
+use std::ops::{Range, RangeInclusive};
+
+/// Identifies a node in a [`TocTree`]: the index of its entry in the flat
+/// list [`extract_toc`](crate::extraction::extract_toc) returns
+pub type TocNodeId = usize;
+
+/// An outline entry with its place in the hierarchy and the part of the
+/// document it covers.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct TocNode {
+    pub id: TocNodeId,
+    pub entry: TocEntry,
+    pub parent: Option<TocNodeId>,
+    /// Child nodes in outline order
+    pub children: Vec<TocNodeId>,
+    /// Document paragraphs from this entry's heading up to the next entry
+    /// at the same or a higher level (or the end of the document)
+    pub paragraph_range: Range<usize>,
+    /// Pages those paragraphs are on
+    pub page_range: RangeInclusive<u32>,
+}
+
+impl TocNode {
+    pub fn title(&self) -> &str {
+        &self.entry.title
+    }
+
+    pub fn is_leaf(&self) -> bool {
+        self.children.is_empty()
+    }
+}
+
+/// The document outline as a tree.
+///
+/// Built from the flat [`TocEntry`] list by nesting each entry under the
+/// closest earlier entry with a lower `level`.
+///
+/// # Example
+/// ```no_run
+/// use voxpdf_core::PDFDocument;
+/// use voxpdf_core::extraction::extract_toc_tree;
+///
+/// let doc = PDFDocument::open("book.pdf")?;
+/// let toc = extract_toc_tree(&doc)?;
+/// if let Some(node) = toc.node_for_paragraph(120) {
+///     println!("Now reading: {}", toc.breadcrumb(node.id, " › "));
+/// }
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct TocTree {
+    nodes: Vec<TocNode>,
+    roots: Vec<TocNodeId>,
+}
+
+impl TocTree {
+    /// Build the tree from entries in outline order and the document-wide
+    /// paragraphs their `paragraph_index` points into
+    pub fn new(entries: Vec<TocEntry>, paragraphs: &[Paragraph]) -> Self {
+        let mut nodes: Vec<TocNode> = Vec::with_capacity(entries.len());
+        let mut roots = Vec::new();
+        // Ancestors of the next entry, innermost last
+        let mut open: Vec<TocNodeId> = Vec::new();
+
+        for (id, entry) in entries.into_iter().enumerate() {
+            while open
+                .last()
+                .is_some_and(|&top| nodes[top].entry.level >= entry.level)
+            {
+                open.pop();
+            }
+
+            let parent = open.last().copied();
+            match parent {
+                Some(parent) => nodes[parent].children.push(id),
+                None => roots.push(id),
+            }
+
+            nodes.push(TocNode {
+                id,
+                entry,
+                parent,
+                children: Vec::new(),
+                paragraph_range: 0..0,
+                page_range: 0..=0,
+            });
+            open.push(id);
+        }
+
+        // Nodes are in pre-order, so a subtree is a contiguous run of ids
+        let mut subtree_len = vec![1; nodes.len()];
+        for id in (0..nodes.len()).rev() {
+            if let Some(parent) = nodes[id].parent {
+                subtree_len[parent] += subtree_len[id];
+            }
+        }
+
+        let total = paragraphs.len();
+        for id in 0..nodes.len() {
+            let start = nodes[id].entry.paragraph_index.min(total);
+            let end = nodes
+                .get(id + subtree_len[id])
+                .map_or(total, |next| next.entry.paragraph_index)
+                .max(start + 1)
+                .min(total);
+
+            let first_page = nodes[id].entry.page_number;
+            let last_page = paragraphs[start..end]
+                .last()
+                .map_or(first_page, |p| p.end_page_number())
+                .max(first_page);
+
+            nodes[id].paragraph_range = start..end;
+            nodes[id].page_range = first_page..=last_page;
+        }
+
+        Self { nodes, roots }
+    }
+
+    pub fn len(&self) -> usize {
+        self.nodes.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.nodes.is_empty()
+    }
+
+    /// Every node in outline order, so `nodes()[id].id == id`
+    pub fn nodes(&self) -> &[TocNode] {
+        &self.nodes
+    }
+
+    pub fn get(&self, id: TocNodeId) -> Option<&TocNode> {
+        self.nodes.get(id)
+    }
+
+    /// Top-level nodes (chapters)
+    pub fn roots(&self) -> impl Iterator<Item = &TocNode> {
+        self.roots.iter().map(|&id| &self.nodes[id])
+    }
+
+    pub fn parent(&self, id: TocNodeId) -> Option<&TocNode> {
+        self.get(id)?.parent.map(|parent| &self.nodes[parent])
+    }
+
+    pub fn children(&self, id: TocNodeId) -> impl Iterator<Item = &TocNode> {
+        self.get(id)
+            .into_iter()
+            .flat_map(|node| node.children.iter().map(|&child| &self.nodes[child]))
+    }
+
+    pub fn next_sibling(&self, id: TocNodeId) -> Option<&TocNode> {
+        let siblings = self.siblings(id)?;
+        let position = siblings.iter().position(|&s| s == id)?;
+        siblings.get(position + 1).map(|&s| &self.nodes[s])
+    }
+
+    pub fn prev_sibling(&self, id: TocNodeId) -> Option<&TocNode> {
+        let siblings = self.siblings(id)?;
+        let position = siblings.iter().position(|&s| s == id)?;
+        position.checked_sub(1).map(|p| &self.nodes[siblings[p]])
+    }
+
+    /// Parent, grandparent and so on up to the top-level node
+    pub fn ancestors(&self, id: TocNodeId) -> impl Iterator<Item = &TocNode> {
+        std::iter::successors(self.parent(id), |node| self.parent(node.id))
+    }
+
+    /// The node and its ancestors, top-level node first
+    pub fn path(&self, id: TocNodeId) -> Vec<&TocNode> {
+        let mut path: Vec<&TocNode> = self.ancestors(id).collect();
+        path.reverse();
+        path.extend(self.get(id));
+        path
+    }
+
+    /// Titles along [`path`](Self::path) joined by `separator`, e.g.
+    /// "Chapter 4 › Section 2"
+    pub fn breadcrumb(&self, id: TocNodeId, separator: &str) -> String {
+        self.path(id)
+            .iter()
+            .map(|node| node.title())
+            .collect::<Vec<_>>()
+            .join(separator)
+    }
+
+    /// The most deeply nested node whose range contains the document
+    /// paragraph `paragraph_index`
+    ///
+    /// Returns `None` for paragraphs before the first entry.
+    pub fn node_for_paragraph(&self, paragraph_index: usize) -> Option<&TocNode> {
+        // Sibling ranges don't overlap, so the last match in pre-order is
+        // the deepest one
+        self.nodes
+            .iter()
+            .rev()
+            .find(|node| node.paragraph_range.contains(&paragraph_index))
+    }
+
+    /// The top-level node (chapter) containing the document paragraph
+    /// `paragraph_index`
+    pub fn chapter_for_paragraph(&self, paragraph_index: usize) -> Option<&TocNode> {
+        let node = self.node_for_paragraph(paragraph_index)?;
+        self.ancestors(node.id).last().or(Some(node))
+    }
+
+    fn siblings(&self, id: TocNodeId) -> Option<&[TocNodeId]> {
+        let node = self.get(id)?;
+        Some(match node.parent {
+            Some(parent) => &self.nodes[parent].children,
+            None => &self.roots,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::{Rect, Word};
+
+    fn entry(title: &str, level: u8, page: u32, paragraph: usize) -> TocEntry {
+        TocEntry::new(title.to_string(), level, page, paragraph)
+    }
+
+    /// Ten paragraphs, two per page
+    fn paragraphs() -> Vec<Paragraph> {
+        (0..10)
+            .map(|i| {
+                let page = i as u32 / 2;
+                let word = Word::new("text", Rect::new(72.0, 100.0, 20.0, 11.0), page, 11.0);
+                Paragraph::new(i, "text".to_string(), page, vec![word])
+            })
+            .collect()
+    }
+
+    fn book() -> TocTree {
+        TocTree::new(
+            vec![
+                entry("Chapter 1", 0, 0, 1),
+                entry("Section 1.1", 1, 1, 2),
+                entry("Section 1.2", 1, 1, 3),
+                entry("Chapter 2", 0, 2, 5),
+                entry("Section 2.1", 1, 3, 6),
+                entry("Detail 2.1.1", 2, 3, 7),
+            ],
+            &paragraphs(),
+        )
+    }
+
+    fn titles<'a>(nodes: impl IntoIterator<Item = &'a TocNode>) -> Vec<&'a str> {
+        nodes.into_iter().map(|n| n.title()).collect()
+    }
+
+    #[test]
+    fn test_hierarchy() {
+        let toc = book();
+        assert_eq!(toc.len(), 6);
+        assert_eq!(titles(toc.roots()), ["Chapter 1", "Chapter 2"]);
+        assert_eq!(titles(toc.children(0)), ["Section 1.1", "Section 1.2"]);
+        assert_eq!(toc.parent(5).unwrap().title(), "Section 2.1");
+        assert!(toc.parent(0).is_none());
+        assert!(toc.get(5).unwrap().is_leaf());
+    }
+
+    #[test]
+    fn test_sibling_navigation() {
+        let toc = book();
+        assert_eq!(toc.next_sibling(1).unwrap().title(), "Section 1.2");
+        assert!(toc.next_sibling(2).is_none());
+        assert_eq!(toc.prev_sibling(3).unwrap().title(), "Chapter 1");
+        assert!(toc.prev_sibling(0).is_none());
+        assert!(toc.next_sibling(99).is_none());
+    }
+
+    #[test]
+    fn test_ranges() {
+        let toc = book();
+        let ranges: Vec<_> = toc
+            .nodes()
+            .iter()
+            .map(|n| (n.paragraph_range.clone(), n.page_range.clone()))
+            .collect();
+        assert_eq!(
+            ranges,
+            [
+                (1..5, 0..=2),
+                (2..3, 1..=1),
+                (3..5, 1..=2),
+                (5..10, 2..=4),
+                (6..10, 3..=4),
+                (7..10, 3..=4),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_paragraph_lookup() {
+        let toc = book();
+        assert!(toc.node_for_paragraph(0).is_none());
+        assert_eq!(toc.node_for_paragraph(1).unwrap().title(), "Chapter 1");
+        assert_eq!(toc.node_for_paragraph(4).unwrap().title(), "Section 1.2");
+        assert_eq!(toc.node_for_paragraph(8).unwrap().title(), "Detail 2.1.1");
+        assert_eq!(toc.chapter_for_paragraph(8).unwrap().title(), "Chapter 2");
+        assert_eq!(toc.chapter_for_paragraph(1).unwrap().title(), "Chapter 1");
+        assert!(toc.node_for_paragraph(10).is_none());
+    }
+
+    #[test]
+    fn test_breadcrumb() {
+        let toc = book();
+        assert_eq!(
+            toc.breadcrumb(5, " › "),
+            "Chapter 2 › Section 2.1 › Detail 2.1.1"
+        );
+        assert_eq!(titles(toc.path(0)), ["Chapter 1"]);
+    }
+
+    #[test]
+    fn test_skipped_levels_and_shared_headings() {
+        // A level-2 entry directly under a chapter, and a section whose
+        // heading is the chapter's own paragraph
+        let toc = TocTree::new(
+            vec![
+                entry("Part", 0, 0, 0),
+                entry("Deep", 2, 0, 0),
+                entry("Shallow", 1, 1, 3),
+            ],
+            &paragraphs(),
+        );
+        assert_eq!(titles(toc.children(0)), ["Deep", "Shallow"]);
+        assert_eq!(toc.get(1).unwrap().paragraph_range, 0..3);
+        assert_eq!(toc.get(2).unwrap().paragraph_range, 3..10);
+    }
+}
@@ -6,6 +6,95 @@ pub struct Word {
     pub bounds: Rect,
     pub page_number: u32,
     pub font_size: f32,
+    /// The pieces of a word split over a line or page break ("exam-" and
+    /// "ple" for "example"), in reading order. Empty for ordinary words,
+    /// which are entirely described by `bounds`.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub fragments: Vec<WordFragment>,
+    /// Baseline origin of the first character, in the same coordinates as
+    /// `bounds`
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub origin: Option<Point>,
+    #[serde(default)]
+    pub direction: TextDirection,
+    /// Font of the first character. Only read when
+    /// [`ExtractionOptions::font_attributes`](crate::extraction::ExtractionOptions::font_attributes)
+    /// is set.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub font: Option<FontInfo>,
+    /// Fill colour of the first character as `0xRRGGBB`, read along with
+    /// `font`
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub color: Option<u32>,
+    /// Every printed character with its box, when
+    /// [`ExtractionOptions::glyph_boxes`](crate::extraction::ExtractionOptions::glyph_boxes)
+    /// is set. A split word keeps the glyphs of all its fragments,
+    /// including the break hyphen.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub glyphs: Vec<Glyph>,
+    /// The word's box along its baseline, for text that isn't upright on
+    /// the page. `bounds` is then the smallest axis-aligned rectangle
+    /// around it.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub quad: Option<Quad>,
+}
+
+/// A point in page coordinates.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct Point {
+    pub x: f32,
+    pub y: f32,
+}
+
+/// A box that may be rotated: the upper and lower corners at the start
+/// (`ul`, `ll`) and end (`ur`, `lr`) of the text it encloses.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct Quad {
+    pub ul: Point,
+    pub ur: Point,
+    pub ll: Point,
+    pub lr: Point,
+}
+
+/// Direction a word's characters advance in.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub enum TextDirection {
+    #[default]
+    LeftToRight,
+    /// Hebrew, Arabic and other right-to-left scripts
+    RightToLeft,
+    /// Vertical writing mode, as used for CJK
+    TopToBottom,
+}
+
+/// The typeface a word is set in.
+///
+/// `bold` comes from MuPDF, which also catches fake bold drawn by
+/// stroking; `italic` and `monospace` are read from the font name.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct FontInfo {
+    /// PostScript name without the subset prefix ("Helvetica-Bold", not
+    /// "ABCDEF+Helvetica-Bold")
+    pub name: String,
+    pub bold: bool,
+    pub italic: bool,
+    pub monospace: bool,
+}
+
+/// One printed character of a [`Word`].
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Glyph {
+    pub c: char,
+    pub bounds: Rect,
+}
+
+/// One printed piece of a [`Word`] that was split across lines.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct WordFragment {
+    /// Text as printed, including any break hyphen
+    pub text: String,
+    pub bounds: Rect,
+    pub page_number: u32,
 }
 
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
@@ -23,6 +112,44 @@ impl Word {
             bounds,
             page_number,
             font_size,
+            fragments: Vec::new(),
+            origin: None,
+            direction: TextDirection::LeftToRight,
+            font: None,
+            color: None,
+            glyphs: Vec::new(),
+            quad: None,
+        }
+    }
+
+    /// True if the word was printed in several pieces
+    pub fn is_split(&self) -> bool {
+        !self.fragments.is_empty()
+    }
+
+    /// Every printed piece of the word with its page: the fragments of a
+    /// split word, otherwise just `bounds`
+    pub fn rects(&self) -> Vec<(u32, &Rect)> {
+        if self.fragments.is_empty() {
+            vec![(self.page_number, &self.bounds)]
+        } else {
+            self.fragments
+                .iter()
+                .map(|f| (f.page_number, &f.bounds))
+                .collect()
+        }
+    }
+
+    /// This word's pieces as fragments (itself, if it isn't split)
+    pub(crate) fn to_fragments(&self) -> Vec<WordFragment> {
+        if self.fragments.is_empty() {
+            vec![WordFragment {
+                text: self.text.clone(),
+                bounds: self.bounds.clone(),
+                page_number: self.page_number,
+            }]
+        } else {
+            self.fragments.clone()
         }
     }
 }
@@ -36,6 +163,41 @@ impl Rect {
             height,
         }
     }
+
+    /// Smallest rectangle containing both
+    pub fn union(&self, other: &Rect) -> Rect {
+        let x = self.x.min(other.x);
+        let y = self.y.min(other.y);
+        let right = (self.x + self.width).max(other.x + other.width);
+        let bottom = (self.y + self.height).max(other.y + other.height);
+        Rect::new(x, y, right - x, bottom - y)
+    }
+}
+
+impl Quad {
+    /// Smallest axis-aligned rectangle containing the quad
+    pub fn bounds(&self) -> Rect {
+        let corners = [self.ul, self.ur, self.ll, self.lr];
+        let min_x = corners.iter().map(|p| p.x).fold(f32::INFINITY, f32::min);
+        let min_y = corners.iter().map(|p| p.y).fold(f32::INFINITY, f32::min);
+        let max_x = corners
+            .iter()
+            .map(|p| p.x)
+            .fold(f32::NEG_INFINITY, f32::max);
+        let max_y = corners
+            .iter()
+            .map(|p| p.y)
+            .fold(f32::NEG_INFINITY, f32::max);
+        Rect::new(min_x, min_y, max_x - min_x, max_y - min_y)
+    }
+
+    /// Angle of the baseline in degrees, clockwise from the x axis (y
+    /// grows downwards)
+    pub fn angle(&self) -> f32 {
+        (self.ur.y - self.ul.y)
+            .atan2(self.ur.x - self.ul.x)
+            .to_degrees()
+    }
 }
 
 #[cfg(test)]
@@ -51,5 +213,28 @@ mod tests {
         assert_eq!(word.bounds, rect);
         assert_eq!(word.page_number, 0);
         assert_eq!(word.font_size, 12.0);
+        assert!(!word.is_split());
+        assert_eq!(word.rects(), vec![(0, &rect)]);
+    }
+
+    #[test]
+    fn test_rect_union() {
+        let a = Rect::new(0.0, 10.0, 20.0, 5.0);
+        let b = Rect::new(15.0, 0.0, 10.0, 8.0);
+        assert_eq!(a.union(&b), Rect::new(0.0, 0.0, 25.0, 15.0));
+    }
+
+    #[test]
+    fn test_rotated_quad() {
+        let p = |x, y| Point { x, y };
+        // Rotated 90 degrees clockwise: the text runs down the page
+        let quad = Quad {
+            ul: p(10.0, 20.0),
+            ur: p(10.0, 30.0),
+            ll: p(0.0, 20.0),
+            lr: p(0.0, 30.0),
+        };
+        assert_eq!(quad.bounds(), Rect::new(0.0, 20.0, 10.0, 10.0));
+        assert_eq!(quad.angle(), 90.0);
     }
 }
@@ -0,0 +1,326 @@
+//! Normalizing extracted text into what speech engines read well.
+//!
+//! PDF text is written for the eye: "§ 3(a)", "1,234.5 km", "Fig. 2",
+//! "→", ligatures like "ﬁ" and typographic quotes. A [`Normalizer`] runs
+//! text through a pipeline of [`NormalizationStage`]s, each proposing
+//! [`Edit`]s, and keeps a map from the result back to the original so a
+//! position the engine reports in the spoken text still finds its word.
+//!
+//! The built-in stages come with rules for English, German, Spanish,
+//! French and Italian; other stages can be added with
+//! [`Normalizer::with_stage`].
+
+mod offsets;
+mod rules;
+mod stages;
+
+pub(crate) use stages::is_grouped_number;
+pub use stages::{AbbreviationExpansion, CharacterFolding, NumberExpansion, SymbolExpansion};
+
+use crate::error::Result;
+use crate::models::{Paragraph, TextRange};
+use crate::pdf::PDFDocument;
+use offsets::OffsetMap;
+use std::fmt;
+use std::ops::Range;
+
+/// Where a rule in a built-in stage applies
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RuleContext {
+    Anywhere,
+    /// Followed by a number: "Fig." in "Fig. 2", "~" in "~5"
+    BeforeNumber,
+    /// Preceded by a number: "%" in "5%"
+    AfterNumber,
+    /// Anywhere, and the full stop is kept when it also ends the sentence:
+    /// "etc."
+    SentenceFinal,
+}
+
+/// Replace the bytes in `range` with `replacement`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Edit {
+    pub range: Range<usize>,
+    pub replacement: String,
+}
+
+impl Edit {
+    pub fn new(range: Range<usize>, replacement: impl Into<String>) -> Self {
+        Self {
+            range,
+            replacement: replacement.into(),
+        }
+    }
+}
+
+/// One step of a [`Normalizer`] pipeline.
+pub trait NormalizationStage: Send + Sync {
+    /// Short name for logging ("symbols")
+    fn name(&self) -> &str;
+
+    /// Edits that make `text` easier to speak, as byte ranges into `text`.
+    /// The pipeline sorts them and drops any overlapping an earlier one or
+    /// not on character boundaries.
+    fn edits(&self, text: &str) -> Vec<Edit>;
+}
+
+/// Runs text through [`NormalizationStage`]s in order.
+///
+/// # Example
+/// ```
+/// use voxpdf_core::normalization::Normalizer;
+///
+/// let normalized = Normalizer::for_language(Some("en")).normalize("See Fig. 2: ~5% of 1,234 km");
+/// assert_eq!(normalized.text, "See Figure 2: approximately 5 percent of 1234 kilometers");
+///
+/// // "kilometers" came from "km"
+/// let spoken = normalized.text.find("kilometers").unwrap();
+/// assert_eq!(normalized.original_range(spoken..spoken + 10), 25..27);
+/// ```
+#[derive(Default)]
+pub struct Normalizer {
+    stages: Vec<Box<dyn NormalizationStage>>,
+}
+
+impl fmt::Debug for Normalizer {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_list()
+            .entries(self.stages.iter().map(|s| s.name()))
+            .finish()
+    }
+}
+
+impl Normalizer {
+    /// A pipeline with no stages, which leaves text unchanged
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// The built-in stages for a BCP 47 language tag ("en-US", "de"): character
+    /// folding, then symbols, abbreviations and numbers. `None` uses the
+    /// English rules; languages without rules only get character folding,
+    /// rather than English words in their text.
+    pub fn for_language(language: Option<&str>) -> Self {
+        let mut normalizer = Self::new().with_stage(CharacterFolding);
+        if let Some(stage) = SymbolExpansion::for_language(language) {
+            normalizer = normalizer.with_stage(stage);
+        }
+        if let Some(stage) = AbbreviationExpansion::for_language(language) {
+            normalizer = normalizer.with_stage(stage);
+        }
+        if let Some(stage) = NumberExpansion::for_language(language) {
+            normalizer = normalizer.with_stage(stage);
+        }
+        normalizer
+    }
+
+    /// The built-in stages for the document's [`language`](PDFDocument::language)
+    pub fn for_document(doc: &PDFDocument) -> Result<Self> {
+        Ok(Self::for_language(doc.language()?))
+    }
+
+    /// Run `stage` after the existing ones
+    pub fn with_stage(mut self, stage: impl NormalizationStage + 'static) -> Self {
+        self.stages.push(Box::new(stage));
+        self
+    }
+
+    /// Names of the stages, in order
+    pub fn stage_names(&self) -> Vec<&str> {
+        self.stages.iter().map(|s| s.name()).collect()
+    }
+
+    /// Run `text` through every stage in turn
+    pub fn normalize(&self, text: &str) -> NormalizedText {
+        let mut map = OffsetMap::identity(text.len());
+        let mut current = text.to_string();
+        for stage in &self.stages {
+            let mut edits: Vec<Edit> = stage
+                .edits(&current)
+                .into_iter()
+                .filter(|e| {
+                    e.range.start <= e.range.end
+                        && current.is_char_boundary(e.range.start)
+                        && current.is_char_boundary(e.range.end)
+                })
+                .collect();
+            edits.sort_by_key(|e| (e.range.start, e.range.end));
+            let mut end = 0;
+            edits.retain(|e| {
+                let keep = e.range.start >= end;
+                if keep {
+                    end = e.range.end;
+                }
+                keep
+            });
+            if !edits.is_empty() {
+                current = map.apply(&current, &edits);
+            }
+        }
+        NormalizedText { text: current, map }
+    }
+
+    /// Normalize a paragraph's text
+    pub fn normalize_paragraph(&self, paragraph: &Paragraph) -> NormalizedText {
+        self.normalize(&paragraph.text)
+    }
+}
+
+/// Text after a [`Normalizer`], with the way back to the original.
+///
+/// Ranges are byte ranges; a range touching a replacement maps to the
+/// whole of what it replaced ("Figure" to "Fig.").
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct NormalizedText {
+    pub text: String,
+    map: OffsetMap,
+}
+
+impl NormalizedText {
+    /// Byte range in the original text that `range` of this text came from
+    pub fn original_range(&self, range: Range<usize>) -> Range<usize> {
+        self.map.original_range(range)
+    }
+
+    /// Byte range in this text that `range` of the original ended up in
+    pub fn normalized_range(&self, range: Range<usize>) -> Range<usize> {
+        self.map.output_range(range)
+    }
+
+    /// Where each word of `paragraph`, whose text was normalized, is in this
+    /// text
+    pub fn word_ranges(&self, paragraph: &Paragraph) -> Vec<TextRange> {
+        let mut ranges = Vec::with_capacity(paragraph.word_ranges.len());
+        // (UTF-8, UTF-16) offsets of the previous word's end, to count from
+        let mut cursor = (0, 0);
+        for word in &paragraph.word_ranges {
+            let utf8 = self.normalized_range(word.utf8.clone());
+            if utf8.start < cursor.0 {
+                cursor = (0, 0);
+            }
+            let start16 = cursor.1 + self.text[cursor.0..utf8.start].encode_utf16().count();
+            let end16 = start16 + self.text[utf8.clone()].encode_utf16().count();
+            cursor = (utf8.end, end16);
+            ranges.push(TextRange {
+                utf8,
+                utf16: start16..end16,
+            });
+        }
+        ranges
+    }
+
+    /// Index of the word of `paragraph` being spoken at byte `offset` of this
+    /// text, as reported by a speech engine
+    pub fn word_at_utf8(&self, paragraph: &Paragraph, offset: usize) -> Option<usize> {
+        paragraph.word_at_utf8(self.map.original_offset(offset))
+    }
+
+    /// Index of the word of `paragraph` being spoken at UTF-16 `offset` of
+    /// this text, as AVSpeechSynthesizer reports it
+    pub fn word_at_utf16(&self, paragraph: &Paragraph, offset: usize) -> Option<usize> {
+        let mut units = 0;
+        let byte = self
+            .text
+            .char_indices()
+            .find(|&(_, c)| {
+                units += c.len_utf16();
+                units > offset
+            })
+            .map_or(self.text.len(), |(i, _)| i);
+        self.word_at_utf8(paragraph, byte)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::paragraph;
+
+    #[test]
+    fn test_pipeline() {
+        let normalizer = Normalizer::for_language(None);
+        assert_eq!(
+            normalizer.stage_names(),
+            ["characters", "symbols", "abbreviations", "numbers"]
+        );
+        let normalized = normalizer.normalize("The ﬁnal “Fig. 2” shows § 3(a): 1,234.5 km → 2 h");
+        assert_eq!(
+            normalized.text,
+            "The final \"Figure 2\" shows section 3 a: 1234.5 kilometers to 2 hours"
+        );
+
+        // Languages without rules are only folded
+        let ja = Normalizer::for_language(Some("ja")).normalize("ﬁ § 5%");
+        assert_eq!(ja.text, "fi § 5%");
+        assert_eq!(Normalizer::new().normalize("§ 5%").text, "§ 5%");
+    }
+
+    #[test]
+    fn test_word_ranges() {
+        let para = paragraph(0, "See Fig. 2, ~5% of 1,234 km.");
+        let normalized = Normalizer::for_language(Some("en")).normalize_paragraph(&para);
+        assert_eq!(
+            normalized.text,
+            "See Figure 2, approximately 5 percent of 1234 kilometers."
+        );
+
+        let spoken: Vec<&str> = normalized
+            .word_ranges(&para)
+            .iter()
+            .map(|r| &normalized.text[r.utf8.clone()])
+            .collect();
+        assert_eq!(
+            spoken,
+            [
+                "See",
+                "Figure",
+                "2,",
+                "approximately 5 percent",
+                "of",
+                "1234",
+                "kilometers."
+            ]
+        );
+
+        // An engine reporting "percent" highlights "~5%"
+        let percent = normalized.text.find("percent").unwrap();
+        assert_eq!(normalized.word_at_utf8(&para, percent), Some(3));
+        assert_eq!(normalized.word_at_utf16(&para, percent + 2), Some(3));
+        assert_eq!(normalized.word_at_utf16(&para, 5), Some(1));
+        assert_eq!(normalized.word_at_utf16(&para, normalized.text.len()), None);
+    }
+
+    #[test]
+    fn test_utf16_offsets() {
+        let para = paragraph(0, "“ﬁne” 5%");
+        let normalized = Normalizer::for_language(None).normalize_paragraph(&para);
+        assert_eq!(normalized.text, "\"fine\" 5 percent");
+        let ranges = normalized.word_ranges(&para);
+        assert_eq!(ranges[0].utf16, 0..6);
+        assert_eq!(ranges[1].utf16, 7..16);
+        assert_eq!(normalized.word_at_utf16(&para, 10), Some(1));
+    }
+
+    #[test]
+    fn test_overlapping_edits_dropped() {
+        struct Overlapping;
+        impl NormalizationStage for Overlapping {
+            fn name(&self) -> &str {
+                "overlapping"
+            }
+            fn edits(&self, _text: &str) -> Vec<Edit> {
+                vec![
+                    Edit::new(2..4, "X"),
+                    Edit::new(0..3, "Y"),
+                    Edit::new(5..6, "Z"),
+                    // Inside "é"
+                    Edit::new(7..8, "W"),
+                ]
+            }
+        }
+        let normalized = Normalizer::new()
+            .with_stage(Overlapping)
+            .normalize("abcdefé");
+        assert_eq!(normalized.text, "YdeZé");
+    }
+}
@@ -0,0 +1,259 @@
+//! Tracking where each stretch of normalized text came from.
+
+use super::Edit;
+use std::ops::Range;
+
+/// A stretch of normalized text and the original text it replaced
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct Span {
+    output: Range<usize>,
+    original: Range<usize>,
+    /// Copied unchanged, so offsets inside map one to one
+    verbatim: bool,
+}
+
+/// Byte offsets between a text and its normalized form.
+///
+/// The spans cover the output in order, and their original ranges never
+/// go backwards. Deletions are kept as spans with an empty output range,
+/// insertions as spans with an empty original one.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct OffsetMap {
+    spans: Vec<Span>,
+    original_len: usize,
+}
+
+impl OffsetMap {
+    /// The identity map over a text `len` bytes long
+    pub(crate) fn identity(len: usize) -> Self {
+        let spans = if len == 0 {
+            Vec::new()
+        } else {
+            vec![Span {
+                output: 0..len,
+                original: 0..len,
+                verbatim: true,
+            }]
+        };
+        Self {
+            spans,
+            original_len: len,
+        }
+    }
+
+    /// Apply sorted, non-overlapping `edits` to `text`, which this map
+    /// describes, returning the edited text and updating the map to match
+    pub(crate) fn apply(&mut self, text: &str, edits: &[Edit]) -> String {
+        let mut output = String::with_capacity(text.len());
+        let mut spans = Vec::with_capacity(self.spans.len() + 2 * edits.len());
+        let mut pos = 0;
+        for edit in edits {
+            self.copy(text, pos..edit.range.start, &mut output, &mut spans);
+            let original = self.original_range(edit.range.clone());
+            let start = output.len();
+            output.push_str(&edit.replacement);
+            spans.push(Span {
+                output: start..output.len(),
+                original,
+                verbatim: false,
+            });
+            pos = edit.range.end;
+        }
+        self.copy(text, pos..text.len(), &mut output, &mut spans);
+
+        self.spans = spans;
+        output
+    }
+
+    /// Copy `text[range]` to `output`, carrying over the spans it covers
+    fn copy(&self, text: &str, range: Range<usize>, output: &mut String, spans: &mut Vec<Span>) {
+        if range.is_empty() {
+            return;
+        }
+        let shift = output.len();
+        output.push_str(&text[range.clone()]);
+
+        let first = self.spans.partition_point(|s| s.output.end <= range.start);
+        for span in &self.spans[first..] {
+            if span.output.start >= range.end {
+                break;
+            }
+            let start = span.output.start.max(range.start);
+            let end = span.output.end.min(range.end);
+            let original = if span.verbatim {
+                let base = span.original.start;
+                base + (start - span.output.start)..base + (end - span.output.start)
+            } else {
+                span.original.clone()
+            };
+            spans.push(Span {
+                output: start - range.start + shift..end - range.start + shift,
+                original,
+                verbatim: span.verbatim,
+            });
+        }
+    }
+
+    /// Original byte offset of output offset `offset`; inside a replacement,
+    /// the start of what it replaced
+    pub(crate) fn original_offset(&self, offset: usize) -> usize {
+        let i = self.spans.partition_point(|s| s.output.end <= offset);
+        match self.spans.get(i) {
+            Some(span) if span.verbatim && span.output.start <= offset => {
+                span.original.start + (offset - span.output.start)
+            }
+            Some(span) => span.original.start,
+            None => self.original_len,
+        }
+    }
+
+    /// Original byte range an output range came from, widened to whole
+    /// replacements
+    pub(crate) fn original_range(&self, range: Range<usize>) -> Range<usize> {
+        if range.is_empty() {
+            let offset = self.original_offset(range.start);
+            return offset..offset;
+        }
+
+        let mut result: Option<Range<usize>> = None;
+        let first = self.spans.partition_point(|s| s.output.end <= range.start);
+        for span in &self.spans[first..] {
+            if span.output.start >= range.end {
+                break;
+            }
+            let original = if span.verbatim {
+                let base = span.original.start;
+                let start = range.start.max(span.output.start) - span.output.start;
+                let end = range.end.min(span.output.end) - span.output.start;
+                base + start..base + end
+            } else {
+                span.original.clone()
+            };
+            result = Some(match result {
+                Some(r) => r.start.min(original.start)..r.end.max(original.end),
+                None => original,
+            });
+        }
+        result.unwrap_or_else(|| {
+            let offset = self.original_offset(range.start);
+            offset..offset
+        })
+    }
+
+    /// Output byte offset of original offset `offset`; inside a replaced
+    /// stretch, the start of its replacement
+    pub(crate) fn output_offset(&self, offset: usize) -> usize {
+        let i = self.spans.partition_point(|s| s.original.end <= offset);
+        match self.spans.get(i) {
+            Some(span) if span.verbatim && span.original.start <= offset => {
+                span.output.start + (offset - span.original.start)
+            }
+            Some(span) => span.output.start,
+            None => self.spans.last().map_or(0, |s| s.output.end),
+        }
+    }
+
+    /// Output byte range an original range ended up in, widened to whole
+    /// replacements
+    pub(crate) fn output_range(&self, range: Range<usize>) -> Range<usize> {
+        if range.is_empty() {
+            let offset = self.output_offset(range.start);
+            return offset..offset;
+        }
+
+        let mut result: Option<Range<usize>> = None;
+        let first = self
+            .spans
+            .partition_point(|s| s.original.end <= range.start);
+        for span in &self.spans[first..] {
+            if span.original.start >= range.end {
+                break;
+            }
+            // Insertions on the edges belong to the neighbouring text
+            if span.original.is_empty() && span.original.start == range.start {
+                continue;
+            }
+            let output = if span.verbatim {
+                let base = span.output.start;
+                let start = range.start.max(span.original.start) - span.original.start;
+                let end = range.end.min(span.original.end) - span.original.start;
+                base + start..base + end
+            } else {
+                span.output.clone()
+            };
+            result = Some(match result {
+                Some(r) => r.start.min(output.start)..r.end.max(output.end),
+                None => output,
+            });
+        }
+        result.unwrap_or_else(|| {
+            let offset = self.output_offset(range.start);
+            offset..offset
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn edit(range: Range<usize>, replacement: &str) -> Edit {
+        Edit::new(range, replacement)
+    }
+
+    #[test]
+    fn test_identity() {
+        let map = OffsetMap::identity(5);
+        assert_eq!(map.original_range(1..3), 1..3);
+        assert_eq!(map.output_range(2..5), 2..5);
+        assert_eq!(map.original_offset(5), 5);
+        assert_eq!(OffsetMap::identity(0).original_range(0..0), 0..0);
+    }
+
+    #[test]
+    fn test_replacement_maps_to_whole_range() {
+        let text = "see Fig. 2";
+        let mut map = OffsetMap::identity(text.len());
+        let out = map.apply(text, &[edit(4..8, "Figure")]);
+        assert_eq!(out, "see Figure 2");
+
+        // Any part of "Figure" is the whole of "Fig."
+        assert_eq!(map.original_range(6..8), 4..8);
+        assert_eq!(map.original_offset(7), 4);
+        // Text after the edit shifts
+        assert_eq!(map.original_range(11..12), 9..10);
+        assert_eq!(map.output_range(4..8), 4..10);
+        assert_eq!(map.output_range(9..10), 11..12);
+    }
+
+    #[test]
+    fn test_deletions_and_insertions() {
+        let text = "co\u{ad}op 5%";
+        let mut map = OffsetMap::identity(text.len());
+        // Drop the soft hyphen (2 bytes), spell out the percent sign
+        let out = map.apply(text, &[edit(2..4, ""), edit(8..9, " percent")]);
+        assert_eq!(out, "coop 5 percent");
+
+        // The word spans the deleted hyphen
+        assert_eq!(map.original_range(0..4), 0..6);
+        assert_eq!(map.output_range(0..6), 0..4);
+        assert_eq!(map.output_range(7..9), 5..14);
+        assert_eq!(map.original_range(6..14), 8..9);
+    }
+
+    #[test]
+    fn test_composed_edits() {
+        let text = "ﬁne";
+        let mut map = OffsetMap::identity(text.len());
+        let once = map.apply(text, &[edit(0..3, "fi")]);
+        assert_eq!(once, "fine");
+        // A later stage edits inside the first replacement
+        let twice = map.apply(&once, &[edit(1..2, "I")]);
+        assert_eq!(twice, "fIne");
+
+        assert_eq!(map.original_range(0..1), 0..3);
+        assert_eq!(map.original_range(1..2), 0..3);
+        assert_eq!(map.original_range(2..4), 3..5);
+        assert_eq!(map.output_range(0..5), 0..4);
+    }
+}
@@ -0,0 +1,333 @@
+//! Built-in per-language rule sets.
+
+use super::RuleContext::{self, AfterNumber, Anywhere, BeforeNumber, SentenceFinal};
+
+/// How one language writes and reads symbols, abbreviations and numbers
+#[derive(Debug)]
+pub(crate) struct LanguageRules {
+    /// Primary language subtag
+    pub language: &'static str,
+    /// Between thousands in written numbers, if a single character
+    pub group_separator: Option<char>,
+    /// Read for the dash in a number range ("1990–1995")
+    pub range_word: &'static str,
+    /// Symbol, spoken form, where it applies; longest first where one is a
+    /// prefix of another
+    pub symbols: &'static [(&'static str, &'static str, RuleContext)],
+    /// Abbreviation as written, spoken form, where it applies
+    pub abbreviations: &'static [(&'static str, &'static str, RuleContext)],
+    /// Unit symbol, singular, plural
+    pub units: &'static [(&'static str, &'static str, &'static str)],
+}
+
+/// Built-in rules; the first is used when no language is known
+pub(crate) const RULES: &[LanguageRules] = &[ENGLISH, GERMAN, SPANISH, FRENCH, ITALIAN];
+
+/// Rules for a BCP 47 language tag ("en-US", "de"); `None` for languages
+/// without built-in rules
+pub(crate) fn rules_for(language: Option<&str>) -> Option<&'static LanguageRules> {
+    let Some(tag) = language else {
+        return Some(&RULES[0]);
+    };
+    let primary = tag.split(['-', '_']).next().unwrap_or("").trim();
+    RULES
+        .iter()
+        .find(|rules| rules.language.eq_ignore_ascii_case(primary))
+}
+
+const ENGLISH: LanguageRules = LanguageRules {
+    language: "en",
+    group_separator: Some(','),
+    range_word: "to",
+    symbols: &[
+        ("§§", "sections", Anywhere),
+        ("§", "section", Anywhere),
+        ("→", "to", Anywhere),
+        ("⇒", "implies", Anywhere),
+        ("≈", "approximately", Anywhere),
+        ("~", "approximately", BeforeNumber),
+        ("±", "plus or minus", Anywhere),
+        ("×", "times", AfterNumber),
+        ("&", "and", Anywhere),
+        ("%", "percent", AfterNumber),
+        ("‰", "per mille", AfterNumber),
+        ("°C", "degrees Celsius", AfterNumber),
+        ("°F", "degrees Fahrenheit", AfterNumber),
+        ("°", "degrees", AfterNumber),
+        ("≤", "less than or equal to", Anywhere),
+        ("≥", "greater than or equal to", Anywhere),
+        ("≠", "not equal to", Anywhere),
+        ("∞", "infinity", Anywhere),
+    ],
+    abbreviations: &[
+        ("e.g.", "for example", Anywhere),
+        ("i.e.", "that is", Anywhere),
+        ("cf.", "compare", Anywhere),
+        ("vs.", "versus", Anywhere),
+        ("approx.", "approximately", Anywhere),
+        ("etc.", "et cetera", SentenceFinal),
+        ("Fig.", "Figure", BeforeNumber),
+        ("fig.", "figure", BeforeNumber),
+        ("Figs.", "Figures", BeforeNumber),
+        ("figs.", "figures", BeforeNumber),
+        ("Eq.", "Equation", BeforeNumber),
+        ("eq.", "equation", BeforeNumber),
+        ("Eqs.", "Equations", BeforeNumber),
+        ("Ch.", "Chapter", BeforeNumber),
+        ("ch.", "chapter", BeforeNumber),
+        ("Sec.", "Section", BeforeNumber),
+        ("sec.", "section", BeforeNumber),
+        ("Vol.", "Volume", BeforeNumber),
+        ("vol.", "volume", BeforeNumber),
+        ("No.", "Number", BeforeNumber),
+        ("no.", "number", BeforeNumber),
+        ("p.", "page", BeforeNumber),
+        ("pp.", "pages", BeforeNumber),
+    ],
+    units: &[
+        ("km", "kilometer", "kilometers"),
+        ("m", "meter", "meters"),
+        ("cm", "centimeter", "centimeters"),
+        ("mm", "millimeter", "millimeters"),
+        ("nm", "nanometer", "nanometers"),
+        ("mi", "mile", "miles"),
+        ("ft", "foot", "feet"),
+        ("kg", "kilogram", "kilograms"),
+        ("g", "gram", "grams"),
+        ("mg", "milligram", "milligrams"),
+        ("lb", "pound", "pounds"),
+        ("ml", "milliliter", "milliliters"),
+        ("h", "hour", "hours"),
+        ("min", "minute", "minutes"),
+        ("s", "second", "seconds"),
+        ("ms", "millisecond", "milliseconds"),
+        ("km/h", "kilometer per hour", "kilometers per hour"),
+        ("mph", "mile per hour", "miles per hour"),
+        ("Hz", "hertz", "hertz"),
+        ("kHz", "kilohertz", "kilohertz"),
+        ("MHz", "megahertz", "megahertz"),
+        ("GHz", "gigahertz", "gigahertz"),
+        ("kB", "kilobyte", "kilobytes"),
+        ("MB", "megabyte", "megabytes"),
+        ("GB", "gigabyte", "gigabytes"),
+        ("TB", "terabyte", "terabytes"),
+        ("W", "watt", "watts"),
+        ("kW", "kilowatt", "kilowatts"),
+        ("V", "volt", "volts"),
+    ],
+};
+
+const GERMAN: LanguageRules = LanguageRules {
+    language: "de",
+    group_separator: Some('.'),
+    range_word: "bis",
+    symbols: &[
+        ("§§", "Paragrafen", Anywhere),
+        ("§", "Paragraf", Anywhere),
+        ("→", "zu", Anywhere),
+        ("⇒", "daraus folgt", Anywhere),
+        ("≈", "ungefähr", Anywhere),
+        ("~", "circa", BeforeNumber),
+        ("±", "plus minus", Anywhere),
+        ("×", "mal", AfterNumber),
+        ("&", "und", Anywhere),
+        ("%", "Prozent", AfterNumber),
+        ("‰", "Promille", AfterNumber),
+        ("°C", "Grad Celsius", AfterNumber),
+        ("°", "Grad", AfterNumber),
+        ("≤", "kleiner gleich", Anywhere),
+        ("≥", "größer gleich", Anywhere),
+        ("≠", "ungleich", Anywhere),
+        ("∞", "unendlich", Anywhere),
+    ],
+    abbreviations: &[
+        ("z.B.", "zum Beispiel", Anywhere),
+        ("d.h.", "das heißt", Anywhere),
+        ("u.a.", "unter anderem", Anywhere),
+        ("bzw.", "beziehungsweise", Anywhere),
+        ("ggf.", "gegebenenfalls", Anywhere),
+        ("vgl.", "vergleiche", Anywhere),
+        ("ca.", "circa", Anywhere),
+        ("usw.", "und so weiter", SentenceFinal),
+        ("Abb.", "Abbildung", BeforeNumber),
+        ("Tab.", "Tabelle", BeforeNumber),
+        ("Kap.", "Kapitel", BeforeNumber),
+        ("Bd.", "Band", BeforeNumber),
+        ("Nr.", "Nummer", BeforeNumber),
+        ("S.", "Seite", BeforeNumber),
+    ],
+    units: &[
+        ("km", "Kilometer", "Kilometer"),
+        ("m", "Meter", "Meter"),
+        ("cm", "Zentimeter", "Zentimeter"),
+        ("mm", "Millimeter", "Millimeter"),
+        ("kg", "Kilogramm", "Kilogramm"),
+        ("g", "Gramm", "Gramm"),
+        ("mg", "Milligramm", "Milligramm"),
+        ("ml", "Milliliter", "Milliliter"),
+        ("h", "Stunde", "Stunden"),
+        ("min", "Minute", "Minuten"),
+        ("s", "Sekunde", "Sekunden"),
+        ("ms", "Millisekunde", "Millisekunden"),
+        ("km/h", "Kilometer pro Stunde", "Kilometer pro Stunde"),
+        ("Hz", "Hertz", "Hertz"),
+        ("kB", "Kilobyte", "Kilobyte"),
+        ("MB", "Megabyte", "Megabyte"),
+        ("GB", "Gigabyte", "Gigabyte"),
+        ("W", "Watt", "Watt"),
+        ("kW", "Kilowatt", "Kilowatt"),
+        ("V", "Volt", "Volt"),
+    ],
+};
+
+const SPANISH: LanguageRules = LanguageRules {
+    language: "es",
+    group_separator: Some('.'),
+    range_word: "a",
+    symbols: &[
+        ("§§", "secciones", Anywhere),
+        ("§", "sección", Anywhere),
+        ("→", "a", Anywhere),
+        ("≈", "aproximadamente", Anywhere),
+        ("~", "aproximadamente", BeforeNumber),
+        ("±", "más o menos", Anywhere),
+        ("×", "por", AfterNumber),
+        ("&", "y", Anywhere),
+        ("%", "por ciento", AfterNumber),
+        ("‰", "por mil", AfterNumber),
+        ("°C", "grados Celsius", AfterNumber),
+        ("°", "grados", AfterNumber),
+        ("≤", "menor o igual que", Anywhere),
+        ("≥", "mayor o igual que", Anywhere),
+        ("≠", "distinto de", Anywhere),
+        ("∞", "infinito", Anywhere),
+    ],
+    abbreviations: &[
+        ("aprox.", "aproximadamente", Anywhere),
+        ("cf.", "véase", Anywhere),
+        ("etc.", "etcétera", SentenceFinal),
+        ("Fig.", "Figura", BeforeNumber),
+        ("fig.", "figura", BeforeNumber),
+        ("pág.", "página", BeforeNumber),
+        ("núm.", "número", BeforeNumber),
+        ("cap.", "capítulo", BeforeNumber),
+        ("vol.", "volumen", BeforeNumber),
+    ],
+    units: &[
+        ("km", "kilómetro", "kilómetros"),
+        ("m", "metro", "metros"),
+        ("cm", "centímetro", "centímetros"),
+        ("mm", "milímetro", "milímetros"),
+        ("kg", "kilogramo", "kilogramos"),
+        ("g", "gramo", "gramos"),
+        ("mg", "miligramo", "miligramos"),
+        ("ml", "mililitro", "mililitros"),
+        ("h", "hora", "horas"),
+        ("min", "minuto", "minutos"),
+        ("s", "segundo", "segundos"),
+        ("ms", "milisegundo", "milisegundos"),
+        ("km/h", "kilómetro por hora", "kilómetros por hora"),
+    ],
+};
+
+const FRENCH: LanguageRules = LanguageRules {
+    language: "fr",
+    // Groups are separated by spaces, which split words
+    group_separator: None,
+    range_word: "à",
+    symbols: &[
+        ("§§", "paragraphes", Anywhere),
+        ("§", "paragraphe", Anywhere),
+        ("→", "à", Anywhere),
+        ("≈", "environ", Anywhere),
+        ("~", "environ", BeforeNumber),
+        ("±", "plus ou moins", Anywhere),
+        ("×", "fois", AfterNumber),
+        ("&", "et", Anywhere),
+        ("%", "pour cent", AfterNumber),
+        ("‰", "pour mille", AfterNumber),
+        ("°C", "degrés Celsius", AfterNumber),
+        ("°", "degrés", AfterNumber),
+        ("≤", "inférieur ou égal à", Anywhere),
+        ("≥", "supérieur ou égal à", Anywhere),
+        ("≠", "différent de", Anywhere),
+        ("∞", "l'infini", Anywhere),
+    ],
+    abbreviations: &[
+        ("c.-à-d.", "c'est-à-dire", Anywhere),
+        ("cf.", "voir", Anywhere),
+        ("env.", "environ", Anywhere),
+        ("etc.", "et cetera", SentenceFinal),
+        ("Fig.", "Figure", BeforeNumber),
+        ("fig.", "figure", BeforeNumber),
+        ("chap.", "chapitre", BeforeNumber),
+        ("vol.", "volume", BeforeNumber),
+        ("n°", "numéro", BeforeNumber),
+        ("p.", "page", BeforeNumber),
+    ],
+    units: &[
+        ("km", "kilomètre", "kilomètres"),
+        ("m", "mètre", "mètres"),
+        ("cm", "centimètre", "centimètres"),
+        ("mm", "millimètre", "millimètres"),
+        ("kg", "kilogramme", "kilogrammes"),
+        ("g", "gramme", "grammes"),
+        ("mg", "milligramme", "milligrammes"),
+        ("ml", "millilitre", "millilitres"),
+        ("h", "heure", "heures"),
+        ("min", "minute", "minutes"),
+        ("s", "seconde", "secondes"),
+        ("ms", "milliseconde", "millisecondes"),
+        ("km/h", "kilomètre par heure", "kilomètres par heure"),
+    ],
+};
+
+const ITALIAN: LanguageRules = LanguageRules {
+    language: "it",
+    group_separator: Some('.'),
+    range_word: "a",
+    symbols: &[
+        ("§§", "paragrafi", Anywhere),
+        ("§", "paragrafo", Anywhere),
+        ("→", "a", Anywhere),
+        ("≈", "circa", Anywhere),
+        ("~", "circa", BeforeNumber),
+        ("±", "più o meno", Anywhere),
+        ("×", "per", AfterNumber),
+        ("&", "e", Anywhere),
+        ("%", "per cento", AfterNumber),
+        ("‰", "per mille", AfterNumber),
+        ("°C", "gradi Celsius", AfterNumber),
+        ("°", "gradi", AfterNumber),
+        ("≤", "minore o uguale a", Anywhere),
+        ("≥", "maggiore o uguale a", Anywhere),
+        ("≠", "diverso da", Anywhere),
+        ("∞", "infinito", Anywhere),
+    ],
+    abbreviations: &[
+        ("cfr.", "confronta", Anywhere),
+        ("ca.", "circa", Anywhere),
+        ("ecc.", "eccetera", SentenceFinal),
+        ("Fig.", "Figura", BeforeNumber),
+        ("fig.", "figura", BeforeNumber),
+        ("pag.", "pagina", BeforeNumber),
+        ("cap.", "capitolo", BeforeNumber),
+        ("vol.", "volume", BeforeNumber),
+        ("n.", "numero", BeforeNumber),
+    ],
+    units: &[
+        ("km", "chilometro", "chilometri"),
+        ("m", "metro", "metri"),
+        ("cm", "centimetro", "centimetri"),
+        ("mm", "millimetro", "millimetri"),
+        ("kg", "chilogrammo", "chilogrammi"),
+        ("g", "grammo", "grammi"),
+        ("mg", "milligrammo", "milligrammi"),
+        ("ml", "millilitro", "millilitri"),
+        ("h", "ora", "ore"),
+        ("min", "minuto", "minuti"),
+        ("s", "secondo", "secondi"),
+        ("ms", "millisecondo", "millisecondi"),
+        ("km/h", "chilometro orario", "chilometri orari"),
+    ],
+};
@@ -0,0 +1,508 @@
+//! Built-in normalization stages.
+
+use super::rules::rules_for;
+use super::{Edit, NormalizationStage, RuleContext};
+
+/// Characters folded to what speech engines expect; an empty replacement
+/// deletes
+const FOLDS: &[(char, &str)] = &[
+    ('ﬀ', "ff"),
+    ('ﬁ', "fi"),
+    ('ﬂ', "fl"),
+    ('ﬃ', "ffi"),
+    ('ﬄ', "ffl"),
+    ('ﬅ', "st"),
+    ('ﬆ', "st"),
+    ('‘', "'"),
+    ('’', "'"),
+    ('‚', "'"),
+    ('‛', "'"),
+    ('“', "\""),
+    ('”', "\""),
+    ('„', "\""),
+    ('‟', "\""),
+    ('«', "\""),
+    ('»', "\""),
+    ('\u{a0}', " "),
+    ('\u{2007}', " "),
+    ('\u{2009}', " "),
+    ('\u{202f}', " "),
+    ('\u{ad}', ""),
+    ('\u{200b}', ""),
+    ('\u{2060}', ""),
+    ('\u{feff}', ""),
+];
+
+/// Quotes and brackets stripped from the front of a token before matching
+const OPENERS: &[char] = &['"', '\'', '“', '‘', '„', '«', '(', '[', '{', '¿', '¡'];
+
+/// Punctuation stripped from the end of a token before matching
+const TRAILERS: &[char] = &[
+    ',', ';', ':', '!', '?', '"', '\'', '”', '’', '»', ')', ']', '}',
+];
+
+/// Dashes read as "to" between numbers
+const RANGE_DASHES: &[char] = &['–', '—'];
+
+/// Longest bracketed label read after a number ("3(a)", "12(iv)")
+const MAX_LABEL_LEN: usize = 4;
+
+/// Ligatures, typographic quotes, unusual spaces and invisible characters
+/// folded to plain text. Language independent.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct CharacterFolding;
+
+impl NormalizationStage for CharacterFolding {
+    fn name(&self) -> &str {
+        "characters"
+    }
+
+    fn edits(&self, text: &str) -> Vec<Edit> {
+        text.char_indices()
+            .filter_map(|(i, c)| {
+                let (_, folded) = FOLDS.iter().find(|(from, _)| *from == c)?;
+                Some(Edit::new(i..i + c.len_utf8(), *folded))
+            })
+            .collect()
+    }
+}
+
+/// Symbols read as words: "§ 3" as "section 3", "5%" as "5 percent".
+#[derive(Debug, Clone, Default)]
+pub struct SymbolExpansion {
+    symbols: Vec<(String, String, RuleContext)>,
+}
+
+impl SymbolExpansion {
+    /// Built-in symbols for a BCP 47 language tag; `None` reads as English,
+    /// and languages without rules get `None`
+    pub fn for_language(language: Option<&str>) -> Option<Self> {
+        let rules = rules_for(language)?;
+        let mut stage = Self::default();
+        for &(symbol, spoken, context) in rules.symbols {
+            stage.add(symbol, spoken, context);
+        }
+        Some(stage)
+    }
+
+    /// Read `symbol` as `spoken` where `context` holds; the longest symbol
+    /// matching at a position wins
+    pub fn add(&mut self, symbol: &str, spoken: &str, context: RuleContext) {
+        if !symbol.is_empty() {
+            self.symbols
+                .push((symbol.to_string(), spoken.to_string(), context));
+        }
+    }
+}
+
+impl NormalizationStage for SymbolExpansion {
+    fn name(&self) -> &str {
+        "symbols"
+    }
+
+    fn edits(&self, text: &str) -> Vec<Edit> {
+        let mut edits = Vec::new();
+        let mut pos = 0;
+        while pos < text.len() {
+            let rest = &text[pos..];
+            let found = self
+                .symbols
+                .iter()
+                .filter(|(symbol, _, context)| {
+                    rest.starts_with(symbol.as_str())
+                        && context_holds(*context, &text[..pos], &rest[symbol.len()..])
+                })
+                .max_by_key(|(symbol, _, _)| symbol.len());
+
+            let Some((symbol, spoken, _)) = found else {
+                pos += rest.chars().next().map_or(1, char::len_utf8);
+                continue;
+            };
+            let end = pos + symbol.len();
+            let mut replacement = String::new();
+            if text[..pos]
+                .chars()
+                .next_back()
+                .is_some_and(char::is_alphanumeric)
+            {
+                replacement.push(' ');
+            }
+            replacement.push_str(spoken);
+            if text[end..]
+                .chars()
+                .next()
+                .is_some_and(char::is_alphanumeric)
+            {
+                replacement.push(' ');
+            }
+            edits.push(Edit::new(pos..end, replacement));
+            pos = end;
+        }
+        edits
+    }
+}
+
+/// Abbreviations read in full: "Fig. 2" as "Figure 2", "e.g." as "for
+/// example".
+#[derive(Debug, Clone, Default)]
+pub struct AbbreviationExpansion {
+    abbreviations: Vec<(String, String, RuleContext)>,
+}
+
+impl AbbreviationExpansion {
+    /// Built-in abbreviations for a BCP 47 language tag; `None` reads as
+    /// English, and languages without rules get `None`
+    pub fn for_language(language: Option<&str>) -> Option<Self> {
+        let rules = rules_for(language)?;
+        let mut stage = Self::default();
+        for &(abbreviation, spoken, context) in rules.abbreviations {
+            stage.add(abbreviation, spoken, context);
+        }
+        Some(stage)
+    }
+
+    /// Read `abbreviation` (as written, including any full stop) as `spoken`
+    /// where `context` holds
+    pub fn add(&mut self, abbreviation: &str, spoken: &str, context: RuleContext) {
+        if !abbreviation.is_empty() {
+            self.abbreviations
+                .push((abbreviation.to_string(), spoken.to_string(), context));
+        }
+    }
+}
+
+impl NormalizationStage for AbbreviationExpansion {
+    fn name(&self) -> &str {
+        "abbreviations"
+    }
+
+    fn edits(&self, text: &str) -> Vec<Edit> {
+        let tokens = tokens(text);
+        let mut edits = Vec::new();
+        for (i, &(start, token)) in tokens.iter().enumerate() {
+            let (offset, core) = token_core(token, false);
+            let Some((_, spoken, context)) = self
+                .abbreviations
+                .iter()
+                .find(|(abbreviation, _, _)| abbreviation == core)
+            else {
+                continue;
+            };
+
+            let before = &text[..start + offset];
+            let after = &text[start + offset + core.len()..];
+            if !context_holds(*context, before, after) {
+                continue;
+            }
+            let mut replacement = spoken.clone();
+            if *context == RuleContext::SentenceFinal {
+                // Keep the full stop the abbreviation shared with the sentence
+                let next = tokens
+                    .get(i + 1)
+                    .map(|&(_, t)| t.trim_start_matches(OPENERS));
+                if next.is_none_or(|t| t.starts_with(char::is_uppercase)) {
+                    replacement.push('.');
+                }
+            }
+            let start = start + offset;
+            edits.push(Edit::new(start..start + core.len(), replacement));
+        }
+        edits
+    }
+}
+
+/// Numbers made readable: thousands separators dropped ("1,234.5"), units
+/// spelled out ("5 km"), ranges read with a word ("1990–1995") and
+/// bracketed labels separated ("3(a)").
+#[derive(Debug, Clone, Default)]
+pub struct NumberExpansion {
+    group_separator: Option<char>,
+    range_word: String,
+    /// Symbol, singular, plural
+    units: Vec<(String, String, String)>,
+}
+
+impl NumberExpansion {
+    /// Built-in number rules for a BCP 47 language tag; `None` reads as
+    /// English, and languages without rules get `None`
+    pub fn for_language(language: Option<&str>) -> Option<Self> {
+        let rules = rules_for(language)?;
+        let mut stage = Self {
+            group_separator: rules.group_separator,
+            range_word: rules.range_word.to_string(),
+            units: Vec::new(),
+        };
+        for &(unit, singular, plural) in rules.units {
+            stage.add_unit(unit, singular, plural);
+        }
+        Some(stage)
+    }
+
+    /// Read `unit` after a number as `singular` after 1 and `plural`
+    /// otherwise
+    pub fn add_unit(&mut self, unit: &str, singular: &str, plural: &str) {
+        if !unit.is_empty() {
+            self.units
+                .push((unit.to_string(), singular.to_string(), plural.to_string()));
+        }
+    }
+
+    fn unit(&self, symbol: &str, one: bool) -> Option<&str> {
+        let (_, singular, plural) = self.units.iter().find(|(unit, _, _)| unit == symbol)?;
+        Some(if one { singular } else { plural })
+    }
+}
+
+impl NormalizationStage for NumberExpansion {
+    fn name(&self) -> &str {
+        "numbers"
+    }
+
+    fn edits(&self, text: &str) -> Vec<Edit> {
+        let tokens = tokens(text);
+        let mut edits = Vec::new();
+        for (i, &(start, token)) in tokens.iter().enumerate() {
+            let (offset, core) = token_core(token, true);
+            let start = start + offset;
+            let number_len = core
+                .find(|c: char| !(c.is_ascii_digit() || c == ',' || c == '.'))
+                .unwrap_or(core.len());
+            let number = core[..number_len].trim_end_matches(['.', ',']);
+            if !number.starts_with(|c: char| c.is_ascii_digit()) {
+                continue;
+            }
+            let suffix = &core[number.len()..];
+
+            if let Some(separator) = self.group_separator {
+                if is_grouped_number(number, separator) {
+                    edits.extend(
+                        number
+                            .match_indices(separator)
+                            .map(|(j, _)| Edit::new(start + j..start + j + 1, "")),
+                    );
+                }
+            }
+            let one = number == "1";
+            let suffix_start = start + number.len();
+
+            if suffix.is_empty() {
+                // "5 km": the unit is the next token
+                let Some(&(next_start, next)) = tokens.get(i + 1) else {
+                    continue;
+                };
+                let (next_offset, unit) = token_core(next, true);
+                if let Some(spoken) = self.unit(unit, one) {
+                    let unit_start = next_start + next_offset;
+                    edits.push(Edit::new(unit_start..unit_start + unit.len(), spoken));
+                }
+            } else if let Some(dash) = suffix.chars().next().filter(|c| RANGE_DASHES.contains(c)) {
+                let rest = &suffix[dash.len_utf8()..];
+                if rest.starts_with(|c: char| c.is_ascii_digit()) {
+                    let end = suffix_start + dash.len_utf8();
+                    edits.push(Edit::new(
+                        suffix_start..end,
+                        format!(" {} ", self.range_word),
+                    ));
+                }
+            } else if let Some(label) = bracketed_label(suffix) {
+                let close = suffix_start + 1 + label.len();
+                edits.push(Edit::new(suffix_start..suffix_start + 1, " "));
+                edits.push(Edit::new(close..close + 1, ""));
+            } else if suffix.chars().count() > 1 {
+                // "5km", but not "1990s"
+                if let Some(spoken) = self.unit(suffix, one) {
+                    edits.push(Edit::new(
+                        suffix_start..suffix_start + suffix.len(),
+                        format!(" {spoken}"),
+                    ));
+                }
+            }
+        }
+        edits
+    }
+}
+
+/// Whether `context` holds for a match between `before` and `after`
+fn context_holds(context: RuleContext, before: &str, after: &str) -> bool {
+    match context {
+        RuleContext::Anywhere | RuleContext::SentenceFinal => true,
+        RuleContext::BeforeNumber => after
+            .trim_start()
+            .trim_start_matches(OPENERS)
+            .starts_with(|c: char| c.is_ascii_digit()),
+        RuleContext::AfterNumber => before
+            .trim_end()
+            .chars()
+            .next_back()
+            .is_some_and(|c| c.is_ascii_digit()),
+    }
+}
+
+/// Whitespace-separated tokens and their byte offsets
+fn tokens(text: &str) -> Vec<(usize, &str)> {
+    let mut tokens = Vec::new();
+    let mut start = None;
+    for (i, c) in text.char_indices() {
+        match (c.is_whitespace(), start) {
+            (true, Some(s)) => {
+                tokens.push((s, &text[s..i]));
+                start = None;
+            }
+            (false, None) => start = Some(i),
+            _ => {}
+        }
+    }
+    if let Some(s) = start {
+        tokens.push((s, &text[s..]));
+    }
+    tokens
+}
+
+/// A token without surrounding quotes, brackets and punctuation, and its
+/// offset in the token. Full stops are stripped only if `strip_stops`.
+fn token_core(token: &str, strip_stops: bool) -> (usize, &str) {
+    let trimmed = token.trim_start_matches(OPENERS);
+    let offset = token.len() - trimmed.len();
+    let mut core = if strip_stops {
+        trimmed.trim_end_matches(|c| TRAILERS.contains(&c) || c == '.')
+    } else {
+        trimmed.trim_end_matches(TRAILERS)
+    };
+    // Keep the bracket closing one inside: "3(a)"
+    if core.matches('(').count() > core.matches(')').count()
+        && trimmed[core.len()..].starts_with(')')
+    {
+        core = &trimmed[..core.len() + 1];
+    }
+    (offset, core)
+}
+
+/// "12,345" or "1,234,567.89" with `separator` between thousands
+pub(crate) fn is_grouped_number(text: &str, separator: char) -> bool {
+    let decimal = if separator == ',' { '.' } else { ',' };
+    let integer = text.split(decimal).next().unwrap_or(text);
+    let fraction = &text[integer.len()..];
+    if !fraction.is_empty() && !fraction[1..].chars().all(|c| c.is_ascii_digit()) {
+        return false;
+    }
+
+    let groups: Vec<&str> = integer.split(separator).collect();
+    groups.len() > 1
+        && (1..=3).contains(&groups[0].len())
+        && groups[1..].iter().all(|g| g.len() == 3)
+        && groups.iter().all(|g| g.chars().all(|c| c.is_ascii_digit()))
+}
+
+/// "a" from "(a)", up to [`MAX_LABEL_LEN`] letters or digits
+fn bracketed_label(text: &str) -> Option<&str> {
+    let label = text.strip_prefix('(')?.strip_suffix(')')?;
+    let len = label.chars().count();
+    ((1..=MAX_LABEL_LEN).contains(&len) && label.chars().all(char::is_alphanumeric))
+        .then_some(label)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::normalization::Normalizer;
+
+    fn run<S: NormalizationStage + Clone + 'static>(stage: &S, text: &str) -> String {
+        Normalizer::new()
+            .with_stage(stage.clone())
+            .normalize(text)
+            .text
+    }
+
+    #[test]
+    fn test_character_folding() {
+        assert_eq!(
+            run(&CharacterFolding, "The ﬁnal “ofﬁce” isn’t co\u{ad}op"),
+            "The final \"office\" isn't coop"
+        );
+        assert_eq!(run(&CharacterFolding, "10\u{a0}km"), "10 km");
+    }
+
+    #[test]
+    fn test_symbols() {
+        let en = SymbolExpansion::for_language(Some("en-US")).unwrap();
+        assert_eq!(run(&en, "see § 3(a)"), "see section 3(a)");
+        assert_eq!(run(&en, "§§3"), "sections 3");
+        assert_eq!(
+            run(&en, "up 5%, at 20°C"),
+            "up 5 percent, at 20 degrees Celsius"
+        );
+        assert_eq!(run(&en, "A → B"), "A to B");
+        // Context: no number, no reading
+        assert_eq!(run(&en, "~/home or ~ 5"), "~/home or approximately 5");
+        assert_eq!(run(&en, "a × b, 3×4"), "a × b, 3 times 4");
+
+        let de = SymbolExpansion::for_language(Some("de")).unwrap();
+        assert_eq!(run(&de, "~5 % mehr"), "circa 5 Prozent mehr");
+        assert!(SymbolExpansion::for_language(Some("ja")).is_none());
+    }
+
+    #[test]
+    fn test_abbreviations() {
+        let en = AbbreviationExpansion::for_language(None).unwrap();
+        assert_eq!(
+            run(&en, "See Fig. 2 (e.g., here)"),
+            "See Figure 2 (for example, here)"
+        );
+        // "Fig." without a number is left alone
+        assert_eq!(run(&en, "the Fig. tree"), "the Fig. tree");
+        assert_eq!(
+            run(&en, "cats, dogs, etc. and more"),
+            "cats, dogs, et cetera and more"
+        );
+        assert_eq!(
+            run(&en, "cats, dogs, etc. Then"),
+            "cats, dogs, et cetera. Then"
+        );
+        assert_eq!(run(&en, "cats, dogs, etc."), "cats, dogs, et cetera.");
+
+        let de = AbbreviationExpansion::for_language(Some("de-AT")).unwrap();
+        assert_eq!(run(&de, "z.B. auf S. 12"), "zum Beispiel auf Seite 12");
+    }
+
+    #[test]
+    fn test_numbers() {
+        let en = NumberExpansion::for_language(Some("en")).unwrap();
+        assert_eq!(run(&en, "1,234.5 km"), "1234.5 kilometers");
+        assert_eq!(run(&en, "1 km and 5km."), "1 kilometer and 5 kilometers.");
+        assert_eq!(run(&en, "in 1990–1995"), "in 1990 to 1995");
+        assert_eq!(run(&en, "§ 3(a) and (12(iv))"), "§ 3 a and (12 iv)");
+        // Plural years and ordinary words stay
+        assert_eq!(
+            run(&en, "the 1990s, 5 in all, 3,14"),
+            "the 1990s, 5 in all, 3,14"
+        );
+
+        let de = NumberExpansion::for_language(Some("de")).unwrap();
+        assert_eq!(
+            run(&de, "1.234,5 km in 2 h"),
+            "1234,5 Kilometer in 2 Stunden"
+        );
+        // French groups with spaces, so nothing to drop
+        let fr = NumberExpansion::for_language(Some("fr")).unwrap();
+        assert_eq!(run(&fr, "1.234 km"), "1.234 kilomètres");
+    }
+
+    #[test]
+    fn test_grouped_numbers() {
+        assert!(is_grouped_number("1,234,567.89", ','));
+        assert!(is_grouped_number("1.234,5", '.'));
+        assert!(!is_grouped_number("1234", ','));
+        assert!(!is_grouped_number("12,34", ','));
+        assert!(!is_grouped_number("3.14", '.'));
+    }
+
+    #[test]
+    fn test_tokens() {
+        assert_eq!(tokens(" a  bc\td "), [(1, "a"), (4, "bc"), (7, "d")]);
+        assert_eq!(token_core("(e.g.,", false), (1, "e.g."));
+        assert_eq!(token_core("km.)", true), (0, "km"));
+        assert_eq!(token_core("(3(a)):", true), (1, "3(a)"));
+        assert_eq!(bracketed_label("(iv)"), Some("iv"));
+        assert_eq!(bracketed_label("(a b)"), None);
+    }
+}
@@ -0,0 +1,249 @@
+//! Document metadata from the Info dictionary, catalog `/Lang` and XMP stream.
+
+use super::text_string::read_text_string;
+use super::PDFDocument;
+use crate::error::{Result, VoxPDFError};
+use crate::models::{DocumentMetadata, PdfDate, XmpMetadata};
+
+impl PDFDocument {
+    /// Read the document's metadata.
+    ///
+    /// Info dictionary and `/Lang` strings are decoded from PDFDocEncoding
+    /// or UTF-16, so titles in any script come back as proper UTF-8.
+    ///
+    /// # Example
+    /// ```no_run
+    /// use voxpdf_core::PDFDocument;
+    ///
+    /// let doc = PDFDocument::open("example.pdf")?;
+    /// let meta = doc.metadata()?;
+    /// if let (Some(title), Some(author)) = (meta.display_title(), meta.display_author()) {
+    ///     println!("{} by {}", title, author);
+    /// }
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn metadata(&self) -> Result<DocumentMetadata> {
+        self.ensure_unlocked()?;
+
+        let trailer = self
+            .doc
+            .trailer()
+            .map_err(|e| VoxPDFError::InvalidPDF(format!("Failed to read trailer: {}", e)))?;
+        let info_dict = trailer.get_dict("Info").ok().flatten();
+
+        let info = |key: &str| -> Option<String> {
+            let value = info_dict.as_ref()?.get_dict(key).ok().flatten()?;
+            let value = read_text_string(&value)?;
+            let value = value.trim();
+            if value.is_empty() {
+                None
+            } else {
+                Some(value.to_string())
+            }
+        };
+
+        let catalog = self
+            .doc
+            .catalog()
+            .map_err(|e| VoxPDFError::InvalidPDF(format!("Failed to read catalog: {}", e)))?;
+
+        let language = catalog
+            .get_dict("Lang")
+            .ok()
+            .flatten()
+            .and_then(|lang| read_text_string(&lang))
+            .map(|s| s.trim().to_string())
+            .filter(|s| !s.is_empty());
+
+        let xmp = catalog
+            .get_dict("Metadata")
+            .ok()
+            .flatten()
+            .and_then(|stream| stream.read_stream().ok())
+            .and_then(|bytes| parse_xmp(&String::from_utf8_lossy(&bytes)));
+
+        Ok(DocumentMetadata {
+            title: info("Title"),
+            author: info("Author"),
+            subject: info("Subject"),
+            keywords: info("Keywords"),
+            creator: info("Creator"),
+            producer: info("Producer"),
+            creation_date: info("CreationDate").and_then(|d| PdfDate::parse(&d)),
+            modification_date: info("ModDate").and_then(|d| PdfDate::parse(&d)),
+            language,
+            xmp,
+        })
+    }
+}
+
+/// Pull the commonly used Dublin Core / XMP / PDF fields out of an XMP packet.
+///
+/// This is a tolerant string scan rather than a full RDF parser: it handles
+/// both the element form (`<dc:title><rdf:Alt><rdf:li>..`) and the attribute
+/// shorthand (`<rdf:Description pdf:Producer="..">`). Returns `None` if the
+/// packet contains none of the fields.
+pub(crate) fn parse_xmp(xml: &str) -> Option<XmpMetadata> {
+    let xmp = XmpMetadata {
+        title: first(xmp_values(xml, "dc:title")),
+        creators: xmp_values(xml, "dc:creator"),
+        description: first(xmp_values(xml, "dc:description")),
+        subjects: xmp_values(xml, "dc:subject"),
+        languages: xmp_values(xml, "dc:language"),
+        creator_tool: first(xmp_values(xml, "xmp:CreatorTool")),
+        create_date: first(xmp_values(xml, "xmp:CreateDate")),
+        modify_date: first(xmp_values(xml, "xmp:ModifyDate")),
+        producer: first(xmp_values(xml, "pdf:Producer")),
+        keywords: first(xmp_values(xml, "pdf:Keywords")),
+    };
+
+    if xmp == XmpMetadata::default() {
+        None
+    } else {
+        Some(xmp)
+    }
+}
+
+fn first(values: Vec<String>) -> Option<String> {
+    values.into_iter().next()
+}
+
+/// All values for a property: the `rdf:li` items of a container, the element's
+/// text, or an attribute value.
+fn xmp_values(xml: &str, name: &str) -> Vec<String> {
+    let open = format!("<{}", name);
+    let close = format!("</{}>", name);
+
+    let mut search_from = 0;
+    while let Some(rel) = xml[search_from..].find(&open) {
+        let start = search_from + rel;
+        let after_name = start + open.len();
+        search_from = after_name;
+
+        // Make sure we matched the whole element name, not a prefix of another
+        match xml[after_name..].chars().next() {
+            Some(c) if c == '>' || c == '/' || c.is_whitespace() => {}
+            _ => continue,
+        }
+
+        let Some(tag_end) = xml[after_name..].find('>').map(|i| after_name + i) else {
+            break;
+        };
+        if xml[..tag_end].ends_with('/') {
+            continue; // Self-closing, no content
+        }
+
+        let Some(content_end) = xml[tag_end..].find(&close).map(|i| tag_end + i) else {
+            break;
+        };
+        let content = &xml[tag_end + 1..content_end];
+
+        let items = element_texts(content, "rdf:li");
+        if !items.is_empty() {
+            return items;
+        }
+
+        let text = unescape_xml(content.trim());
+        if !text.is_empty() && !text.contains('<') {
+            return vec![text];
+        }
+    }
+
+    // Attribute shorthand: name="value" or name='value'
+    for quote in ['"', '\''] {
+        let needle = format!("{}={}", name, quote);
+        if let Some(pos) = xml.find(&needle) {
+            let value_start = pos + needle.len();
+            if let Some(len) = xml[value_start..].find(quote) {
+                let value = unescape_xml(xml[value_start..value_start + len].trim());
+                if !value.is_empty() {
+                    return vec![value];
+                }
+            }
+        }
+    }
+
+    Vec::new()
+}
+
+/// Text content of every `<tag ...>text</tag>` inside `xml`.
+fn element_texts(xml: &str, tag: &str) -> Vec<String> {
+    let open = format!("<{}", tag);
+    let close = format!("</{}>", tag);
+    let mut out = Vec::new();
+    let mut rest = xml;
+
+    while let Some(start) = rest.find(&open) {
+        let Some(tag_end) = rest[start..].find('>').map(|i| start + i) else {
+            break;
+        };
+        let Some(end) = rest[tag_end..].find(&close).map(|i| tag_end + i) else {
+            break;
+        };
+
+        let text = unescape_xml(rest[tag_end + 1..end].trim());
+        if !text.is_empty() {
+            out.push(text);
+        }
+        rest = &rest[end + close.len()..];
+    }
+
+    out
+}
+
+fn unescape_xml(s: &str) -> String {
+    s.replace("&lt;", "<")
+        .replace("&gt;", ">")
+        .replace("&quot;", "\"")
+        .replace("&apos;", "'")
+        .replace("&amp;", "&")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const PACKET: &str = r#"<?xpacket begin="" id="W5M0MpCehiHzreSzNTczkc9d"?>
+<x:xmpmeta xmlns:x="adobe:ns:meta/">
+  <rdf:RDF xmlns:rdf="http://www.w3.org/1999/02/22-rdf-syntax-ns#">
+    <rdf:Description rdf:about="" pdf:Producer="LaTeX with hyperref"
+        xmp:CreatorTool='pdfTeX'>
+      <dc:title><rdf:Alt><rdf:li xml:lang="x-default">Networks &amp; Graphs</rdf:li></rdf:Alt></dc:title>
+      <dc:creator>
+        <rdf:Seq>
+          <rdf:li>Ada Lovelace</rdf:li>
+          <rdf:li>Alan Turing</rdf:li>
+        </rdf:Seq>
+      </dc:creator>
+      <dc:language><rdf:Bag><rdf:li>en-GB</rdf:li></rdf:Bag></dc:language>
+      <xmp:CreateDate>2023-04-01T09:30:00Z</xmp:CreateDate>
+    </rdf:Description>
+  </rdf:RDF>
+</x:xmpmeta>
+<?xpacket end="w"?>"#;
+
+    #[test]
+    fn test_parse_xmp_elements_and_attributes() {
+        let xmp = parse_xmp(PACKET).unwrap();
+        assert_eq!(xmp.title.as_deref(), Some("Networks & Graphs"));
+        assert_eq!(xmp.creators, vec!["Ada Lovelace", "Alan Turing"]);
+        assert_eq!(xmp.languages, vec!["en-GB"]);
+        assert_eq!(xmp.create_date.as_deref(), Some("2023-04-01T09:30:00Z"));
+        assert_eq!(xmp.producer.as_deref(), Some("LaTeX with hyperref"));
+        assert_eq!(xmp.creator_tool.as_deref(), Some("pdfTeX"));
+        assert!(xmp.description.is_none());
+        assert!(xmp.subjects.is_empty());
+    }
+
+    #[test]
+    fn test_parse_xmp_without_known_fields() {
+        assert!(parse_xmp("<x:xmpmeta></x:xmpmeta>").is_none());
+        assert!(parse_xmp("").is_none());
+    }
+
+    #[test]
+    fn test_xmp_value_does_not_match_name_prefix() {
+        let xml = "<dc:titleExtra>wrong</dc:titleExtra><dc:title>right</dc:title>";
+        assert_eq!(xmp_values(xml, "dc:title"), vec!["right"]);
+    }
+}
@@ -1,10 +1,95 @@
 use crate::error::{Result, VoxPDFError};
-use mupdf::Document as MuPdfDocument;
+use crate::extraction::{
+    extract_document_paragraphs, extract_toc_tree, ExtractionOptions, LanguageDetector,
+    RunningText, SentenceSplitter,
+};
+use crate::models::{PageLabels, Paragraph, TocTree};
+use crate::normalization::Normalizer;
+use mupdf::pdf::PdfDocument as MuPdfDocument;
+#[cfg(feature = "toc-fallback")]
+use mupdf::pdf::{Encryption, PdfWriteOptions};
+use once_cell::unsync::OnceCell;
+use std::collections::HashMap;
+use std::io::Read;
+use std::sync::Arc;
+
+mod metadata;
+#[cfg(feature = "toc-fallback")]
+mod outline;
+mod page_info;
+mod page_labels;
+mod tagged;
+mod text_string;
+
+pub(crate) use tagged::LanguageMark;
+
+/// Where a document's bytes come from.
+///
+/// MuPDF documents are not thread-safe, so parallel and streaming extraction
+/// re-open the document on each worker. The source records enough to do that
+/// without touching the filesystem for in-memory documents.
+#[derive(Debug, Clone)]
+pub enum DocumentSource {
+    /// A file on disk
+    Path(String),
+    /// An in-memory buffer shared between all re-opened copies
+    Bytes(Arc<[u8]>),
+}
+
+impl From<String> for DocumentSource {
+    fn from(path: String) -> Self {
+        DocumentSource::Path(path)
+    }
+}
+
+impl From<&str> for DocumentSource {
+    fn from(path: &str) -> Self {
+        DocumentSource::Path(path.to_string())
+    }
+}
+
+impl From<Vec<u8>> for DocumentSource {
+    fn from(bytes: Vec<u8>) -> Self {
+        DocumentSource::Bytes(bytes.into())
+    }
+}
+
+impl From<Arc<[u8]>> for DocumentSource {
+    fn from(bytes: Arc<[u8]>) -> Self {
+        DocumentSource::Bytes(bytes)
+    }
+}
 
 /// Wrapper around MuPDF document
+///
+/// Holds MuPDF's PDF-specific handle, which derefs to the generic document API
+/// and also exposes the catalog and raw objects.
 pub struct PDFDocument {
     pub(crate) doc: MuPdfDocument,
-    path: String,
+    source: DocumentSource,
+    /// Password that unlocked this document, replayed when re-opening copies
+    password: Option<String>,
+    locked: bool,
+    options: ExtractionOptions,
+    /// Running headers and footers, learned on first use
+    running_text: OnceCell<RunningText>,
+    /// Printed page labels, read on first use
+    page_labels: OnceCell<PageLabels>,
+    /// Every page's paragraphs, stitched, extracted on first use
+    paragraphs: OnceCell<Vec<Paragraph>>,
+    /// Outline tree over `paragraphs`
+    toc: OnceCell<TocTree>,
+    /// BCP 47 tag from the metadata, read on first use
+    language: OnceCell<Option<String>>,
+    /// Sentence rules for the document's language
+    sentence_splitter: OnceCell<SentenceSplitter>,
+    /// Speech normalization for the document's language
+    normalizer: OnceCell<Normalizer>,
+    /// Language identification seeded with the document's language
+    language_detector: OnceCell<LanguageDetector>,
+    /// `/Lang` of structure elements, by (page object, MCID), read on
+    /// first use; `None` if the document isn't tagged
+    struct_languages: OnceCell<Option<HashMap<(i32, i32), String>>>,
 }
 
 impl PDFDocument {
@@ -18,15 +103,278 @@ impl PDFDocument {
     /// # Ok::<(), Box<dyn std::error::Error>>(())
     /// ```
     pub fn open(path: &str) -> Result<Self> {
-        let doc = MuPdfDocument::open(path)
-            .map_err(|e| VoxPDFError::InvalidPDF(format!("Failed to open PDF: {}", e)))?;
+        Self::from_source(DocumentSource::Path(path.to_string()))
+    }
+
+    /// Open a PDF from an in-memory buffer
+    ///
+    /// The buffer is kept alive for the lifetime of the document and shared
+    /// with any copies re-opened for parallel or streaming extraction.
+    ///
+    /// # Example
+    /// ```no_run
+    /// use voxpdf_core::PDFDocument;
+    ///
+    /// let bytes = std::fs::read("example.pdf")?;
+    /// let doc = PDFDocument::from_bytes(bytes)?;
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn from_bytes(bytes: impl Into<Arc<[u8]>>) -> Result<Self> {
+        Self::from_source(DocumentSource::Bytes(bytes.into()))
+    }
+
+    /// Open a PDF by reading an arbitrary reader to the end
+    ///
+    /// # Example
+    /// ```no_run
+    /// use voxpdf_core::PDFDocument;
+    ///
+    /// let file = std::fs::File::open("example.pdf")?;
+    /// let doc = PDFDocument::from_reader(file)?;
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn from_reader<R: Read>(mut reader: R) -> Result<Self> {
+        let mut bytes = Vec::new();
+        reader.read_to_end(&mut bytes)?;
+        Self::from_bytes(bytes)
+    }
+
+    /// Open a PDF from a [`DocumentSource`]
+    pub fn from_source(source: DocumentSource) -> Result<Self> {
+        let doc = match &source {
+            DocumentSource::Path(path) => MuPdfDocument::open(path.as_str()),
+            DocumentSource::Bytes(bytes) => MuPdfDocument::from_bytes(bytes),
+        }
+        .map_err(|e| VoxPDFError::InvalidPDF(format!("Failed to open PDF: {}", e)))?;
+
+        let locked = doc.needs_password().unwrap_or(false);
 
         Ok(PDFDocument {
             doc,
-            path: path.to_string(),
+            source,
+            password: None,
+            locked,
+            options: ExtractionOptions::default(),
+            running_text: OnceCell::new(),
+            page_labels: OnceCell::new(),
+            paragraphs: OnceCell::new(),
+            toc: OnceCell::new(),
+            language: OnceCell::new(),
+            sentence_splitter: OnceCell::new(),
+            normalizer: OnceCell::new(),
+            language_detector: OnceCell::new(),
+            struct_languages: OnceCell::new(),
+        })
+    }
+
+    /// Open a PDF from a [`DocumentSource`] and unlock it with `password`
+    ///
+    /// Returns [`VoxPDFError::PasswordRequired`] if the password is rejected.
+    /// Unencrypted documents ignore the password.
+    pub fn from_source_with_password(source: DocumentSource, password: &str) -> Result<Self> {
+        let mut doc = Self::from_source(source)?;
+        if doc.needs_password() && !doc.authenticate(password)? {
+            return Err(VoxPDFError::PasswordRequired);
+        }
+        Ok(doc)
+    }
+
+    /// Returns a closure that opens an independent, already-unlocked copy of
+    /// this document with the same extraction options
+    ///
+    /// MuPDF handles are `!Send`, so worker threads use this to open their own.
+    pub(crate) fn reopener(
+        &self,
+    ) -> impl Fn() -> Result<PDFDocument> + Send + Sync + Clone + 'static {
+        let source = self.source.clone();
+        let password = self.password.clone();
+        let options = self.options.clone();
+        move || {
+            let mut doc = match &password {
+                Some(password) => PDFDocument::from_source_with_password(source.clone(), password)?,
+                None => PDFDocument::from_source(source.clone())?,
+            };
+            doc.options = options.clone();
+            Ok(doc)
+        }
+    }
+
+    /// Options used by extraction functions that aren't given explicit ones
+    pub fn extraction_options(&self) -> &ExtractionOptions {
+        &self.options
+    }
+
+    /// Set the options used for this document's word and paragraph extraction
+    ///
+    /// Applies to [`extract_word_positions`](crate::extraction::extract_word_positions),
+    /// parallel and streaming extraction of this document, and the FFI.
+    pub fn set_extraction_options(&mut self, options: ExtractionOptions) {
+        if options != self.options {
+            self.running_text = OnceCell::new();
+        }
+        self.paragraphs = OnceCell::new();
+        self.toc = OnceCell::new();
+        self.options = options;
+    }
+
+    /// Running headers, footers and page numbers of this document
+    ///
+    /// Learned with [`RunningText::detect`] on first use and cached, so
+    /// per-page extraction (including the FFI) only pays for it once.
+    pub fn running_text(&self) -> Result<&RunningText> {
+        self.running_text
+            .get_or_try_init(|| RunningText::detect(self, &self.options))
+    }
+
+    /// The whole document's paragraphs, stitched across page breaks
+    ///
+    /// Extracted with [`extract_document_paragraphs`] on first use and cached.
+    /// TOC entries' `paragraph_index` points into this sequence.
+    pub fn document_paragraphs(&self) -> Result<&[Paragraph]> {
+        self.paragraphs
+            .get_or_try_init(|| extract_document_paragraphs(self, (0..self.page_count()).collect()))
+            .map(Vec::as_slice)
+    }
+
+    /// The document outline as a tree over [`document_paragraphs`](Self::document_paragraphs)
+    ///
+    /// Built with [`extract_toc_tree`] on first use and cached.
+    pub fn toc_tree(&self) -> Result<&TocTree> {
+        self.toc.get_or_try_init(|| extract_toc_tree(self))
+    }
+
+    /// The document's language: catalog `/Lang`, falling back to XMP
+    /// `dc:language`
+    ///
+    /// Read from [`metadata`](Self::metadata) on first use and cached.
+    pub fn language(&self) -> Result<Option<&str>> {
+        self.language
+            .get_or_try_init(|| Ok(self.metadata()?.display_language().map(str::to_string)))
+            .map(Option::as_deref)
+    }
+
+    /// Sentence rules for the document's language
+    ///
+    /// Built with [`SentenceSplitter::for_document`] on first use and cached.
+    pub fn sentence_splitter(&self) -> Result<&SentenceSplitter> {
+        self.sentence_splitter
+            .get_or_try_init(|| SentenceSplitter::for_document(self))
+    }
+
+    /// Speech normalization pipeline for the document's language
+    ///
+    /// Built with [`Normalizer::for_document`] on first use and cached.
+    pub fn normalizer(&self) -> Result<&Normalizer> {
+        self.normalizer
+            .get_or_try_init(|| Normalizer::for_document(self))
+    }
+
+    /// Language identification seeded with the document's language
+    ///
+    /// Built with [`LanguageDetector::for_document`] on first use and cached.
+    pub fn language_detector(&self) -> Result<&LanguageDetector> {
+        self.language_detector
+            .get_or_try_init(|| LanguageDetector::for_document(self))
+    }
+
+    /// Returns true if the document is encrypted and has not been unlocked yet
+    ///
+    /// Text, words and TOC extraction fail with [`VoxPDFError::PasswordRequired`]
+    /// until [`authenticate`](Self::authenticate) succeeds.
+    ///
+    /// # Example
+    /// ```no_run
+    /// use voxpdf_core::PDFDocument;
+    ///
+    /// let mut doc = PDFDocument::open("protected.pdf")?;
+    /// if doc.needs_password() && !doc.authenticate("secret")? {
+    ///     eprintln!("wrong password");
+    /// }
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn needs_password(&self) -> bool {
+        self.locked
+    }
+
+    /// Try to unlock an encrypted document with a user or owner password
+    ///
+    /// Returns `Ok(true)` if the document is now readable and `Ok(false)` if the
+    /// password was rejected. Always succeeds for unencrypted documents.
+    pub fn authenticate(&mut self, password: &str) -> Result<bool> {
+        if !self.locked {
+            return Ok(true);
+        }
+
+        let ok = self
+            .doc
+            .authenticate(password)
+            .map_err(|e| VoxPDFError::InvalidPDF(format!("Failed to authenticate: {}", e)))?;
+
+        if ok {
+            self.locked = false;
+            self.password = Some(password.to_string());
+        }
+
+        Ok(ok)
+    }
+
+    /// Fail with [`VoxPDFError::PasswordRequired`] while the document is locked
+    pub(crate) fn ensure_unlocked(&self) -> Result<()> {
+        if self.locked {
+            return Err(VoxPDFError::PasswordRequired);
+        }
+        Ok(())
+    }
+
+    /// Load a page, checking the page range and encryption state first
+    pub(crate) fn load_page(&self, page_num: u32) -> Result<mupdf::Page> {
+        self.ensure_unlocked()?;
+
+        let count = self.page_count();
+        if page_num >= count {
+            return Err(VoxPDFError::PageNotFound(page_num, count as usize));
+        }
+
+        self.doc.load_page(page_num as i32).map_err(|e| {
+            VoxPDFError::ExtractionError(format!("Failed to load page {}: {}", page_num, e))
         })
     }
 
+    /// Load the same source with lopdf for structure the MuPDF API doesn't expose
+    ///
+    /// Encrypted documents are decrypted with the password that unlocked this
+    /// document (or the empty user password). lopdf only handles the user
+    /// password under RC4 (`/V` 1–2), so owner-password and AES documents are
+    /// loaded from a decrypted copy written by MuPDF instead.
+    #[cfg(feature = "toc-fallback")]
+    pub(crate) fn load_lopdf(&self) -> Result<lopdf::Document> {
+        self.ensure_unlocked()?;
+
+        let mut doc = match &self.source {
+            DocumentSource::Path(path) => lopdf::Document::load(path)?,
+            DocumentSource::Bytes(bytes) => lopdf::Document::load_mem(bytes)?,
+        };
+
+        if doc.is_encrypted() && doc.decrypt(self.password.as_deref().unwrap_or("")).is_err() {
+            doc = lopdf::Document::load_mem(&self.decrypted_bytes()?)?;
+        }
+
+        Ok(doc)
+    }
+
+    /// Serialize the unlocked document without encryption
+    #[cfg(feature = "toc-fallback")]
+    fn decrypted_bytes(&self) -> Result<Vec<u8>> {
+        let mut options = PdfWriteOptions::default();
+        options.set_encryption(Encryption::None);
+
+        let mut bytes = Vec::new();
+        self.doc
+            .write_to_with_options(&mut bytes, options)
+            .map_err(|e| VoxPDFError::InvalidPDF(format!("Unsupported encryption: {}", e)))?;
+        Ok(bytes)
+    }
+
     /// Get the number of pages in the document
     ///
     /// # Example
@@ -41,9 +389,17 @@ impl PDFDocument {
         self.doc.page_count().unwrap_or(0) as u32
     }
 
-    /// Get the file path of this document
-    pub fn path(&self) -> &str {
-        &self.path
+    /// Get the file path of this document, if it was opened from disk
+    pub fn path(&self) -> Option<&str> {
+        match &self.source {
+            DocumentSource::Path(path) => Some(path),
+            DocumentSource::Bytes(_) => None,
+        }
+    }
+
+    /// Get the source this document was opened from
+    pub fn source(&self) -> &DocumentSource {
+        &self.source
     }
 
     /// Try to load outlines and return detailed error info for debugging
@@ -62,4 +418,58 @@ mod tests {
         let doc = PDFDocument::open("tests/fixtures/simple.pdf").unwrap();
         assert_eq!(doc.page_count(), 1);
     }
+
+    #[test]
+    fn test_from_bytes_has_no_path() {
+        let bytes = std::fs::read("tests/fixtures/simple.pdf").unwrap();
+        let doc = PDFDocument::from_bytes(bytes).unwrap();
+        assert_eq!(doc.page_count(), 1);
+        assert!(doc.path().is_none());
+        assert!(matches!(doc.source(), DocumentSource::Bytes(_)));
+    }
+
+    #[test]
+    fn test_unencrypted_document_is_unlocked() {
+        let mut doc = PDFDocument::open("tests/fixtures/simple.pdf").unwrap();
+        assert!(!doc.needs_password());
+        assert!(doc.authenticate("anything").unwrap());
+    }
+
+    #[cfg(feature = "toc-fallback")]
+    #[test]
+    fn test_load_lopdf_decrypts_with_either_password() {
+        for password in ["secret", "owner"] {
+            let mut doc = PDFDocument::open("tests/fixtures/encrypted.pdf").unwrap();
+            assert!(doc.authenticate(password).unwrap());
+
+            let lopdf = doc.load_lopdf().unwrap();
+            let (_, page_id) = lopdf.get_pages().into_iter().next().unwrap();
+            let content = lopdf.get_page_content(page_id).unwrap();
+            assert!(String::from_utf8_lossy(&content).contains("Hello"));
+        }
+    }
+
+    #[test]
+    fn test_option_changes_reset_running_text() {
+        let mut doc = PDFDocument::open("tests/fixtures/simple.pdf").unwrap();
+        doc.running_text().unwrap();
+
+        doc.set_extraction_options(ExtractionOptions::default());
+        assert!(doc.running_text.get().is_some());
+
+        doc.set_extraction_options(ExtractionOptions {
+            line_tolerance_ratio: 0.6,
+            ..Default::default()
+        });
+        assert!(doc.running_text.get().is_none());
+    }
+
+    #[test]
+    fn test_load_page_out_of_range() {
+        let doc = PDFDocument::open("tests/fixtures/simple.pdf").unwrap();
+        assert!(matches!(
+            doc.load_page(5),
+            Err(VoxPDFError::PageNotFound(5, 1))
+        ));
+    }
 }
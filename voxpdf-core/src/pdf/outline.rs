@@ -0,0 +1,352 @@
+//! Outline reading with lopdf, for documents whose outline MuPDF rejects.
+//!
+//! Files that need this fallback are broken by definition, so nothing here
+//! trusts the structure: the item tree is walked iteratively with a visited
+//! set and a depth cap, name trees are bounded, and unreadable items are
+//! skipped rather than failing the whole outline.
+
+use super::text_string::decode_text_string;
+use super::PDFDocument;
+use crate::error::Result;
+use crate::extraction::toc::{MAX_OUTLINE_DEPTH, MAX_OUTLINE_ITEMS};
+use crate::models::TocEntry;
+use lopdf::{Dictionary, Document, Object, ObjectId};
+use std::collections::{HashMap, HashSet};
+
+/// Name trees in real files are shallow; this guards against cycles.
+const MAX_TREE_DEPTH: usize = 32;
+
+/// A named destination may name another; follow at most this many
+const MAX_NAME_HOPS: usize = 4;
+
+impl PDFDocument {
+    /// Read the outline with lopdf, which is more lenient than MuPDF
+    ///
+    /// Entries have `paragraph_index` 0, like
+    /// [`extract_outline`](crate::extraction::extract_outline) returns them.
+    pub(crate) fn lopdf_outline(&self) -> Result<Vec<TocEntry>> {
+        let doc = self.load_lopdf()?;
+        Ok(read_outline(&doc)
+            .into_iter()
+            .map(|item| item.into_entry(self))
+            .collect())
+    }
+}
+
+/// An outline item as stored in the file
+#[derive(Debug, PartialEq)]
+struct OutlineItem {
+    title: String,
+    level: u8,
+    dest: Option<ExplicitDest>,
+    is_open: bool,
+}
+
+impl OutlineItem {
+    fn into_entry(self, pdf: &PDFDocument) -> TocEntry {
+        let page = self.dest.as_ref().map_or(0, |d| d.page);
+        let mut entry = TocEntry::new(self.title, self.level, page, 0);
+        entry.dest_top = self.dest.and_then(|d| d.display_top(pdf));
+        entry.is_open = self.is_open;
+        entry
+    }
+}
+
+/// An explicit destination: a page and, for `/XYZ`, `/FitH`, `/FitBH` and
+/// `/FitR`, the user-space point the view scrolls to
+#[derive(Debug, PartialEq)]
+struct ExplicitDest {
+    page: u32,
+    left: Option<f32>,
+    top: Option<f32>,
+}
+
+impl ExplicitDest {
+    /// `top` in display coordinates, like MuPDF reports outline positions
+    fn display_top(&self, pdf: &PDFDocument) -> Option<f32> {
+        let top = self.top?;
+        let info = pdf.page_info(self.page).ok()?;
+        let left = self.left.unwrap_or(info.crop_box.x);
+        let (_, y) = info.user_space_to_top_left(left, top);
+        Some(y.max(0.0))
+    }
+}
+
+/// Outline items in pre-order
+///
+/// A `/First` or `/Next` link back to an item already read ends that chain;
+/// items nested deeper than `MAX_OUTLINE_DEPTH` are left out.
+fn read_outline(doc: &Document) -> Vec<OutlineItem> {
+    let first = doc
+        .catalog()
+        .ok()
+        .and_then(|catalog| dict(doc, catalog.get(b"Outlines").ok()?))
+        .and_then(|outlines| outlines.get(b"First").ok()?.as_reference().ok());
+    let Some(first) = first else {
+        return Vec::new();
+    };
+
+    let destinations = Destinations::new(doc);
+    let mut items = Vec::new();
+    let mut visited = HashSet::new();
+    // Popped in pre-order: an item's children before its next sibling
+    let mut stack = vec![(first, 0)];
+
+    while let Some((id, depth)) = stack.pop() {
+        if items.len() >= MAX_OUTLINE_ITEMS {
+            break;
+        }
+        if !visited.insert(id) {
+            continue;
+        }
+        let Ok(item) = doc.get_dictionary(id) else {
+            continue;
+        };
+
+        if let Ok(next) = item.get(b"Next").and_then(Object::as_reference) {
+            stack.push((next, depth));
+        }
+        if depth < MAX_OUTLINE_DEPTH {
+            if let Ok(first) = item.get(b"First").and_then(Object::as_reference) {
+                stack.push((first, depth + 1));
+            }
+        }
+
+        // Items without a title still hold their children
+        let title = item
+            .get(b"Title")
+            .ok()
+            .and_then(|title| doc.dereference(title).ok())
+            .and_then(|(_, title)| title.as_str().ok());
+        let Some(title) = title else {
+            continue;
+        };
+
+        let is_open = item
+            .get(b"Count")
+            .ok()
+            .and_then(|count| doc.dereference(count).ok())
+            .and_then(|(_, count)| count.as_i64().ok())
+            .is_some_and(|count| count > 0);
+
+        items.push(OutlineItem {
+            title: decode_text_string(title).trim().to_string(),
+            level: depth as u8,
+            dest: destinations.for_item(item),
+            is_open,
+        });
+    }
+
+    items
+}
+
+/// Resolves outline destinations to pages
+struct Destinations<'a> {
+    doc: &'a Document,
+    /// Page object to 0-indexed page number
+    pages: HashMap<ObjectId, u32>,
+    /// PDF 1.1 catalog `/Dests` dictionary, keyed by name
+    dests: Option<&'a Dictionary>,
+    /// PDF 1.2 `/Names /Dests` name tree, keyed by string
+    names: HashMap<&'a [u8], &'a Object>,
+}
+
+impl<'a> Destinations<'a> {
+    fn new(doc: &'a Document) -> Self {
+        let pages = doc
+            .get_pages()
+            .into_iter()
+            .map(|(number, id)| (id, number.saturating_sub(1)))
+            .collect();
+
+        let catalog = doc.catalog().ok();
+        let dests = catalog.and_then(|catalog| dict(doc, catalog.get(b"Dests").ok()?));
+
+        let mut names = HashMap::new();
+        let tree = catalog
+            .and_then(|catalog| dict(doc, catalog.get(b"Names").ok()?))
+            .and_then(|names| dict(doc, names.get(b"Dests").ok()?));
+        if let Some(tree) = tree {
+            collect_names(doc, tree, 0, &mut HashSet::new(), &mut names);
+        }
+
+        Self {
+            doc,
+            pages,
+            dests,
+            names,
+        }
+    }
+
+    /// The item's `/Dest`, or the destination of its `/GoTo` action
+    fn for_item(&self, item: &Dictionary) -> Option<ExplicitDest> {
+        if let Ok(dest) = item.get(b"Dest") {
+            return self.resolve(dest, 0);
+        }
+
+        let action = dict(self.doc, item.get(b"A").ok()?)?;
+        if action.get(b"S").and_then(Object::as_name).ok()? != b"GoTo" {
+            return None;
+        }
+        self.resolve(action.get(b"D").ok()?, 0)
+    }
+
+    /// An explicit destination array, a name or string to look up, or a
+    /// dictionary holding the destination in `/D`
+    fn resolve(&self, dest: &Object, hops: usize) -> Option<ExplicitDest> {
+        if hops > MAX_NAME_HOPS {
+            return None;
+        }
+
+        let (_, dest) = self.doc.dereference(dest).ok()?;
+        let named = match dest {
+            Object::Array(array) => return self.explicit(array),
+            Object::Dictionary(dict) => dict.get(b"D").ok()?,
+            Object::Name(name) => self.lookup(name)?,
+            Object::String(name, _) => self.lookup(name)?,
+            _ => return None,
+        };
+        self.resolve(named, hops + 1)
+    }
+
+    /// Names and strings share one namespace in practice, so either kind
+    /// of key is looked up in both places
+    fn lookup(&self, name: &[u8]) -> Option<&'a Object> {
+        self.names
+            .get(name)
+            .copied()
+            .or_else(|| self.dests?.get(name).ok())
+    }
+
+    fn explicit(&self, dest: &[Object]) -> Option<ExplicitDest> {
+        let page = match dest.first()? {
+            Object::Reference(id) => *self.pages.get(id)?,
+            // Remote-style page number, which some producers write locally
+            Object::Integer(page) => u32::try_from(*page)
+                .ok()
+                .filter(|&page| (page as usize) < self.pages.len())?,
+            _ => return None,
+        };
+
+        // Null operands mean "unchanged" and fail `as_float`
+        let number = |i: usize| dest.get(i).and_then(|o| o.as_float().ok());
+        let (left, top) = match dest.get(1).and_then(|o| o.as_name().ok()) {
+            Some(b"XYZ") => (number(2), number(3)),
+            Some(b"FitH") | Some(b"FitBH") => (None, number(2)),
+            Some(b"FitR") => (number(2), number(5)),
+            _ => (None, None),
+        };
+
+        Some(ExplicitDest { page, left, top })
+    }
+}
+
+/// Walk a name tree node: `/Names [key value ...]` leaves and `/Kids`.
+fn collect_names<'a>(
+    doc: &'a Document,
+    node: &'a Dictionary,
+    depth: usize,
+    visited: &mut HashSet<ObjectId>,
+    names: &mut HashMap<&'a [u8], &'a Object>,
+) {
+    if depth > MAX_TREE_DEPTH {
+        return;
+    }
+
+    if let Some(pairs) = array(doc, node.get(b"Names").ok()) {
+        for pair in pairs.chunks_exact(2) {
+            if let Ok(key) = pair[0].as_str() {
+                // The first definition of a name wins
+                names.entry(key).or_insert(&pair[1]);
+            }
+        }
+    }
+
+    for kid in array(doc, node.get(b"Kids").ok()).unwrap_or_default() {
+        let Ok(id) = kid.as_reference() else {
+            continue;
+        };
+        if !visited.insert(id) {
+            continue;
+        }
+        if let Ok(kid) = doc.get_dictionary(id) {
+            collect_names(doc, kid, depth + 1, visited, names);
+        }
+    }
+}
+
+/// A dictionary, following a reference if there is one
+fn dict<'a>(doc: &'a Document, object: &'a Object) -> Option<&'a Dictionary> {
+    doc.dereference(object).ok()?.1.as_dict().ok()
+}
+
+fn array<'a>(doc: &'a Document, object: Option<&'a Object>) -> Option<&'a [Object]> {
+    doc.dereference(object?)
+        .ok()?
+        .1
+        .as_array()
+        .ok()
+        .map(Vec::as_slice)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn outline(path: &str) -> Vec<OutlineItem> {
+        let doc = Document::load(path).unwrap();
+        read_outline(&doc)
+    }
+
+    fn summary(items: &[OutlineItem]) -> Vec<(&str, u8, Option<u32>)> {
+        items
+            .iter()
+            .map(|item| {
+                (
+                    item.title.as_str(),
+                    item.level,
+                    item.dest.as_ref().map(|d| d.page),
+                )
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_fallback_fixture() {
+        let items = outline("tests/fixtures/outline-fallback.pdf");
+        assert_eq!(
+            summary(&items),
+            [
+                ("Введение", 0, Some(0)),
+                ("Café — the “first” steps", 1, Some(0)),
+                ("Named by /Dests", 0, Some(1)),
+                ("Named by the /Names tree", 1, Some(2)),
+                ("GoTo action", 0, Some(2)),
+                ("Named GoTo action", 0, Some(3)),
+                ("Missing destination", 0, None),
+            ]
+        );
+
+        assert_eq!(
+            items[2].dest,
+            Some(ExplicitDest {
+                page: 1,
+                left: Some(72.0),
+                top: Some(700.0),
+            })
+        );
+        assert_eq!(items[4].dest.as_ref().unwrap().top, Some(500.0));
+        assert!(items[0].is_open);
+        assert!(!items[2].is_open);
+    }
+
+    #[test]
+    fn test_depth_cap() {
+        // One item per level, 100 levels deep
+        let items = outline("tests/fixtures/outline-deep.pdf");
+        assert_eq!(items.len(), MAX_OUTLINE_DEPTH + 1);
+        assert_eq!(items[0].title, "Level 0");
+        assert_eq!(items[MAX_OUTLINE_DEPTH].level as usize, MAX_OUTLINE_DEPTH);
+        assert!(items.iter().all(|item| item.dest.is_some()));
+    }
+}
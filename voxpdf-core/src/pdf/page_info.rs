@@ -0,0 +1,133 @@
+//! Page geometry from the page dictionary (boxes, `/Rotate`, `/UserUnit`).
+
+use super::PDFDocument;
+use crate::error::{Result, VoxPDFError};
+use crate::models::{PageInfo, Rect};
+use mupdf::pdf::PdfObject;
+
+/// US Letter, MuPDF's fallback when a page has no usable `/MediaBox`
+const DEFAULT_MEDIA_BOX: [f32; 4] = [0.0, 0.0, 612.0, 792.0];
+
+impl PDFDocument {
+    /// Read a page's geometry.
+    ///
+    /// `/MediaBox`, `/CropBox` and `/Rotate` are inherited from the page tree
+    /// the same way MuPDF resolves them, so the result describes the space
+    /// word bounds are reported in.
+    ///
+    /// # Example
+    /// ```no_run
+    /// use voxpdf_core::{CoordinateSpace, PDFDocument};
+    /// use voxpdf_core::extraction::extract_word_positions;
+    ///
+    /// let doc = PDFDocument::open("example.pdf")?;
+    /// let info = doc.page_info(0)?;
+    /// for word in extract_word_positions(&doc, 0)? {
+    ///     let native = info.transform_rect(&word.bounds, CoordinateSpace::BottomLeft);
+    ///     println!("{} at ({}, {})", word.text, native.x, native.y);
+    /// }
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn page_info(&self, page_num: u32) -> Result<PageInfo> {
+        self.ensure_unlocked()?;
+
+        let count = self.page_count();
+        if page_num >= count {
+            return Err(VoxPDFError::PageNotFound(page_num, count as usize));
+        }
+
+        let page = self.doc.find_page(page_num as i32).map_err(|e| {
+            VoxPDFError::ExtractionError(format!("Failed to find page {}: {}", page_num, e))
+        })?;
+
+        let media = inherited_box(&page, "MediaBox")
+            .filter(|b| b[2] > b[0] && b[3] > b[1])
+            .unwrap_or(DEFAULT_MEDIA_BOX);
+
+        let crop = inherited_box(&page, "CropBox")
+            .map(|b| intersect(b, media))
+            .filter(|b| b[2] > b[0] && b[3] > b[1])
+            .unwrap_or(media);
+
+        let rotation = page
+            .get_dict_inheritable("Rotate")
+            .ok()
+            .flatten()
+            .and_then(|r| r.as_int().ok())
+            .map(normalize_rotation)
+            .unwrap_or(0);
+
+        let user_unit = page
+            .get_dict("UserUnit")
+            .ok()
+            .flatten()
+            .and_then(|u| u.as_float().ok())
+            .filter(|u| *u > 0.0)
+            .unwrap_or(1.0);
+
+        Ok(PageInfo {
+            index: page_num,
+            media_box: to_rect(media),
+            crop_box: to_rect(crop),
+            rotation,
+            user_unit,
+            label: self.cached_page_labels()?.label(page_num),
+        })
+    }
+}
+
+/// Read a `[x0 y0 x1 y1]` box, normalizing the corner order.
+fn inherited_box(page: &PdfObject, key: &str) -> Option<[f32; 4]> {
+    let array = page.get_dict_inheritable(key).ok()??;
+    if array.len().ok()? < 4 {
+        return None;
+    }
+
+    let mut values = [0.0; 4];
+    for (i, value) in values.iter_mut().enumerate() {
+        *value = array.get_array(i as i32).ok()??.as_float().ok()?;
+    }
+
+    let [a, b, c, d] = values;
+    Some([a.min(c), b.min(d), a.max(c), b.max(d)])
+}
+
+fn intersect(a: [f32; 4], b: [f32; 4]) -> [f32; 4] {
+    [
+        a[0].max(b[0]),
+        a[1].max(b[1]),
+        a[2].min(b[2]),
+        a[3].min(b[3]),
+    ]
+}
+
+fn to_rect(b: [f32; 4]) -> Rect {
+    Rect::new(b[0], b[1], b[2] - b[0], b[3] - b[1])
+}
+
+/// Clamp `/Rotate` to 0, 90, 180 or 270 (MuPDF rounds to the nearest
+/// quarter turn, and accepts negative values).
+fn normalize_rotation(degrees: i32) -> u16 {
+    let quarter = ((degrees as f32) / 90.0).round() as i32;
+    (quarter.rem_euclid(4) * 90) as u16
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_normalize_rotation() {
+        assert_eq!(normalize_rotation(0), 0);
+        assert_eq!(normalize_rotation(90), 90);
+        assert_eq!(normalize_rotation(-90), 270);
+        assert_eq!(normalize_rotation(450), 90);
+        assert_eq!(normalize_rotation(180), 180);
+    }
+
+    #[test]
+    fn test_intersect_crop_with_media() {
+        let crop = intersect([-10.0, 50.0, 700.0, 700.0], DEFAULT_MEDIA_BOX);
+        assert_eq!(crop, [0.0, 50.0, 612.0, 700.0]);
+    }
+}
@@ -0,0 +1,154 @@
+//! Printed page labels from the catalog `/PageLabels` number tree.
+
+use super::text_string::read_text_string;
+use super::PDFDocument;
+use crate::error::{Result, VoxPDFError};
+use crate::models::{PageLabelRange, PageLabelStyle, PageLabels};
+use mupdf::pdf::PdfObject;
+use std::collections::HashSet;
+
+/// Number trees in real files are shallow; this guards against runaway
+/// nesting, while the visited set catches `/Kids` cycles.
+const MAX_TREE_DEPTH: usize = 32;
+
+impl PDFDocument {
+    /// Read the document's page labels.
+    ///
+    /// Returns empty labels (not an error) if the document has no
+    /// `/PageLabels`. Malformed entries are skipped.
+    ///
+    /// # Example
+    /// ```no_run
+    /// use voxpdf_core::PDFDocument;
+    ///
+    /// let doc = PDFDocument::open("book.pdf")?;
+    /// let labels = doc.page_labels()?;
+    /// if let Some(page) = labels.find("xii") {
+    ///     println!("Page xii is physical page {}", page);
+    /// }
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn page_labels(&self) -> Result<PageLabels> {
+        self.cached_page_labels().cloned()
+    }
+
+    /// Page labels, parsed once per document
+    pub(crate) fn cached_page_labels(&self) -> Result<&PageLabels> {
+        self.ensure_unlocked()?;
+
+        self.page_labels.get_or_try_init(|| {
+            let catalog = self
+                .doc
+                .catalog()
+                .map_err(|e| VoxPDFError::InvalidPDF(format!("Failed to read catalog: {}", e)))?;
+
+            let mut ranges = Vec::new();
+            if let Some(tree) = catalog.get_dict("PageLabels").ok().flatten() {
+                collect_ranges(&tree, 0, &mut HashSet::new(), &mut ranges);
+            }
+
+            Ok(PageLabels::new(ranges, self.page_count()))
+        })
+    }
+
+    /// Printed label of a physical page (0-indexed), if the document defines one
+    pub fn page_label(&self, page_num: u32) -> Result<Option<String>> {
+        let count = self.page_count();
+        if page_num >= count {
+            return Err(VoxPDFError::PageNotFound(page_num, count as usize));
+        }
+
+        Ok(self.cached_page_labels()?.label(page_num))
+    }
+
+    /// Physical page (0-indexed) for a printed label such as "xii" or "17"
+    ///
+    /// See [`PageLabels::find`] for the matching rules.
+    pub fn page_index_for_label(&self, label: &str) -> Result<Option<u32>> {
+        Ok(self.cached_page_labels()?.find(label))
+    }
+}
+
+/// Walk a number tree node: `/Nums [key value ...]` leaves and `/Kids`.
+fn collect_ranges(
+    node: &PdfObject,
+    depth: usize,
+    visited: &mut HashSet<i32>,
+    ranges: &mut Vec<PageLabelRange>,
+) {
+    if depth > MAX_TREE_DEPTH {
+        return;
+    }
+    let number = node.as_indirect().unwrap_or(0);
+    if number > 0 && !visited.insert(number) {
+        return;
+    }
+
+    if let Some(nums) = node.get_dict("Nums").ok().flatten() {
+        let len = nums.len().unwrap_or(0);
+        for i in (0..len.saturating_sub(1)).step_by(2) {
+            let start = nums
+                .get_array(i as i32)
+                .ok()
+                .flatten()
+                .and_then(|k| k.as_int().ok());
+            let dict = nums.get_array(i as i32 + 1).ok().flatten();
+
+            if let (Some(start), Some(dict)) = (start, dict) {
+                if start >= 0 {
+                    ranges.push(parse_label_dict(start as u32, &dict));
+                }
+            }
+        }
+    }
+
+    if let Some(kids) = node.get_dict("Kids").ok().flatten() {
+        for i in 0..kids.len().unwrap_or(0) {
+            if let Some(kid) = kids.get_array(i as i32).ok().flatten() {
+                collect_ranges(&kid, depth + 1, visited, ranges);
+            }
+        }
+    }
+}
+
+fn parse_label_dict(start_page: u32, dict: &PdfObject) -> PageLabelRange {
+    let style = dict
+        .get_dict("S")
+        .ok()
+        .flatten()
+        .and_then(|s| s.as_name().ok().map(parse_style))
+        .unwrap_or(PageLabelStyle::None);
+
+    let prefix = dict
+        .get_dict("P")
+        .ok()
+        .flatten()
+        .and_then(|p| read_text_string(&p))
+        .unwrap_or_default();
+
+    let first_number = dict
+        .get_dict("St")
+        .ok()
+        .flatten()
+        .and_then(|st| st.as_int().ok())
+        .filter(|st| *st >= 1)
+        .unwrap_or(1) as u32;
+
+    PageLabelRange {
+        start_page,
+        style,
+        prefix,
+        first_number,
+    }
+}
+
+fn parse_style(name: &[u8]) -> PageLabelStyle {
+    match name {
+        b"D" => PageLabelStyle::Decimal,
+        b"R" => PageLabelStyle::UpperRoman,
+        b"r" => PageLabelStyle::LowerRoman,
+        b"A" => PageLabelStyle::UpperLetters,
+        b"a" => PageLabelStyle::LowerLetters,
+        _ => PageLabelStyle::None,
+    }
+}
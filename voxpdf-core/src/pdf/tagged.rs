@@ -0,0 +1,1110 @@
+//! Language tags from tagged PDF: `/Lang` on marked content and on
+//! structure elements.
+//!
+//! MuPDF's text extraction doesn't say which marked-content sequence a
+//! character was drawn in, so each page's content stream is scanned for
+//! where text is drawn and under which language, and words are matched to
+//! those positions afterwards. Positions along a line are advanced by the
+//! width of the text shown, from the fonts' `/Widths` (or `/W` for CID
+//! fonts), so a language that changes mid-line is placed where it starts.
+//!
+//! Documents whose catalog doesn't declare them tagged (`/MarkInfo` with
+//! `/Marked true`, or a `/StructTreeRoot`) aren't scanned at all.
+//!
+//! Only the page's own content streams are scanned: text drawn by a Form
+//! XObject (`Do`) gets no mark, so its words take their language from an
+//! earlier mark on the same line or, failing that, from detection.
+
+use super::text_string::{decode_text_string, read_text_string};
+use super::PDFDocument;
+use crate::error::Result;
+use crate::models::Word;
+use mupdf::pdf::PdfObject;
+use std::collections::{HashMap, HashSet};
+
+/// Deepest structure element nesting walked
+const MAX_STRUCT_DEPTH: usize = 64;
+
+/// How far a word may start left of a mark and still be drawn by it, as a
+/// fraction of the font size
+const MARK_X_TOLERANCE: f32 = 0.5;
+
+/// How far a mark's baseline may be from a word's, as a fraction of the
+/// font size
+const MARK_Y_TOLERANCE: f32 = 0.3;
+
+/// Glyph width assumed for fonts without widths, in thousandths of an em
+const AVERAGE_GLYPH_WIDTH: f32 = 500.0;
+
+/// Glyph width of Courier, the one monospaced standard font
+const COURIER_GLYPH_WIDTH: f32 = 600.0;
+
+/// Where a text-showing operator starts drawing, and the language it draws
+/// in.
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) struct LanguageMark {
+    /// BCP 47 tag, or `None` for text outside any `/Lang`
+    pub language: Option<String>,
+    pub page_number: u32,
+    /// Where the operator starts drawing, in top-left display space
+    pub x: f32,
+    pub y: f32,
+    /// Order of the operator in the page's content stream
+    pub sequence: usize,
+}
+
+impl LanguageMark {
+    /// The mark `word` was drawn by: the rightmost one on its baseline that
+    /// starts at or before it, or the later drawn of marks at the same place
+    pub(crate) fn for_word<'a>(marks: &'a [LanguageMark], word: &Word) -> Option<&'a LanguageMark> {
+        let size = word.font_size.max(1.0);
+        let (x, baseline) = match word.origin {
+            Some(origin) => (origin.x, origin.y),
+            None => (word.bounds.x, word.bounds.y + word.bounds.height * 0.8),
+        };
+        marks
+            .iter()
+            .filter(|m| {
+                m.page_number == word.page_number
+                    && (m.y - baseline).abs() <= MARK_Y_TOLERANCE * size
+                    && m.x <= x + MARK_X_TOLERANCE * size
+            })
+            .max_by(|a, b| a.x.total_cmp(&b.x).then(a.sequence.cmp(&b.sequence)))
+    }
+}
+
+/// Glyph widths of a page font, in thousandths of an em
+#[derive(Debug, Clone, PartialEq)]
+struct FontWidths {
+    /// Bytes per character code: 1 for simple fonts, 2 for CID fonts
+    code_len: usize,
+    /// `(first code, last code, width)`, sorted by first code
+    ranges: Vec<(u32, u32, f32)>,
+    /// Width of codes outside `ranges`
+    default: f32,
+}
+
+impl Default for FontWidths {
+    fn default() -> Self {
+        Self {
+            code_len: 1,
+            ranges: Vec::new(),
+            default: AVERAGE_GLYPH_WIDTH,
+        }
+    }
+}
+
+impl FontWidths {
+    fn width(&self, code: u32) -> f32 {
+        let after = self.ranges.partition_point(|&(first, _, _)| first <= code);
+        match after.checked_sub(1).map(|i| self.ranges[i]) {
+            Some((_, last, width)) if code <= last => width,
+            _ => self.default,
+        }
+    }
+}
+
+/// A named entry of a page's `/Properties` resources
+#[derive(Debug, Clone, Default, PartialEq)]
+struct MarkedContentProperties {
+    language: Option<String>,
+    mcid: Option<i32>,
+}
+
+impl PDFDocument {
+    /// Where text is drawn on `pages`, and in which language, for pages with
+    /// any `/Lang` in their marked content or structure elements.
+    ///
+    /// Untagged documents, and pages without language tags, contribute no
+    /// marks. Unreadable objects are skipped rather than failing extraction.
+    pub(crate) fn language_marks(&self, pages: &[u32]) -> Result<Vec<LanguageMark>> {
+        self.ensure_unlocked()?;
+
+        let struct_languages = self.struct_languages.get_or_init(|| {
+            let catalog = self.doc.catalog().ok()?;
+            is_tagged(&catalog).then(|| struct_tree_languages(&catalog))
+        });
+        let Some(struct_languages) = struct_languages else {
+            return Ok(Vec::new());
+        };
+
+        let mut marks = Vec::new();
+        for &page_number in pages {
+            let Ok(page) = self.doc.find_page(page_number as i32) else {
+                continue;
+            };
+            let page_object = page.as_indirect().unwrap_or(0);
+            let mcids: HashMap<i32, String> = struct_languages
+                .iter()
+                .filter(|((p, _), _)| *p == page_object)
+                .map(|(&(_, mcid), lang)| (mcid, lang.clone()))
+                .collect();
+
+            let properties = page_properties(&page);
+            let fonts = page_fonts(&page);
+            let content = page_content(&page);
+            let tagged = !mcids.is_empty()
+                || properties.values().any(|p| p.language.is_some())
+                || content.windows(5).any(|w| w == b"/Lang");
+            if !tagged {
+                continue;
+            }
+
+            let info = self.page_info(page_number)?;
+            let scanned = scan_content(&content, &properties, &fonts, &mcids);
+            for (sequence, (language, (x, y))) in scanned.into_iter().enumerate() {
+                let (x, y) = info.user_space_to_top_left(x, y);
+                marks.push(LanguageMark {
+                    language,
+                    page_number,
+                    x,
+                    y,
+                    sequence,
+                });
+            }
+        }
+        Ok(marks)
+    }
+}
+
+/// True if the catalog declares the document tagged: `/MarkInfo` with
+/// `/Marked true`, or a structure tree
+fn is_tagged(catalog: &PdfObject) -> bool {
+    let marked = catalog
+        .get_dict("MarkInfo")
+        .ok()
+        .flatten()
+        .and_then(|info| info.get_dict("Marked").ok().flatten())
+        .and_then(|marked| marked.as_bool().ok())
+        .unwrap_or(false);
+    marked || catalog.get_dict("StructTreeRoot").ok().flatten().is_some()
+}
+
+/// `/Lang` of the structure element each marked-content sequence belongs
+/// to, by (page object number, MCID)
+fn struct_tree_languages(catalog: &PdfObject) -> HashMap<(i32, i32), String> {
+    let mut languages = HashMap::new();
+    if let Some(root) = catalog.get_dict("StructTreeRoot").ok().flatten() {
+        let mut visited = HashSet::new();
+        walk_struct_element(&root, None, None, 0, &mut visited, &mut languages);
+    }
+    languages
+}
+
+fn walk_struct_element(
+    node: &PdfObject,
+    language: Option<&str>,
+    page: Option<i32>,
+    depth: usize,
+    visited: &mut HashSet<i32>,
+    languages: &mut HashMap<(i32, i32), String>,
+) {
+    if depth > MAX_STRUCT_DEPTH {
+        return;
+    }
+    let number = node.as_indirect().unwrap_or(0);
+    if number > 0 && !visited.insert(number) {
+        return;
+    }
+
+    if node.is_int().unwrap_or(false) {
+        if let (Some(language), Some(page), Ok(mcid)) = (language, page, node.as_int()) {
+            languages.insert((page, mcid), language.to_string());
+        }
+        return;
+    }
+    if node.is_array().unwrap_or(false) {
+        for i in 0..node.len().unwrap_or(0) {
+            if let Some(child) = node.get_array(i as i32).ok().flatten() {
+                walk_struct_element(&child, language, page, depth + 1, visited, languages);
+            }
+        }
+        return;
+    }
+    if !node.is_dict().unwrap_or(false) {
+        return;
+    }
+
+    let page = node
+        .get_dict("Pg")
+        .ok()
+        .flatten()
+        .and_then(|pg| pg.as_indirect().ok())
+        .filter(|&n| n > 0)
+        .or(page);
+    let own = node
+        .get_dict("Lang")
+        .ok()
+        .flatten()
+        .and_then(|l| text_string(&l));
+    let language = own.as_deref().or(language);
+
+    match node
+        .get_dict("Type")
+        .ok()
+        .flatten()
+        .and_then(|t| name(&t))
+        .as_deref()
+    {
+        Some("MCR") => {
+            let mcid = node
+                .get_dict("MCID")
+                .ok()
+                .flatten()
+                .and_then(|m| m.as_int().ok());
+            if let (Some(language), Some(page), Some(mcid)) = (language, page, mcid) {
+                languages.insert((page, mcid), language.to_string());
+            }
+        }
+        Some("OBJR") => {}
+        _ => {
+            if let Some(kids) = node.get_dict("K").ok().flatten() {
+                walk_struct_element(&kids, language, page, depth + 1, visited, languages);
+            }
+        }
+    }
+}
+
+/// The page's `/Properties` resources that carry a `/Lang` or `/MCID`
+fn page_properties(page: &PdfObject) -> HashMap<Vec<u8>, MarkedContentProperties> {
+    let mut properties = HashMap::new();
+    let Some(dict) = page
+        .get_dict_inheritable("Resources")
+        .ok()
+        .flatten()
+        .and_then(|r| r.get_dict("Properties").ok().flatten())
+    else {
+        return properties;
+    };
+
+    for i in 0..dict.dict_len().unwrap_or(0) as i32 {
+        let key = dict.get_dict_key(i).ok().flatten();
+        let value = dict.get_dict_val(i).ok().flatten();
+        let (Some(key), Some(value)) = (key, value) else {
+            continue;
+        };
+        let Ok(key) = key.as_name() else {
+            continue;
+        };
+        let entry = MarkedContentProperties {
+            language: value
+                .get_dict("Lang")
+                .ok()
+                .flatten()
+                .and_then(|l| text_string(&l)),
+            mcid: value
+                .get_dict("MCID")
+                .ok()
+                .flatten()
+                .and_then(|m| m.as_int().ok()),
+        };
+        if entry != MarkedContentProperties::default() {
+            properties.insert(key.to_vec(), entry);
+        }
+    }
+    properties
+}
+
+/// Widths of the page's `/Font` resources, by resource name
+fn page_fonts(page: &PdfObject) -> HashMap<Vec<u8>, FontWidths> {
+    let mut fonts = HashMap::new();
+    let Some(dict) = page
+        .get_dict_inheritable("Resources")
+        .ok()
+        .flatten()
+        .and_then(|r| r.get_dict("Font").ok().flatten())
+    else {
+        return fonts;
+    };
+
+    for i in 0..dict.dict_len().unwrap_or(0) as i32 {
+        let key = dict.get_dict_key(i).ok().flatten();
+        let value = dict.get_dict_val(i).ok().flatten();
+        let (Some(key), Some(value)) = (key, value) else {
+            continue;
+        };
+        if let Ok(key) = key.as_name() {
+            fonts.insert(key.to_vec(), font_widths(&value));
+        }
+    }
+    fonts
+}
+
+/// Widths from a simple font's `/Widths`, or a CID font's `/W` and `/DW`
+fn font_widths(font: &PdfObject) -> FontWidths {
+    let get = |dict: &PdfObject, key: &str| dict.get_dict(key).ok().flatten();
+
+    if get(font, "Subtype").and_then(|s| name(&s)).as_deref() == Some("Type0") {
+        let cid_font = get(font, "DescendantFonts").and_then(|d| d.get_array(0).ok().flatten());
+        let mut widths = FontWidths {
+            code_len: 2,
+            default: 1000.0,
+            ..Default::default()
+        };
+        if let Some(cid_font) = cid_font {
+            if let Some(dw) = get(&cid_font, "DW").and_then(|dw| number(&dw)) {
+                widths.default = dw;
+            }
+            if let Some(w) = get(&cid_font, "W") {
+                widths.ranges = cid_width_ranges(&w);
+            }
+        }
+        return widths;
+    }
+
+    let base_font = get(font, "BaseFont")
+        .and_then(|b| name(&b))
+        .unwrap_or_default();
+    let missing = get(font, "FontDescriptor")
+        .and_then(|d| get(&d, "MissingWidth"))
+        .and_then(|w| number(&w))
+        .filter(|&w| w > 0.0);
+    let default = missing.unwrap_or(if base_font.contains("Courier") {
+        COURIER_GLYPH_WIDTH
+    } else {
+        AVERAGE_GLYPH_WIDTH
+    });
+
+    let first = get(font, "FirstChar")
+        .and_then(|f| f.as_int().ok())
+        .unwrap_or(0)
+        .max(0) as u32;
+    let mut ranges = Vec::new();
+    if let Some(widths) = get(font, "Widths") {
+        for i in 0..widths.len().unwrap_or(0) {
+            let width = widths.get_array(i as i32).ok().flatten();
+            if let Some(width) = width.and_then(|w| number(&w)) {
+                let code = first + i as u32;
+                ranges.push((code, code, width));
+            }
+        }
+    }
+    FontWidths {
+        code_len: 1,
+        ranges,
+        default,
+    }
+}
+
+/// A CID font's `/W`: `first [w1 w2 ...]` and `first last w` entries
+fn cid_width_ranges(w: &PdfObject) -> Vec<(u32, u32, f32)> {
+    let len = w.len().unwrap_or(0) as i32;
+    let item = |i: i32| w.get_array(i).ok().flatten();
+    let code = |i: i32| item(i).and_then(|c| c.as_int().ok()).filter(|&c| c >= 0);
+
+    let mut ranges = Vec::new();
+    let mut i = 0;
+    while i + 1 < len {
+        let (Some(first), Some(next)) = (code(i), item(i + 1)) else {
+            break;
+        };
+        let first = first as u32;
+        if next.is_array().unwrap_or(false) {
+            for j in 0..next.len().unwrap_or(0) {
+                let width = next.get_array(j as i32).ok().flatten();
+                if let Some(width) = width.and_then(|w| number(&w)) {
+                    let code = first + j as u32;
+                    ranges.push((code, code, width));
+                }
+            }
+            i += 2;
+        } else {
+            let (Some(last), Some(width)) = (code(i + 1), item(i + 2).and_then(|w| number(&w)))
+            else {
+                break;
+            };
+            ranges.push((first, last as u32, width));
+            i += 3;
+        }
+    }
+    ranges.sort_by_key(|&(first, _, _)| first);
+    ranges
+}
+
+/// The page's content streams, decoded and joined
+fn page_content(page: &PdfObject) -> Vec<u8> {
+    let Some(contents) = page.get_dict("Contents").ok().flatten() else {
+        return Vec::new();
+    };
+    if !contents.is_array().unwrap_or(false) {
+        return contents.read_stream().unwrap_or_default();
+    }
+
+    let mut content = Vec::new();
+    for i in 0..contents.len().unwrap_or(0) {
+        if let Some(stream) = contents.get_array(i as i32).ok().flatten() {
+            content.extend(stream.read_stream().unwrap_or_default());
+            content.push(b'\n');
+        }
+    }
+    content
+}
+
+fn number(object: &PdfObject) -> Option<f32> {
+    if !object.is_number().unwrap_or(false) {
+        return None;
+    }
+    object.as_float().ok()
+}
+
+fn name(object: &PdfObject) -> Option<String> {
+    object
+        .as_name()
+        .ok()
+        .map(|n| String::from_utf8_lossy(n).into_owned())
+}
+
+/// A text string object as a trimmed, non-empty Rust string
+fn text_string(object: &PdfObject) -> Option<String> {
+    non_empty(read_text_string(object)?)
+}
+
+fn non_empty(text: String) -> Option<String> {
+    let text = text.trim();
+    (!text.is_empty()).then(|| text.to_string())
+}
+
+/// Content stream tokens, as far as following marked content and text
+/// positions needs
+#[derive(Debug, Clone, PartialEq)]
+enum Token {
+    Number(f32),
+    Name(Vec<u8>),
+    String(Vec<u8>),
+    DictStart,
+    DictEnd,
+    ArrayStart,
+    ArrayEnd,
+    /// `true`, `false` and `null`
+    Keyword,
+    Operator(Vec<u8>),
+}
+
+struct Tokenizer<'a> {
+    data: &'a [u8],
+    pos: usize,
+}
+
+fn is_whitespace(b: u8) -> bool {
+    matches!(b, b'\0' | b'\t' | b'\n' | b'\x0c' | b'\r' | b' ')
+}
+
+fn is_delimiter(b: u8) -> bool {
+    matches!(
+        b,
+        b'(' | b')' | b'<' | b'>' | b'[' | b']' | b'{' | b'}' | b'/' | b'%'
+    )
+}
+
+impl<'a> Tokenizer<'a> {
+    fn new(data: &'a [u8]) -> Self {
+        Self { data, pos: 0 }
+    }
+
+    fn peek(&self) -> Option<u8> {
+        self.data.get(self.pos).copied()
+    }
+
+    /// Skip inline image data, just after its `ID` operator, up to the `EI`
+    fn skip_inline_image(&mut self) {
+        self.pos += 1;
+        while self.pos + 1 < self.data.len() {
+            let at_ei = &self.data[self.pos..self.pos + 2] == b"EI"
+                && is_whitespace(self.data[self.pos - 1])
+                && self
+                    .data
+                    .get(self.pos + 2)
+                    .is_none_or(|&b| is_whitespace(b));
+            if at_ei {
+                self.pos += 2;
+                return;
+            }
+            self.pos += 1;
+        }
+        self.pos = self.data.len();
+    }
+
+    fn literal_string(&mut self) -> Vec<u8> {
+        let mut out = Vec::new();
+        let mut depth = 1;
+        self.pos += 1;
+        while let Some(b) = self.peek() {
+            self.pos += 1;
+            match b {
+                b'(' => {
+                    depth += 1;
+                    out.push(b);
+                }
+                b')' => {
+                    depth -= 1;
+                    if depth == 0 {
+                        break;
+                    }
+                    out.push(b);
+                }
+                b'\\' => {
+                    let Some(escaped) = self.peek() else {
+                        break;
+                    };
+                    self.pos += 1;
+                    match escaped {
+                        b'n' => out.push(b'\n'),
+                        b'r' => out.push(b'\r'),
+                        b't' => out.push(b'\t'),
+                        b'b' => out.push(b'\x08'),
+                        b'f' => out.push(b'\x0c'),
+                        b'0'..=b'7' => {
+                            let mut value = u32::from(escaped - b'0');
+                            for _ in 0..2 {
+                                match self.peek() {
+                                    Some(d @ b'0'..=b'7') => {
+                                        value = value * 8 + u32::from(d - b'0');
+                                        self.pos += 1;
+                                    }
+                                    _ => break,
+                                }
+                            }
+                            out.push(value as u8);
+                        }
+                        // Line continuation
+                        b'\r' => {
+                            if self.peek() == Some(b'\n') {
+                                self.pos += 1;
+                            }
+                        }
+                        b'\n' => {}
+                        other => out.push(other),
+                    }
+                }
+                _ => out.push(b),
+            }
+        }
+        out
+    }
+
+    fn hex_string(&mut self) -> Vec<u8> {
+        self.pos += 1;
+        let mut digits = Vec::new();
+        while let Some(b) = self.peek() {
+            self.pos += 1;
+            if b == b'>' {
+                break;
+            }
+            if let Some(d) = (b as char).to_digit(16) {
+                digits.push(d as u8);
+            }
+        }
+        if digits.len() % 2 == 1 {
+            digits.push(0);
+        }
+        digits.chunks(2).map(|p| p[0] << 4 | p[1]).collect()
+    }
+
+    fn name(&mut self) -> Vec<u8> {
+        self.pos += 1;
+        let mut out = Vec::new();
+        while let Some(b) = self.peek() {
+            if is_whitespace(b) || is_delimiter(b) {
+                break;
+            }
+            self.pos += 1;
+            let escaped = (b == b'#')
+                .then(|| self.data.get(self.pos..self.pos + 2))
+                .flatten()
+                .and_then(|hex| u8::from_str_radix(std::str::from_utf8(hex).ok()?, 16).ok());
+            match escaped {
+                Some(value) => {
+                    out.push(value);
+                    self.pos += 2;
+                }
+                None => out.push(b),
+            }
+        }
+        out
+    }
+}
+
+impl Iterator for Tokenizer<'_> {
+    type Item = Token;
+
+    fn next(&mut self) -> Option<Token> {
+        loop {
+            let b = self.peek()?;
+            if is_whitespace(b) {
+                self.pos += 1;
+                continue;
+            }
+            if b == b'%' {
+                while self.peek().is_some_and(|b| b != b'\n' && b != b'\r') {
+                    self.pos += 1;
+                }
+                continue;
+            }
+            let next = self.data.get(self.pos + 1).copied();
+            return Some(match (b, next) {
+                (b'(', _) => Token::String(self.literal_string()),
+                (b'<', Some(b'<')) => {
+                    self.pos += 2;
+                    Token::DictStart
+                }
+                (b'>', Some(b'>')) => {
+                    self.pos += 2;
+                    Token::DictEnd
+                }
+                (b'<', _) => Token::String(self.hex_string()),
+                (b'/', _) => Token::Name(self.name()),
+                (b'[', _) => {
+                    self.pos += 1;
+                    Token::ArrayStart
+                }
+                (b']', _) => {
+                    self.pos += 1;
+                    Token::ArrayEnd
+                }
+                _ if is_delimiter(b) => {
+                    // Stray ')', '>', '{' or '}'
+                    self.pos += 1;
+                    continue;
+                }
+                _ => {
+                    let start = self.pos;
+                    while self
+                        .peek()
+                        .is_some_and(|b| !is_whitespace(b) && !is_delimiter(b))
+                    {
+                        self.pos += 1;
+                    }
+                    let word = &self.data[start..self.pos];
+                    if let Some(n) = std::str::from_utf8(word).ok().and_then(|s| s.parse().ok()) {
+                        Token::Number(n)
+                    } else if matches!(word, b"true" | b"false" | b"null") {
+                        Token::Keyword
+                    } else {
+                        Token::Operator(word.to_vec())
+                    }
+                }
+            });
+        }
+    }
+}
+
+/// An affine matrix `[a b c d e f]`
+type Matrix = [f32; 6];
+
+const IDENTITY: Matrix = [1.0, 0.0, 0.0, 1.0, 0.0, 0.0];
+
+/// `m` then `n`
+fn concat(m: &Matrix, n: &Matrix) -> Matrix {
+    [
+        m[0] * n[0] + m[1] * n[2],
+        m[0] * n[1] + m[1] * n[3],
+        m[2] * n[0] + m[3] * n[2],
+        m[2] * n[1] + m[3] * n[3],
+        m[4] * n[0] + m[5] * n[2] + n[4],
+        m[4] * n[1] + m[5] * n[3] + n[5],
+    ]
+}
+
+fn numbers<const N: usize>(operands: &[Token]) -> Option<[f32; N]> {
+    let start = operands.len().checked_sub(N)?;
+    let mut values = [0.0; N];
+    for (value, token) in values.iter_mut().zip(&operands[start..]) {
+        match token {
+            Token::Number(n) => *value = *n,
+            _ => return None,
+        }
+    }
+    Some(values)
+}
+
+/// The language a `BDC` sets, from its operands: an inline `/Lang`, a
+/// named property list's, or the structure element's its `/MCID` belongs
+/// to
+fn marked_content_language(
+    operands: &[Token],
+    properties: &HashMap<Vec<u8>, MarkedContentProperties>,
+    mcids: &HashMap<i32, String>,
+) -> Option<String> {
+    let props = match operands {
+        [Token::Name(_), Token::Name(resource)] => properties.get(resource)?.clone(),
+        [Token::Name(_), Token::DictStart, entries @ ..] => {
+            let mut props = MarkedContentProperties::default();
+            let mut depth = 0;
+            for pair in entries.windows(2) {
+                match &pair[0] {
+                    Token::DictStart | Token::ArrayStart => depth += 1,
+                    Token::DictEnd | Token::ArrayEnd => depth -= 1,
+                    Token::Name(key) if depth == 0 => match (key.as_slice(), &pair[1]) {
+                        (b"Lang", Token::String(lang)) => {
+                            props.language = non_empty(decode_text_string(lang));
+                        }
+                        (b"MCID", Token::Number(n)) => props.mcid = Some(*n as i32),
+                        _ => {}
+                    },
+                    _ => {}
+                }
+            }
+            props
+        }
+        _ => return None,
+    };
+    props
+        .language
+        .or_else(|| props.mcid.and_then(|id| mcids.get(&id).cloned()))
+}
+
+/// Text state parameters that affect how far shown text advances
+#[derive(Debug, Clone)]
+struct TextState {
+    font: Option<Vec<u8>>,
+    font_size: f32,
+    char_spacing: f32,
+    word_spacing: f32,
+    /// `Tz`, as a fraction
+    horizontal_scale: f32,
+    leading: f32,
+}
+
+impl Default for TextState {
+    fn default() -> Self {
+        Self {
+            font: None,
+            font_size: 0.0,
+            char_spacing: 0.0,
+            word_spacing: 0.0,
+            horizontal_scale: 1.0,
+            leading: 0.0,
+        }
+    }
+}
+
+/// How far showing `shown` moves along the line, in unscaled text space:
+/// glyph widths plus character and word spacing, less `TJ` adjustments
+fn text_advance(shown: &[Token], font: &FontWidths, state: &TextState) -> f32 {
+    let mut advance = 0.0;
+    for token in shown {
+        match token {
+            Token::String(bytes) => {
+                for chunk in bytes.chunks(font.code_len) {
+                    let code = chunk.iter().fold(0, |code, &b| code << 8 | u32::from(b));
+                    advance += font.width(code) / 1000.0 * state.font_size + state.char_spacing;
+                    // Word spacing applies to single-byte spaces only
+                    if font.code_len == 1 && code == 32 {
+                        advance += state.word_spacing;
+                    }
+                }
+            }
+            Token::Number(n) => advance -= n / 1000.0 * state.font_size,
+            _ => {}
+        }
+    }
+    advance * state.horizontal_scale
+}
+
+/// Follow a content stream's marked content and text positioning, giving
+/// the user-space origin of each text-showing operator and the language
+/// it draws in, in stream order. An operator carrying on along the line
+/// of the one before, in the same language, gives no entry of its own.
+fn scan_content(
+    content: &[u8],
+    properties: &HashMap<Vec<u8>, MarkedContentProperties>,
+    fonts: &HashMap<Vec<u8>, FontWidths>,
+    mcids: &HashMap<i32, String>,
+) -> Vec<(Option<String>, (f32, f32))> {
+    let mut marks: Vec<(Option<String>, (f32, f32))> = Vec::new();
+    let mut operands = Vec::new();
+    let mut ctm = IDENTITY;
+    let mut saved = Vec::new();
+    // Text line matrix, and the text matrix that advances as text is shown
+    let mut line = IDENTITY;
+    let mut text = IDENTITY;
+    let mut state = TextState::default();
+    let mut languages: Vec<Option<String>> = Vec::new();
+    let unknown_font = FontWidths::default();
+
+    let mut tokens = Tokenizer::new(content);
+    while let Some(token) = tokens.next() {
+        let Token::Operator(op) = token else {
+            operands.push(token);
+            continue;
+        };
+
+        let current = languages.last().cloned().flatten();
+        let mut next_line = None;
+        let mut show = false;
+        match op.as_slice() {
+            b"q" => saved.push((ctm, state.clone())),
+            b"Q" => (ctm, state) = saved.pop().unwrap_or((IDENTITY, TextState::default())),
+            b"cm" => {
+                if let Some(m) = numbers::<6>(&operands) {
+                    ctm = concat(&m, &ctm);
+                }
+            }
+            b"BT" => (line, text) = (IDENTITY, IDENTITY),
+            b"Tm" => {
+                if let Some(m) = numbers::<6>(&operands) {
+                    (line, text) = (m, m);
+                }
+            }
+            b"Td" | b"TD" => {
+                if let Some([tx, ty]) = numbers::<2>(&operands) {
+                    if op == b"TD" {
+                        state.leading = -ty;
+                    }
+                    next_line = Some((tx, ty));
+                }
+            }
+            b"T*" => next_line = Some((0.0, -state.leading)),
+            b"TL" => {
+                if let Some([tl]) = numbers::<1>(&operands) {
+                    state.leading = tl;
+                }
+            }
+            b"Tf" => {
+                if let [.., Token::Name(font), Token::Number(size)] = operands.as_slice() {
+                    state.font = Some(font.clone());
+                    state.font_size = *size;
+                }
+            }
+            b"Tc" => {
+                if let Some([tc]) = numbers::<1>(&operands) {
+                    state.char_spacing = tc;
+                }
+            }
+            b"Tw" => {
+                if let Some([tw]) = numbers::<1>(&operands) {
+                    state.word_spacing = tw;
+                }
+            }
+            b"Tz" => {
+                if let Some([tz]) = numbers::<1>(&operands) {
+                    state.horizontal_scale = tz / 100.0;
+                }
+            }
+            b"'" => {
+                next_line = Some((0.0, -state.leading));
+                show = true;
+            }
+            b"\"" => {
+                if let [.., Token::Number(aw), Token::Number(ac), Token::String(_)] =
+                    operands.as_slice()
+                {
+                    state.word_spacing = *aw;
+                    state.char_spacing = *ac;
+                }
+                next_line = Some((0.0, -state.leading));
+                show = true;
+            }
+            b"Tj" | b"TJ" => show = true,
+            b"BMC" => languages.push(current),
+            b"BDC" => {
+                let language = marked_content_language(&operands, properties, mcids);
+                languages.push(language.or(current));
+            }
+            b"EMC" => {
+                languages.pop();
+            }
+            b"ID" => tokens.skip_inline_image(),
+            _ => {}
+        }
+
+        if let Some((tx, ty)) = next_line {
+            line = concat(&[1.0, 0.0, 0.0, 1.0, tx, ty], &line);
+            text = line;
+        }
+        if show {
+            let at = concat(&text, &ctm);
+            let (x, y) = (at[4], at[5]);
+            let language = languages.last().cloned().flatten();
+            let continues = marks.last().is_some_and(|(last, (last_x, last_y))| {
+                *last == language && *last_y == y && *last_x <= x
+            });
+            if !continues {
+                marks.push((language, (x, y)));
+            }
+
+            // The string operand, or the array of strings and adjustments
+            let shown = match operands.iter().position(|t| *t == Token::ArrayStart) {
+                Some(start) if op == b"TJ" => &operands[start + 1..],
+                _ => match operands.last() {
+                    Some(last @ Token::String(_)) => std::slice::from_ref(last),
+                    _ => &[],
+                },
+            };
+            let font = state
+                .font
+                .as_ref()
+                .and_then(|f| fonts.get(f))
+                .unwrap_or(&unknown_font);
+            let advance = text_advance(shown, font, &state);
+            text = concat(&[1.0, 0.0, 0.0, 1.0, advance, 0.0], &text);
+        }
+        operands.clear();
+    }
+    marks
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::Rect;
+
+    fn scan(content: &str) -> Vec<(Option<String>, (f32, f32))> {
+        let properties = HashMap::from([(
+            b"P0".to_vec(),
+            MarkedContentProperties {
+                language: Some("es".to_string()),
+                mcid: None,
+            },
+        )]);
+        let fonts = HashMap::from([
+            (
+                b"F1".to_vec(),
+                FontWidths {
+                    code_len: 1,
+                    ranges: vec![(32, 32, 250.0)],
+                    default: 500.0,
+                },
+            ),
+            (
+                b"F2".to_vec(),
+                FontWidths {
+                    code_len: 2,
+                    ranges: vec![(1, 2, 1000.0)],
+                    default: 500.0,
+                },
+            ),
+        ]);
+        let mcids = HashMap::from([(3, "fr-FR".to_string())]);
+        scan_content(content.as_bytes(), &properties, &fonts, &mcids)
+    }
+
+    fn lang(tag: &str) -> Option<String> {
+        Some(tag.to_string())
+    }
+
+    #[test]
+    fn test_marked_content_languages() {
+        let marks = scan(
+            "BT /F1 12 Tf 72 700 Td (Hello) Tj ET
+             /P <</MCID 3>> BDC BT 72 640 Td (Bonjour) Tj ET EMC
+             /Span <</Lang (de-DE) /ActualText (x\\)y)>> BDC
+               BT 72 580 Td [(Guten) -250 (Tag)] TJ 0 -14 Td (Welt) Tj ET
+             EMC
+             /Span /P0 BDC /Artifact BMC BT 1 0 0 1 72 520 Tm (Hola) Tj ET EMC EMC",
+        );
+        assert_eq!(
+            marks,
+            [
+                (None, (72.0, 700.0)),
+                (lang("fr-FR"), (72.0, 640.0)),
+                (lang("de-DE"), (72.0, 580.0)),
+                (lang("de-DE"), (72.0, 566.0)),
+                (lang("es"), (72.0, 520.0)),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_text_positioning() {
+        // Leading, T* and ' move down a line; cm scales and q/Q restore
+        let marks = scan(
+            "q 2 0 0 2 10 20 cm BT 14 TL 5 100 Td (a) Tj T* (b) Tj (c) ' ET Q
+             BT 50 60 TD (d) Tj T* (e) Tj ET",
+        );
+        let origins: Vec<(f32, f32)> = marks.into_iter().map(|(_, at)| at).collect();
+        assert_eq!(
+            origins,
+            [
+                (20.0, 220.0),
+                (20.0, 192.0),
+                (20.0, 164.0),
+                (50.0, 60.0),
+                (50.0, 120.0),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_language_changing_mid_line() {
+        // Spaces are 2.5pt and other glyphs 5pt wide at 10pt
+        let marks = scan(
+            "BT /F1 10 Tf 72 700 Td (Hello ) Tj
+             /Span <</Lang (fr)>> BDC (le monde) Tj EMC ( again) Tj ET",
+        );
+        assert_eq!(
+            marks,
+            [
+                (None, (72.0, 700.0)),
+                (lang("fr"), (99.5, 700.0)),
+                (None, (137.0, 700.0)),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_text_advance() {
+        // Character and word spacing, TJ adjustments and horizontal scaling;
+        // q/Q restore the text state
+        let marks = scan(
+            "q BT /F1 10 Tf 2 Tc 4 Tw 50 Tz 0 0 Td [(a b) -1000 (c)] TJ (x) Tj
+             /Span <</Lang (de)>> BDC (y) Tj EMC ET Q
+             BT /F2 20 Tf 0 -50 Td <000100020003> Tj
+             /Span <</Lang (it)>> BDC (z) Tj EMC ET",
+        );
+        assert_eq!(
+            marks,
+            [
+                // (x) carries on the same line in the same language
+                (None, (0.0, 0.0)),
+                (lang("de"), (23.25, 0.0)),
+                // Two-byte codes: 1 and 2 are an em wide, 3 half
+                (None, (0.0, -50.0)),
+                (lang("it"), (50.0, -50.0)),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_tokenizer_skips_strings_and_inline_images() {
+        let marks = scan(
+            "% comment with BDC and (Tj)
+             /Span <</Lang <FEFF00690074>>> BDC
+             BI /W 2 /H 1 /BPC 8 /CS /G ID \x01EMC\x02 EI
+             BT 72 500 Td (\\(EMC\\) \\051) Tj ET EMC",
+        );
+        assert_eq!(marks, [(lang("it"), (72.0, 500.0))]);
+    }
+
+    #[test]
+    fn test_mark_for_word() {
+        let mark = |language: &str, x: f32, y: f32, sequence: usize| LanguageMark {
+            language: lang(language),
+            page_number: 1,
+            x,
+            y,
+            sequence,
+        };
+        let marks = [
+            mark("en", 72.0, 100.0, 0),
+            mark("fr", 200.0, 100.0, 1),
+            mark("de", 72.0, 120.0, 2),
+            // Drawn at the same place: the later one wins
+            mark("pt", 72.0, 140.0, 4),
+            mark("es", 72.0, 140.0, 3),
+        ];
+        let word_at = |x: f32, y: f32, page_number: u32| {
+            Word::new("word", Rect::new(x, y, 30.0, 12.0), page_number, 12.0)
+        };
+        let word = |x: f32, page_number: u32| word_at(x, 90.0, page_number);
+
+        let language =
+            |w: &Word| LanguageMark::for_word(&marks, w).and_then(|m| m.language.clone());
+        assert_eq!(language(&word(72.0, 1)), lang("en"));
+        assert_eq!(language(&word(150.0, 1)), lang("en"));
+        assert_eq!(language(&word(230.0, 1)), lang("fr"));
+        assert_eq!(language(&word(20.0, 1)), None);
+        assert_eq!(language(&word(72.0, 0)), None);
+        assert_eq!(language(&word_at(72.0, 130.0, 1)), lang("pt"));
+    }
+}
@@ -0,0 +1,111 @@
+//! Decoding of PDF text strings (outline titles, document information,
+//! page label prefixes, language tags).
+//!
+//! This is the one decoder for every text string read from the object tree.
+
+use mupdf::pdf::PdfObject;
+
+/// PDFDocEncoding code points 0x18..=0x1F: spacing accents
+const DOC_ENCODING_18: [char; 8] = ['˘', 'ˇ', 'ˆ', '˙', '˝', '˛', '˚', '˜'];
+
+/// PDFDocEncoding code points 0x80..=0xA0; 0x9F is undefined
+const DOC_ENCODING_80: [char; 33] = [
+    '•', '†', '‡', '…', '—', '–', 'ƒ', '⁄', '‹', '›', '−', '‰', '„', '“', '”', '‘', '’', '‚', '™',
+    'ﬁ', 'ﬂ', 'Ł', 'Œ', 'Š', 'Ÿ', 'Ž', 'ı', 'ł', 'œ', 'š', 'ž', '\u{FFFD}', '€',
+];
+
+/// Escape that brackets a language code inside a UTF-16 text string
+const LANGUAGE_ESCAPE: u16 = 0x1B;
+
+/// Decode a PDF text string (PDF 32000-1 §7.9.2.2).
+///
+/// Strings starting with a byte order mark are UTF-16BE (or, as some
+/// producers write them, UTF-16LE) or UTF-8; anything else is
+/// PDFDocEncoding. Embedded language escapes are dropped and undecodable
+/// bytes become U+FFFD.
+pub(crate) fn decode_text_string(bytes: &[u8]) -> String {
+    match bytes {
+        [0xFE, 0xFF, rest @ ..] => decode_utf16(rest, u16::from_be_bytes),
+        [0xFF, 0xFE, rest @ ..] => decode_utf16(rest, u16::from_le_bytes),
+        [0xEF, 0xBB, 0xBF, rest @ ..] => String::from_utf8_lossy(rest).into_owned(),
+        _ => bytes.iter().map(|&b| doc_encoding_char(b)).collect(),
+    }
+}
+
+/// Decode a string object.
+///
+/// MuPDF's `as_string` panics on strings that aren't valid UTF-8, which
+/// PDFDocEncoding and UTF-16 strings often aren't, so read the raw bytes.
+pub(crate) fn read_text_string(object: &PdfObject) -> Option<String> {
+    object.as_bytes().ok().map(decode_text_string)
+}
+
+fn decode_utf16(bytes: &[u8], unit: fn([u8; 2]) -> u16) -> String {
+    let mut units = Vec::with_capacity(bytes.len() / 2);
+    let mut in_language = false;
+    for pair in bytes.chunks_exact(2) {
+        let u = unit([pair[0], pair[1]]);
+        if u == LANGUAGE_ESCAPE {
+            in_language = !in_language;
+        } else if !in_language {
+            units.push(u);
+        }
+    }
+
+    char::decode_utf16(units)
+        .map(|c| c.unwrap_or(char::REPLACEMENT_CHARACTER))
+        .collect()
+}
+
+fn doc_encoding_char(byte: u8) -> char {
+    match byte {
+        0x18..=0x1F => DOC_ENCODING_18[(byte - 0x18) as usize],
+        0x80..=0xA0 => DOC_ENCODING_80[(byte - 0x80) as usize],
+        0x7F | 0xAD => char::REPLACEMENT_CHARACTER,
+        // Everything else matches ISO Latin-1
+        _ => byte as char,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_pdf_doc_encoding() {
+        assert_eq!(decode_text_string(b"Plain ASCII"), "Plain ASCII");
+        assert_eq!(
+            decode_text_string(b"Caf\xe9 \x84 \x93rst \x8dquoted\x8e \xa0"),
+            "Café — ﬁrst “quoted” €"
+        );
+        assert_eq!(decode_text_string(b"\x9f"), "\u{FFFD}");
+    }
+
+    #[test]
+    fn test_utf16() {
+        assert_eq!(
+            decode_text_string(b"\xfe\xff\x00K\x00a\x00p\x00i\x00t\x00e\x00l\x00 \x04\x1f"),
+            "Kapitel П"
+        );
+        // Surrogate pair
+        assert_eq!(decode_text_string(b"\xfe\xff\xd8\x3d\xde\x00"), "😀");
+        assert_eq!(decode_text_string(b"\xff\xfeA\x00\xe9\x00"), "Aé");
+        // Unpaired surrogate and a trailing odd byte
+        assert_eq!(
+            decode_text_string(b"\xfe\xff\xd8\x3d\x00A\x00"),
+            "\u{FFFD}A"
+        );
+    }
+
+    #[test]
+    fn test_utf16_language_escape() {
+        // ESC "en" ESC before the text
+        let bytes = b"\xfe\xff\x00\x1b\x00e\x00n\x00\x1b\x00H\x00i";
+        assert_eq!(decode_text_string(bytes), "Hi");
+    }
+
+    #[test]
+    fn test_utf8() {
+        assert_eq!(decode_text_string("\u{FEFF}Über".as_bytes()), "Über");
+    }
+}
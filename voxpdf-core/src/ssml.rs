@@ -0,0 +1,662 @@
+//! SSML markup for speech engines.
+//!
+//! [`SsmlRenderer`] turns paragraphs into a `<speak>` document: `<p>` and
+//! `<s>` from paragraphs and sentences, pauses sized by what each paragraph
+//! is (heading, body text or list item), `<emphasis>` for bold and italic
+//! runs, `<say-as>` for dates, numbers and acronyms, `xml:lang` on
+//! paragraphs in another language than the document, and a `<mark>` naming
+//! each word so highlighting can follow the engine's bookmark events.
+
+use crate::error::Result;
+use crate::extraction::SentenceSplitter;
+use crate::models::{primary_subtag, Paragraph, Word};
+use crate::normalization::is_grouped_number;
+use crate::pdf::PDFDocument;
+use serde::{Deserialize, Serialize};
+use std::collections::HashSet;
+use std::fmt::Write;
+
+/// TOC entries pointing at paragraphs longer than this fell back to body
+/// text rather than finding their heading
+const MAX_HEADING_WORDS: usize = 20;
+
+/// Markers that start a list item and aren't read out
+const BULLETS: &[&str] = &[
+    "•", "◦", "▪", "▫", "‣", "⁃", "●", "○", "■", "□", "-", "–", "—", "*", "·",
+];
+
+/// Longest all-capitals word read as an acronym
+const MAX_ACRONYM_LEN: usize = 6;
+
+/// Acronyms up to this long are spelled out even if they have vowels
+/// ("USA"); longer ones only without ("HTML", but "NATO" is a word)
+const MAX_SPELLED_WITH_VOWELS: usize = 3;
+
+/// Which markup [`SsmlRenderer`] emits.
+///
+/// Deserializing fills missing fields with defaults, so partial JSON such as
+/// `{"marks": false}` is accepted.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct SsmlOptions {
+    /// A `<mark>` before every word, named by [`mark_name`]
+    pub marks: bool,
+
+    /// `<emphasis>` around bold (strong) and italic (moderate) runs. Needs
+    /// fonts, read when
+    /// [`ExtractionOptions::font_attributes`](crate::extraction::ExtractionOptions::font_attributes)
+    /// is set (the default).
+    pub emphasis: bool,
+
+    /// `<say-as>` for dates, grouped numbers, ordinals and acronyms
+    pub say_as: bool,
+
+    /// Pause around a heading, in milliseconds
+    pub heading_break_ms: u32,
+
+    /// Pause between paragraphs of body text, in milliseconds
+    pub paragraph_break_ms: u32,
+
+    /// Pause between list items, in milliseconds
+    pub list_item_break_ms: u32,
+}
+
+impl Default for SsmlOptions {
+    fn default() -> Self {
+        Self {
+            marks: true,
+            emphasis: true,
+            say_as: true,
+            heading_break_ms: 750,
+            paragraph_break_ms: 400,
+            list_item_break_ms: 200,
+        }
+    }
+}
+
+/// What a paragraph is, as far as pausing around it goes
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Block {
+    Heading,
+    Body,
+    /// `true` if the first word is a bullet to leave unread
+    ListItem(bool),
+}
+
+/// Renders paragraphs as SSML.
+///
+/// # Example
+/// ```
+/// use voxpdf_core::models::{Paragraph, Rect, Word};
+/// use voxpdf_core::ssml::SsmlRenderer;
+///
+/// let text = "Launched by NASA on 1977-09-05.";
+/// let words = text
+///     .split(' ')
+///     .map(|w| Word::new(w, Rect::new(0.0, 0.0, 10.0, 10.0), 0, 10.0))
+///     .collect();
+/// let paragraph = Paragraph::new(0, text.to_string(), 0, words);
+///
+/// let ssml = SsmlRenderer::new(Some("en-US")).render(&[paragraph]);
+/// assert!(ssml.starts_with("<speak version=\"1.1\""));
+/// assert!(ssml.contains("<mark name=\"p0w0\"/>Launched"));
+/// assert!(ssml.contains("<say-as interpret-as=\"date\" format=\"ymd\">1977-09-05</say-as>."));
+/// ```
+#[derive(Debug, Clone)]
+pub struct SsmlRenderer {
+    options: SsmlOptions,
+    splitter: SentenceSplitter,
+    /// BCP 47 tag for `xml:lang`
+    language: Option<String>,
+    /// `Paragraph::index` of every heading
+    headings: HashSet<usize>,
+}
+
+impl Default for SsmlRenderer {
+    fn default() -> Self {
+        Self::new(None)
+    }
+}
+
+impl SsmlRenderer {
+    /// A renderer for text in `language` (a BCP 47 tag such as "en-US"),
+    /// which sets `xml:lang`, the sentence rules and how dates and numbers
+    /// are read
+    pub fn new(language: Option<&str>) -> Self {
+        Self {
+            options: SsmlOptions::default(),
+            splitter: SentenceSplitter::for_language(language),
+            language: language.map(str::to_string),
+            headings: HashSet::new(),
+        }
+    }
+
+    /// A renderer for the document's
+    /// [`document_paragraphs`](PDFDocument::document_paragraphs), with its
+    /// [`language`](PDFDocument::language) and the headings its table of
+    /// contents points at
+    pub fn for_document(doc: &PDFDocument) -> Result<Self> {
+        let headings = doc
+            .toc_tree()?
+            .nodes()
+            .iter()
+            .map(|node| node.entry.paragraph_index);
+
+        let mut renderer = Self::new(doc.language()?).with_headings(headings);
+        renderer.splitter = doc.sentence_splitter()?.clone();
+        Ok(renderer)
+    }
+
+    pub fn with_options(mut self, options: SsmlOptions) -> Self {
+        self.options = options;
+        self
+    }
+
+    /// Treat the paragraphs with these indices
+    /// ([`Paragraph::index`]) as headings
+    pub fn with_headings(mut self, indices: impl IntoIterator<Item = usize>) -> Self {
+        self.headings.extend(indices);
+        self
+    }
+
+    pub fn options(&self) -> &SsmlOptions {
+        &self.options
+    }
+
+    /// A `<speak>` document reading `paragraphs` in order. Running headers,
+    /// footers and page numbers are left out.
+    pub fn render(&self, paragraphs: &[Paragraph]) -> String {
+        let mut out =
+            String::from(r#"<speak version="1.1" xmlns="http://www.w3.org/2001/10/synthesis""#);
+        if let Some(language) = &self.language {
+            let _ = write!(out, r#" xml:lang="{}""#, escape(language));
+        }
+        out.push('>');
+
+        let mut previous: Option<Block> = None;
+        for paragraph in paragraphs.iter().filter(|p| !p.is_running_text()) {
+            let block = self.block(paragraph);
+            if let Some(previous) = previous {
+                // The longer pause of the two, so headings are set apart on
+                // both sides
+                let pause = self.pause(previous).max(self.pause(block));
+                let _ = write!(out, "\n<break time=\"{}ms\"/>", pause);
+            }
+            out.push('\n');
+            self.write_paragraph(&mut out, paragraph, block);
+            previous = Some(block);
+        }
+
+        out.push_str("\n</speak>");
+        out
+    }
+
+    /// One paragraph as a `<p>` element, to embed in a `<speak>` document
+    /// of the caller's own
+    pub fn render_paragraph(&self, paragraph: &Paragraph) -> String {
+        let mut out = String::new();
+        self.write_paragraph(&mut out, paragraph, self.block(paragraph));
+        out
+    }
+
+    fn block(&self, paragraph: &Paragraph) -> Block {
+        if self.headings.contains(&paragraph.index) && paragraph.words.len() <= MAX_HEADING_WORDS {
+            return Block::Heading;
+        }
+
+        let Some(first) = paragraph.words.first() else {
+            return Block::Body;
+        };
+        if BULLETS.contains(&first.text.as_str()) {
+            Block::ListItem(true)
+        } else if paragraph.words.len() > 1 && is_enumerator(&first.text) {
+            Block::ListItem(false)
+        } else {
+            Block::Body
+        }
+    }
+
+    fn pause(&self, block: Block) -> u32 {
+        match block {
+            Block::Heading => self.options.heading_break_ms,
+            Block::Body => self.options.paragraph_break_ms,
+            Block::ListItem(_) => self.options.list_item_break_ms,
+        }
+    }
+
+    fn write_paragraph(&self, out: &mut String, paragraph: &Paragraph, block: Block) {
+        let words = &paragraph.words;
+        let skip = usize::from(block == Block::ListItem(true));
+
+        // Emphasis only marks words out from their paragraph; a heading or
+        // a quotation set entirely in bold or italic reads as usual
+        let emphasis = self.options.emphasis
+            && block != Block::Heading
+            && words
+                .iter()
+                .skip(skip)
+                .any(|w| emphasis_level(w) != emphasis_level(&words[skip]));
+        // In all-capitals text every word looks like an acronym
+        let shouting = 2 * words.iter().filter(|w| is_capitals(&w.text)).count() > words.len();
+        let say_as = self.options.say_as && !shouting;
+
+        // A passage in another language is read by a voice for it
+        let switch = paragraph.language.as_ref().filter(|language| {
+            self.language
+                .as_deref()
+                .is_none_or(|own| primary_subtag(own) != language.primary())
+        });
+        match switch {
+            Some(language) => {
+                let _ = write!(out, r#"<p xml:lang="{}">"#, escape(&language.tag));
+            }
+            None => out.push_str("<p>"),
+        }
+        for sentence in self.splitter.split(paragraph) {
+            let range = sentence.word_range.start.max(skip)..sentence.word_range.end;
+            if range.is_empty() {
+                continue;
+            }
+
+            out.push_str("<s>");
+            let mut open: Option<&str> = None;
+            for (i, word) in words[range.clone()].iter().enumerate() {
+                let level = emphasis.then(|| emphasis_level(word)).flatten();
+                if level != open && open.is_some() {
+                    out.push_str("</emphasis>");
+                }
+                if i > 0 {
+                    out.push(' ');
+                }
+                if level != open {
+                    if let Some(level) = level {
+                        let _ = write!(out, r#"<emphasis level="{}">"#, level);
+                    }
+                    open = level;
+                }
+
+                if self.options.marks {
+                    let name = mark_name(paragraph.index, range.start + i);
+                    let _ = write!(out, r#"<mark name="{}"/>"#, name);
+                }
+                self.write_word(out, &word.text, say_as);
+            }
+            if open.is_some() {
+                out.push_str("</emphasis>");
+            }
+            out.push_str("</s>");
+        }
+        out.push_str("</p>");
+    }
+
+    fn write_word(&self, out: &mut String, text: &str, say_as: bool) {
+        let start = text.find(char::is_alphanumeric).unwrap_or(text.len());
+        let end = text.rfind(char::is_alphanumeric).map_or(start, |i| {
+            i + text[i..].chars().next().map_or(0, char::len_utf8)
+        });
+        let (lead, core, trail) = (&text[..start], &text[start..end], &text[end..]);
+
+        let Some(say) = say_as.then(|| self.interpret(core)).flatten() else {
+            out.push_str(&escape(text));
+            return;
+        };
+
+        out.push_str(&escape(lead));
+        let _ = write!(out, r#"<say-as interpret-as="{}""#, say.interpret_as);
+        if let Some(format) = say.format {
+            let _ = write!(out, r#" format="{}""#, format);
+        }
+        let _ = write!(out, ">{}</say-as>", escape(&say.content));
+        out.push_str(&escape(trail));
+    }
+
+    /// How an engine should read `core`, a word without its surrounding
+    /// punctuation, if it needs telling
+    fn interpret(&self, core: &str) -> Option<SayAs> {
+        let language = self.language.as_deref().unwrap_or("");
+        let primary = language.split(['-', '_']).next().unwrap_or("");
+        let english = primary.is_empty() || primary.eq_ignore_ascii_case("en");
+
+        if let Some(format) = date_format(core, language) {
+            return Some(SayAs::new("date", Some(format), core));
+        }
+        if english {
+            if let Some(digits) = english_ordinal(core) {
+                return Some(SayAs::new("ordinal", None, digits));
+            }
+        }
+        if is_grouped_number(core, if english { ',' } else { '.' }) {
+            return Some(SayAs::new("cardinal", None, core));
+        }
+        if let Some(letters) = spelled_acronym(core) {
+            return Some(SayAs::new("characters", None, &letters));
+        }
+        None
+    }
+}
+
+/// Contents of a `<say-as>` element
+struct SayAs {
+    interpret_as: &'static str,
+    format: Option<&'static str>,
+    content: String,
+}
+
+impl SayAs {
+    fn new(interpret_as: &'static str, format: Option<&'static str>, content: &str) -> Self {
+        Self {
+            interpret_as,
+            format,
+            content: content.to_string(),
+        }
+    }
+}
+
+/// Name of the `<mark>` before a word: "p3w12" for word 12 of the
+/// paragraph with [`index`](Paragraph::index) 3
+pub fn mark_name(paragraph_index: usize, word_index: usize) -> String {
+    format!("p{}w{}", paragraph_index, word_index)
+}
+
+/// The paragraph and word indices a [`mark_name`] stands for
+pub fn parse_mark(name: &str) -> Option<(usize, usize)> {
+    let (paragraph, word) = name.strip_prefix('p')?.split_once('w')?;
+    Some((paragraph.parse().ok()?, word.parse().ok()?))
+}
+
+/// Escape text for XML content and attribute values
+fn escape(text: &str) -> String {
+    let mut out = String::with_capacity(text.len());
+    for c in text.chars() {
+        match c {
+            '&' => out.push_str("&amp;"),
+            '<' => out.push_str("&lt;"),
+            '>' => out.push_str("&gt;"),
+            '"' => out.push_str("&quot;"),
+            '\'' => out.push_str("&apos;"),
+            c => out.push(c),
+        }
+    }
+    out
+}
+
+fn emphasis_level(word: &Word) -> Option<&'static str> {
+    let font = word.font.as_ref()?;
+    if font.bold {
+        Some("strong")
+    } else if font.italic {
+        Some("moderate")
+    } else {
+        None
+    }
+}
+
+/// "1.", "2)", "(a)", "iv." and the like, numbering a list item
+fn is_enumerator(text: &str) -> bool {
+    let Some(label) = text
+        .strip_suffix(['.', ')'])
+        .map(|label| label.strip_prefix('(').unwrap_or(label))
+    else {
+        return false;
+    };
+
+    let digits = !label.is_empty() && label.len() <= 3 && label.chars().all(|c| c.is_ascii_digit());
+    let letter = label.len() == 1 && label.chars().all(|c| c.is_ascii_lowercase());
+    let roman = !label.is_empty()
+        && label.len() <= 5
+        && label.chars().all(|c| matches!(c, 'i' | 'v' | 'x'));
+    digits || letter || roman
+}
+
+/// Two or more capitals and no lowercase letters
+fn is_capitals(text: &str) -> bool {
+    text.chars().filter(|c| c.is_uppercase()).count() >= 2 && !text.chars().any(char::is_lowercase)
+}
+
+/// The `<say-as>` format of a date: ISO "2024-03-10" anywhere, "10.03.2024"
+/// outside English, and "03/10/2024" when the language says which number
+/// is the month
+fn date_format(text: &str, language: &str) -> Option<&'static str> {
+    let parts: Vec<&str> = text.split(['-', '/', '.']).collect();
+    let [a, b, c] = parts.as_slice() else {
+        return None;
+    };
+    if !parts
+        .iter()
+        .all(|p| !p.is_empty() && p.chars().all(|c| c.is_ascii_digit()))
+    {
+        return None;
+    }
+    let separator = text[a.len()..].chars().next()?;
+    if text[a.len() + b.len() + 1..].chars().next()? != separator {
+        return None;
+    }
+
+    let number = |s: &str| s.parse::<u32>().unwrap_or(0);
+    let valid = |day: &str, month: &str| {
+        day.len() <= 2
+            && month.len() <= 2
+            && (1..=31).contains(&number(day))
+            && (1..=12).contains(&number(month))
+    };
+
+    match separator {
+        '-' if a.len() == 4 && valid(c, b) => Some("ymd"),
+        '.' if c.len() == 4
+            && !language.is_empty()
+            && !language.starts_with("en")
+            && valid(a, b) =>
+        {
+            Some("dmy")
+        }
+        '/' if c.len() == 4 && language.eq_ignore_ascii_case("en-US") && valid(b, a) => Some("mdy"),
+        '/' if c.len() == 4
+            && !language.is_empty()
+            && !language.eq_ignore_ascii_case("en-US")
+            && valid(a, b) =>
+        {
+            Some("dmy")
+        }
+        _ => None,
+    }
+}
+
+/// The number of an English ordinal such as "21st" or "3rd"
+fn english_ordinal(text: &str) -> Option<&str> {
+    let digits = text.trim_end_matches(|c: char| c.is_ascii_alphabetic());
+    let suffix = text[digits.len()..].to_ascii_lowercase();
+    if digits.is_empty() || !digits.chars().all(|c| c.is_ascii_digit()) {
+        return None;
+    }
+
+    let n: u64 = digits.parse().ok()?;
+    let expected = match (n % 10, n % 100) {
+        (_, 11..=13) => "th",
+        (1, _) => "st",
+        (2, _) => "nd",
+        (3, _) => "rd",
+        _ => "th",
+    };
+    (suffix == expected).then_some(digits)
+}
+
+/// The letters to spell out of an acronym ("FBI", "HTML", "U.S"), or `None`
+/// for words read as written ("NATO", "Paris")
+fn spelled_acronym(text: &str) -> Option<String> {
+    let letters: String = text.chars().filter(|&c| c != '.').collect();
+    let dotted = letters.len() < text.len();
+    if letters.len() < 2
+        || letters.len() > MAX_ACRONYM_LEN
+        || !letters.chars().all(|c| c.is_ascii_uppercase())
+    {
+        return None;
+    }
+    if dotted {
+        // Every letter stands alone: "U.S", not "No.5"
+        let alternating = text.chars().enumerate().all(|(i, c)| {
+            if i % 2 == 1 {
+                c == '.'
+            } else {
+                c.is_ascii_uppercase()
+            }
+        });
+        return alternating.then_some(letters);
+    }
+
+    let vowels = letters
+        .chars()
+        .any(|c| matches!(c, 'A' | 'E' | 'I' | 'O' | 'U'));
+    (letters.len() <= MAX_SPELLED_WITH_VOWELS || !vowels).then_some(letters)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::{FontInfo, Language, LanguageSource};
+    use crate::test_support::paragraph;
+
+    fn plain() -> SsmlOptions {
+        SsmlOptions {
+            marks: false,
+            ..Default::default()
+        }
+    }
+
+    fn styled(word: &mut Word, bold: bool, italic: bool) {
+        word.font = Some(FontInfo {
+            name: "Times".to_string(),
+            bold,
+            italic,
+            monospace: false,
+        });
+    }
+
+    #[test]
+    fn test_document_structure() {
+        let paragraphs = [
+            paragraph(0, "1 Introduction"),
+            paragraph(1, "Read this. Then that."),
+            paragraph(2, "• Apples"),
+            paragraph(3, "• Pears & plums"),
+            paragraph(4, "2 Methods"),
+        ];
+        let renderer = SsmlRenderer::new(Some("en-GB"))
+            .with_options(plain())
+            .with_headings([0, 4]);
+
+        assert_eq!(
+            renderer.render(&paragraphs),
+            "<speak version=\"1.1\" xmlns=\"http://www.w3.org/2001/10/synthesis\" xml:lang=\"en-GB\">\n\
+             <p><s>1 Introduction</s></p>\n<break time=\"750ms\"/>\n\
+             <p><s>Read this.</s><s>Then that.</s></p>\n<break time=\"400ms\"/>\n\
+             <p><s>Apples</s></p>\n<break time=\"200ms\"/>\n\
+             <p><s>Pears &amp; plums</s></p>\n<break time=\"750ms\"/>\n\
+             <p><s>2 Methods</s></p>\n\
+             </speak>"
+        );
+    }
+
+    #[test]
+    fn test_marks_follow_word_indices() {
+        let renderer = SsmlRenderer::new(None);
+        let ssml = renderer.render_paragraph(&paragraph(7, "- One two."));
+        assert_eq!(
+            ssml,
+            "<p><s><mark name=\"p7w1\"/>One <mark name=\"p7w2\"/>two.</s></p>"
+        );
+        assert_eq!(parse_mark("p7w2"), Some((7, 2)));
+        assert_eq!(parse_mark("p7"), None);
+        assert_eq!(parse_mark("x7w2"), None);
+    }
+
+    #[test]
+    fn test_emphasis_runs() {
+        let mut para = paragraph(0, "A very bold claim, in italics.");
+        styled(&mut para.words[1], true, false);
+        styled(&mut para.words[2], true, false);
+        styled(&mut para.words[5], false, true);
+        let renderer = SsmlRenderer::new(None).with_options(plain());
+        assert_eq!(
+            renderer.render_paragraph(&para),
+            "<p><s>A <emphasis level=\"strong\">very bold</emphasis> claim, in \
+             <emphasis level=\"moderate\">italics.</emphasis></s></p>"
+        );
+
+        // A paragraph set entirely in italics has nothing to stand out from
+        for word in &mut para.words {
+            styled(word, false, true);
+        }
+        assert!(!renderer.render_paragraph(&para).contains("emphasis"));
+    }
+
+    #[test]
+    fn test_say_as() {
+        let english = SsmlRenderer::new(Some("en-US")).with_options(plain());
+        assert_eq!(
+            english.render_paragraph(&paragraph(
+                0,
+                "On 07/04/1976 (the 4th) the FBI paid 12,500 dollars, not NATO."
+            )),
+            "<p><s>On <say-as interpret-as=\"date\" format=\"mdy\">07/04/1976</say-as> \
+             (the <say-as interpret-as=\"ordinal\">4</say-as>) \
+             the <say-as interpret-as=\"characters\">FBI</say-as> \
+             paid <say-as interpret-as=\"cardinal\">12,500</say-as> dollars, not NATO.</s></p>"
+        );
+
+        let german = SsmlRenderer::new(Some("de")).with_options(plain());
+        assert_eq!(
+            german.render_paragraph(&paragraph(0, "Am 03.10.1990 kamen 1.200 Gäste.")),
+            "<p><s>Am <say-as interpret-as=\"date\" format=\"dmy\">03.10.1990</say-as> \
+             kamen <say-as interpret-as=\"cardinal\">1.200</say-as> Gäste.</s></p>"
+        );
+
+        // Shouted text isn't a string of acronyms
+        let shouting = english.render_paragraph(&paragraph(0, "DO NOT ENTER"));
+        assert_eq!(shouting, "<p><s>DO NOT ENTER</s></p>");
+    }
+
+    #[test]
+    fn test_paragraph_language() {
+        let renderer = SsmlRenderer::new(Some("en-US")).with_options(plain());
+        let mut para = paragraph(0, "Bonjour tout le monde.");
+        para.language = Some(Language::new("fr-FR", 1.0, LanguageSource::Tagged));
+        assert_eq!(
+            renderer.render_paragraph(&para),
+            "<p xml:lang=\"fr-FR\"><s>Bonjour tout le monde.</s></p>"
+        );
+
+        // The document's language, whatever the region, needs no switch
+        para.language = Some(Language::new("en-GB", 0.9, LanguageSource::Detected));
+        assert_eq!(
+            renderer.render_paragraph(&para),
+            "<p><s>Bonjour tout le monde.</s></p>"
+        );
+    }
+
+    #[test]
+    fn test_word_classifiers() {
+        assert_eq!(date_format("2024-03-10", ""), Some("ymd"));
+        assert_eq!(date_format("2024-13-10", ""), None);
+        assert_eq!(date_format("03/10/2024", ""), None);
+        assert_eq!(date_format("03/10/2024", "en-GB"), Some("dmy"));
+        assert_eq!(date_format("13/10/2024", "en-US"), None);
+        assert_eq!(date_format("03-10/2024", "en-US"), None);
+
+        assert_eq!(english_ordinal("21st"), Some("21"));
+        assert_eq!(english_ordinal("11th"), Some("11"));
+        assert_eq!(english_ordinal("11st"), None);
+        assert_eq!(english_ordinal("th"), None);
+
+        assert_eq!(spelled_acronym("U.S").as_deref(), Some("US"));
+        assert_eq!(spelled_acronym("HTML").as_deref(), Some("HTML"));
+        assert_eq!(spelled_acronym("UNESCO"), None);
+        assert_eq!(spelled_acronym("I"), None);
+        assert_eq!(spelled_acronym("Paris"), None);
+
+        assert!(is_enumerator("3."));
+        assert!(is_enumerator("(b)"));
+        assert!(is_enumerator("iv)"));
+        assert!(!is_enumerator("Dr."));
+        assert!(!is_enumerator("1990."));
+    }
+}
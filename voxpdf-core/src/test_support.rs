@@ -0,0 +1,27 @@
+//! Paragraph factories shared by unit tests.
+
+use crate::models::{Paragraph, Rect, Word};
+
+/// A paragraph of `text` on page 0, with one 10-point word per
+/// space-separated piece
+pub(crate) fn paragraph(index: usize, text: &str) -> Paragraph {
+    let words = text
+        .split(' ')
+        .map(|w| Word::new(w, Rect::new(0.0, 0.0, 10.0, 10.0), 0, 10.0))
+        .collect();
+    Paragraph::new(index, text.to_string(), 0, words)
+}
+
+/// A one-line paragraph of `text` at `y` on `page`: one `size`-point box per
+/// whitespace-separated word, 40 points apart from x = 72
+pub(crate) fn line_paragraph(index: usize, page: u32, y: f32, size: f32, text: &str) -> Paragraph {
+    let words = text
+        .split_whitespace()
+        .enumerate()
+        .map(|(i, t)| {
+            let bounds = Rect::new(72.0 + i as f32 * 40.0, y, 30.0, size);
+            Word::new(t, bounds, page, size)
+        })
+        .collect();
+    Paragraph::new(index, text.to_string(), page, words)
+}
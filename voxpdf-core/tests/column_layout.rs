@@ -0,0 +1,123 @@
+use voxpdf_core::extraction::{
+    detect_paragraphs, extract_column_layout, extract_pages_parallel, extract_word_positions,
+    extract_word_positions_with_options, ExtractionOptions,
+};
+use voxpdf_core::PDFDocument;
+
+const FIXTURE: &str = "tests/fixtures/columns.pdf";
+
+/// Position of the first word of each "<name> column line N" line
+fn line_starts(words: &[voxpdf_core::Word], name: &str) -> Vec<usize> {
+    words
+        .iter()
+        .enumerate()
+        .filter(|(_, w)| w.text == name)
+        .map(|(i, _)| i)
+        .collect()
+}
+
+#[test]
+fn test_detects_column_counts() {
+    let doc = PDFDocument::open(FIXTURE).unwrap();
+
+    assert_eq!(extract_column_layout(&doc, 0).unwrap().column_count, 2);
+    assert_eq!(extract_column_layout(&doc, 1).unwrap().column_count, 3);
+}
+
+#[test]
+fn test_two_column_words_in_reading_order() {
+    let doc = PDFDocument::open(FIXTURE).unwrap();
+    let words = extract_word_positions(&doc, 0).unwrap();
+
+    assert_eq!(words[0].text, "A", "title comes first");
+
+    let left = line_starts(&words, "left");
+    let right = line_starts(&words, "right");
+    assert_eq!(left.len(), 8);
+    assert_eq!(right.len(), 8);
+    assert!(
+        left.iter().max() < right.iter().min(),
+        "whole left column should precede the right column"
+    );
+}
+
+#[test]
+fn test_three_column_words_in_reading_order() {
+    let doc = PDFDocument::open(FIXTURE).unwrap();
+    let words = extract_word_positions(&doc, 1).unwrap();
+
+    let first = line_starts(&words, "first");
+    let second = line_starts(&words, "second");
+    let third = line_starts(&words, "third");
+    assert!(first.iter().max() < second.iter().min());
+    assert!(second.iter().max() < third.iter().min());
+}
+
+#[test]
+fn test_layout_regions_index_extracted_words() {
+    let doc = PDFDocument::open(FIXTURE).unwrap();
+    let words = extract_word_positions(&doc, 0).unwrap();
+    let layout = extract_column_layout(&doc, 0).unwrap();
+
+    assert_eq!(
+        layout.reading_order().collect::<Vec<_>>(),
+        (0..words.len()).collect::<Vec<_>>()
+    );
+}
+
+#[test]
+fn test_paragraphs_do_not_interleave_columns() {
+    let doc = PDFDocument::open(FIXTURE).unwrap();
+    let words = extract_word_positions(&doc, 0).unwrap();
+    let text = detect_paragraphs(words)
+        .iter()
+        .map(|p| p.text.clone())
+        .collect::<Vec<_>>()
+        .join(" ");
+
+    // Consecutive lines of one column read as one run of text
+    assert!(
+        text.contains("left column line 1 left column line 2"),
+        "{}",
+        text
+    );
+    assert!(
+        text.contains("right column line 7 right column line 8"),
+        "{}",
+        text
+    );
+
+    let last_left = text.find("left column line 8").unwrap();
+    let first_right = text.find("right column line 1").unwrap();
+    assert!(last_left < first_right);
+}
+
+#[test]
+fn test_parallel_results_carry_layout() {
+    let doc = PDFDocument::open(FIXTURE).unwrap();
+    let results = extract_pages_parallel(&doc, vec![0, 1]).unwrap();
+
+    assert_eq!(results[0].layout.column_count, 2);
+    assert_eq!(results[1].layout.column_count, 3);
+    assert_eq!(results[1].layout.page_number, 1);
+}
+
+#[test]
+fn test_column_detection_can_be_disabled() {
+    let doc = PDFDocument::open(FIXTURE).unwrap();
+    let options = ExtractionOptions {
+        detect_columns: false,
+        ..Default::default()
+    };
+    let words = extract_word_positions_with_options(&doc, 0, &options).unwrap();
+
+    // Stream order interleaves the columns
+    let left = line_starts(&words, "left");
+    let right = line_starts(&words, "right");
+    assert!(left.iter().max() > right.iter().min());
+}
+
+#[test]
+fn test_column_detection_is_on_by_default() {
+    assert!(ExtractionOptions::default().detect_columns);
+}
@@ -0,0 +1,79 @@
+use voxpdf_core::extraction::{
+    detect_paragraphs_with_options, detect_profile, extract_pages_parallel, extract_word_positions,
+    extract_word_positions_with_options, ExtractionEvent, ExtractionOptions, ExtractionProfile,
+    StreamingExtractor,
+};
+use voxpdf_core::PDFDocument;
+
+fn wide_options() -> ExtractionOptions {
+    ExtractionOptions {
+        word_gap_space_ratio: 0.8,
+        paragraph_gap_ratio: 4.0,
+        ..Default::default()
+    }
+}
+
+#[test]
+fn test_document_options_are_used_by_default() {
+    let mut doc = PDFDocument::open("tests/fixtures/simple.pdf").unwrap();
+    assert_eq!(doc.extraction_options(), &ExtractionOptions::default());
+
+    doc.set_extraction_options(wide_options());
+    assert_eq!(doc.extraction_options(), &wide_options());
+
+    let implicit = extract_word_positions(&doc, 0).unwrap();
+    let explicit = extract_word_positions_with_options(&doc, 0, &wide_options()).unwrap();
+    assert_eq!(implicit, explicit);
+}
+
+#[test]
+fn test_parallel_extraction_keeps_document_options() {
+    let mut doc = PDFDocument::open("tests/fixtures/simple.pdf").unwrap();
+    doc.set_extraction_options(wide_options());
+
+    let expected_words = extract_word_positions_with_options(&doc, 0, &wide_options()).unwrap();
+    let expected_paragraphs =
+        detect_paragraphs_with_options(expected_words.clone(), &wide_options());
+
+    // Worker threads re-open the document; the options must travel with it
+    let results = extract_pages_parallel(&doc, vec![0]).unwrap();
+    assert_eq!(results[0].words, expected_words);
+    assert_eq!(results[0].paragraphs.len(), expected_paragraphs.len());
+}
+
+#[test]
+fn test_streaming_extractor_with_options() {
+    let extractor = StreamingExtractor::new().with_options(wide_options());
+    extractor.extract_async("tests/fixtures/simple.pdf", 0, 0);
+
+    let mut pages = 0;
+    while let Some(event) = extractor.receive() {
+        match event {
+            ExtractionEvent::PageComplete { paragraphs, .. } => {
+                assert!(!paragraphs.is_empty());
+                pages += 1;
+            }
+            ExtractionEvent::Complete => break,
+            ExtractionEvent::Error { error, .. } => panic!("Unexpected error: {}", error),
+        }
+    }
+
+    assert_eq!(pages, 1);
+}
+
+#[test]
+fn test_detect_profile_on_sparse_landscape_pages() {
+    // Two of three pages display rotated (landscape) with a single word each
+    let doc = PDFDocument::open("tests/fixtures/rotated.pdf").unwrap();
+    let detected = detect_profile(&doc).unwrap();
+
+    assert_eq!(detected.profile, ExtractionProfile::Slides);
+    assert!(detected.confidence > 0.0 && detected.confidence <= 1.0);
+}
+
+#[test]
+fn test_profile_options_apply_to_document() {
+    let mut doc = PDFDocument::open("tests/fixtures/simple.pdf").unwrap();
+    doc.set_extraction_options(ExtractionProfile::Legal.options());
+    assert!(!doc.extraction_options().dehyphenate);
+}
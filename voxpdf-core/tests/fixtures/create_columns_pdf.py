@@ -0,0 +1,68 @@
+#!/usr/bin/env python3
+"""
+Create a PDF with multi-column pages for reading-order tests.
+
+Writes the PDF by hand (no reportlab needed). Column lines are drawn
+interleaved (left line 1, right line 1, left line 2, ...) so that content
+stream order is NOT reading order.
+
+Page 0: full-width 18pt title, then two 10pt columns at x=72 and x=322
+Page 1: three 10pt columns at x=72, x=252 and x=432
+"""
+
+PDF_PATH = "columns.pdf"
+LINES = 8
+
+
+def stream(data):
+    return b"<< /Length " + str(len(data)).encode() + b" >>\nstream\n" + data + b"\nendstream"
+
+
+def text(x, y, size, s):
+    return b"BT /F1 %d Tf %d %d Td (%s) Tj ET\n" % (size, x, y, s.encode())
+
+
+def columns_page(xs, names, title=None):
+    out = b""
+    top = 700
+    if title:
+        out += text(72, 740, 18, title)
+    for line in range(LINES):
+        y = top - line * 14
+        for x, name in zip(xs, names):
+            out += text(x, y, 10, "%s column line %d" % (name, line + 1))
+    return out
+
+
+page0 = columns_page([72, 322], ["left", "right"], title="A Two Column Layout Test For Reading Order")
+page1 = columns_page([72, 252, 432], ["first", "second", "third"])
+
+objects = [
+    b"<< /Type /Catalog /Pages 2 0 R >>",
+    b"<< /Type /Pages /Kids [3 0 R 4 0 R] /Count 2 /MediaBox [0 0 612 792] "
+    b"/Resources << /Font << /F1 7 0 R >> >> >>",
+    b"<< /Type /Page /Parent 2 0 R /Contents 5 0 R >>",
+    b"<< /Type /Page /Parent 2 0 R /Contents 6 0 R >>",
+    stream(page0),
+    stream(page1),
+    b"<< /Type /Font /Subtype /Type1 /BaseFont /Helvetica >>",
+]
+
+out = bytearray(b"%PDF-1.4\n%\xe2\xe3\xcf\xd3\n")
+offsets = []
+for num, body in enumerate(objects, start=1):
+    offsets.append(len(out))
+    out += str(num).encode() + b" 0 obj\n" + body + b"\nendobj\n"
+
+xref_offset = len(out)
+out += b"xref\n0 " + str(len(objects) + 1).encode() + b"\n"
+out += b"0000000000 65535 f \n"
+for offset in offsets:
+    out += b"%010d 00000 n \n" % offset
+out += b"trailer\n<< /Size " + str(len(objects) + 1).encode() + b" /Root 1 0 R >>\n"
+out += b"startxref\n" + str(xref_offset).encode() + b"\n%%EOF\n"
+
+with open(PDF_PATH, "wb") as f:
+    f.write(out)
+
+print(f"Created {PDF_PATH}")
@@ -0,0 +1,83 @@
+#!/usr/bin/env python3
+"""
+Create a PDF whose paragraphs run on across page breaks.
+
+Writes the PDF by hand (no reportlab needed).
+
+Page 0: a complete paragraph, then one that breaks off mid-sentence
+Page 1: the end of that sentence, then a paragraph with non-ASCII text
+        ("café") ending in "exam-"
+Page 2: "ined" finishing the hyphenated word, ending with a full stop
+Page 3: a fresh paragraph that must not be joined to page 2
+"""
+
+PDF_PATH = "page-continuation.pdf"
+
+PAGES = [
+    [
+        ["The opening paragraph is complete."],
+        ["This sentence starts on the first page and", "keeps going until the page runs out, so the"],
+    ],
+    [
+        ["reader hears it without a pause."],
+        ["The café menu's last paragraph ends with a word that was carefully exam-"],
+    ],
+    [
+        ["ined by the typesetter."],
+    ],
+    [
+        ["A fresh paragraph starts the final page."],
+    ],
+]
+
+
+def stream(data):
+    return b"<< /Length " + str(len(data)).encode() + b" >>\nstream\n" + data + b"\nendstream"
+
+
+def page_content(paragraphs):
+    out = b""
+    y = 700
+    for lines in paragraphs:
+        for line in lines:
+            out += b"BT /F1 11 Tf 72 %d Td (%s) Tj ET\n" % (y, line.encode("cp1252"))
+            y -= 14
+        y -= 28
+    return out
+
+
+count = len(PAGES)
+font_num = 3 + 2 * count
+objects = [
+    b"<< /Type /Catalog /Pages 2 0 R >>",
+    b"<< /Type /Pages /Kids ["
+    + b" ".join(b"%d 0 R" % (3 + i) for i in range(count))
+    + b"] /Count %d /MediaBox [0 0 612 792] " % count
+    + b"/Resources << /Font << /F1 %d 0 R >> >> >>" % font_num,
+]
+for i in range(count):
+    objects.append(b"<< /Type /Page /Parent 2 0 R /Contents %d 0 R >>" % (3 + count + i))
+for paragraphs in PAGES:
+    objects.append(stream(page_content(paragraphs)))
+objects.append(
+    b"<< /Type /Font /Subtype /Type1 /BaseFont /Helvetica /Encoding /WinAnsiEncoding >>"
+)
+
+out = bytearray(b"%PDF-1.4\n%\xe2\xe3\xcf\xd3\n")
+offsets = []
+for num, body in enumerate(objects, start=1):
+    offsets.append(len(out))
+    out += str(num).encode() + b" 0 obj\n" + body + b"\nendobj\n"
+
+xref_offset = len(out)
+out += b"xref\n0 " + str(len(objects) + 1).encode() + b"\n"
+out += b"0000000000 65535 f \n"
+for offset in offsets:
+    out += b"%010d 00000 n \n" % offset
+out += b"trailer\n<< /Size " + str(len(objects) + 1).encode() + b" /Root 1 0 R >>\n"
+out += b"startxref\n" + str(xref_offset).encode() + b"\n%%EOF\n"
+
+with open(PDF_PATH, "wb") as f:
+    f.write(out)
+
+print(f"Created {PDF_PATH}")
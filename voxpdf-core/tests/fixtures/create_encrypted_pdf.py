@@ -0,0 +1,108 @@
+#!/usr/bin/env python3
+"""
+Create a password-protected PDF for testing encrypted document support.
+
+Writes the PDF by hand (no reportlab/pypdf needed) using the Standard
+Security Handler, revision 2 (40-bit RC4), which both MuPDF and lopdf can
+decrypt.
+
+User password: "secret"
+Owner password: "owner"
+"""
+
+import hashlib
+import struct
+
+PDF_PATH = "encrypted.pdf"
+USER_PASSWORD = b"secret"
+OWNER_PASSWORD = b"owner"
+PERMISSIONS = -4  # all permissions granted
+FILE_ID = bytes.fromhex("5665785044462d656e637279707465")  # "VoxPDF-encrypted"
+
+PAD = bytes.fromhex(
+    "28bf4e5e4e758a4164004e56fffa0108"
+    "2e2e00b6d0683e802f0ca9fe6453697a"
+)
+
+
+def rc4(key, data):
+    s = list(range(256))
+    j = 0
+    for i in range(256):
+        j = (j + s[i] + key[i % len(key)]) % 256
+        s[i], s[j] = s[j], s[i]
+    out = bytearray()
+    i = j = 0
+    for byte in data:
+        i = (i + 1) % 256
+        j = (j + s[i]) % 256
+        s[i], s[j] = s[j], s[i]
+        out.append(byte ^ s[(s[i] + s[j]) % 256])
+    return bytes(out)
+
+
+def pad(password):
+    return (password + PAD)[:32]
+
+
+# Algorithm 3: owner password entry
+owner_key = hashlib.md5(pad(OWNER_PASSWORD)).digest()[:5]
+O = rc4(owner_key, pad(USER_PASSWORD))
+
+# Algorithm 2: file encryption key
+key = hashlib.md5(
+    pad(USER_PASSWORD) + O + struct.pack("<i", PERMISSIONS) + FILE_ID
+).digest()[:5]
+
+# Algorithm 4: user password entry (revision 2)
+U = rc4(key, PAD)
+
+
+def object_key(num, gen=0):
+    material = key + struct.pack("<i", num)[:3] + struct.pack("<i", gen)[:2]
+    return hashlib.md5(material).digest()[: min(len(key) + 5, 16)]
+
+
+def hex_string(data):
+    return b"<" + data.hex().encode() + b">"
+
+
+content = b"BT /F1 12 Tf 100 592 Td (Hello) Tj 60 0 Td (World) Tj ET"
+encrypted_content = rc4(object_key(4), content)
+
+objects = [
+    b"<< /Type /Catalog /Pages 2 0 R >>",
+    b"<< /Type /Pages /Kids [3 0 R] /Count 1 >>",
+    b"<< /Type /Page /Parent 2 0 R /MediaBox [0 0 612 792] "
+    b"/Resources << /Font << /F1 5 0 R >> >> /Contents 4 0 R >>",
+    b"<< /Length " + str(len(encrypted_content)).encode() + b" >>\nstream\n"
+    + encrypted_content + b"\nendstream",
+    b"<< /Type /Font /Subtype /Type1 /BaseFont /Helvetica >>",
+    b"<< /Filter /Standard /V 1 /R 2 /Length 40 /O " + hex_string(O)
+    + b" /U " + hex_string(U) + b" /P " + str(PERMISSIONS).encode() + b" >>",
+]
+
+out = bytearray(b"%PDF-1.4\n%\xe2\xe3\xcf\xd3\n")
+offsets = []
+for num, body in enumerate(objects, start=1):
+    offsets.append(len(out))
+    out += str(num).encode() + b" 0 obj\n" + body + b"\nendobj\n"
+
+xref_offset = len(out)
+out += b"xref\n0 " + str(len(objects) + 1).encode() + b"\n"
+out += b"0000000000 65535 f \n"
+for offset in offsets:
+    out += b"%010d 00000 n \n" % offset
+out += (
+    b"trailer\n<< /Size " + str(len(objects) + 1).encode()
+    + b" /Root 1 0 R /Encrypt 6 0 R /ID [" + hex_string(FILE_ID)
+    + hex_string(FILE_ID) + b"] >>\n"
+)
+out += b"startxref\n" + str(xref_offset).encode() + b"\n%%EOF\n"
+
+with open(PDF_PATH, "wb") as f:
+    f.write(out)
+
+print(f"Created {PDF_PATH}")
+print(f"  User password: {USER_PASSWORD.decode()}")
+print(f"  Owner password: {OWNER_PASSWORD.decode()}")
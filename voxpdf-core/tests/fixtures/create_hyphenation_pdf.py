@@ -0,0 +1,63 @@
+#!/usr/bin/env python3
+"""
+Create a PDF with words hyphenated at line ends.
+
+Writes the PDF by hand (no reportlab needed). The font encoding maps byte
+173 to /sfthyphen so the third line ends in a real soft hyphen (U+00AD).
+
+One 11pt paragraph of four lines:
+    The results of the survey were carefully exam-
+    ined by a reviewer whose work is well-
+    known, and the figures were all re<soft hyphen>
+    checked before the so- called final version.
+"""
+
+PDF_PATH = "hyphenation.pdf"
+
+LINES = [
+    b"The results of the survey were carefully exam-",
+    b"ined by a reviewer whose work is well-",
+    b"known, and the figures were all re\xad",
+    b"checked before the so- called final version.",
+]
+
+
+def stream(data):
+    return b"<< /Length " + str(len(data)).encode() + b" >>\nstream\n" + data + b"\nendstream"
+
+
+content = b""
+y = 700
+for line in LINES:
+    content += b"BT /F1 11 Tf 72 %d Td (%s) Tj ET\n" % (y, line)
+    y -= 13
+
+objects = [
+    b"<< /Type /Catalog /Pages 2 0 R >>",
+    b"<< /Type /Pages /Kids [3 0 R] /Count 1 /MediaBox [0 0 612 792] >>",
+    b"<< /Type /Page /Parent 2 0 R /Contents 4 0 R "
+    b"/Resources << /Font << /F1 5 0 R >> >> >>",
+    stream(content),
+    b"<< /Type /Font /Subtype /Type1 /BaseFont /Helvetica "
+    b"/Encoding << /Type /Encoding /BaseEncoding /WinAnsiEncoding "
+    b"/Differences [173 /sfthyphen] >> >>",
+]
+
+out = bytearray(b"%PDF-1.4\n%\xe2\xe3\xcf\xd3\n")
+offsets = []
+for num, body in enumerate(objects, start=1):
+    offsets.append(len(out))
+    out += str(num).encode() + b" 0 obj\n" + body + b"\nendobj\n"
+
+xref_offset = len(out)
+out += b"xref\n0 " + str(len(objects) + 1).encode() + b"\n"
+out += b"0000000000 65535 f \n"
+for offset in offsets:
+    out += b"%010d 00000 n \n" % offset
+out += b"trailer\n<< /Size " + str(len(objects) + 1).encode() + b" /Root 1 0 R >>\n"
+out += b"startxref\n" + str(xref_offset).encode() + b"\n%%EOF\n"
+
+with open(PDF_PATH, "wb") as f:
+    f.write(out)
+
+print(f"Created {PDF_PATH}")
@@ -0,0 +1,87 @@
+#!/usr/bin/env python3
+"""
+Create a PDF with numbered headings but no outline.
+
+Writes the PDF by hand (no reportlab needed).
+
+Page 0: "1 Introduction" (18pt), body text, "1.1 Scope" (14pt), body text
+Page 1: body text, "1.2 Terminology" (14pt), body text
+Page 2: "2 Methods" (18pt), body text, a numbered 11pt sentence that is not
+        a heading, body text
+"""
+
+PDF_PATH = "inferred-headings.pdf"
+
+# (font size, baseline y, text)
+PAGES = [
+    [
+        (18, 720, "1 Introduction"),
+        (11, 690, "This report describes how the survey was planned and carried out over"),
+        (11, 676, "the course of a single season, and what the team learned from the"),
+        (11, 662, "results that came back from every site that took part in it."),
+        (14, 620, "1.1 Scope"),
+        (11, 595, "Only the coastal sites are covered here, because the inland sites were"),
+        (11, 581, "surveyed by another team using a different method altogether."),
+    ],
+    [
+        (11, 720, "The boundary between coastal and inland sites follows the regional"),
+        (11, 706, "map that every team agreed to use before the season began."),
+        (14, 670, "1.2 Terminology"),
+        (11, 645, "A site is a single location visited once, and a visit is one day of"),
+        (11, 631, "counting carried out by two people working together at that site."),
+    ],
+    [
+        (18, 720, "2 Methods"),
+        (11, 690, "Every count followed the same written protocol, which is attached to"),
+        (11, 676, "this report, so that results from different teams can be compared."),
+        (11, 640, "3 samples were discarded."),
+        (11, 604, "The remaining samples were checked twice by different people before"),
+        (11, 590, "they were entered into the shared spreadsheet used for the analysis."),
+    ],
+]
+
+
+def stream(data):
+    return b"<< /Length " + str(len(data)).encode() + b" >>\nstream\n" + data + b"\nendstream"
+
+
+def page_content(lines):
+    out = b""
+    for size, y, text in lines:
+        out += b"BT /F1 %d Tf 72 %d Td (%s) Tj ET\n" % (size, y, text.encode())
+    return out
+
+
+count = len(PAGES)
+font_num = 3 + 2 * count
+objects = [
+    b"<< /Type /Catalog /Pages 2 0 R >>",
+    b"<< /Type /Pages /Kids ["
+    + b" ".join(b"%d 0 R" % (3 + i) for i in range(count))
+    + b"] /Count %d /MediaBox [0 0 612 792] " % count
+    + b"/Resources << /Font << /F1 %d 0 R >> >> >>" % font_num,
+]
+for i in range(count):
+    objects.append(b"<< /Type /Page /Parent 2 0 R /Contents %d 0 R >>" % (3 + count + i))
+for lines in PAGES:
+    objects.append(stream(page_content(lines)))
+objects.append(b"<< /Type /Font /Subtype /Type1 /BaseFont /Helvetica >>")
+
+out = bytearray(b"%PDF-1.4\n%\xe2\xe3\xcf\xd3\n")
+offsets = []
+for num, body in enumerate(objects, start=1):
+    offsets.append(len(out))
+    out += str(num).encode() + b" 0 obj\n" + body + b"\nendobj\n"
+
+xref_offset = len(out)
+out += b"xref\n0 " + str(len(objects) + 1).encode() + b"\n"
+out += b"0000000000 65535 f \n"
+for offset in offsets:
+    out += b"%010d 00000 n \n" % offset
+out += b"trailer\n<< /Size " + str(len(objects) + 1).encode() + b" /Root 1 0 R >>\n"
+out += b"startxref\n" + str(xref_offset).encode() + b"\n%%EOF\n"
+
+with open(PDF_PATH, "wb") as f:
+    f.write(out)
+
+print(f"Created {PDF_PATH}")
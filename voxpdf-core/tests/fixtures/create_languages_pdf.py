@@ -0,0 +1,92 @@
+#!/usr/bin/env python3
+"""
+Create a tagged PDF whose paragraphs are in different languages.
+
+Writes the PDF by hand (no reportlab needed).
+
+- Catalog `/Lang (en-US)` and a structure tree
+- Five two-line 12pt Helvetica paragraphs (WinAnsiEncoding), 60pt apart:
+  1. English, marked content MCID 0 of a `/P` element without `/Lang`
+  2. French, MCID 1 of a `/P` element with `/Lang (fr-FR)`
+  3. German, inside `/Span <</Lang (de-DE)>> BDC`
+  4. Spanish, untagged
+  5. English, untagged, ending with a French sentence
+"""
+
+PDF_PATH = "languages.pdf"
+
+# WinAnsi: \351 eacute, \340 agrave, \366 odieresis, \337 germandbls,
+# \361 ntilde, \355 iacute
+PARAGRAPHS = [
+    (
+        b"/P <</MCID 0>> BDC",
+        rb"The museum opens at nine in the morning and closes",
+        rb"at six in the evening, except on public holidays.",
+    ),
+    (
+        b"/P <</MCID 1>> BDC",
+        rb"Le mus\351e ouvre \340 neuf heures du matin et ferme",
+        rb"\340 dix-huit heures, sauf les jours f\351ri\351s.",
+    ),
+    (
+        b"/Span <</Lang (de-DE)>> BDC",
+        rb"Das Museum \366ffnet um neun Uhr morgens und schlie\337t",
+        rb"um achtzehn Uhr, au\337er an Feiertagen.",
+    ),
+    (
+        None,
+        rb"El museo abre a las nueve de la ma\361ana y cierra a",
+        rb"las seis de la tarde, excepto los d\355as festivos.",
+    ),
+    (
+        None,
+        rb"Visitors often ask about the sign that still hangs on the",
+        rb"door of the old gallery. Nous sommes ferm\351s pour les vacances.",
+    ),
+]
+
+CONTENT = b""
+for i, (marked, first, second) in enumerate(PARAGRAPHS):
+    y = 700 - 60 * i
+    text = (
+        b"BT /F1 12 Tf 14 TL 72 " + str(y).encode() + b" Td\n"
+        b"(" + first + b") Tj T* (" + second + b") Tj\nET\n"
+    )
+    CONTENT += marked + b"\n" + text + b"EMC\n" if marked else text
+
+
+def stream(data):
+    return b"<< /Length " + str(len(data)).encode() + b" >>\nstream\n" + data + b"\nendstream"
+
+
+objects = [
+    b"<< /Type /Catalog /Pages 2 0 R /Lang (en-US) /MarkInfo << /Marked true >> "
+    b"/StructTreeRoot 6 0 R >>",
+    b"<< /Type /Pages /Kids [3 0 R] /Count 1 /MediaBox [0 0 612 792] "
+    b"/Resources << /Font << /F1 5 0 R >> >> >>",
+    b"<< /Type /Page /Parent 2 0 R /Contents 4 0 R /StructParents 0 >>",
+    stream(CONTENT),
+    b"<< /Type /Font /Subtype /Type1 /BaseFont /Helvetica /Encoding /WinAnsiEncoding >>",
+    b"<< /Type /StructTreeRoot /K [7 0 R 8 0 R] /ParentTree << /Nums [0 [7 0 R 8 0 R]] >> >>",
+    b"<< /Type /StructElem /S /P /P 6 0 R /Pg 3 0 R /K 0 >>",
+    b"<< /Type /StructElem /S /P /P 6 0 R /Pg 3 0 R /Lang (fr-FR) /K 1 >>",
+]
+
+out = bytearray(b"%PDF-1.4\n%\xe2\xe3\xcf\xd3\n")
+offsets = []
+for num, body in enumerate(objects, start=1):
+    offsets.append(len(out))
+    out += str(num).encode() + b" 0 obj\n" + body + b"\nendobj\n"
+
+xref_offset = len(out)
+out += b"xref\n0 " + str(len(objects) + 1).encode() + b"\n"
+out += b"0000000000 65535 f \n"
+for offset in offsets:
+    out += b"%010d 00000 n \n" % offset
+out += b"trailer\n<< /Size " + str(len(objects) + 1).encode() + b" /Root 1 0 R >>\n"
+out += b"startxref\n" + str(xref_offset).encode() + b"\n%%EOF\n"
+
+with open(PDF_PATH, "wb") as f:
+    f.write(out)
+
+print(f"Created {PDF_PATH}")
@@ -0,0 +1,77 @@
+#!/usr/bin/env python3
+"""
+Create a tagged PDF whose lines change language part way along.
+
+Writes the PDF by hand (no reportlab needed).
+
+- Catalog `/Lang (en-US)`, one page
+- 12pt Helvetica (WinAnsiEncoding) with its `/Widths`, so a reader can tell
+  where along the line each text-showing operator starts
+- Two one-line paragraphs, 60pt apart, each shown by two `Tj` operators
+  on the same line:
+  1. Untagged English, then `/Span <</Lang (fr-FR)>>` around one word
+  2. `/Span <</Lang (de-DE)>>` around five words, then
+     `/Span <</Lang (en-GB)>>` around three
+"""
+
+PDF_PATH = "mixed-languages.pdf"
+
+# Helvetica advance widths (thousandths of an em) for printable ASCII;
+# the accented letters used here are all 556 wide
+ASCII_WIDTHS = [
+    278, 278, 355, 556, 556, 889, 667, 191, 333, 333, 389, 584, 278, 333, 278, 278,
+    556, 556, 556, 556, 556, 556, 556, 556, 556, 556, 278, 278, 584, 584, 584, 556,
+    1015, 667, 667, 722, 722, 667, 611, 778, 722, 278, 500, 667, 556, 833, 722, 778,
+    667, 778, 722, 667, 611, 722, 667, 944, 667, 667, 611, 278, 278, 278, 469, 556,
+    333, 556, 556, 500, 556, 556, 278, 556, 556, 222, 222, 500, 222, 833, 556, 556,
+    556, 556, 333, 500, 278, 556, 500, 722, 500, 500, 500, 334, 260, 334, 584,
+]
+WIDTHS = ASCII_WIDTHS + [556] * (256 - 32 - len(ASCII_WIDTHS))
+
+# WinAnsi: \351 eacute
+CONTENT = (
+    b"BT /F1 12 Tf 72 700 Td\n"
+    b"(The sign on the old gallery door still says ) Tj\n"
+    b"/Span <</Lang (fr-FR)>> BDC (ferm\\351.) Tj EMC\n"
+    b"ET\n"
+    b"BT /F1 12 Tf 72 640 Td\n"
+    b"/Span <</Lang (de-DE)>> BDC (Die alte Galerie am Marktplatz ) Tj EMC\n"
+    b"/Span <</Lang (en-GB)>> BDC (is now closed.) Tj EMC\n"
+    b"ET\n"
+)
+
+
+def stream(data):
+    return b"<< /Length " + str(len(data)).encode() + b" >>\nstream\n" + data + b"\nendstream"
+
+
+objects = [
+    b"<< /Type /Catalog /Pages 2 0 R /Lang (en-US) /MarkInfo << /Marked true >> >>",
+    b"<< /Type /Pages /Kids [3 0 R] /Count 1 /MediaBox [0 0 612 792] "
+    b"/Resources << /Font << /F1 5 0 R >> >> >>",
+    b"<< /Type /Page /Parent 2 0 R /Contents 4 0 R >>",
+    stream(CONTENT),
+    b"<< /Type /Font /Subtype /Type1 /BaseFont /Helvetica /Encoding /WinAnsiEncoding "
+    b"/FirstChar 32 /LastChar 255 /Widths ["
+    + b" ".join(str(w).encode() for w in WIDTHS)
+    + b"] >>",
+]
+
+out = bytearray(b"%PDF-1.4\n%\xe2\xe3\xcf\xd3\n")
+offsets = []
+for num, body in enumerate(objects, start=1):
+    offsets.append(len(out))
+    out += str(num).encode() + b" 0 obj\n" + body + b"\nendobj\n"
+
+xref_offset = len(out)
+out += b"xref\n0 " + str(len(objects) + 1).encode() + b"\n"
+out += b"0000000000 65535 f \n"
+for offset in offsets:
+    out += b"%010d 00000 n \n" % offset
+out += b"trailer\n<< /Size " + str(len(objects) + 1).encode() + b" /Root 1 0 R >>\n"
+out += b"startxref\n" + str(xref_offset).encode() + b"\n%%EOF\n"
+
+with open(PDF_PATH, "wb") as f:
+    f.write(out)
+
+print(f"Created {PDF_PATH}")
@@ -0,0 +1,55 @@
+#!/usr/bin/env python3
+"""
+Create a one-page PDF whose outline nests 100 levels deep, one item per
+level, each item the only child of the one before.
+
+Writes the PDF by hand (no reportlab needed).
+"""
+
+PDF_PATH = "outline-deep.pdf"
+DEPTH = 100
+
+
+def stream(data):
+    return b"<< /Length " + str(len(data)).encode() + b" >>\nstream\n" + data + b"\nendstream"
+
+
+outlines_num = 6
+item_num = lambda level: outlines_num + 1 + level
+
+objects = [
+    b"<< /Type /Catalog /Pages 2 0 R /Outlines %d 0 R >>" % outlines_num,
+    b"<< /Type /Pages /Kids [3 0 R] /Count 1 /MediaBox [0 0 612 792] "
+    b"/Resources << /Font << /F1 5 0 R >> >> >>",
+    b"<< /Type /Page /Parent 2 0 R /Contents 4 0 R >>",
+    stream(b"BT /F1 18 Tf 72 720 Td (Deep outline) Tj ET"),
+    b"<< /Type /Font /Subtype /Type1 /BaseFont /Helvetica >>",
+    b"<< /Type /Outlines /First %d 0 R /Last %d 0 R /Count 1 >>" % (item_num(0), item_num(0)),
+]
+for level in range(DEPTH):
+    parent = outlines_num if level == 0 else item_num(level - 1)
+    children = b""
+    if level + 1 < DEPTH:
+        children = b"/First %d 0 R /Last %d 0 R " % (item_num(level + 1), item_num(level + 1))
+    objects.append(
+        b"<< /Title (Level %d) /Parent %d 0 R %s/Dest [3 0 R /Fit] >>" % (level, parent, children)
+    )
+
+out = bytearray(b"%PDF-1.4\n%\xe2\xe3\xcf\xd3\n")
+offsets = []
+for num, body in enumerate(objects, start=1):
+    offsets.append(len(out))
+    out += str(num).encode() + b" 0 obj\n" + body + b"\nendobj\n"
+
+xref_offset = len(out)
+out += b"xref\n0 " + str(len(objects) + 1).encode() + b"\n"
+out += b"0000000000 65535 f \n"
+for offset in offsets:
+    out += b"%010d 00000 n \n" % offset
+out += b"trailer\n<< /Size " + str(len(objects) + 1).encode() + b" /Root 1 0 R >>\n"
+out += b"startxref\n" + str(xref_offset).encode() + b"\n%%EOF\n"
+
+with open(PDF_PATH, "wb") as f:
+    f.write(out)
+
+print(f"Created {PDF_PATH}")
@@ -0,0 +1,108 @@
+#!/usr/bin/env python3
+"""
+Create a PDF whose outline MuPDF rejects, so it is read by the lopdf
+fallback, and which exercises every kind of title and destination the
+fallback handles.
+
+Writes the PDF by hand (no reportlab needed).
+
+Outline (pages are 0-indexed):
+- "Введение": UTF-16BE title with a BOM, /XYZ on page 0, open with one child
+  - "Café — the “first” steps": PDFDocEncoding title, /FitH on page 0
+- "Named by /Dests": /Dest is a name looked up in the catalog /Dests
+  dictionary (/XYZ 72 700 on page 1), closed with one child
+  - "Named by the /Names tree": /Dest is a string looked up in a two-level
+    /Names /Dests tree, whose value is a dictionary with /D (page 2)
+- "GoTo action": /A is a reference to a GoTo action (/FitH 500 on page 2)
+- "Named GoTo action": /A is a GoTo action whose /D is a string (page 3)
+- "Missing destination": /Dest names nothing; its /Next points back at the
+  first item and its /First at itself, a cycle MuPDF refuses to load
+"""
+
+PDF_PATH = "outline-fallback.pdf"
+
+PAGES = [
+    "Introduction",
+    "Chapter two",
+    "Section two point one",
+    "Chapter three",
+]
+
+
+def stream(data):
+    return b"<< /Length " + str(len(data)).encode() + b" >>\nstream\n" + data + b"\nendstream"
+
+
+def utf16_hex(text):
+    return b"<FEFF" + text.encode("utf-16-be").hex().upper().encode() + b">"
+
+
+count = len(PAGES)
+page_num = lambda i: 3 + i
+content_num = lambda i: 3 + count + i
+font_num = 3 + 2 * count
+outlines_num = font_num + 1
+# Outline items
+intro, cafe, named, tree, goto, named_goto, missing = range(outlines_num + 1, outlines_num + 8)
+# Destination structures
+dests_num = missing + 1
+names_root, names_leaf, tree_dest, goto_action = range(dests_num + 1, dests_num + 5)
+
+objects = [
+    b"<< /Type /Catalog /Pages 2 0 R /Outlines %d 0 R /Dests %d 0 R "
+    b"/Names << /Dests %d 0 R >> >>" % (outlines_num, dests_num, names_root),
+    b"<< /Type /Pages /Kids ["
+    + b" ".join(b"%d 0 R" % page_num(i) for i in range(count))
+    + b"] /Count %d /MediaBox [0 0 612 792] " % count
+    + b"/Resources << /Font << /F1 %d 0 R >> >> >>" % font_num,
+]
+for i in range(count):
+    objects.append(b"<< /Type /Page /Parent 2 0 R /Contents %d 0 R >>" % content_num(i))
+for text in PAGES:
+    objects.append(stream(b"BT /F1 18 Tf 72 720 Td (%s) Tj ET" % text.encode()))
+objects.append(b"<< /Type /Font /Subtype /Type1 /BaseFont /Helvetica >>")
+objects += [
+    b"<< /Type /Outlines /First %d 0 R /Last %d 0 R /Count 6 >>" % (intro, missing),
+    b"<< /Title %s /Parent %d 0 R /Next %d 0 R /First %d 0 R /Last %d 0 R /Count 1 "
+    b"/Dest [%d 0 R /XYZ 72 720 0] >>"
+    % (utf16_hex("Введение"), outlines_num, named, cafe, cafe, page_num(0)),
+    # PDFDocEncoding: 0xE9 é, 0x84 em dash, 0x8D/0x8E curly double quotes
+    b"<< /Title (Caf\xe9 \x84 the \x8dfirst\x8e steps) /Parent %d 0 R "
+    b"/Dest [%d 0 R /FitH 400] >>" % (intro, page_num(0)),
+    b"<< /Title (Named by /Dests) /Parent %d 0 R /Prev %d 0 R /Next %d 0 R "
+    b"/First %d 0 R /Last %d 0 R /Count -1 /Dest /chapter2 >>"
+    % (outlines_num, intro, goto, tree, tree),
+    b"<< /Title (Named by the /Names tree) /Parent %d 0 R /Dest (sec2.1) >>" % named,
+    b"<< /Title (GoTo action) /Parent %d 0 R /Prev %d 0 R /Next %d 0 R /A %d 0 R >>"
+    % (outlines_num, named, named_goto, goto_action),
+    b"<< /Title (Named GoTo action) /Parent %d 0 R /Prev %d 0 R /Next %d 0 R "
+    b"/A << /S /GoTo /D (chapter3) >> >>" % (outlines_num, goto, missing),
+    b"<< /Title (Missing destination) /Parent %d 0 R /Prev %d 0 R /Next %d 0 R "
+    b"/First %d 0 R /Last %d 0 R /Dest /nowhere >>"
+    % (outlines_num, named_goto, intro, missing, missing),
+    b"<< /chapter2 [%d 0 R /XYZ 72 700 null] >>" % page_num(1),
+    b"<< /Kids [%d 0 R] >>" % names_leaf,
+    b"<< /Limits [(chapter3) (sec2.1)] /Names [(chapter3) [%d 0 R /Fit] (sec2.1) %d 0 R] >>"
+    % (page_num(3), tree_dest),
+    b"<< /D [%d 0 R /XYZ 0 600 0] >>" % page_num(2),
+    b"<< /S /GoTo /D [%d 0 R /FitH 500] >>" % page_num(2),
+]
+
+out = bytearray(b"%PDF-1.4\n%\xe2\xe3\xcf\xd3\n")
+offsets = []
+for num, body in enumerate(objects, start=1):
+    offsets.append(len(out))
+    out += str(num).encode() + b" 0 obj\n" + body + b"\nendobj\n"
+
+xref_offset = len(out)
+out += b"xref\n0 " + str(len(objects) + 1).encode() + b"\n"
+out += b"0000000000 65535 f \n"
+for offset in offsets:
+    out += b"%010d 00000 n \n" % offset
+out += b"trailer\n<< /Size " + str(len(objects) + 1).encode() + b" /Root 1 0 R >>\n"
+out += b"startxref\n" + str(xref_offset).encode() + b"\n%%EOF\n"
+
+with open(PDF_PATH, "wb") as f:
+    f.write(out)
+
+print(f"Created {PDF_PATH}")
@@ -0,0 +1,63 @@
+#!/usr/bin/env python3
+"""
+Create a PDF with a /PageLabels number tree for testing page label lookup.
+
+Writes the PDF by hand (no reportlab needed). The number tree is split across
+/Kids so the tree walk is exercised, not just a flat /Nums array.
+
+Physical pages 0-2: i, ii, iii   (/S /r)
+Physical pages 3-5: 1, 2, 3      (/S /D)
+Physical pages 6-7: App-A, App-B (/S /A /P (App-))
+"""
+
+PDF_PATH = "page-labels.pdf"
+PAGE_COUNT = 8
+
+# Object numbers: 1 catalog, 2 pages, 3-4 label tree kids, 5 font,
+# then one page + one content stream per page
+FIRST_PAGE = 6
+
+
+def stream(data):
+    return b"<< /Length " + str(len(data)).encode() + b" >>\nstream\n" + data + b"\nendstream"
+
+
+page_refs = b" ".join(
+    str(FIRST_PAGE + 2 * i).encode() + b" 0 R" for i in range(PAGE_COUNT)
+)
+
+objects = [
+    b"<< /Type /Catalog /Pages 2 0 R /PageLabels << /Kids [3 0 R 4 0 R] >> >>",
+    b"<< /Type /Pages /Kids [" + page_refs + b"] /Count " + str(PAGE_COUNT).encode()
+    + b" /MediaBox [0 0 612 792] >>",
+    b"<< /Limits [0 0] /Nums [0 << /S /r >>] >>",
+    b"<< /Limits [3 6] /Nums [3 << /S /D >> 6 << /S /A /P (App-) >>] >>",
+    b"<< /Type /Font /Subtype /Type1 /BaseFont /Helvetica >>",
+]
+
+for i in range(PAGE_COUNT):
+    page_num = FIRST_PAGE + 2 * i
+    objects.append(
+        b"<< /Type /Page /Parent 2 0 R /Resources << /Font << /F1 5 0 R >> >> "
+        b"/Contents " + str(page_num + 1).encode() + b" 0 R >>"
+    )
+    objects.append(stream(b"BT /F1 12 Tf 100 700 Td (Physical page " + str(i).encode() + b") Tj ET"))
+
+out = bytearray(b"%PDF-1.4\n%\xe2\xe3\xcf\xd3\n")
+offsets = []
+for num, body in enumerate(objects, start=1):
+    offsets.append(len(out))
+    out += str(num).encode() + b" 0 obj\n" + body + b"\nendobj\n"
+
+xref_offset = len(out)
+out += b"xref\n0 " + str(len(objects) + 1).encode() + b"\n"
+out += b"0000000000 65535 f \n"
+for offset in offsets:
+    out += b"%010d 00000 n \n" % offset
+out += b"trailer\n<< /Size " + str(len(objects) + 1).encode() + b" /Root 1 0 R >>\n"
+out += b"startxref\n" + str(xref_offset).encode() + b"\n%%EOF\n"
+
+with open(PDF_PATH, "wb") as f:
+    f.write(out)
+
+print(f"Created {PDF_PATH}")
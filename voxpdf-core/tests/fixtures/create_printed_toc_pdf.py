@@ -0,0 +1,145 @@
+#!/usr/bin/env python3
+"""
+Create a PDF whose contents page is printed but which has no outline and no
+page labels.
+
+Writes the PDF by hand (no reportlab needed).
+
+Page 0: title page
+Page 1: "Contents" (18pt) and six rows with dotted leaders and right-aligned
+        page numbers; sections are indented and "1.2" wraps onto two lines
+Page 2: "Preface" (printed page iii)
+Page 3: "1 Getting Started" (printed page 1)
+Page 4: "1.1 Choosing the Right Tools" and "1.2 Packing for a Long Day in
+        Difficult Weather" (printed page 2)
+Page 5: "2 Field Work" (printed page 3)
+Page 6: "2.1 Keeping Notes" (printed page 4)
+"""
+
+PDF_PATH = "printed-toc.pdf"
+
+LEADER = " . . . . . . . . . ."
+BODY = [
+    [
+        "This guide grew out of notes kept by the survey teams over several",
+        "seasons, and it is meant to be read before the first day in the field.",
+    ],
+    [
+        "Every visit to a site was planned a week ahead and checked again on",
+        "the morning itself, so that nobody set out without the right kit.",
+    ],
+    [
+        "A good pair of boots matters more than any instrument, because most",
+        "of the day is spent walking between the counting points.",
+    ],
+    [
+        "Pack food and water for the whole day even when the site is close to",
+        "a town, since the weather on the coast can change within an hour.",
+    ],
+    [
+        "Counts start at the northern end of each site and move south, with",
+        "both observers keeping to the same pace along the shore.",
+    ],
+    [
+        "Notes are written in pencil on waterproof paper and copied into the",
+        "shared spreadsheet on the evening of the same day.",
+    ],
+]
+
+# (font size, x, baseline y, text)
+PAGES = [
+    [
+        (24, 72, 600, "A Field Guide"),
+        (14, 72, 570, "for Coastal Surveys"),
+    ],
+    [
+        (18, 72, 720, "Contents"),
+        (11, 72, 680, "Preface" + LEADER),
+        (11, 520, 680, "iii"),
+        (11, 72, 660, "1 Getting Started" + LEADER),
+        (11, 520, 660, "1"),
+        (11, 90, 640, "1.1 Choosing the Right Tools" + LEADER),
+        (11, 520, 640, "2"),
+        (11, 90, 620, "1.2 Packing for a Long Day in"),
+        (11, 90, 607, "Difficult Weather" + LEADER),
+        (11, 520, 607, "2"),
+        (11, 72, 587, "2 Field Work" + LEADER),
+        (11, 520, 587, "3"),
+        (11, 90, 567, "2.1 Keeping Notes" + LEADER),
+        (11, 520, 567, "4"),
+    ],
+    [
+        (18, 72, 720, "Preface"),
+        (11, 72, 690, BODY[0][0]),
+        (11, 72, 676, BODY[0][1]),
+    ],
+    [
+        (18, 72, 720, "1 Getting Started"),
+        (11, 72, 690, BODY[1][0]),
+        (11, 72, 676, BODY[1][1]),
+    ],
+    [
+        (14, 72, 720, "1.1 Choosing the Right Tools"),
+        (11, 72, 695, BODY[2][0]),
+        (11, 72, 681, BODY[2][1]),
+        (14, 72, 640, "1.2 Packing for a Long Day in Difficult Weather"),
+        (11, 72, 615, BODY[3][0]),
+        (11, 72, 601, BODY[3][1]),
+    ],
+    [
+        (18, 72, 720, "2 Field Work"),
+        (11, 72, 690, BODY[4][0]),
+        (11, 72, 676, BODY[4][1]),
+    ],
+    [
+        (14, 72, 720, "2.1 Keeping Notes"),
+        (11, 72, 695, BODY[5][0]),
+        (11, 72, 681, BODY[5][1]),
+    ],
+]
+
+
+def stream(data):
+    return b"<< /Length " + str(len(data)).encode() + b" >>\nstream\n" + data + b"\nendstream"
+
+
+def page_content(lines):
+    out = b""
+    for size, x, y, text in lines:
+        out += b"BT /F1 %d Tf %d %d Td (%s) Tj ET\n" % (size, x, y, text.encode())
+    return out
+
+
+count = len(PAGES)
+font_num = 3 + 2 * count
+objects = [
+    b"<< /Type /Catalog /Pages 2 0 R >>",
+    b"<< /Type /Pages /Kids ["
+    + b" ".join(b"%d 0 R" % (3 + i) for i in range(count))
+    + b"] /Count %d /MediaBox [0 0 612 792] " % count
+    + b"/Resources << /Font << /F1 %d 0 R >> >> >>" % font_num,
+]
+for i in range(count):
+    objects.append(b"<< /Type /Page /Parent 2 0 R /Contents %d 0 R >>" % (3 + count + i))
+for lines in PAGES:
+    objects.append(stream(page_content(lines)))
+objects.append(b"<< /Type /Font /Subtype /Type1 /BaseFont /Helvetica >>")
+
+out = bytearray(b"%PDF-1.4\n%\xe2\xe3\xcf\xd3\n")
+offsets = []
+for num, body in enumerate(objects, start=1):
+    offsets.append(len(out))
+    out += str(num).encode() + b" 0 obj\n" + body + b"\nendobj\n"
+
+xref_offset = len(out)
+out += b"xref\n0 " + str(len(objects) + 1).encode() + b"\n"
+out += b"0000000000 65535 f \n"
+for offset in offsets:
+    out += b"%010d 00000 n \n" % offset
+out += b"trailer\n<< /Size " + str(len(objects) + 1).encode() + b" /Root 1 0 R >>\n"
+out += b"startxref\n" + str(xref_offset).encode() + b"\n%%EOF\n"
+
+with open(PDF_PATH, "wb") as f:
+    f.write(out)
+
+print(f"Created {PDF_PATH}")
@@ -0,0 +1,52 @@
+#!/usr/bin/env python3
+"""
+Create a PDF exercising page geometry: /Rotate, /CropBox, /UserUnit and
+attributes inherited from the page tree.
+
+Writes the PDF by hand (no reportlab needed).
+
+Page 0: /Rotate 90 (overrides the tree), "Rotated" drawn at (100, 700)
+Page 1: /Rotate 270 and /MediaBox inherited, /CropBox [50 50 562 742]
+Page 2: /Rotate 0, /MediaBox [0 0 300 400], /UserUnit 2
+"""
+
+PDF_PATH = "rotated.pdf"
+
+
+def stream(data):
+    return b"<< /Length " + str(len(data)).encode() + b" >>\nstream\n" + data + b"\nendstream"
+
+
+objects = [
+    b"<< /Type /Catalog /Pages 2 0 R >>",
+    b"<< /Type /Pages /Kids [3 0 R 4 0 R 5 0 R] /Count 3 "
+    b"/MediaBox [0 0 612 792] /Rotate 270 "
+    b"/Resources << /Font << /F1 9 0 R >> >> >>",
+    b"<< /Type /Page /Parent 2 0 R /Rotate 90 /Contents 6 0 R >>",
+    b"<< /Type /Page /Parent 2 0 R /CropBox [50 50 562 742] /Contents 7 0 R >>",
+    b"<< /Type /Page /Parent 2 0 R /Rotate 0 /MediaBox [0 0 300 400] "
+    b"/UserUnit 2 /Contents 8 0 R >>",
+    stream(b"BT /F1 12 Tf 100 700 Td (Rotated) Tj ET"),
+    stream(b"BT /F1 12 Tf 100 600 Td (Cropped) Tj ET"),
+    stream(b"BT /F1 12 Tf 50 300 Td (Scaled) Tj ET"),
+    b"<< /Type /Font /Subtype /Type1 /BaseFont /Helvetica >>",
+]
+
+out = bytearray(b"%PDF-1.6\n%\xe2\xe3\xcf\xd3\n")
+offsets = []
+for num, body in enumerate(objects, start=1):
+    offsets.append(len(out))
+    out += str(num).encode() + b" 0 obj\n" + body + b"\nendobj\n"
+
+xref_offset = len(out)
+out += b"xref\n0 " + str(len(objects) + 1).encode() + b"\n"
+out += b"0000000000 65535 f \n"
+for offset in offsets:
+    out += b"%010d 00000 n \n" % offset
+out += b"trailer\n<< /Size " + str(len(objects) + 1).encode() + b" /Root 1 0 R >>\n"
+out += b"startxref\n" + str(xref_offset).encode() + b"\n%%EOF\n"
+
+with open(PDF_PATH, "wb") as f:
+    f.write(out)
+
+print(f"Created {PDF_PATH}")
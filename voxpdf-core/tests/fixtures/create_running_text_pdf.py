@@ -0,0 +1,67 @@
+#!/usr/bin/env python3
+"""
+Create a PDF with running headers and page numbers.
+
+Writes the PDF by hand (no reportlab needed).
+
+6 pages. Even pages carry the header "VoxPDF Field Guide", odd pages
+"Chapter 2 - Extraction", both at the top of the page. Every page has its
+page number (first page numbered 11) centred at the bottom and two body
+paragraphs in between.
+"""
+
+PDF_PATH = "running-text.pdf"
+PAGES = 6
+FIRST_NUMBER = 11
+
+
+def stream(data):
+    return b"<< /Length " + str(len(data)).encode() + b" >>\nstream\n" + data + b"\nendstream"
+
+
+def text(x, y, size, s):
+    return b"BT /F1 %d Tf %d %d Td (%s) Tj ET\n" % (size, x, y, s.encode())
+
+
+def page_content(page):
+    header = "VoxPDF Field Guide" if page % 2 == 0 else "Chapter 2 - Extraction"
+    out = text(72, 756, 9, header)
+    out += text(72, 700, 11, "Body text on physical page %d begins here." % page)
+    out += text(72, 686, 11, "It continues onto a second line.")
+    out += text(72, 640, 11, "A second paragraph follows after a gap.")
+    out += text(300, 40, 9, str(FIRST_NUMBER + page))
+    return out
+
+
+font_num = 3 + 2 * PAGES
+objects = [
+    b"<< /Type /Catalog /Pages 2 0 R >>",
+    b"<< /Type /Pages /Kids ["
+    + b" ".join(b"%d 0 R" % (3 + i) for i in range(PAGES))
+    + b"] /Count %d /MediaBox [0 0 612 792] " % PAGES
+    + b"/Resources << /Font << /F1 %d 0 R >> >> >>" % font_num,
+]
+for i in range(PAGES):
+    objects.append(b"<< /Type /Page /Parent 2 0 R /Contents %d 0 R >>" % (3 + PAGES + i))
+for i in range(PAGES):
+    objects.append(stream(page_content(i)))
+objects.append(b"<< /Type /Font /Subtype /Type1 /BaseFont /Helvetica >>")
+
+out = bytearray(b"%PDF-1.4\n%\xe2\xe3\xcf\xd3\n")
+offsets = []
+for num, body in enumerate(objects, start=1):
+    offsets.append(len(out))
+    out += str(num).encode() + b" 0 obj\n" + body + b"\nendobj\n"
+
+xref_offset = len(out)
+out += b"xref\n0 " + str(len(objects) + 1).encode() + b"\n"
+out += b"0000000000 65535 f \n"
+for offset in offsets:
+    out += b"%010d 00000 n \n" % offset
+out += b"trailer\n<< /Size " + str(len(objects) + 1).encode() + b" /Root 1 0 R >>\n"
+out += b"startxref\n" + str(xref_offset).encode() + b"\n%%EOF\n"
+
+with open(PDF_PATH, "wb") as f:
+    f.write(out)
+
+print(f"Created {PDF_PATH}")
@@ -0,0 +1,58 @@
+#!/usr/bin/env python3
+"""
+Create a German PDF whose sentence boundaries depend on the document
+language.
+
+Writes the PDF by hand (no reportlab needed).
+
+- Catalog `/Lang (de-DE)`
+- One 11pt Helvetica paragraph of three lines with abbreviations ("Dr.",
+  "vgl.", "S.") and ordinals ("3. Oktober", "1. Mai") that don't end
+  sentences
+"""
+
+PDF_PATH = "sentences.pdf"
+
+LINES = [
+    "Am 3. Oktober 1990 kam Dr. Weber nach Berlin, vgl.",
+    "S. 12 im Anhang. Er blieb bis zum 1. Mai. Danach zog",
+    "er weiter.",
+]
+
+CONTENT = b"BT /F1 11 Tf 14 TL 72 700 Td\n"
+for line in LINES:
+    CONTENT += b"(" + line.encode("latin-1") + b") Tj T*\n"
+CONTENT += b"ET\n"
+
+
+def stream(data):
+    return b"<< /Length " + str(len(data)).encode() + b" >>\nstream\n" + data + b"\nendstream"
+
+
+objects = [
+    b"<< /Type /Catalog /Pages 2 0 R /Lang (de-DE) >>",
+    b"<< /Type /Pages /Kids [3 0 R] /Count 1 /MediaBox [0 0 612 792] "
+    b"/Resources << /Font << /F1 5 0 R >> >> >>",
+    b"<< /Type /Page /Parent 2 0 R /Contents 4 0 R >>",
+    stream(CONTENT),
+    b"<< /Type /Font /Subtype /Type1 /BaseFont /Helvetica >>",
+]
+
+out = bytearray(b"%PDF-1.4\n%\xe2\xe3\xcf\xd3\n")
+offsets = []
+for num, body in enumerate(objects, start=1):
+    offsets.append(len(out))
+    out += str(num).encode() + b" 0 obj\n" + body + b"\nendobj\n"
+
+xref_offset = len(out)
+out += b"xref\n0 " + str(len(objects) + 1).encode() + b"\n"
+out += b"0000000000 65535 f \n"
+for offset in offsets:
+    out += b"%010d 00000 n \n" % offset
+out += b"trailer\n<< /Size " + str(len(objects) + 1).encode() + b" /Root 1 0 R >>\n"
+out += b"startxref\n" + str(xref_offset).encode() + b"\n%%EOF\n"
+
+with open(PDF_PATH, "wb") as f:
+    f.write(out)
+
+print(f"Created {PDF_PATH}")
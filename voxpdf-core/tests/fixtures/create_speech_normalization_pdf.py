@@ -0,0 +1,55 @@
+#!/usr/bin/env python3
+"""
+Create a PDF whose text reads badly to a speech engine as extracted.
+
+Writes the PDF by hand (no reportlab needed).
+
+- No `/Lang`, so English rules apply
+- One 11pt Helvetica line in the font's standard encoding, with an "fi"
+  ligature, typographic quotes, a section sign, an en dash range, an
+  abbreviation, a grouped number with a unit, and "~5%"
+"""
+
+PDF_PATH = "speech-normalization.pdf"
+
+# Standard encoding: \256 fi, \247 section, \252 quotedblleft,
+# \272 quotedblright, \261 endash
+LINE = (
+    rb"The \256nal \256gure, Fig. 2, covers \247 3\(a\): \2521,234.5 km\272 "
+    rb"in 1990\2611995, ~5%."
+)
+
+CONTENT = b"BT /F1 11 Tf 72 700 Td\n(" + LINE + b") Tj\nET\n"
+
+
+def stream(data):
+    return b"<< /Length " + str(len(data)).encode() + b" >>\nstream\n" + data + b"\nendstream"
+
+
+objects = [
+    b"<< /Type /Catalog /Pages 2 0 R >>",
+    b"<< /Type /Pages /Kids [3 0 R] /Count 1 /MediaBox [0 0 612 792] "
+    b"/Resources << /Font << /F1 5 0 R >> >> >>",
+    b"<< /Type /Page /Parent 2 0 R /Contents 4 0 R >>",
+    stream(CONTENT),
+    b"<< /Type /Font /Subtype /Type1 /BaseFont /Helvetica >>",
+]
+
+out = bytearray(b"%PDF-1.4\n%\xe2\xe3\xcf\xd3\n")
+offsets = []
+for num, body in enumerate(objects, start=1):
+    offsets.append(len(out))
+    out += str(num).encode() + b" 0 obj\n" + body + b"\nendobj\n"
+
+xref_offset = len(out)
+out += b"xref\n0 " + str(len(objects) + 1).encode() + b"\n"
+out += b"0000000000 65535 f \n"
+for offset in offsets:
+    out += b"%010d 00000 n \n" % offset
+out += b"trailer\n<< /Size " + str(len(objects) + 1).encode() + b" /Root 1 0 R >>\n"
+out += b"startxref\n" + str(xref_offset).encode() + b"\n%%EOF\n"
+
+with open(PDF_PATH, "wb") as f:
+    f.write(out)
+
+print(f"Created {PDF_PATH}")
@@ -0,0 +1,60 @@
+#!/usr/bin/env python3
+"""
+Create a one-page PDF with words in different fonts and colours.
+
+Writes the PDF by hand (no reportlab needed).
+
+Line 1 (12pt, baseline 720): "Plain" (Helvetica, black), "Bold"
+(Helvetica-Bold, red), "Italic" (Times-Italic, black), "code" (Courier,
+blue)
+"""
+
+PDF_PATH = "styled-text.pdf"
+
+# (font resource, x, fill colour, text)
+WORDS = [
+    (b"F1", 72, b"0 0 0", b"Plain"),
+    (b"F2", 120, b"1 0 0", b"Bold"),
+    (b"F3", 168, b"0 0 0", b"Italic"),
+    (b"F4", 216, b"0 0 1", b"code"),
+]
+FONTS = [b"Helvetica", b"Helvetica-Bold", b"Times-Italic", b"Courier"]
+
+
+def stream(data):
+    return b"<< /Length " + str(len(data)).encode() + b" >>\nstream\n" + data + b"\nendstream"
+
+
+content = b""
+for font, x, color, text in WORDS:
+    content += b"BT /%s 12 Tf %s rg %d 720 Td (%s) Tj ET\n" % (font, color, x, text)
+
+font_resources = b" ".join(b"/F%d %d 0 R" % (i + 1, 5 + i) for i in range(len(FONTS)))
+objects = [
+    b"<< /Type /Catalog /Pages 2 0 R >>",
+    b"<< /Type /Pages /Kids [3 0 R] /Count 1 /MediaBox [0 0 612 792] "
+    b"/Resources << /Font << " + font_resources + b" >> >> >>",
+    b"<< /Type /Page /Parent 2 0 R /Contents 4 0 R >>",
+    stream(content),
+]
+for name in FONTS:
+    objects.append(b"<< /Type /Font /Subtype /Type1 /BaseFont /%s >>" % name)
+
+out = bytearray(b"%PDF-1.4\n%\xe2\xe3\xcf\xd3\n")
+offsets = []
+for num, body in enumerate(objects, start=1):
+    offsets.append(len(out))
+    out += str(num).encode() + b" 0 obj\n" + body + b"\nendobj\n"
+
+xref_offset = len(out)
+out += b"xref\n0 " + str(len(objects) + 1).encode() + b"\n"
+out += b"0000000000 65535 f \n"
+for offset in offsets:
+    out += b"%010d 00000 n \n" % offset
+out += b"trailer\n<< /Size " + str(len(objects) + 1).encode() + b" /Root 1 0 R >>\n"
+out += b"startxref\n" + str(xref_offset).encode() + b"\n%%EOF\n"
+
+with open(PDF_PATH, "wb") as f:
+    f.write(out)
+
+print(f"Created {PDF_PATH}")
@@ -0,0 +1,95 @@
+#!/usr/bin/env python3
+"""
+Create a PDF whose outline points at headings in the middle of pages.
+
+Writes the PDF by hand (no reportlab needed).
+
+Page 0: an intro paragraph, then the "1 Getting Started" heading; the
+        outline entry "Getting Started" is an /XYZ destination just above it
+Page 1: a paragraph, then "1.1 Installing"; the nested entry "Installing
+        the Tools" is an /XYZ destination with a null left and zoom
+Page 2: a paragraph, then "2 Next Steps"; the entry "Next Steps" is a GoTo
+        action to /Fit, so only its title can find the heading
+"""
+
+PDF_PATH = "toc-positions.pdf"
+
+# (font size, baseline y, text)
+PAGES = [
+    [
+        (11, 700, "This guide walks through the basics."),
+        (18, 500, "1 Getting Started"),
+        (11, 460, "Everything begins with a short checklist."),
+    ],
+    [
+        (11, 700, "Check that your machine meets the requirements."),
+        (14, 420, "1.1 Installing"),
+        (11, 390, "Install the tools with the package manager."),
+    ],
+    [
+        (11, 700, "The tools are now ready to use."),
+        (18, 600, "2 Next Steps"),
+        (11, 560, "Read on to learn more about configuration."),
+    ],
+]
+
+
+def stream(data):
+    return b"<< /Length " + str(len(data)).encode() + b" >>\nstream\n" + data + b"\nendstream"
+
+
+def page_content(lines):
+    out = b""
+    for size, y, text in lines:
+        out += b"BT /F1 %d Tf 72 %d Td (%s) Tj ET\n" % (size, y, text.encode())
+    return out
+
+
+count = len(PAGES)
+page_num = lambda i: 3 + i
+content_num = lambda i: 3 + count + i
+font_num = 3 + 2 * count
+outlines_num = font_num + 1
+started, installing, next_steps = outlines_num + 1, outlines_num + 2, outlines_num + 3
+
+objects = [
+    b"<< /Type /Catalog /Pages 2 0 R /Outlines %d 0 R /PageMode /UseOutlines >>" % outlines_num,
+    b"<< /Type /Pages /Kids ["
+    + b" ".join(b"%d 0 R" % page_num(i) for i in range(count))
+    + b"] /Count %d /MediaBox [0 0 612 792] " % count
+    + b"/Resources << /Font << /F1 %d 0 R >> >> >>" % font_num,
+]
+for i in range(count):
+    objects.append(b"<< /Type /Page /Parent 2 0 R /Contents %d 0 R >>" % content_num(i))
+for lines in PAGES:
+    objects.append(stream(page_content(lines)))
+objects.append(b"<< /Type /Font /Subtype /Type1 /BaseFont /Helvetica >>")
+objects += [
+    b"<< /Type /Outlines /First %d 0 R /Last %d 0 R /Count 3 >>" % (started, next_steps),
+    b"<< /Title (Getting Started) /Parent %d 0 R /Next %d 0 R /First %d 0 R /Last %d 0 R "
+    b"/Count 1 /Dest [%d 0 R /XYZ 72 520 0] >>"
+    % (outlines_num, next_steps, installing, installing, page_num(0)),
+    b"<< /Title (Installing the Tools) /Parent %d 0 R /Dest [%d 0 R /XYZ null 436 null] >>"
+    % (started, page_num(1)),
+    b"<< /Title (Next Steps) /Parent %d 0 R /Prev %d 0 R /A << /S /GoTo /D [%d 0 R /Fit] >> >>"
+    % (outlines_num, started, page_num(2)),
+]
+
+out = bytearray(b"%PDF-1.4\n%\xe2\xe3\xcf\xd3\n")
+offsets = []
+for num, body in enumerate(objects, start=1):
+    offsets.append(len(out))
+    out += str(num).encode() + b" 0 obj\n" + body + b"\nendobj\n"
+
+xref_offset = len(out)
+out += b"xref\n0 " + str(len(objects) + 1).encode() + b"\n"
+out += b"0000000000 65535 f \n"
+for offset in offsets:
+    out += b"%010d 00000 n \n" % offset
+out += b"trailer\n<< /Size " + str(len(objects) + 1).encode() + b" /Root 1 0 R >>\n"
+out += b"startxref\n" + str(xref_offset).encode() + b"\n%%EOF\n"
+
+with open(PDF_PATH, "wb") as f:
+    f.write(out)
+
+print(f"Created {PDF_PATH}")
@@ -0,0 +1,51 @@
+#!/usr/bin/env python3
+"""
+Create an untagged PDF whose marked content carries a `/Lang` anyway.
+
+Writes the PDF by hand (no reportlab needed).
+
+- Catalog `/Lang (en-US)`, one page, no `/MarkInfo` and no `/StructTreeRoot`
+- One line of English in 12pt Helvetica, inside `/Span <</Lang (fr-FR)>>`
+"""
+
+PDF_PATH = "untagged-languages.pdf"
+
+CONTENT = (
+    b"BT /F1 12 Tf 72 700 Td\n"
+    b"/Span <</Lang (fr-FR)>> BDC "
+    b"(The gallery on the market square is closed for the winter.) Tj EMC\n"
+    b"ET\n"
+)
+
+
+def stream(data):
+    return b"<< /Length " + str(len(data)).encode() + b" >>\nstream\n" + data + b"\nendstream"
+
+
+objects = [
+    b"<< /Type /Catalog /Pages 2 0 R /Lang (en-US) >>",
+    b"<< /Type /Pages /Kids [3 0 R] /Count 1 /MediaBox [0 0 612 792] "
+    b"/Resources << /Font << /F1 5 0 R >> >> >>",
+    b"<< /Type /Page /Parent 2 0 R /Contents 4 0 R >>",
+    stream(CONTENT),
+    b"<< /Type /Font /Subtype /Type1 /BaseFont /Helvetica /Encoding /WinAnsiEncoding >>",
+]
+
+out = bytearray(b"%PDF-1.4\n%\xe2\xe3\xcf\xd3\n")
+offsets = []
+for num, body in enumerate(objects, start=1):
+    offsets.append(len(out))
+    out += str(num).encode() + b" 0 obj\n" + body + b"\nendobj\n"
+
+xref_offset = len(out)
+out += b"xref\n0 " + str(len(objects) + 1).encode() + b"\n"
+out += b"0000000000 65535 f \n"
+for offset in offsets:
+    out += b"%010d 00000 n \n" % offset
+out += b"trailer\n<< /Size " + str(len(objects) + 1).encode() + b" /Root 1 0 R >>\n"
+out += b"startxref\n" + str(xref_offset).encode() + b"\n%%EOF\n"
+
+with open(PDF_PATH, "wb") as f:
+    f.write(out)
+
+print(f"Created {PDF_PATH}")
@@ -0,0 +1,56 @@
+#!/usr/bin/env python3
+"""
+Create a PDF whose word gaps only make sense relative to each font's space
+width.
+
+Writes the PDF by hand (no reportlab needed).
+
+- 6pt Helvetica footnote with words separated by TJ offsets, no spaces
+- 28pt Helvetica heading letter-spaced by 0.1em (Tc 2.8)
+- 12pt Courier set wide: 0.25em between letters, 0.6em spaces
+- 11pt Helvetica body line, whose spaces set the width for lines that have
+  none
+"""
+
+PDF_PATH = "word-spacing.pdf"
+
+CONTENT = (
+    b"BT /F1 6 Tf 72 700 Td [(small)-300(footnote)-300(text)] TJ ET\n"
+    b"BT /F1 28 Tf 2.8 Tc 72 650 Td (Tracked Heading) Tj ET\n"
+    b"BT /F2 12 Tf 3 Tc 72 600 Td (Wide set mono) Tj ET\n"
+    b"BT /F1 11 Tf 72 550 Td (Body text sets the usual space width.) Tj ET\n"
+)
+
+
+def stream(data):
+    return b"<< /Length " + str(len(data)).encode() + b" >>\nstream\n" + data + b"\nendstream"
+
+
+objects = [
+    b"<< /Type /Catalog /Pages 2 0 R >>",
+    b"<< /Type /Pages /Kids [3 0 R] /Count 1 /MediaBox [0 0 612 792] "
+    b"/Resources << /Font << /F1 5 0 R /F2 6 0 R >> >> >>",
+    b"<< /Type /Page /Parent 2 0 R /Contents 4 0 R >>",
+    stream(CONTENT),
+    b"<< /Type /Font /Subtype /Type1 /BaseFont /Helvetica >>",
+    b"<< /Type /Font /Subtype /Type1 /BaseFont /Courier >>",
+]
+
+out = bytearray(b"%PDF-1.4\n%\xe2\xe3\xcf\xd3\n")
+offsets = []
+for num, body in enumerate(objects, start=1):
+    offsets.append(len(out))
+    out += str(num).encode() + b" 0 obj\n" + body + b"\nendobj\n"
+
+xref_offset = len(out)
+out += b"xref\n0 " + str(len(objects) + 1).encode() + b"\n"
+out += b"0000000000 65535 f \n"
+for offset in offsets:
+    out += b"%010d 00000 n \n" % offset
+out += b"trailer\n<< /Size " + str(len(objects) + 1).encode() + b" /Root 1 0 R >>\n"
+out += b"startxref\n" + str(xref_offset).encode() + b"\n%%EOF\n"
+
+with open(PDF_PATH, "wb") as f:
+    f.write(out)
+
+print(f"Created {PDF_PATH}")
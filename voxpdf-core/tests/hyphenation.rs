@@ -1,12 +1,15 @@
-use voxpdf_core::extraction::reassemble_hyphenated_words;
+use voxpdf_core::extraction::{
+    extract_pages_parallel, reassemble_hyphenated_words, ExtractionOptions,
+};
 use voxpdf_core::models::Paragraph;
+use voxpdf_core::PDFDocument;
 
 #[test]
 fn test_reassemble_simple_hyphenation() {
     // Create paragraph with hyphenated word
     let para = Paragraph::new(
         0,
-        "This is an exam- ple of hyphenation".to_string(),
+        "This is an exam-\nple of hyphenation".to_string(),
         0,
         vec![],
     );
@@ -27,3 +30,47 @@ fn test_preserve_intentional_hyphens() {
     // Should preserve intentional hyphen
     assert!(result[0].text.contains("self-contained"));
 }
+
+#[test]
+fn test_dehyphenates_line_ends_in_pdf() {
+    let doc = PDFDocument::open("tests/fixtures/hyphenation.pdf").unwrap();
+    let results = extract_pages_parallel(&doc, vec![0]).unwrap();
+    let paragraphs = &results[0].paragraphs;
+
+    assert_eq!(paragraphs.len(), 1);
+    assert_eq!(
+        paragraphs[0].text,
+        "The results of the survey were carefully examined by a reviewer whose work is \
+         well-known, and the figures were all rechecked before the so- called final version."
+    );
+
+    // Text and words agree, so highlighting can follow the words one by one
+    let from_words: Vec<&str> = paragraphs[0]
+        .words
+        .iter()
+        .map(|w| w.text.as_str())
+        .collect();
+    assert_eq!(from_words.join(" "), paragraphs[0].text);
+    for (word, range) in paragraphs[0].words.iter().zip(&paragraphs[0].word_ranges) {
+        assert_eq!(&paragraphs[0].text[range.utf8.clone()], word.text);
+    }
+
+    let examined = paragraphs[0]
+        .words
+        .iter()
+        .find(|w| w.text == "examined")
+        .unwrap();
+    assert_eq!(examined.fragments.len(), 2);
+}
+
+#[test]
+fn test_dehyphenation_can_be_disabled() {
+    let mut doc = PDFDocument::open("tests/fixtures/hyphenation.pdf").unwrap();
+    doc.set_extraction_options(ExtractionOptions {
+        dehyphenate: false,
+        ..Default::default()
+    });
+    let results = extract_pages_parallel(&doc, vec![0]).unwrap();
+
+    assert!(results[0].paragraphs[0].text.contains("exam- ined"));
+}
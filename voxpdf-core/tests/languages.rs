@@ -0,0 +1,108 @@
+use voxpdf_core::extraction::{extract_document_paragraphs, ExtractionOptions};
+use voxpdf_core::models::LanguageSource;
+use voxpdf_core::PDFDocument;
+
+#[test]
+fn test_paragraph_languages() {
+    let doc = PDFDocument::open("tests/fixtures/languages.pdf").unwrap();
+    let paragraphs = extract_document_paragraphs(&doc, vec![0]).unwrap();
+    assert_eq!(paragraphs.len(), 5);
+
+    let languages: Vec<(&str, LanguageSource)> = paragraphs
+        .iter()
+        .map(|p| {
+            let language = p.language.as_ref().unwrap();
+            (language.tag.as_str(), language.source)
+        })
+        .collect();
+    assert_eq!(
+        languages,
+        [
+            // A structure element without /Lang: checked against the catalog's
+            ("en-US", LanguageSource::Document),
+            // The structure element's /Lang, through its MCID
+            ("fr-FR", LanguageSource::Tagged),
+            // Marked content's own /Lang
+            ("de-DE", LanguageSource::Tagged),
+            ("es", LanguageSource::Detected),
+            ("en-US", LanguageSource::Document),
+        ]
+    );
+    assert_eq!(paragraphs[1].language.as_ref().unwrap().confidence, 1.0);
+    assert!(paragraphs[3].language.as_ref().unwrap().confidence > 0.5);
+}
+
+#[test]
+fn test_document_language() {
+    let doc = PDFDocument::open("tests/fixtures/languages.pdf").unwrap();
+    assert_eq!(doc.language().unwrap(), Some("en-US"));
+
+    let doc = PDFDocument::open("tests/fixtures/simple.pdf").unwrap();
+    assert_eq!(doc.language().unwrap(), None);
+}
+
+#[test]
+fn test_language_changing_mid_line() {
+    let doc = PDFDocument::open("tests/fixtures/mixed-languages.pdf").unwrap();
+    let paragraphs = extract_document_paragraphs(&doc, vec![0]).unwrap();
+    assert_eq!(paragraphs.len(), 2);
+
+    let languages: Vec<(&str, LanguageSource)> = paragraphs
+        .iter()
+        .map(|p| {
+            let language = p.language.as_ref().unwrap();
+            (language.tag.as_str(), language.source)
+        })
+        .collect();
+    assert_eq!(
+        languages,
+        [
+            // Only the last word is tagged French: not enough for the line
+            ("en-US", LanguageSource::Document),
+            // Five German words outvote the three English ones after them
+            ("de-DE", LanguageSource::Tagged),
+        ]
+    );
+}
+
+#[test]
+fn test_untagged_document_skips_lang_scan() {
+    let doc = PDFDocument::open("tests/fixtures/untagged-languages.pdf").unwrap();
+    let paragraphs = extract_document_paragraphs(&doc, vec![0]).unwrap();
+    assert_eq!(paragraphs.len(), 1);
+
+    // The marked content's /Lang (fr-FR) is ignored without /MarkInfo or a
+    // structure tree, so the English line is checked against the catalog's
+    let language = paragraphs[0].language.as_ref().unwrap();
+    assert_eq!(
+        (language.tag.as_str(), language.source),
+        ("en-US", LanguageSource::Document)
+    );
+}
+
+#[test]
+fn test_sentence_languages() {
+    let doc = PDFDocument::open("tests/fixtures/languages.pdf").unwrap();
+    let para = &doc.document_paragraphs().unwrap()[4];
+    let mut sentences = doc.sentence_splitter().unwrap().split(para);
+    doc.language_detector()
+        .unwrap()
+        .tag_sentences(para, &mut sentences);
+
+    let tags: Vec<&str> = sentences
+        .iter()
+        .map(|s| s.language.as_ref().unwrap().tag.as_str())
+        .collect();
+    assert_eq!(tags, ["en-US", "fr"]);
+}
+
+#[test]
+fn test_language_detection_off() {
+    let mut doc = PDFDocument::open("tests/fixtures/languages.pdf").unwrap();
+    doc.set_extraction_options(ExtractionOptions {
+        detect_language: false,
+        ..Default::default()
+    });
+    let paragraphs = doc.document_paragraphs().unwrap();
+    assert!(paragraphs.iter().all(|p| p.language.is_none()));
+}
@@ -0,0 +1,43 @@
+use voxpdf_core::PDFDocument;
+
+#[test]
+fn test_info_dictionary_metadata() {
+    let doc = PDFDocument::open("tests/fixtures/simple.pdf").unwrap();
+    let meta = doc.metadata().unwrap();
+
+    // reportlab fills in placeholder Info values
+    assert_eq!(meta.title.as_deref(), Some("untitled"));
+    assert_eq!(meta.author.as_deref(), Some("anonymous"));
+    assert_eq!(meta.subject.as_deref(), Some("unspecified"));
+    assert!(meta.keywords.is_none(), "Empty /Keywords should be None");
+    assert!(meta.producer.as_deref().unwrap().contains("ReportLab"));
+
+    let created = meta.creation_date.expect("Should parse /CreationDate");
+    assert_eq!((created.year, created.month, created.day), (2025, 11, 7));
+    assert_eq!(created.utc_offset_minutes, Some(-360));
+
+    // No /Lang or XMP packet in this fixture
+    assert!(meta.language.is_none());
+    assert!(meta.xmp.is_none());
+}
+
+#[test]
+fn test_metadata_serializes_to_json() {
+    let doc = PDFDocument::open("tests/fixtures/simple.pdf").unwrap();
+    let meta = doc.metadata().unwrap();
+
+    let json = serde_json::to_string(&meta).unwrap();
+    assert!(json.contains("\"author\":\"anonymous\""));
+
+    let roundtrip: voxpdf_core::DocumentMetadata = serde_json::from_str(&json).unwrap();
+    assert_eq!(roundtrip, meta);
+}
+
+#[test]
+fn test_encrypted_metadata_requires_password() {
+    let mut doc = PDFDocument::open("tests/fixtures/encrypted.pdf").unwrap();
+    assert!(doc.metadata().is_err());
+
+    assert!(doc.authenticate("secret").unwrap());
+    assert!(doc.metadata().is_ok());
+}
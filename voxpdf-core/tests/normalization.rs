@@ -0,0 +1,50 @@
+use voxpdf_core::extraction::extract_document_paragraphs;
+use voxpdf_core::normalization::Normalizer;
+use voxpdf_core::PDFDocument;
+
+const SPOKEN: &str = "The final figure, Figure 2, covers section 3 a: \"1234.5 kilometers\" \
+                      in 1990 to 1995, approximately 5 percent.";
+
+#[test]
+fn test_normalized_paragraph() {
+    let doc = PDFDocument::open("tests/fixtures/speech-normalization.pdf").unwrap();
+    let paragraphs = extract_document_paragraphs(&doc, vec![0]).unwrap();
+    assert_eq!(paragraphs.len(), 1);
+    let para = &paragraphs[0];
+
+    // No /Lang: English rules
+    let normalizer = Normalizer::for_document(&doc).unwrap();
+    let normalized = normalizer.normalize_paragraph(para);
+    assert_eq!(normalized.text, SPOKEN);
+
+    // Every word still has a place in the spoken text, in order
+    let ranges = normalized.word_ranges(para);
+    assert_eq!(ranges.len(), para.words.len());
+    assert!(ranges.windows(2).all(|w| w[0].utf8.end <= w[1].utf8.start));
+    let spoken: Vec<&str> = ranges
+        .iter()
+        .map(|r| &normalized.text[r.utf8.clone()])
+        .collect();
+    assert_eq!(spoken[3], "Figure");
+    assert_eq!(spoken[6], "section");
+    assert_eq!(spoken[12], "approximately 5 percent.");
+
+    // Highlighting "percent" lands on "~5%."
+    let percent = normalized.text.find("percent").unwrap();
+    let word = normalized.word_at_utf16(para, percent).unwrap();
+    assert_eq!(para.words[word].text, "~5%.");
+    let original = normalized.original_range(percent..percent + 7);
+    assert_eq!(&para.text[original], "%");
+}
+
+#[test]
+fn test_document_normalizer_is_cached() {
+    let doc = PDFDocument::open("tests/fixtures/speech-normalization.pdf").unwrap();
+    let first = doc.normalizer().unwrap() as *const Normalizer;
+    let second = doc.normalizer().unwrap() as *const Normalizer;
+    assert_eq!(first, second);
+    assert_eq!(
+        doc.normalizer().unwrap().stage_names(),
+        ["characters", "symbols", "abbreviations", "numbers"]
+    );
+}
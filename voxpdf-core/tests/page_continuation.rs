@@ -0,0 +1,96 @@
+use voxpdf_core::extraction::{
+    extract_document_paragraphs, extract_pages_parallel, stitch_paragraphs, ExtractionOptions,
+};
+use voxpdf_core::PDFDocument;
+
+const FIXTURE: &str = "tests/fixtures/page-continuation.pdf";
+
+#[test]
+fn test_paragraphs_continue_across_pages() {
+    let doc = PDFDocument::open(FIXTURE).unwrap();
+    let paragraphs = extract_document_paragraphs(&doc, (0..4).collect()).unwrap();
+    let texts: Vec<&str> = paragraphs.iter().map(|p| p.text.as_str()).collect();
+
+    assert_eq!(
+        texts,
+        [
+            "The opening paragraph is complete.",
+            "This sentence starts on the first page and keeps going until the page runs out, \
+             so the reader hears it without a pause.",
+            "The café menu's last paragraph ends with a word that was carefully examined by \
+             the typesetter.",
+            "A fresh paragraph starts the final page.",
+        ]
+    );
+
+    let indices: Vec<usize> = paragraphs.iter().map(|p| p.index).collect();
+    assert_eq!(indices, [0, 1, 2, 3]);
+}
+
+#[test]
+fn test_joined_words_keep_their_pages() {
+    let doc = PDFDocument::open(FIXTURE).unwrap();
+    let paragraphs = extract_document_paragraphs(&doc, (0..4).collect()).unwrap();
+
+    let joined = &paragraphs[1];
+    assert_eq!(joined.page_number, 0);
+    assert_eq!(joined.end_page_number(), 1);
+
+    let reader = joined.words.iter().find(|w| w.text == "reader").unwrap();
+    assert_eq!(reader.page_number, 1);
+    let keeps = joined.words.iter().find(|w| w.text == "keeps").unwrap();
+    assert_eq!(keeps.page_number, 0);
+
+    // The word hyphenated over the page break is one word printed on two pages
+    let examined = paragraphs[2]
+        .words
+        .iter()
+        .find(|w| w.text == "examined")
+        .unwrap();
+    let pages: Vec<u32> = examined.rects().iter().map(|(page, _)| *page).collect();
+    assert_eq!(pages, [1, 2]);
+    assert_eq!(examined.fragments[0].text, "exam-");
+}
+
+#[test]
+fn test_word_ranges_survive_stitching() {
+    let doc = PDFDocument::open(FIXTURE).unwrap();
+    let paragraphs = extract_document_paragraphs(&doc, (0..4).collect()).unwrap();
+
+    for paragraph in &paragraphs {
+        assert_eq!(paragraph.word_ranges.len(), paragraph.words.len());
+        for (word, range) in paragraph.words.iter().zip(&paragraph.word_ranges) {
+            assert_eq!(&paragraph.text[range.utf8.clone()], word.text);
+        }
+    }
+
+    // A synthesizer speaking "examined" finds the word printed over the break.
+    // "café" makes the UTF-16 offset differ from the UTF-8 one
+    let last = &paragraphs[2];
+    let utf8 = last.text.find("examined").unwrap();
+    let utf16 = last.text[..utf8].encode_utf16().count();
+    assert_ne!(utf8, utf16);
+    let index = last.word_at_utf16(utf16).unwrap();
+    assert_eq!(last.words[index].text, "examined");
+    assert_eq!(last.word_ranges[index].utf8, utf8..utf8 + 8);
+    assert_eq!(last.word_ranges[index].utf16, utf16..utf16 + 8);
+
+    let cafe = last.words.iter().position(|w| w.text == "café").unwrap();
+    assert_eq!(last.word_ranges[cafe].utf8, 4..9);
+    assert_eq!(last.word_ranges[cafe].utf16, 4..8);
+}
+
+#[test]
+fn test_per_page_results_are_unchanged() {
+    let doc = PDFDocument::open(FIXTURE).unwrap();
+    let pages = extract_pages_parallel(&doc, (0..4).collect()).unwrap();
+
+    let per_page: Vec<usize> = pages.iter().map(|p| p.paragraphs.len()).collect();
+    assert_eq!(per_page, [2, 2, 1, 1]);
+
+    let stitched = stitch_paragraphs(
+        pages.into_iter().map(|p| p.paragraphs),
+        &ExtractionOptions::default(),
+    );
+    assert_eq!(stitched.len(), 4);
+}
@@ -0,0 +1,79 @@
+use voxpdf_core::extraction::extract_word_positions;
+use voxpdf_core::{CoordinateSpace, PDFDocument, Rect, VoxPDFError};
+
+const FIXTURE: &str = "tests/fixtures/rotated.pdf";
+
+#[test]
+fn test_page_info_unrotated() {
+    let doc = PDFDocument::open("tests/fixtures/simple.pdf").unwrap();
+    let info = doc.page_info(0).unwrap();
+
+    assert_eq!(info.index, 0);
+    assert_eq!(info.media_box, Rect::new(0.0, 0.0, 612.0, 792.0));
+    assert_eq!(info.crop_box, info.media_box);
+    assert_eq!(info.rotation, 0);
+    assert_eq!(info.user_unit, 1.0);
+    assert_eq!((info.width(), info.height()), (612.0, 792.0));
+}
+
+#[test]
+fn test_page_info_inherits_from_page_tree() {
+    let doc = PDFDocument::open(FIXTURE).unwrap();
+
+    let first = doc.page_info(0).unwrap();
+    assert_eq!(first.rotation, 90, "Page /Rotate overrides the tree");
+    assert_eq!(first.media_box, Rect::new(0.0, 0.0, 612.0, 792.0));
+    assert_eq!((first.width(), first.height()), (792.0, 612.0));
+
+    let second = doc.page_info(1).unwrap();
+    assert_eq!(second.rotation, 270, "/Rotate inherited from the tree");
+    assert_eq!(second.media_box, Rect::new(0.0, 0.0, 612.0, 792.0));
+    assert_eq!(second.crop_box, Rect::new(50.0, 50.0, 512.0, 692.0));
+    assert_eq!((second.width(), second.height()), (692.0, 512.0));
+
+    let third = doc.page_info(2).unwrap();
+    assert_eq!(third.rotation, 0);
+    assert_eq!(third.user_unit, 2.0);
+    assert_eq!((third.width(), third.height()), (600.0, 800.0));
+}
+
+#[test]
+fn test_page_info_out_of_range() {
+    let doc = PDFDocument::open(FIXTURE).unwrap();
+    assert!(matches!(
+        doc.page_info(3),
+        Err(VoxPDFError::PageNotFound(3, 3))
+    ));
+}
+
+#[test]
+fn test_word_bounds_map_back_to_user_space() {
+    let doc = PDFDocument::open(FIXTURE).unwrap();
+
+    // (page, word, text origin) as drawn in the content streams
+    for (page, text, x, y) in [(0, "Rotated", 100.0, 700.0), (1, "Cropped", 100.0, 600.0)] {
+        let info = doc.page_info(page).unwrap();
+        let words = extract_word_positions(&doc, page).unwrap();
+        let word = words.iter().find(|w| w.text == text).unwrap();
+
+        let native = info.transform_rect(&word.bounds, CoordinateSpace::BottomLeft);
+        let contains = |px: f32, py: f32| {
+            px >= native.x - 0.5
+                && px <= native.x + native.width + 0.5
+                && py >= native.y - 0.5
+                && py <= native.y + native.height + 0.5
+        };
+        assert!(
+            contains(x, y),
+            "{} was drawn at ({}, {}) in user space, got {:?}",
+            text,
+            x,
+            y,
+            native
+        );
+
+        let normalized = info.transform_rect(&word.bounds, CoordinateSpace::Normalized);
+        assert!((0.0..=1.0).contains(&normalized.x));
+        assert!((0.0..=1.0).contains(&normalized.y));
+    }
+}
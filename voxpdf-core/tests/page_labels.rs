@@ -0,0 +1,50 @@
+use voxpdf_core::{PDFDocument, VoxPDFError};
+
+const FIXTURE: &str = "tests/fixtures/page-labels.pdf";
+
+#[test]
+fn test_labels_for_each_page() {
+    let doc = PDFDocument::open(FIXTURE).unwrap();
+    let labels = doc.page_labels().unwrap();
+
+    let expected = ["i", "ii", "iii", "1", "2", "3", "App-A", "App-B"];
+    for (page, label) in expected.iter().enumerate() {
+        assert_eq!(labels.label(page as u32).as_deref(), Some(*label));
+    }
+
+    assert_eq!(doc.page_label(3).unwrap().as_deref(), Some("1"));
+    assert_eq!(doc.page_info(6).unwrap().label.as_deref(), Some("App-A"));
+}
+
+#[test]
+fn test_find_page_by_label() {
+    let doc = PDFDocument::open(FIXTURE).unwrap();
+
+    assert_eq!(doc.page_index_for_label("ii").unwrap(), Some(1));
+    assert_eq!(doc.page_index_for_label("II").unwrap(), Some(1));
+    assert_eq!(doc.page_index_for_label("3").unwrap(), Some(5));
+    assert_eq!(doc.page_index_for_label("App-B").unwrap(), Some(7));
+    assert_eq!(doc.page_index_for_label("iv").unwrap(), None);
+}
+
+#[test]
+fn test_document_without_labels() {
+    let doc = PDFDocument::open("tests/fixtures/simple.pdf").unwrap();
+    let labels = doc.page_labels().unwrap();
+
+    assert!(labels.is_empty());
+    assert_eq!(doc.page_label(0).unwrap(), None);
+    assert_eq!(doc.page_info(0).unwrap().label, None);
+
+    // Falls back to 1-based page numbers
+    assert_eq!(doc.page_index_for_label("1").unwrap(), Some(0));
+}
+
+#[test]
+fn test_page_label_out_of_range() {
+    let doc = PDFDocument::open(FIXTURE).unwrap();
+    assert!(matches!(
+        doc.page_label(8),
+        Err(VoxPDFError::PageNotFound(8, 8))
+    ));
+}
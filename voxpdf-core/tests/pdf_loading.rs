@@ -13,3 +13,77 @@ fn test_open_nonexistent_pdf() {
     let result = PDFDocument::open(path);
     assert!(result.is_err());
 }
+
+#[test]
+fn test_open_from_bytes() {
+    let bytes = std::fs::read("tests/fixtures/simple.pdf").unwrap();
+    let doc = PDFDocument::from_bytes(bytes).expect("Failed to open PDF from bytes");
+    assert_eq!(doc.page_count(), 1);
+    assert!(doc.path().is_none());
+}
+
+#[test]
+fn test_open_from_reader() {
+    let file = std::fs::File::open("tests/fixtures/simple.pdf").unwrap();
+    let doc = PDFDocument::from_reader(file).expect("Failed to open PDF from reader");
+    assert_eq!(doc.page_count(), 1);
+}
+
+#[test]
+fn test_open_invalid_bytes() {
+    let result = PDFDocument::from_bytes(b"not a pdf".to_vec());
+    assert!(result.is_err());
+}
+
+#[test]
+fn test_bytes_document_parallel_extraction() {
+    use voxpdf_core::extraction::extract_pages_parallel;
+
+    let bytes = std::fs::read("tests/fixtures/simple.pdf").unwrap();
+    let doc = PDFDocument::from_bytes(bytes).unwrap();
+
+    let results = extract_pages_parallel(&doc, vec![0]).unwrap();
+    assert_eq!(results.len(), 1);
+    assert!(!results[0].words.is_empty());
+}
+
+#[test]
+fn test_encrypted_pdf_requires_password() {
+    use voxpdf_core::extraction::{extract_page_text, extract_toc, extract_word_positions};
+    use voxpdf_core::VoxPDFError;
+
+    let mut doc = PDFDocument::open("tests/fixtures/encrypted.pdf").unwrap();
+    assert!(doc.needs_password());
+    assert_eq!(doc.page_count(), 1);
+
+    assert!(matches!(
+        extract_word_positions(&doc, 0),
+        Err(VoxPDFError::PasswordRequired)
+    ));
+    assert!(matches!(
+        extract_toc(&doc),
+        Err(VoxPDFError::PasswordRequired)
+    ));
+
+    assert!(!doc.authenticate("wrong").unwrap());
+    assert!(doc.needs_password());
+
+    assert!(doc.authenticate("secret").unwrap());
+    assert!(!doc.needs_password());
+
+    let text = extract_page_text(&doc, 0).unwrap();
+    assert!(text.contains("Hello"));
+}
+
+#[test]
+fn test_encrypted_pdf_parallel_extraction_reuses_password() {
+    use voxpdf_core::extraction::extract_pages_parallel;
+
+    let bytes = std::fs::read("tests/fixtures/encrypted.pdf").unwrap();
+    let mut doc = PDFDocument::from_bytes(bytes).unwrap();
+    assert!(doc.authenticate("secret").unwrap());
+
+    let results = extract_pages_parallel(&doc, vec![0]).unwrap();
+    assert_eq!(results.len(), 1);
+    assert!(results[0].words.iter().any(|w| w.text == "Hello"));
+}
@@ -0,0 +1,97 @@
+use voxpdf_core::extraction::{
+    detect_paragraphs, detect_running_text, extract_pages_parallel, extract_word_positions,
+    ExtractionEvent, ExtractionOptions, StreamingExtractor,
+};
+use voxpdf_core::models::ParagraphKind;
+use voxpdf_core::PDFDocument;
+
+const FIXTURE: &str = "tests/fixtures/running-text.pdf";
+
+fn keep_options() -> ExtractionOptions {
+    ExtractionOptions {
+        keep_running_text: true,
+        ..Default::default()
+    }
+}
+
+#[test]
+fn test_parallel_extraction_strips_running_text() {
+    let doc = PDFDocument::open(FIXTURE).unwrap();
+    let results = extract_pages_parallel(&doc, (0..6).collect()).unwrap();
+
+    for result in &results {
+        let texts: Vec<&str> = result.paragraphs.iter().map(|p| p.text.as_str()).collect();
+        assert_eq!(texts.len(), 2, "page {}: {:?}", result.page_num, texts);
+        assert!(texts[0].starts_with("Body text on physical page"));
+        assert!(result
+            .paragraphs
+            .iter()
+            .all(|p| p.kind == ParagraphKind::Body));
+        assert_eq!(result.paragraphs[1].index, 1);
+    }
+}
+
+#[test]
+fn test_keep_running_text_tags_paragraphs() {
+    let mut doc = PDFDocument::open(FIXTURE).unwrap();
+    doc.set_extraction_options(keep_options());
+    let results = extract_pages_parallel(&doc, vec![0, 1]).unwrap();
+
+    for result in &results {
+        let kinds: Vec<ParagraphKind> = result.paragraphs.iter().map(|p| p.kind).collect();
+        assert_eq!(
+            kinds,
+            [
+                ParagraphKind::Header,
+                ParagraphKind::Body,
+                ParagraphKind::Body,
+                ParagraphKind::PageNumber
+            ],
+            "page {}",
+            result.page_num
+        );
+    }
+    assert_eq!(results[0].paragraphs[0].text, "VoxPDF Field Guide");
+    assert_eq!(results[1].paragraphs[0].text, "Chapter 2 - Extraction");
+}
+
+#[test]
+fn test_running_text_is_cached_and_applied_per_page() {
+    let doc = PDFDocument::open(FIXTURE).unwrap();
+    let running = doc.running_text().unwrap();
+    assert!(!running.is_empty());
+    assert_eq!(running, &detect_running_text(&doc).unwrap());
+
+    let height = doc.page_info(3).unwrap().height();
+    let paragraphs = detect_paragraphs(extract_word_positions(&doc, 3).unwrap());
+    assert_eq!(paragraphs.len(), 4);
+
+    let kept = running.apply(paragraphs, height, doc.extraction_options());
+    assert_eq!(kept.len(), 2);
+}
+
+#[test]
+fn test_streaming_strips_running_text() {
+    let extractor = StreamingExtractor::new();
+    extractor.extract_async(FIXTURE, 0, 5);
+
+    let mut pages = 0;
+    while let Some(event) = extractor.receive() {
+        match event {
+            ExtractionEvent::PageComplete { paragraphs, .. } => {
+                assert_eq!(paragraphs.len(), 2);
+                pages += 1;
+            }
+            ExtractionEvent::Complete => break,
+            ExtractionEvent::Error { error, .. } => panic!("Unexpected error: {}", error),
+        }
+    }
+    assert_eq!(pages, 6);
+}
+
+#[test]
+fn test_single_page_document_keeps_everything() {
+    // simple.pdf has one page, so nothing can repeat
+    let doc = PDFDocument::open("tests/fixtures/simple.pdf").unwrap();
+    assert!(doc.running_text().unwrap().is_empty());
+}
@@ -0,0 +1,48 @@
+use voxpdf_core::extraction::{extract_document_paragraphs, SentenceSplitter};
+use voxpdf_core::PDFDocument;
+
+#[test]
+fn test_sentences_follow_document_language() {
+    let doc = PDFDocument::open("tests/fixtures/sentences.pdf").unwrap();
+    let paragraphs = extract_document_paragraphs(&doc, vec![0]).unwrap();
+    assert_eq!(paragraphs.len(), 1);
+
+    let splitter = SentenceSplitter::for_document(&doc).unwrap();
+    let sentences = splitter.split(&paragraphs[0]);
+    let texts: Vec<&str> = sentences.iter().map(|s| s.text.as_str()).collect();
+    assert_eq!(
+        texts,
+        [
+            "Am 3. Oktober 1990 kam Dr. Weber nach Berlin, vgl. S. 12 im Anhang.",
+            "Er blieb bis zum 1. Mai.",
+            "Danach zog er weiter.",
+        ]
+    );
+
+    // English rules read "3. Oktober" as the end of a sentence
+    let english = SentenceSplitter::for_language(Some("en")).split(&paragraphs[0]);
+    assert_eq!(english[0].text, "Am 3.");
+
+    // Sentences cover the paragraph's words in order, one box per line
+    let mut next_word = 0;
+    for sentence in &sentences {
+        assert_eq!(sentence.word_range.start, next_word);
+        next_word = sentence.word_range.end;
+
+        assert_eq!(
+            paragraphs[0].text[sentence.text_range.utf8.clone()],
+            sentence.text
+        );
+        assert_eq!(sentence.page_number(), Some(0));
+    }
+    assert_eq!(next_word, paragraphs[0].words.len());
+    assert_eq!(sentences[0].rects.len(), 2);
+    assert_eq!(sentences[1].rects.len(), 1);
+    assert_eq!(sentences[2].rects.len(), 2);
+
+    // The second line's boxes sit below the first
+    let (_, first_line) = &sentences[0].rects[0];
+    let (_, second_line) = &sentences[0].rects[1];
+    assert!(second_line.y > first_line.y + first_line.height / 2.0);
+    assert!(second_line.x < first_line.x + 1.0);
+}
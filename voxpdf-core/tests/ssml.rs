@@ -0,0 +1,40 @@
+use voxpdf_core::ssml::{parse_mark, SsmlRenderer};
+use voxpdf_core::PDFDocument;
+
+#[test]
+fn test_headings_from_toc_get_longer_pauses() {
+    let doc = PDFDocument::open("tests/fixtures/toc-positions.pdf").unwrap();
+    let paragraphs = doc.document_paragraphs().unwrap();
+    let ssml = SsmlRenderer::for_document(&doc).unwrap().render(paragraphs);
+
+    assert!(ssml.starts_with("<speak "));
+    assert!(ssml.ends_with("</speak>"));
+
+    // "1 Getting Started" is set apart on both sides
+    let heading = ssml.find(">Started</s></p>").unwrap();
+    let start = ssml[..heading].rfind("\n<p>").unwrap();
+    assert!(ssml[..start].ends_with("<break time=\"750ms\"/>"));
+    assert!(ssml[heading..].starts_with(">Started</s></p>\n<break time=\"750ms\"/>"));
+
+    // Every mark names a word of the paragraph it's in
+    for mark in ssml.split("<mark name=\"").skip(1) {
+        let name = &mark[..mark.find('"').unwrap()];
+        let (paragraph, word) = parse_mark(name).unwrap();
+        let text = &paragraphs[paragraph].words[word].text;
+        assert!(mark[name.len() + 3..].starts_with(text.as_str()));
+    }
+}
+
+#[test]
+fn test_bold_and_italic_words_are_emphasised() {
+    // Default options read the fonts emphasis needs
+    let doc = PDFDocument::open("tests/fixtures/styled-text.pdf").unwrap();
+    let paragraphs = doc.document_paragraphs().unwrap();
+    let ssml = SsmlRenderer::for_document(&doc)
+        .unwrap()
+        .render_paragraph(&paragraphs[0]);
+
+    assert!(ssml.contains("<emphasis level=\"strong\"><mark name=\"p0w1\"/>Bold</emphasis>"));
+    assert!(ssml.contains("<emphasis level=\"moderate\"><mark name=\"p0w2\"/>Italic</emphasis>"));
+    assert!(ssml.contains("> <mark name=\"p0w3\"/>code</s>"));
+}
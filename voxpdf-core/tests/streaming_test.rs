@@ -23,3 +23,53 @@ fn test_streaming_extraction() {
 
     assert!(received_complete);
 }
+
+#[test]
+fn test_streaming_extraction_from_bytes() {
+    use voxpdf_core::PDFDocument;
+
+    let bytes = std::fs::read("tests/fixtures/simple.pdf").unwrap();
+    let doc = PDFDocument::from_bytes(bytes).unwrap();
+
+    let extractor = StreamingExtractor::new();
+    extractor.extract_document_async(&doc, 0, 0);
+
+    let mut pages = 0;
+    while let Some(event) = extractor.receive() {
+        match event {
+            ExtractionEvent::PageComplete { paragraphs, .. } => {
+                assert!(!paragraphs.is_empty());
+                pages += 1;
+            }
+            ExtractionEvent::Complete => break,
+            ExtractionEvent::Error { error, .. } => panic!("Unexpected error: {}", error),
+        }
+    }
+
+    assert_eq!(pages, 1);
+}
+
+#[test]
+fn test_streaming_encrypted_document_reuses_password() {
+    use voxpdf_core::PDFDocument;
+
+    let mut doc = PDFDocument::open("tests/fixtures/encrypted.pdf").unwrap();
+    assert!(doc.authenticate("secret").unwrap());
+
+    let extractor = StreamingExtractor::new();
+    extractor.extract_document_async(&doc, 0, 0);
+
+    let mut pages = 0;
+    while let Some(event) = extractor.receive() {
+        match event {
+            ExtractionEvent::PageComplete { paragraphs, .. } => {
+                assert!(paragraphs.iter().any(|p| p.text.contains("Hello")));
+                pages += 1;
+            }
+            ExtractionEvent::Complete => break,
+            ExtractionEvent::Error { error, .. } => panic!("Unexpected error: {}", error),
+        }
+    }
+
+    assert_eq!(pages, 1);
+}
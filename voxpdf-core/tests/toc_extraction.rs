@@ -80,3 +80,237 @@ fn test_extract_toc_simple() {
         "First entry should be a chapter"
     );
 }
+
+#[test]
+fn test_toc_entries_point_at_heading_paragraphs() {
+    // toc-positions.pdf: headings sit mid-page; two entries use /XYZ
+    // destinations, the third only a /Fit action
+    let doc = PDFDocument::open("tests/fixtures/toc-positions.pdf").unwrap();
+    let toc_entries = extract_toc(&doc).unwrap();
+    let paragraphs = doc.document_paragraphs().unwrap();
+
+    let headings: Vec<(&str, u32, &str)> = toc_entries
+        .iter()
+        .map(|e| {
+            (
+                e.title.as_str(),
+                e.page_number,
+                paragraphs[e.paragraph_index].text.as_str(),
+            )
+        })
+        .collect();
+    assert_eq!(
+        headings,
+        [
+            ("Getting Started", 0, "1 Getting Started"),
+            ("Installing the Tools", 1, "1.1 Installing"),
+            ("Next Steps", 2, "2 Next Steps"),
+        ]
+    );
+
+    // /XYZ 72 520 on a 792pt page; the /Fit destination has no position
+    let top = toc_entries[0].dest_top.unwrap();
+    assert!((top - 272.0).abs() < 1.0, "dest_top = {top}");
+}
+
+#[test]
+fn test_extract_outline_skips_paragraph_mapping() {
+    let doc = PDFDocument::open("tests/fixtures/toc-positions.pdf").unwrap();
+    let outline = voxpdf_core::extraction::extract_outline(&doc).unwrap();
+
+    assert_eq!(outline.len(), 3);
+    assert!(outline.iter().all(|e| e.paragraph_index == 0));
+    assert_eq!(outline[1].level, 1);
+}
+
+#[test]
+fn test_toc_tree_navigation() {
+    let doc = PDFDocument::open("tests/fixtures/toc-positions.pdf").unwrap();
+    let toc = doc.toc_tree().unwrap();
+    let paragraphs = doc.document_paragraphs().unwrap();
+
+    let roots: Vec<&str> = toc.roots().map(|n| n.title()).collect();
+    assert_eq!(roots, ["Getting Started", "Next Steps"]);
+
+    let started = toc.get(0).unwrap();
+    assert!(started.entry.is_open, "/Count 1 means expanded");
+    assert_eq!(started.children, [1]);
+    assert_eq!(toc.next_sibling(0).unwrap().title(), "Next Steps");
+    assert_eq!(*started.page_range.start(), 0);
+    // The paragraph before "2 Next Steps" on page 2 still belongs to it
+    assert_eq!(*started.page_range.end(), 2);
+
+    // Every paragraph from "1.1 Installing" up to "2 Next Steps" belongs to
+    // the nested section
+    let installing = toc.get(1).unwrap();
+    for index in installing.paragraph_range.clone() {
+        assert_eq!(toc.node_for_paragraph(index).unwrap().id, 1);
+        assert_eq!(toc.chapter_for_paragraph(index).unwrap().id, 0);
+    }
+    assert_eq!(
+        toc.breadcrumb(1, " › "),
+        "Getting Started › Installing the Tools"
+    );
+
+    let next_steps = toc.get(2).unwrap();
+    assert_eq!(next_steps.paragraph_range.end, paragraphs.len());
+    assert!(
+        toc.node_for_paragraph(0).is_none(),
+        "intro precedes the outline"
+    );
+}
+
+#[test]
+fn test_toc_tree_simple() {
+    let doc = PDFDocument::open("tests/fixtures/toc-test.pdf").unwrap();
+    let toc = voxpdf_core::extraction::extract_toc_tree(&doc).unwrap();
+
+    assert_eq!(toc.len(), 5);
+    assert_eq!(toc.roots().count(), 4);
+    let nested = toc.nodes().iter().find(|n| n.entry.level == 1).unwrap();
+    assert_eq!(toc.parent(nested.id).unwrap().title(), "Chapter 3: Methods");
+}
+
+#[test]
+fn test_toc_inferred_without_outline() {
+    // inferred-headings.pdf has numbered headings at 18pt and 14pt but no
+    // /Outlines
+    let doc = PDFDocument::open("tests/fixtures/inferred-headings.pdf").unwrap();
+    let toc_entries = extract_toc(&doc).unwrap();
+    let paragraphs = doc.document_paragraphs().unwrap();
+
+    let headings: Vec<(&str, u8, u32)> = toc_entries
+        .iter()
+        .map(|e| (e.title.as_str(), e.level, e.page_number))
+        .collect();
+    assert_eq!(
+        headings,
+        [
+            ("1 Introduction", 0, 0),
+            ("1.1 Scope", 1, 0),
+            ("1.2 Terminology", 1, 1),
+            ("2 Methods", 0, 2),
+        ]
+    );
+
+    for entry in &toc_entries {
+        assert!(entry.inferred);
+        assert!(entry.confidence >= 0.5 && entry.confidence <= 1.0);
+        assert_eq!(paragraphs[entry.paragraph_index].text, entry.title);
+    }
+
+    let toc = doc.toc_tree().unwrap();
+    assert_eq!(toc.breadcrumb(2, " › "), "1 Introduction › 1.2 Terminology");
+}
+
+#[test]
+fn test_toc_inference_can_be_disabled() {
+    use voxpdf_core::extraction::ExtractionOptions;
+
+    let mut doc = PDFDocument::open("tests/fixtures/inferred-headings.pdf").unwrap();
+    doc.set_extraction_options(ExtractionOptions {
+        infer_toc: false,
+        ..Default::default()
+    });
+    assert!(extract_toc(&doc).unwrap().is_empty());
+}
+
+#[test]
+fn test_outline_entries_are_not_inferred() {
+    let doc = PDFDocument::open("tests/fixtures/toc-test.pdf").unwrap();
+    let toc_entries = extract_toc(&doc).unwrap();
+    assert!(toc_entries
+        .iter()
+        .all(|e| !e.inferred && e.confidence == 1.0));
+}
+
+#[test]
+fn test_printed_toc() {
+    use voxpdf_core::extraction::extract_printed_toc;
+
+    // printed-toc.pdf prints its contents on page 1 but has no /Outlines
+    // or /PageLabels; printed page 1 is physical page 3
+    let doc = PDFDocument::open("tests/fixtures/printed-toc.pdf").unwrap();
+    let printed = extract_printed_toc(&doc).unwrap();
+    let paragraphs = doc.document_paragraphs().unwrap();
+
+    assert_eq!(printed.pages, [1]);
+    let rows: Vec<(&str, u8, u32, &str)> = printed
+        .entries
+        .iter()
+        .map(|e| {
+            (
+                e.title.as_str(),
+                e.level,
+                e.page_number,
+                e.printed_page.as_deref().unwrap(),
+            )
+        })
+        .collect();
+    assert_eq!(
+        rows,
+        [
+            ("Preface", 0, 2, "iii"),
+            ("1 Getting Started", 0, 3, "1"),
+            ("1.1 Choosing the Right Tools", 1, 4, "2"),
+            ("1.2 Packing for a Long Day in Difficult Weather", 1, 4, "2"),
+            ("2 Field Work", 0, 5, "3"),
+            ("2.1 Keeping Notes", 1, 6, "4"),
+        ]
+    );
+
+    for entry in &printed.entries {
+        // Every title is found on the page it maps to
+        assert!(entry.inferred);
+        assert_eq!(entry.confidence, 1.0);
+        assert_eq!(paragraphs[entry.paragraph_index].text, entry.title);
+    }
+}
+
+#[test]
+fn test_no_printed_toc() {
+    use voxpdf_core::extraction::extract_printed_toc;
+
+    let doc = PDFDocument::open("tests/fixtures/inferred-headings.pdf").unwrap();
+    let printed = extract_printed_toc(&doc).unwrap();
+    assert!(printed.is_empty());
+    assert!(printed.pages.is_empty());
+}
+
+#[test]
+#[cfg(feature = "toc-fallback")]
+fn test_lopdf_fallback_titles_and_destinations() {
+    use voxpdf_core::extraction::extract_outline;
+
+    // outline-fallback.pdf has a cyclic /Next chain, which MuPDF rejects
+    let doc = PDFDocument::open("tests/fixtures/outline-fallback.pdf").unwrap();
+    let entries = extract_outline(&doc).unwrap();
+
+    let outline: Vec<(&str, u8, u32)> = entries
+        .iter()
+        .map(|e| (e.title.as_str(), e.level, e.page_number))
+        .collect();
+    assert_eq!(
+        outline,
+        [
+            ("Введение", 0, 0),
+            ("Café — the “first” steps", 1, 0),
+            ("Named by /Dests", 0, 1),
+            ("Named by the /Names tree", 1, 2),
+            ("GoTo action", 0, 2),
+            ("Named GoTo action", 0, 3),
+            ("Missing destination", 0, 0),
+        ]
+    );
+
+    // /XYZ 72 720 and /XYZ 72 700 on 792pt-high pages
+    assert_eq!(entries[0].dest_top, Some(72.0));
+    assert_eq!(entries[2].dest_top, Some(92.0));
+    assert_eq!(entries[3].dest_top, Some(192.0));
+    assert_eq!(entries[5].dest_top, None);
+    assert!(entries[0].is_open);
+    assert!(!entries[2].is_open);
+
+    // The full TOC maps the same entries to paragraphs
+    assert_eq!(extract_toc(&doc).unwrap().len(), entries.len());
+}
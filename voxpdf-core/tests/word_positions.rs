@@ -29,3 +29,180 @@ fn test_word_positions_accuracy() {
         assert!(word.bounds.height > 0.0, "Word should have height");
     }
 }
+
+#[test]
+fn test_word_fonts_and_colours() {
+    use voxpdf_core::extraction::ExtractionOptions;
+    use voxpdf_core::models::TextDirection;
+
+    let mut doc = PDFDocument::open("tests/fixtures/styled-text.pdf").unwrap();
+    doc.set_extraction_options(ExtractionOptions {
+        font_attributes: true,
+        glyph_boxes: true,
+        ..Default::default()
+    });
+    let words = extract_word_positions(&doc, 0).unwrap();
+
+    let styles: Vec<(&str, bool, bool, bool, u32)> = words
+        .iter()
+        .map(|w| {
+            let font = w.font.as_ref().unwrap();
+            (
+                w.text.as_str(),
+                font.bold,
+                font.italic,
+                font.monospace,
+                w.color.unwrap(),
+            )
+        })
+        .collect();
+    assert_eq!(
+        styles,
+        [
+            ("Plain", false, false, false, 0x000000),
+            ("Bold", true, false, false, 0xFF0000),
+            ("Italic", false, true, false, 0x000000),
+            ("code", false, false, true, 0x0000FF),
+        ]
+    );
+
+    for word in &words {
+        assert_eq!(word.direction, TextDirection::LeftToRight);
+        // 720pt up from the bottom of a 792pt page
+        let origin = word.origin.unwrap();
+        assert!((origin.y - 72.0).abs() < 0.5);
+        assert!((origin.x - word.bounds.x).abs() < 0.5);
+
+        let glyphs: String = word.glyphs.iter().map(|g| g.c).collect();
+        assert_eq!(glyphs, word.text);
+        assert!(word
+            .glyphs
+            .windows(2)
+            .all(|pair| pair[0].bounds.x < pair[1].bounds.x));
+    }
+}
+
+#[test]
+fn test_word_fonts_by_default_and_glyphs_opt_in() {
+    use voxpdf_core::extraction::ExtractionOptions;
+
+    let mut doc = PDFDocument::open("tests/fixtures/styled-text.pdf").unwrap();
+    let words = extract_word_positions(&doc, 0).unwrap();
+
+    assert_eq!(words.len(), 4);
+    for word in &words {
+        assert!(word.origin.is_some());
+        assert!(word.font.is_some() && word.color.is_some());
+        assert!(word.glyphs.is_empty());
+    }
+
+    doc.set_extraction_options(ExtractionOptions {
+        font_attributes: false,
+        ..Default::default()
+    });
+    let words = extract_word_positions(&doc, 0).unwrap();
+    assert!(words.iter().all(|w| w.font.is_none() && w.color.is_none()));
+}
+
+/// How far word boxes may stray from `simple-positions.json`, in points
+/// for positions and widths and in ems for the vertical extent
+const X_TOLERANCE: f32 = 0.5;
+const WIDTH_TOLERANCE: f32 = 1.0;
+/// The box must reach this far above the baseline (cap height)...
+const MIN_ASCENT: f32 = 0.6;
+/// ...and no further below it than the deepest descender
+const MAX_DESCENT: f32 = 0.35;
+
+#[test]
+fn test_word_positions_match_expected() {
+    use voxpdf_core::CoordinateSpace;
+
+    let expected: serde_json::Value = serde_json::from_str(
+        &std::fs::read_to_string("tests/fixtures/simple-positions.json").unwrap(),
+    )
+    .unwrap();
+
+    let doc = PDFDocument::open("tests/fixtures/simple.pdf").unwrap();
+    let info = doc.page_info(0).unwrap();
+    let words = extract_word_positions(&doc, 0).unwrap();
+
+    for expected in expected["words"].as_array().unwrap() {
+        let text = expected["text"].as_str().unwrap();
+        let value = |key: &str| expected[key].as_f64().unwrap() as f32;
+        // The fixture gives the baseline origin in PDF user space and the
+        // font size as height
+        let (x, baseline, width, size) = (value("x"), value("y"), value("width"), value("height"));
+
+        let word = words.iter().find(|w| w.text == text).unwrap();
+        let native = info.transform_rect(&word.bounds, CoordinateSpace::BottomLeft);
+        let (bottom, top) = (native.y, native.y + native.height);
+
+        assert!(
+            (native.x - x).abs() <= X_TOLERANCE,
+            "{}: x {} vs {}",
+            text,
+            native.x,
+            x
+        );
+        assert!(
+            (native.width - width).abs() <= WIDTH_TOLERANCE,
+            "{}: width {} vs {}",
+            text,
+            native.width,
+            width
+        );
+        assert!(
+            top >= baseline + size * MIN_ASCENT && bottom < baseline,
+            "{}: {:?} should cover the glyphs above the baseline at {}",
+            text,
+            native,
+            baseline
+        );
+        assert!(
+            bottom >= baseline - size * MAX_DESCENT,
+            "{}: {:?} reaches too far below the baseline at {}",
+            text,
+            native,
+            baseline
+        );
+        assert!(word.quad.is_none());
+    }
+}
+
+#[test]
+fn test_rotated_words_keep_their_quad() {
+    // /Rotate 90: the line runs down the displayed page
+    let doc = PDFDocument::open("tests/fixtures/rotated.pdf").unwrap();
+    let words = extract_word_positions(&doc, 0).unwrap();
+    let word = words.iter().find(|w| w.text == "Rotated").unwrap();
+
+    let quad = word.quad.expect("rotated text should have a quad");
+    assert!((quad.angle().abs() - 90.0).abs() < 1.0, "{:?}", quad);
+
+    // `bounds` is the box around the quad
+    let around = quad.bounds();
+    assert!((word.bounds.x - around.x).abs() < 0.01);
+    assert!((word.bounds.y - around.y).abs() < 0.01);
+    assert!((word.bounds.width - around.width).abs() < 0.01);
+    assert!((word.bounds.height - around.height).abs() < 0.01);
+    // Tall and narrow, like the line it's part of
+    assert!(word.bounds.height > word.bounds.width);
+
+    // Upright text on the same document has none
+    let words = extract_word_positions(&doc, 2).unwrap();
+    assert!(words.iter().all(|w| w.quad.is_none()));
+}
+
+#[test]
+fn test_word_gaps_follow_space_width() {
+    let doc = PDFDocument::open("tests/fixtures/word-spacing.pdf").unwrap();
+    let words = extract_word_positions(&doc, 0).unwrap();
+
+    let text: Vec<&str> = words.iter().map(|w| w.text.as_str()).collect();
+    // Gaps without space characters still split
+    assert_eq!(text[..3], ["small", "footnote", "text"]);
+    // Letter spacing narrower than the font's space doesn't
+    assert_eq!(text[3..5], ["Tracked", "Heading"]);
+    assert_eq!(text[5..8], ["Wide", "set", "mono"]);
+    assert_eq!(text[8..].join(" "), "Body text sets the usual space width.");
+}
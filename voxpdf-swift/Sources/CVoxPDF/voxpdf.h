@@ -13,11 +13,112 @@ typedef enum {
     CVoxPDFErrorIoError = 3,
     CVoxPDFErrorOutOfMemory = 4,
     CVoxPDFErrorInvalidText = 5,
+    CVoxPDFErrorPasswordRequired = 6,
+    CVoxPDFErrorInvalidArgument = 7,
+    CVoxPDFErrorIndexOutOfRange = 8,
 } CVoxPDFError;
 
+// Metadata fields for voxpdf_get_metadata_field
+typedef enum {
+    CMetadataFieldTitle = 0,
+    CMetadataFieldAuthor = 1,
+    CMetadataFieldSubject = 2,
+    CMetadataFieldKeywords = 3,
+    CMetadataFieldCreator = 4,
+    CMetadataFieldProducer = 5,
+    CMetadataFieldCreationDate = 6,
+    CMetadataFieldModificationDate = 7,
+    CMetadataFieldLanguage = 8,
+} CMetadataField;
+
+// Coordinate spaces for voxpdf_transform_rect
+typedef enum {
+    CCoordinateSpaceTopLeft = 0,
+    CCoordinateSpaceBottomLeft = 1,
+    CCoordinateSpaceNormalized = 2,
+} CCoordinateSpace;
+
+// Built-in extraction profiles
+typedef enum {
+    CExtractionProfileNovel = 0,
+    CExtractionProfileAcademic = 1,
+    CExtractionProfileTextbook = 2,
+    CExtractionProfileLegal = 3,
+    CExtractionProfileSlides = 4,
+    CExtractionProfileMagazine = 5,
+} CExtractionProfile;
+
+// What a paragraph is (running text is only returned with keep_running_text)
+typedef enum {
+    CParagraphKindBody = 0,
+    CParagraphKindHeader = 1,
+    CParagraphKindFooter = 2,
+    CParagraphKindPageNumber = 3,
+} CParagraphKind;
+
+// Writing direction of a word
+typedef enum {
+    CTextDirectionLeftToRight = 0,
+    CTextDirectionRightToLeft = 1,
+    CTextDirectionTopToBottom = 2,
+} CTextDirection;
+
+// Where a paragraph's or sentence's language came from
+typedef enum {
+    CLanguageSourceDocument = 0,  // Catalog /Lang, agreed with by the text
+    CLanguageSourceTagged = 1,    // Tagged-PDF /Lang
+    CLanguageSourceDetected = 2,  // Identified from the text
+} CLanguageSource;
+
 // Opaque document pointer
 typedef struct CVoxPDFDocument CVoxPDFDocument;
 
+// Rectangle (origin plus size)
+typedef struct {
+    float x;
+    float y;
+    float width;
+    float height;
+} CRect;
+
+// Extraction options (JSON; missing fields keep their defaults)
+bool voxpdf_set_extraction_options(
+    CVoxPDFDocument* doc,
+    const char* options_json,
+    CVoxPDFError* error_out
+);
+
+bool voxpdf_get_extraction_options(
+    const CVoxPDFDocument* doc,
+    const char** json_out,
+    CVoxPDFError* error_out
+);
+
+// profile is a CExtractionProfile
+bool voxpdf_apply_profile(
+    CVoxPDFDocument* doc,
+    uint32_t profile,
+    CVoxPDFError* error_out
+);
+
+bool voxpdf_detect_profile(
+    const CVoxPDFDocument* doc,
+    CExtractionProfile* profile_out,
+    float* confidence_out,
+    CVoxPDFError* error_out
+);
+
+// Page geometry (boxes in PDF user space, bottom-left origin)
+typedef struct {
+    uint32_t index;
+    CRect media_box;
+    CRect crop_box;
+    uint16_t rotation;
+    float user_unit;
+    float width;
+    float height;
+} CPageInfo;
+
 // Word position structure
 typedef struct {
     float x;
@@ -28,25 +129,163 @@ typedef struct {
     float font_size;
 } CWordPosition;
 
+// Corners at the start (ul, ll) and end (ur, lr) of a word
+typedef struct {
+    float ul_x;
+    float ul_y;
+    float ur_x;
+    float ur_y;
+    float ll_x;
+    float ll_y;
+    float lr_x;
+    float lr_y;
+} CQuad;
+
+// Word style; font fields and color need the font_attributes option,
+// glyphs need glyph_boxes
+typedef struct {
+    float origin_x;     // Baseline origin of the first character
+    float origin_y;
+    CTextDirection direction;
+    bool has_font;
+    bool bold;
+    bool italic;
+    bool monospace;
+    uint32_t color;     // 0xRRGGBB
+    size_t glyph_count;
+    bool has_quad;      // Set for text that isn't upright on the page
+    CQuad quad;
+} CWordStyle;
+
+// Box of one character of a word
+typedef struct {
+    uint32_t codepoint;
+    float x;
+    float y;
+    float width;
+    float height;
+} CGlyph;
+
 // Paragraph structure
 typedef struct {
     size_t index;
     uint32_t page_number;
     size_t word_count;
+    CParagraphKind kind;
 } CParagraph;
 
+// Position of a word or sentence in its paragraph's text, as start..end offsets
+typedef struct {
+    size_t utf8_start;   // UTF-8 bytes
+    size_t utf8_end;
+    size_t utf16_start;  // UTF-16 code units, as in NSRange
+    size_t utf16_end;
+} CTextRange;
+
+// Sentence of a document paragraph
+typedef struct {
+    size_t index;
+    CTextRange text_range;  // Offsets into the paragraph text
+    size_t word_start;  // Indices into the paragraph's words
+    size_t word_end;
+    size_t rect_count;  // One box per printed line
+    uint32_t page_number;
+} CSentence;
+
+// Language of a paragraph or sentence (the BCP 47 tag is returned separately)
+typedef struct {
+    float confidence;  // 0-1
+    CLanguageSource source;
+} CLanguage;
+
 // TOC entry structure
 typedef struct {
     uint8_t level;
     uint32_t page_number;
     size_t paragraph_index;
+    bool inferred;      // From heading typography; the PDF has no outline
+    float confidence;   // 1.0 for outline entries
 } CTocEntry;
 
+// TOC tree node; ids are TOC entry indices, missing links are flagged by has_*
+typedef struct {
+    size_t id;
+    bool has_parent;
+    size_t parent;
+    size_t child_count;
+    size_t first_child;
+    bool has_next_sibling;
+    size_t next_sibling;
+    bool has_prev_sibling;
+    size_t prev_sibling;
+    bool is_open;
+    size_t paragraph_start;
+    size_t paragraph_end;
+    uint32_t page_start;
+    uint32_t page_end;
+} CTocNode;
+
 // Document lifecycle
 CVoxPDFDocument* voxpdf_open(const char* path, CVoxPDFError* error_out);
+CVoxPDFDocument* voxpdf_open_bytes(const uint8_t* data, size_t len, CVoxPDFError* error_out);
 size_t voxpdf_get_page_count(const CVoxPDFDocument* doc);
 void voxpdf_free_document(CVoxPDFDocument* doc);
 
+// Encryption
+bool voxpdf_needs_password(const CVoxPDFDocument* doc);
+bool voxpdf_authenticate(
+    CVoxPDFDocument* doc,
+    const char* password,
+    CVoxPDFError* error_out
+);
+
+// Metadata (field is a CMetadataField; value_out is set to NULL when the
+// field is absent)
+bool voxpdf_get_metadata_field(
+    const CVoxPDFDocument* doc,
+    uint32_t field,
+    const char** value_out,
+    CVoxPDFError* error_out
+);
+
+bool voxpdf_get_metadata_json(
+    const CVoxPDFDocument* doc,
+    const char** json_out,
+    CVoxPDFError* error_out
+);
+
+// Page geometry
+bool voxpdf_get_page_info(
+    const CVoxPDFDocument* doc,
+    uint32_t page,
+    CPageInfo* info_out,
+    CVoxPDFError* error_out
+);
+
+// space is a CCoordinateSpace
+bool voxpdf_transform_rect(
+    const CPageInfo* info,
+    CRect rect,
+    uint32_t space,
+    CRect* rect_out,
+    CVoxPDFError* error_out
+);
+
+// Page labels (label_out is set to NULL when the page has no label)
+bool voxpdf_get_page_label(
+    const CVoxPDFDocument* doc,
+    uint32_t page,
+    const char** label_out,
+    CVoxPDFError* error_out
+);
+
+bool voxpdf_find_page_by_label(
+    const CVoxPDFDocument* doc,
+    const char* label,
+    uint32_t* page_out,
+    CVoxPDFError* error_out
+);
+
 // Text extraction
 bool voxpdf_extract_page_text(
     const CVoxPDFDocument* doc,
@@ -71,6 +310,25 @@ bool voxpdf_get_word(
     CVoxPDFError* error_out
 );
 
+// font_name_out is set to NULL when the font isn't known
+bool voxpdf_get_word_style(
+    const CVoxPDFDocument* doc,
+    uint32_t page,
+    size_t index,
+    CWordStyle* style_out,
+    const char** font_name_out,
+    CVoxPDFError* error_out
+);
+
+bool voxpdf_get_word_glyph(
+    const CVoxPDFDocument* doc,
+    uint32_t page,
+    size_t word_index,
+    size_t glyph_index,
+    CGlyph* glyph_out,
+    CVoxPDFError* error_out
+);
+
 // Paragraphs
 size_t voxpdf_get_paragraph_count(
     const CVoxPDFDocument* doc,
@@ -87,6 +345,122 @@ bool voxpdf_get_paragraph(
     CVoxPDFError* error_out
 );
 
+// Document-wide paragraphs, stitched across page breaks
+size_t voxpdf_get_document_paragraph_count(
+    const CVoxPDFDocument* doc,
+    CVoxPDFError* error_out
+);
+
+bool voxpdf_get_document_paragraph(
+    const CVoxPDFDocument* doc,
+    size_t index,
+    CParagraph* para_out,
+    const char** text_out,
+    CVoxPDFError* error_out
+);
+
+// Words of document paragraphs by text offset
+bool voxpdf_get_paragraph_word_range(
+    const CVoxPDFDocument* doc,
+    size_t paragraph_index,
+    size_t word_index,
+    CTextRange* range_out,
+    CVoxPDFError* error_out
+);
+
+// Returns false with no error if the offset is past the last word
+bool voxpdf_get_paragraph_word_at_utf16(
+    const CVoxPDFDocument* doc,
+    size_t paragraph_index,
+    size_t utf16_offset,
+    size_t* word_index_out,
+    CVoxPDFError* error_out
+);
+
+// Sentences of document paragraphs, split by the document's language rules
+size_t voxpdf_get_sentence_count(
+    const CVoxPDFDocument* doc,
+    size_t paragraph_index,
+    CVoxPDFError* error_out
+);
+
+bool voxpdf_get_sentence(
+    const CVoxPDFDocument* doc,
+    size_t paragraph_index,
+    size_t sentence_index,
+    CSentence* sentence_out,
+    const char** text_out,
+    CVoxPDFError* error_out
+);
+
+// Box around one line of a sentence (top-left origin)
+bool voxpdf_get_sentence_rect(
+    const CVoxPDFDocument* doc,
+    size_t paragraph_index,
+    size_t sentence_index,
+    size_t rect_index,
+    CRect* rect_out,
+    uint32_t* page_out,
+    CVoxPDFError* error_out
+);
+
+// Languages of document paragraphs and sentences as BCP 47 tags.
+// Returns false with no error if there is none
+bool voxpdf_get_paragraph_language(
+    const CVoxPDFDocument* doc,
+    size_t paragraph_index,
+    CLanguage* language_out,
+    const char** tag_out,
+    CVoxPDFError* error_out
+);
+
+bool voxpdf_get_sentence_language(
+    const CVoxPDFDocument* doc,
+    size_t paragraph_index,
+    size_t sentence_index,
+    CLanguage* language_out,
+    const char** tag_out,
+    CVoxPDFError* error_out
+);
+
+// Document paragraph text normalized for speech (symbols, abbreviations,
+// numbers), following the document's language
+bool voxpdf_normalize_paragraph(
+    const CVoxPDFDocument* doc,
+    size_t paragraph_index,
+    const char** text_out,
+    CVoxPDFError* error_out
+);
+
+// Words of document paragraphs by offset into the normalized text
+bool voxpdf_get_normalized_word_range(
+    const CVoxPDFDocument* doc,
+    size_t paragraph_index,
+    size_t word_index,
+    CTextRange* range_out,
+    CVoxPDFError* error_out
+);
+
+// Returns false with no error if the offset is past the last word
+bool voxpdf_get_normalized_word_at_utf16(
+    const CVoxPDFDocument* doc,
+    size_t paragraph_index,
+    size_t utf16_offset,
+    size_t* word_index_out,
+    CVoxPDFError* error_out
+);
+
+// SSML for document paragraphs [paragraph_start, paragraph_end).
+// options_json may be NULL for the defaults; marks are named "p{paragraph}w{word}"
+bool voxpdf_render_ssml(
+    const CVoxPDFDocument* doc,
+    size_t paragraph_start,
+    size_t paragraph_end,
+    const char* options_json,
+    const char** ssml_out,
+    CVoxPDFError* error_out
+);
+
 // TOC
 size_t voxpdf_get_toc_count(
     const CVoxPDFDocument* doc,
@@ -101,6 +475,20 @@ bool voxpdf_get_toc_entry(
     CVoxPDFError* error_out
 );
 
+bool voxpdf_get_toc_node(
+    const CVoxPDFDocument* doc,
+    size_t id,
+    CTocNode* node_out,
+    CVoxPDFError* error_out
+);
+
+bool voxpdf_get_toc_node_for_paragraph(
+    const CVoxPDFDocument* doc,
+    size_t paragraph_index,
+    CTocNode* node_out,
+    CVoxPDFError* error_out
+);
+
 // Memory management
 void voxpdf_free_string(char* s);
 
@@ -0,0 +1,40 @@
+import Foundation
+import CVoxPDF
+
+/// Where a language came from.
+public enum LanguageSource {
+    /// The document's declared language, which the text agrees with or is
+    /// too short to contradict.
+    case document
+    /// A tagged-PDF `/Lang` on the text.
+    case tagged
+    /// Identified from the text itself.
+    case detected
+
+    init(_ source: CLanguageSource) {
+        switch source {
+        case CLanguageSourceTagged: self = .tagged
+        case CLanguageSourceDetected: self = .detected
+        default: self = .document
+        }
+    }
+}
+
+/// The language of a paragraph or sentence, for picking a voice.
+public struct Language {
+    /// BCP 47 tag, such as "fr-FR", as `AVSpeechSynthesisVoice(language:)`
+    /// takes it.
+    public let tag: String
+
+    /// 0–1: 1 for tagged text, otherwise how clearly the text points at
+    /// this language.
+    public let confidence: Float
+
+    public let source: LanguageSource
+
+    init(tag: String, cLanguage: CLanguage) {
+        self.tag = tag
+        self.confidence = cLanguage.confidence
+        self.source = LanguageSource(cLanguage.source)
+    }
+}
@@ -1,4 +1,5 @@
 import Foundation
+import CoreGraphics
 import CVoxPDF
 
 public class PDFDocument {
@@ -93,6 +94,55 @@ public class PDFDocument {
         return words
     }
 
+    /// Returns the origin, direction and typography of a word.
+    ///
+    /// Fonts and colours need the `font_attributes` extraction option.
+    ///
+    /// - Parameters:
+    ///   - page: The zero-indexed page number
+    ///   - index: An index into `wordPositions(page:)`
+    /// - Throws: `VoxPDFError` if extraction fails
+    public func wordStyle(page: Int, index: Int) throws -> WordStyle {
+        var error: CVoxPDFError = CVoxPDFErrorOk
+        var cStyle = CWordStyle()
+        var namePtr: UnsafePointer<CChar>?
+
+        let result = voxpdf_get_word_style(handle, UInt32(page), index, &cStyle, &namePtr, &error)
+        guard result, error.rawValue == 0 else {
+            throw VoxPDFError(code: Int32(error.rawValue), context: "page \(page), word \(index)")
+        }
+
+        var fontName: String?
+        if let ptr = namePtr {
+            fontName = String(cString: ptr)
+            voxpdf_free_string(UnsafeMutablePointer(mutating: ptr))
+        }
+
+        return WordStyle(fontName: fontName, cStyle: cStyle)
+    }
+
+    /// Returns the character boxes of a word.
+    ///
+    /// Empty unless the `glyph_boxes` extraction option is enabled.
+    ///
+    /// - Parameters:
+    ///   - page: The zero-indexed page number
+    ///   - wordIndex: An index into `wordPositions(page:)`
+    /// - Throws: `VoxPDFError` if extraction fails
+    public func glyphs(page: Int, wordIndex: Int) throws -> [Glyph] {
+        let style = try wordStyle(page: page, index: wordIndex)
+        var error: CVoxPDFError = CVoxPDFErrorOk
+
+        return try (0..<style.glyphCount).map { glyphIndex in
+            var cGlyph = CGlyph()
+            guard voxpdf_get_word_glyph(handle, UInt32(page), wordIndex, glyphIndex, &cGlyph, &error),
+                  error.rawValue == 0 else {
+                throw VoxPDFError(code: Int32(error.rawValue), context: "page \(page), word \(wordIndex)")
+            }
+            return Glyph(cGlyph: cGlyph)
+        }
+    }
+
     /// Extracts paragraphs from a specific page.
     ///
     /// - Parameter page: The zero-indexed page number
@@ -113,7 +163,7 @@ public class PDFDocument {
         paragraphs.reserveCapacity(count)
 
         for index in 0..<count {
-            var cPara = CParagraph(index: 0, page_number: 0, word_count: 0)
+            var cPara = CParagraph(index: 0, page_number: 0, word_count: 0, kind: CParagraphKindBody)
             var textPtr: UnsafePointer<CChar>?
 
             let result = voxpdf_get_paragraph(
@@ -141,6 +191,276 @@ public class PDFDocument {
         return paragraphs
     }
 
+    /// Extracts every page's paragraphs, joining paragraphs that continue
+    /// across page breaks.
+    ///
+    /// `TocEntry.paragraphIndex` indexes into this array.
+    ///
+    /// - Returns: Array of paragraphs in reading order
+    /// - Throws: `VoxPDFError` if extraction fails
+    public func documentParagraphs() throws -> [Paragraph] {
+        var error: CVoxPDFError = CVoxPDFErrorOk
+
+        let count = voxpdf_get_document_paragraph_count(handle, &error)
+        guard error.rawValue == 0 else {
+            throw VoxPDFError(code: Int32(error.rawValue), context: "document paragraphs")
+        }
+
+        var paragraphs: [Paragraph] = []
+        paragraphs.reserveCapacity(count)
+
+        for index in 0..<count {
+            var cPara = CParagraph(index: 0, page_number: 0, word_count: 0, kind: CParagraphKindBody)
+            var textPtr: UnsafePointer<CChar>?
+
+            let result = voxpdf_get_document_paragraph(
+                handle,
+                index,
+                &cPara,
+                &textPtr,
+                &error
+            )
+
+            guard result, error.rawValue == 0, let ptr = textPtr else {
+                throw VoxPDFError(code: Int32(error.rawValue), context: "document paragraph \(index)")
+            }
+
+            let text = String(cString: ptr)
+            voxpdf_free_string(UnsafeMutablePointer(mutating: ptr))
+
+            paragraphs.append(Paragraph(text: text, cParagraph: cPara))
+        }
+
+        return paragraphs
+    }
+
+    /// Returns where a word sits in a document paragraph's text.
+    ///
+    /// - Parameters:
+    ///   - index: An index into `documentParagraphs()`
+    ///   - wordIndex: An index into the paragraph's words
+    /// - Returns: The word's range in UTF-16 code units, as `NSString` and
+    ///   `AVSpeechSynthesizer` count them
+    /// - Throws: `VoxPDFError` if either index is out of range or extraction fails
+    public func wordRange(inParagraph index: Int, wordIndex: Int) throws -> NSRange {
+        var error: CVoxPDFError = CVoxPDFErrorOk
+        var cRange = CTextRange()
+
+        guard voxpdf_get_paragraph_word_range(handle, index, wordIndex, &cRange, &error),
+              error.rawValue == 0 else {
+            throw VoxPDFError(code: Int32(error.rawValue), context: "paragraph \(index), word \(wordIndex)")
+        }
+
+        return NSRange(location: cRange.utf16_start, length: cRange.utf16_end - cRange.utf16_start)
+    }
+
+    /// Finds the word a speech synthesizer is speaking.
+    ///
+    /// Pass the location of the range from
+    /// `speechSynthesizer(_:willSpeakRangeOfSpeechString:utterance:)` when
+    /// the utterance is the paragraph's text.
+    ///
+    /// - Parameters:
+    ///   - index: An index into `documentParagraphs()`
+    ///   - utf16Offset: An offset into the paragraph's text in UTF-16 code units
+    /// - Returns: The index of the word at the offset (or the next one, if
+    ///   the offset is between words), or nil past the last word
+    /// - Throws: `VoxPDFError` if the paragraph index is out of range or extraction fails
+    public func wordIndex(inParagraph index: Int, utf16Offset: Int) throws -> Int? {
+        var error: CVoxPDFError = CVoxPDFErrorOk
+        var wordIndex = 0
+
+        let found = voxpdf_get_paragraph_word_at_utf16(handle, index, utf16Offset, &wordIndex, &error)
+        guard error.rawValue == 0 else {
+            throw VoxPDFError(code: Int32(error.rawValue), context: "document paragraph \(index)")
+        }
+
+        return found ? wordIndex : nil
+    }
+
+    /// Splits a document paragraph into sentences, using the rules for the
+    /// document's language.
+    ///
+    /// - Parameter index: An index into `documentParagraphs()`
+    /// - Returns: Array of sentences in reading order
+    /// - Throws: `VoxPDFError` if the index is out of range or extraction fails
+    public func sentences(inParagraph index: Int) throws -> [Sentence] {
+        var error: CVoxPDFError = CVoxPDFErrorOk
+
+        let count = voxpdf_get_sentence_count(handle, index, &error)
+        guard error.rawValue == 0 else {
+            throw VoxPDFError(code: Int32(error.rawValue), context: "document paragraph \(index)")
+        }
+
+        return try (0..<count).map { sentenceIndex in
+            var cSentence = CSentence()
+            var textPtr: UnsafePointer<CChar>?
+
+            guard voxpdf_get_sentence(handle, index, sentenceIndex, &cSentence, &textPtr, &error),
+                  error.rawValue == 0, let ptr = textPtr else {
+                throw VoxPDFError(code: Int32(error.rawValue), context: "paragraph \(index), sentence \(sentenceIndex)")
+            }
+
+            let text = String(cString: ptr)
+            voxpdf_free_string(UnsafeMutablePointer(mutating: ptr))
+
+            let lines = try (0..<cSentence.rect_count).map { rectIndex -> (page: Int, bounds: CGRect) in
+                var cRect = CRect()
+                var page: UInt32 = 0
+                guard voxpdf_get_sentence_rect(handle, index, sentenceIndex, rectIndex, &cRect, &page, &error),
+                      error.rawValue == 0 else {
+                    throw VoxPDFError(code: Int32(error.rawValue), context: "paragraph \(index), sentence \(sentenceIndex)")
+                }
+                let bounds = CGRect(
+                    x: CGFloat(cRect.x),
+                    y: CGFloat(cRect.y),
+                    width: CGFloat(cRect.width),
+                    height: CGFloat(cRect.height)
+                )
+                return (page: Int(page), bounds: bounds)
+            }
+
+            var cLanguage = CLanguage()
+            var tagPtr: UnsafePointer<CChar>?
+            var language: Language?
+            if voxpdf_get_sentence_language(handle, index, sentenceIndex, &cLanguage, &tagPtr, &error),
+               let tagPtr = tagPtr {
+                language = Language(tag: String(cString: tagPtr), cLanguage: cLanguage)
+                voxpdf_free_string(UnsafeMutablePointer(mutating: tagPtr))
+            }
+            guard error.rawValue == 0 else {
+                throw VoxPDFError(code: Int32(error.rawValue), context: "paragraph \(index), sentence \(sentenceIndex)")
+            }
+
+            return Sentence(text: text, cSentence: cSentence, lines: lines, language: language)
+        }
+    }
+
+    /// Returns the language of a document paragraph, for choosing a voice.
+    ///
+    /// Taken from tagged-PDF `/Lang` attributes where the text has them,
+    /// otherwise identified from the text, favouring the document's own
+    /// language.
+    ///
+    /// - Parameter index: An index into `documentParagraphs()`
+    /// - Returns: The paragraph's language, or nil if it's too short to
+    ///   identify and the document declares none
+    /// - Throws: `VoxPDFError` if the index is out of range or extraction fails
+    public func language(ofParagraph index: Int) throws -> Language? {
+        var error: CVoxPDFError = CVoxPDFErrorOk
+        var cLanguage = CLanguage()
+        var tagPtr: UnsafePointer<CChar>?
+
+        let found = voxpdf_get_paragraph_language(handle, index, &cLanguage, &tagPtr, &error)
+        guard error.rawValue == 0 else {
+            throw VoxPDFError(code: Int32(error.rawValue), context: "document paragraph \(index)")
+        }
+        guard found, let ptr = tagPtr else {
+            return nil
+        }
+
+        let tag = String(cString: ptr)
+        voxpdf_free_string(UnsafeMutablePointer(mutating: ptr))
+        return Language(tag: tag, cLanguage: cLanguage)
+    }
+
+    /// Returns a document paragraph's text normalized for speech.
+    ///
+    /// Ligatures and typographic quotes are folded, and symbols ("§", "%"),
+    /// abbreviations ("Fig."), units and number ranges are written out as
+    /// words in the document's language. Speak this instead of the
+    /// paragraph's text and map reported ranges back with
+    /// `normalizedWordIndex(inParagraph:utf16Offset:)`.
+    ///
+    /// - Parameter index: An index into `documentParagraphs()`
+    /// - Returns: The text to speak
+    /// - Throws: `VoxPDFError` if the index is out of range or extraction fails
+    public func normalizedText(ofParagraph index: Int) throws -> String {
+        var error: CVoxPDFError = CVoxPDFErrorOk
+        var textPtr: UnsafePointer<CChar>?
+
+        guard voxpdf_normalize_paragraph(handle, index, &textPtr, &error),
+              error.rawValue == 0, let ptr = textPtr else {
+            throw VoxPDFError(code: Int32(error.rawValue), context: "document paragraph \(index)")
+        }
+
+        defer { voxpdf_free_string(UnsafeMutablePointer(mutating: ptr)) }
+
+        return String(cString: ptr)
+    }
+
+    /// Returns where a word sits in a document paragraph's normalized text.
+    ///
+    /// - Parameters:
+    ///   - index: An index into `documentParagraphs()`
+    ///   - wordIndex: An index into the paragraph's words
+    /// - Returns: The range of what the word is read as, in UTF-16 code units
+    /// - Throws: `VoxPDFError` if either index is out of range or extraction fails
+    public func normalizedWordRange(inParagraph index: Int, wordIndex: Int) throws -> NSRange {
+        var error: CVoxPDFError = CVoxPDFErrorOk
+        var cRange = CTextRange()
+
+        guard voxpdf_get_normalized_word_range(handle, index, wordIndex, &cRange, &error),
+              error.rawValue == 0 else {
+            throw VoxPDFError(code: Int32(error.rawValue), context: "paragraph \(index), word \(wordIndex)")
+        }
+
+        return NSRange(location: cRange.utf16_start, length: cRange.utf16_end - cRange.utf16_start)
+    }
+
+    /// Finds the word a speech synthesizer is speaking when the utterance
+    /// is `normalizedText(ofParagraph:)`.
+    ///
+    /// - Parameters:
+    ///   - index: An index into `documentParagraphs()`
+    ///   - utf16Offset: An offset into the normalized text in UTF-16 code units
+    /// - Returns: The index of the word read at the offset, or nil past the
+    ///   last word
+    /// - Throws: `VoxPDFError` if the paragraph index is out of range or extraction fails
+    public func normalizedWordIndex(inParagraph index: Int, utf16Offset: Int) throws -> Int? {
+        var error: CVoxPDFError = CVoxPDFErrorOk
+        var wordIndex = 0
+
+        let found = voxpdf_get_normalized_word_at_utf16(handle, index, utf16Offset, &wordIndex, &error)
+        guard error.rawValue == 0 else {
+            throw VoxPDFError(code: Int32(error.rawValue), context: "document paragraph \(index)")
+        }
+
+        return found ? wordIndex : nil
+    }
+
+    /// Renders document paragraphs as an SSML `<speak>` document.
+    ///
+    /// Each word is preceded by a `<mark>` named "p{paragraph}w{word}",
+    /// indexing `documentParagraphs()` and the paragraph's words.
+    ///
+    /// - Parameters:
+    ///   - paragraphs: A range of indices into `documentParagraphs()`
+    ///   - optionsJSON: Renderer options such as `{"marks": false}`, or nil
+    ///     for the defaults
+    /// - Returns: The SSML markup
+    /// - Throws: `VoxPDFError` if the range is out of bounds, the options are
+    ///   invalid or extraction fails
+    public func ssml(paragraphs: Range<Int>, optionsJSON: String? = nil) throws -> String {
+        var error: CVoxPDFError = CVoxPDFErrorOk
+        var ssmlPtr: UnsafePointer<CChar>?
+
+        let result: Bool
+        if let optionsJSON = optionsJSON {
+            result = voxpdf_render_ssml(handle, paragraphs.lowerBound, paragraphs.upperBound, optionsJSON, &ssmlPtr, &error)
+        } else {
+            result = voxpdf_render_ssml(handle, paragraphs.lowerBound, paragraphs.upperBound, nil, &ssmlPtr, &error)
+        }
+
+        guard result, error.rawValue == 0, let ptr = ssmlPtr else {
+            throw VoxPDFError(code: Int32(error.rawValue), context: "SSML for paragraphs \(paragraphs)")
+        }
+
+        defer { voxpdf_free_string(UnsafeMutablePointer(mutating: ptr)) }
+
+        return String(cString: ptr)
+    }
+
     /// Extracts the table of contents from the document.
     ///
     /// - Returns: Array of TOC entries (empty if document has no TOC)
@@ -157,7 +477,7 @@ public class PDFDocument {
         entries.reserveCapacity(count)
 
         for index in 0..<count {
-            var cToc = CTocEntry(level: 0, page_number: 0, paragraph_index: 0)
+            var cToc = CTocEntry(level: 0, page_number: 0, paragraph_index: 0, inferred: false, confidence: 0)
             var titlePtr: UnsafePointer<CChar>?
 
             let result = voxpdf_get_toc_entry(
@@ -180,4 +500,38 @@ public class PDFDocument {
 
         return entries
     }
+
+    /// Looks up a node of the table-of-contents tree.
+    ///
+    /// - Parameter id: The index of the entry in `tableOfContents()`
+    /// - Returns: The node with its parent, child and sibling links
+    /// - Throws: `VoxPDFError` if the id is out of range or extraction fails
+    public func tocNode(id: Int) throws -> TocNode {
+        var error: CVoxPDFError = CVoxPDFErrorOk
+        var cNode = CTocNode()
+
+        guard voxpdf_get_toc_node(handle, id, &cNode, &error), error.rawValue == 0 else {
+            throw VoxPDFError(code: Int32(error.rawValue), context: "TOC node \(id)")
+        }
+
+        return TocNode(cTocNode: cNode)
+    }
+
+    /// Finds the most deeply nested table-of-contents entry containing a
+    /// paragraph, e.g. to show "Chapter 4 › Section 2" while listening.
+    ///
+    /// - Parameter index: An index into `documentParagraphs()`
+    /// - Returns: The node, or nil if the paragraph comes before the first entry
+    /// - Throws: `VoxPDFError` if extraction fails
+    public func tocNode(containingParagraph index: Int) throws -> TocNode? {
+        var error: CVoxPDFError = CVoxPDFErrorOk
+        var cNode = CTocNode()
+
+        let found = voxpdf_get_toc_node_for_paragraph(handle, index, &cNode, &error)
+        guard error.rawValue == 0 else {
+            throw VoxPDFError(code: Int32(error.rawValue), context: "TOC node for paragraph \(index)")
+        }
+
+        return found ? TocNode(cTocNode: cNode) : nil
+    }
 }
@@ -1,6 +1,27 @@
 import Foundation
 import CVoxPDF
 
+/// What a paragraph is on the page.
+public enum ParagraphKind {
+    /// Body text, including headings.
+    case body
+    /// A running header repeated across pages.
+    case header
+    /// A running footer repeated across pages.
+    case footer
+    /// A page number in the top or bottom margin.
+    case pageNumber
+
+    init(_ kind: CParagraphKind) {
+        switch kind {
+        case CParagraphKindHeader: self = .header
+        case CParagraphKindFooter: self = .footer
+        case CParagraphKindPageNumber: self = .pageNumber
+        default: self = .body
+        }
+    }
+}
+
 /// A paragraph of text extracted from a PDF page.
 public struct Paragraph {
     /// The index of this paragraph within the page.
@@ -15,10 +36,16 @@ public struct Paragraph {
     /// The number of words in this paragraph.
     public let wordCount: Int
 
+    /// Whether this is body text or a running header, footer or page number.
+    ///
+    /// Running text is only returned when the document keeps it.
+    public let kind: ParagraphKind
+
     init(text: String, cParagraph: CParagraph) {
         self.index = cParagraph.index
         self.text = text
         self.pageNumber = Int(cParagraph.page_number)
         self.wordCount = cParagraph.word_count
+        self.kind = ParagraphKind(cParagraph.kind)
     }
 }
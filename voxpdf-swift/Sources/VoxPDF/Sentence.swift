@@ -0,0 +1,43 @@
+import Foundation
+import CoreGraphics
+import CVoxPDF
+
+/// A sentence of a document paragraph, sized for one spoken utterance.
+public struct Sentence {
+    /// The index of this sentence within its paragraph.
+    public let index: Int
+
+    /// The text of the sentence.
+    public let text: String
+
+    /// The sentence's range in the paragraph's text, in UTF-8 bytes.
+    public let utf8Range: Range<Int>
+
+    /// The sentence's range in the paragraph's text, in UTF-16 code units
+    /// as `NSString` and speech synthesizers count them.
+    public let utf16Range: NSRange
+
+    /// Indices into the paragraph's words.
+    public let wordRange: Range<Int>
+
+    /// One box per printed line, with the zero-indexed page it's on.
+    public let lines: [(page: Int, bounds: CGRect)]
+
+    /// The zero-indexed page the sentence starts on.
+    public let pageNumber: Int
+
+    /// The paragraph's language, unless the sentence is clearly in another.
+    public let language: Language?
+
+    init(text: String, cSentence: CSentence, lines: [(page: Int, bounds: CGRect)], language: Language?) {
+        self.index = cSentence.index
+        self.text = text
+        let range = cSentence.text_range
+        self.utf8Range = range.utf8_start..<range.utf8_end
+        self.utf16Range = NSRange(location: range.utf16_start, length: range.utf16_end - range.utf16_start)
+        self.wordRange = cSentence.word_start..<cSentence.word_end
+        self.lines = lines
+        self.pageNumber = Int(cSentence.page_number)
+        self.language = language
+    }
+}
@@ -12,9 +12,16 @@ public struct TocEntry {
     /// The zero-indexed page number where this entry points.
     public let pageNumber: Int
 
-    /// The paragraph index for navigation.
+    /// Index of the entry's heading in `PDFDocument.documentParagraphs()`.
     public let paragraphIndex: Int
 
+    /// Whether the entry was inferred from heading typography because the
+    /// PDF has no outline.
+    public let isInferred: Bool
+
+    /// How likely the entry is a real heading: 1.0 for outline entries.
+    public let confidence: Float
+
     /// Returns true if this is a top-level chapter (level 0).
     public var isChapter: Bool {
         level == 0
@@ -30,5 +37,7 @@ public struct TocEntry {
         self.level = Int(cTocEntry.level)
         self.pageNumber = Int(cTocEntry.page_number)
         self.paragraphIndex = cTocEntry.paragraph_index
+        self.isInferred = cTocEntry.inferred
+        self.confidence = cTocEntry.confidence
     }
 }
@@ -0,0 +1,46 @@
+import Foundation
+import CVoxPDF
+
+/// A node in the table-of-contents tree.
+///
+/// Node ids are indices into `PDFDocument.tableOfContents()`.
+public struct TocNode {
+    /// The index of this node's entry in the table of contents.
+    public let id: Int
+
+    /// The enclosing entry, or nil for a top-level chapter.
+    public let parentID: Int?
+
+    /// The number of nested entries.
+    public let childCount: Int
+
+    /// The first nested entry, if any.
+    public let firstChildID: Int?
+
+    /// The next entry at the same level under the same parent.
+    public let nextSiblingID: Int?
+
+    /// The previous entry at the same level under the same parent.
+    public let previousSiblingID: Int?
+
+    /// Whether the outline shows this entry's children expanded.
+    public let isOpen: Bool
+
+    /// Indices into `PDFDocument.documentParagraphs()` covered by this entry.
+    public let paragraphRange: Range<Int>
+
+    /// Zero-indexed pages covered by this entry.
+    public let pageRange: ClosedRange<Int>
+
+    init(cTocNode: CTocNode) {
+        self.id = cTocNode.id
+        self.parentID = cTocNode.has_parent ? cTocNode.parent : nil
+        self.childCount = cTocNode.child_count
+        self.firstChildID = cTocNode.child_count > 0 ? cTocNode.first_child : nil
+        self.nextSiblingID = cTocNode.has_next_sibling ? cTocNode.next_sibling : nil
+        self.previousSiblingID = cTocNode.has_prev_sibling ? cTocNode.prev_sibling : nil
+        self.isOpen = cTocNode.is_open
+        self.paragraphRange = cTocNode.paragraph_start..<cTocNode.paragraph_end
+        self.pageRange = Int(cTocNode.page_start)...Int(cTocNode.page_end)
+    }
+}
@@ -6,6 +6,9 @@ public enum VoxPDFError: Error {
     case ioError(String?)
     case outOfMemory
     case invalidText(context: String?)
+    case passwordRequired
+    case invalidArgument(context: String?)
+    case indexOutOfRange(context: String?)
     case unknown(code: Int32)
 
     init(code: Int32, context: String? = nil) {
@@ -15,6 +18,9 @@ public enum VoxPDFError: Error {
         case 3: self = .ioError(context)
         case 4: self = .outOfMemory
         case 5: self = .invalidText(context: context)
+        case 6: self = .passwordRequired
+        case 7: self = .invalidArgument(context: context)
+        case 8: self = .indexOutOfRange(context: context)
         default: self = .unknown(code: code)
         }
     }
@@ -47,6 +53,18 @@ extension VoxPDFError: LocalizedError {
                 return "Invalid text data: \(context)"
             }
             return "Invalid text data"
+        case .passwordRequired:
+            return "PDF is encrypted and requires a password"
+        case .invalidArgument(let context):
+            if let context = context {
+                return "Invalid argument: \(context)"
+            }
+            return "Invalid argument"
+        case .indexOutOfRange(let context):
+            if let context = context {
+                return "Index out of range: \(context)"
+            }
+            return "Index out of range"
         case .unknown(let code):
             return "Unknown error (code: \(code))"
         }
@@ -20,3 +20,89 @@ public struct Word {
         self.fontSize = cPosition.font_size
     }
 }
+
+/// Writing direction of a word.
+public enum TextDirection {
+    case leftToRight
+    case rightToLeft
+    case topToBottom
+
+    init(_ direction: CTextDirection) {
+        switch direction {
+        case CTextDirectionRightToLeft: self = .rightToLeft
+        case CTextDirectionTopToBottom: self = .topToBottom
+        default: self = .leftToRight
+        }
+    }
+}
+
+/// The font a word is set in.
+public struct FontInfo {
+    public let name: String
+    public let bold: Bool
+    public let italic: Bool
+    public let monospace: Bool
+}
+
+/// Origin, direction and typography of a word.
+public struct WordStyle {
+    /// Baseline origin of the first character
+    public let origin: CGPoint
+    public let direction: TextDirection
+    /// Only set when the extraction options enable `font_attributes`
+    public let font: FontInfo?
+    /// Fill colour as 0xRRGGBB, when `font_attributes` is enabled
+    public let color: UInt32?
+    /// Number of glyph boxes; 0 unless `glyph_boxes` is enabled
+    public let glyphCount: Int
+    /// Upper-left, upper-right, lower-left and lower-right corners of a
+    /// word that isn't upright on the page; `Word.bounds` is the box
+    /// around them
+    public let quad: [CGPoint]?
+
+    init(fontName: String?, cStyle: CWordStyle) {
+        self.origin = CGPoint(x: CGFloat(cStyle.origin_x), y: CGFloat(cStyle.origin_y))
+        self.direction = TextDirection(cStyle.direction)
+        if cStyle.has_font, let name = fontName {
+            self.font = FontInfo(
+                name: name,
+                bold: cStyle.bold,
+                italic: cStyle.italic,
+                monospace: cStyle.monospace
+            )
+            self.color = cStyle.color
+        } else {
+            self.font = nil
+            self.color = nil
+        }
+        self.glyphCount = cStyle.glyph_count
+        if cStyle.has_quad {
+            let q = cStyle.quad
+            self.quad = [
+                CGPoint(x: CGFloat(q.ul_x), y: CGFloat(q.ul_y)),
+                CGPoint(x: CGFloat(q.ur_x), y: CGFloat(q.ur_y)),
+                CGPoint(x: CGFloat(q.ll_x), y: CGFloat(q.ll_y)),
+                CGPoint(x: CGFloat(q.lr_x), y: CGFloat(q.lr_y)),
+            ]
+        } else {
+            self.quad = nil
+        }
+    }
+}
+
+/// One character of a word and its box.
+public struct Glyph {
+    public let character: Character
+    public let bounds: CGRect
+
+    init(cGlyph: CGlyph) {
+        let scalar = Unicode.Scalar(cGlyph.codepoint) ?? "\u{FFFD}"
+        self.character = Character(scalar)
+        self.bounds = CGRect(
+            x: CGFloat(cGlyph.x),
+            y: CGFloat(cGlyph.y),
+            width: CGFloat(cGlyph.width),
+            height: CGFloat(cGlyph.height)
+        )
+    }
+}